@@ -1,6 +1,8 @@
 //! Cryptographic signature keys storage API
 
-use namada_core::storage;
+use namada_core::hash::Hash;
+use namada_core::storage::{self, Epoch};
+use namada_core::token;
 use namada_storage::{Result, StorageRead, StorageWrite};
 
 use super::*;
@@ -38,6 +40,116 @@ where
     storage.write(&threshold_key, threshold)
 }
 
+/// The name of the wasm code of the vesting validity predicate, the only one
+/// that enforces the vesting schedule of an account
+pub const VP_VESTING_WASM: &str = "vp_vesting.wasm";
+
+/// Check if the validity predicate of an account is the vesting validity
+/// predicate
+pub fn has_vesting_vp<S>(storage: &S, owner: &Address) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let vp_hash: Option<Hash> =
+        storage.read(&storage::Key::validity_predicate(owner))?;
+    let vesting_vp_hash: Option<Hash> =
+        storage.read(&storage::Key::wasm_hash(VP_VESTING_WASM))?;
+    Ok(vp_hash.is_some() && vp_hash == vesting_vp_hash)
+}
+
+/// Write the vesting schedule of an account
+pub fn write_vesting_schedule<S>(
+    storage: &mut S,
+    owner: &Address,
+    schedule: VestingSchedule,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    storage.write(&vesting_key(owner), schedule)
+}
+
+/// Get the vesting schedule associated with an account
+pub fn vesting_schedule<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<Option<VestingSchedule>>
+where
+    S: StorageRead,
+{
+    storage.read(&vesting_key(owner))
+}
+
+/// Get the amount of native token of an account that is still locked by its
+/// vesting schedule at the given epoch
+pub fn locked_amount<S>(
+    storage: &S,
+    owner: &Address,
+    epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    Ok(vesting_schedule(storage, owner)?
+        .map(|schedule| schedule.locked(epoch))
+        .unwrap_or_default())
+}
+
+/// Write the fee allowance granted by the granter to the grantee, or delete
+/// it if `None`
+pub fn write_fee_allowance<S>(
+    storage: &mut S,
+    granter: &Address,
+    grantee: &Address,
+    allowance: Option<FeeAllowance>,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    let key = fee_grant_key(granter, grantee);
+    match allowance {
+        Some(allowance) => storage.write(&key, allowance),
+        None => storage.delete(&key),
+    }
+}
+
+/// Get the fee allowance granted by the granter to the grantee
+pub fn fee_allowance<S>(
+    storage: &S,
+    granter: &Address,
+    grantee: &Address,
+) -> Result<Option<FeeAllowance>>
+where
+    S: StorageRead,
+{
+    storage.read(&fee_grant_key(granter, grantee))
+}
+
+/// The maximum number of sequence numbers that the wrappers held in the
+/// mempool may skip past the next one expected from their fee payer
+pub const MAX_SEQUENCE_GAP: u64 = 32;
+
+/// Get the next sequence number expected from the given account. The
+/// sequence of an account starts at zero.
+pub fn next_sequence<S>(storage: &S, owner: &Address) -> Result<u64>
+where
+    S: StorageRead,
+{
+    Ok(storage.read(&sequence_key(owner))?.unwrap_or_default())
+}
+
+/// Write the next sequence number expected from the given account
+pub fn write_next_sequence<S>(
+    storage: &mut S,
+    owner: &Address,
+    sequence: u64,
+) -> Result<()>
+where
+    S: StorageWrite,
+{
+    storage.write(&sequence_key(owner), sequence)
+}
+
 /// Get the threshold associated with an account
 pub fn threshold<S>(storage: &S, owner: &Address) -> Result<Option<u8>>
 where
@@ -11,6 +11,9 @@ struct Keys {
     public_keys: &'static str,
     threshold: &'static str,
     protocol_public_keys: &'static str,
+    vesting: &'static str,
+    fee_grants: &'static str,
+    sequence: &'static str,
 }
 
 /// Obtain a storage key for user's public key.
@@ -68,6 +71,80 @@ pub fn threshold_key(owner: &Address) -> storage::Key {
     }
 }
 
+/// Check if the given storage key is a vesting schedule key. If it is, returns
+/// the owner.
+pub fn is_vesting_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(prefix)]
+            if prefix.as_str() == Keys::VALUES.vesting =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
+
+/// Obtain the storage key for a user vesting schedule
+pub fn vesting_key(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.vesting.to_string()),
+        ],
+    }
+}
+
+/// Obtain the storage key for the fee allowance granted by the granter to the
+/// grantee
+pub fn fee_grant_key(granter: &Address, grantee: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(granter.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.fee_grants.to_string()),
+            DbKeySeg::AddressSeg(grantee.to_owned()),
+        ],
+    }
+}
+
+/// Check if the given storage key is a fee allowance key. If it is, returns
+/// the granter and the grantee.
+pub fn is_fee_grant_key(key: &storage::Key) -> Option<(&Address, &Address)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(granter),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(grantee),
+        ] if prefix.as_str() == Keys::VALUES.fee_grants => {
+            Some((granter, grantee))
+        }
+        _ => None,
+    }
+}
+
+/// Obtain the storage key for the next sequence number expected from an
+/// account in the wrappers it pays the fees of
+pub fn sequence_key(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.sequence.to_string()),
+        ],
+    }
+}
+
+/// Check if the given storage key is a sequence number key. If it is, returns
+/// the owner.
+pub fn is_sequence_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(prefix)]
+            if prefix.as_str() == Keys::VALUES.sequence =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
+
 /// Obtain a storage key for user's protocol public key.
 pub fn protocol_pk_key(owner: &Address) -> storage::Key {
     storage::Key {
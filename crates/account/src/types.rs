@@ -2,6 +2,8 @@ use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::key::common;
+use namada_core::storage::Epoch;
+use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -28,6 +30,136 @@ pub struct InitAccount {
     pub vp_code_hash: Hash,
     /// The account signature threshold
     pub threshold: u8,
+    /// The vesting schedule locking the native tokens of the account
+    pub vesting: Option<VestingSchedule>,
+}
+
+/// A schedule locking an amount of the native token of an account. The
+/// tokens unlock linearly from the start epoch to the end epoch, but none of
+/// them can be spent before the cliff epoch. A schedule whose cliff epoch is
+/// its end epoch unlocks all the tokens at once.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestingSchedule {
+    /// The total amount of native token locked by the schedule
+    pub amount: token::Amount,
+    /// The epoch from which the tokens start unlocking
+    pub start_epoch: Epoch,
+    /// The epoch before which none of the tokens are unlocked
+    pub cliff_epoch: Epoch,
+    /// The epoch from which all the tokens are unlocked
+    pub end_epoch: Epoch,
+}
+
+impl VestingSchedule {
+    /// Check that the schedule locks some tokens and that its epochs are
+    /// ordered
+    pub fn is_valid(&self) -> bool {
+        !self.amount.is_zero()
+            && self.start_epoch <= self.cliff_epoch
+            && self.cliff_epoch <= self.end_epoch
+            && self.start_epoch < self.end_epoch
+    }
+
+    /// Return the amount of token unlocked at the given epoch
+    pub fn unlocked(&self, epoch: Epoch) -> token::Amount {
+        if epoch < self.cliff_epoch {
+            return token::Amount::zero();
+        }
+        if epoch >= self.end_epoch {
+            return self.amount;
+        }
+        let elapsed = epoch.0.saturating_sub(self.start_epoch.0);
+        let duration = self.end_epoch.0 - self.start_epoch.0;
+        self.amount
+            .checked_mul(token::Amount::from_u64(elapsed))
+            .and_then(|amount| {
+                amount.checked_div(token::Amount::from_u64(duration))
+            })
+            .unwrap_or_else(|| (self.amount * (elapsed, duration)).0)
+    }
+
+    /// Return the amount of token still locked at the given epoch
+    pub fn locked(&self, epoch: Epoch) -> token::Amount {
+        self.amount
+            .checked_sub(self.unlocked(epoch))
+            .unwrap_or_default()
+    }
+}
+
+/// An allowance granted by an account to pay the fees of the wrapper
+/// transactions of another account
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct FeeAllowance {
+    /// The token in which the fees are paid
+    pub token: Address,
+    /// The amount of fees that can still be paid with the allowance
+    pub spend_limit: token::Amount,
+    /// The epoch from which the allowance can no longer be used
+    pub expiration: Option<Epoch>,
+    /// The code hashes of the only inner transactions whose fees can be paid,
+    /// if restricted
+    pub allowed_txs: Option<Vec<Hash>>,
+}
+
+impl FeeAllowance {
+    /// Check if the allowance can no longer be used at the given epoch
+    pub fn is_expired(&self, epoch: Epoch) -> bool {
+        matches!(self.expiration, Some(expiration) if epoch >= expiration)
+    }
+
+    /// Check if the allowance can pay the fees of an inner transaction with
+    /// the given code hash
+    pub fn is_tx_allowed(&self, code_hash: &Hash) -> bool {
+        self.allowed_txs
+            .as_ref()
+            .map_or(true, |allowed_txs| allowed_txs.contains(code_hash))
+    }
+}
+
+/// A tx data type to grant, replace or revoke a fee allowance
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct GrantFeeAllowance {
+    /// The account paying the fees
+    pub granter: Address,
+    /// The account whose fees are paid
+    pub grantee: Address,
+    /// The new allowance, or `None` to revoke the current one
+    pub allowance: Option<FeeAllowance>,
 }
 
 /// A tx data type to update an account's validity predicate
@@ -61,11 +193,61 @@ pub mod tests {
     use namada_core::address::testing::arb_non_internal_address;
     use namada_core::hash::testing::arb_hash;
     use namada_core::key::testing::arb_common_pk;
+    use namada_core::token::testing::arb_amount;
     use proptest::prelude::Just;
     use proptest::{collection, option, prop_compose};
 
     use super::*;
 
+    prop_compose! {
+        /// Generate an arbitrary vesting schedule
+        pub fn arb_vesting_schedule()(
+            amount in arb_amount(),
+            start_epoch in 0u64..1000,
+            cliff_offset in 0u64..1000,
+            end_offset in 1u64..1000,
+        ) -> VestingSchedule {
+            VestingSchedule {
+                amount,
+                start_epoch: Epoch(start_epoch),
+                cliff_epoch: Epoch(start_epoch + cliff_offset),
+                end_epoch: Epoch(start_epoch + cliff_offset + end_offset),
+            }
+        }
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary fee allowance
+        pub fn arb_fee_allowance()(
+            token in arb_non_internal_address(),
+            spend_limit in arb_amount(),
+            expiration in option::of(0u64..1000),
+            allowed_txs in option::of(collection::vec(arb_hash(), 0..10)),
+        ) -> FeeAllowance {
+            FeeAllowance {
+                token,
+                spend_limit,
+                expiration: expiration.map(Epoch),
+                allowed_txs,
+            }
+        }
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary fee allowance grant
+        pub fn arb_grant_fee_allowance()(
+            granter in arb_non_internal_address(),
+            grantee in arb_non_internal_address(),
+            allowance in option::of(arb_fee_allowance()),
+        ) -> GrantFeeAllowance {
+            GrantFeeAllowance {
+                granter,
+                grantee,
+                allowance,
+            }
+        }
+    }
+
     prop_compose! {
         /// Generate an account initialization
         pub fn arb_init_account()(
@@ -74,11 +256,13 @@ pub mod tests {
             threshold in 0..=public_keys.len() as u8,
             public_keys in Just(public_keys),
             vp_code_hash in arb_hash(),
+            vesting in option::of(arb_vesting_schedule()),
         ) -> InitAccount {
             InitAccount {
                 public_keys,
                 vp_code_hash,
                 threshold,
+                vesting,
             }
         }
     }
@@ -217,6 +217,7 @@ pub mod cmds {
                 .subcommand(TxTransfer::def().display_order(1))
                 .subcommand(TxIbcTransfer::def().display_order(1))
                 .subcommand(TxUpdateAccount::def().display_order(1))
+                .subcommand(TxGrantFeeAllowance::def().display_order(1))
                 .subcommand(TxInitAccount::def().display_order(1))
                 .subcommand(TxRevealPk::def().display_order(1))
                 // Governance transactions
@@ -231,8 +232,13 @@ pub mod cmds {
                 .subcommand(Bond::def().display_order(2))
                 .subcommand(Unbond::def().display_order(2))
                 .subcommand(Withdraw::def().display_order(2))
+                .subcommand(CancelUnbond::def().display_order(2))
                 .subcommand(Redelegate::def().display_order(2))
+                .subcommand(TokenizeBond::def().display_order(2))
+                .subcommand(RedeemShares::def().display_order(2))
                 .subcommand(ClaimRewards::def().display_order(2))
+                .subcommand(ChangeAutoCompound::def().display_order(2))
+                .subcommand(ChangeRewardRecipient::def().display_order(2))
                 .subcommand(TxCommissionRateChange::def().display_order(2))
                 .subcommand(TxChangeConsensusKey::def().display_order(2))
                 .subcommand(TxMetadataChange::def().display_order(2))
@@ -241,6 +247,8 @@ pub mod cmds {
                 // PGF transactions
                 .subcommand(TxUpdateStewardCommission::def().display_order(4))
                 .subcommand(TxResignSteward::def().display_order(4))
+                .subcommand(TxClaimPgfStream::def().display_order(4))
+                .subcommand(TxCancelPgfStream::def().display_order(4))
                 // Queries
                 .subcommand(QueryEpoch::def().display_order(5))
                 .subcommand(QueryNextEpochInfo::def().display_order(5))
@@ -254,6 +262,7 @@ pub mod cmds {
                 .subcommand(QueryBonds::def().display_order(5))
                 .subcommand(QueryBondedStake::def().display_order(5))
                 .subcommand(QuerySlashes::def().display_order(5))
+                .subcommand(QueryLiveness::def().display_order(5))
                 .subcommand(QueryDelegations::def().display_order(5))
                 .subcommand(QueryFindValidator::def().display_order(5))
                 .subcommand(QueryResult::def().display_order(5))
@@ -282,6 +291,8 @@ pub mod cmds {
             let tx_ibc_transfer = Self::parse_with_ctx(matches, TxIbcTransfer);
             let tx_update_account =
                 Self::parse_with_ctx(matches, TxUpdateAccount);
+            let tx_grant_fee_allowance =
+                Self::parse_with_ctx(matches, TxGrantFeeAllowance);
             let tx_init_account = Self::parse_with_ctx(matches, TxInitAccount);
             let tx_become_validator =
                 Self::parse_with_ctx(matches, TxBecomeValidator);
@@ -302,6 +313,10 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, TxUpdateStewardCommission);
             let tx_resign_steward =
                 Self::parse_with_ctx(matches, TxResignSteward);
+            let tx_claim_pgf_stream =
+                Self::parse_with_ctx(matches, TxClaimPgfStream);
+            let tx_cancel_pgf_stream =
+                Self::parse_with_ctx(matches, TxCancelPgfStream);
             let tx_commission_rate_change =
                 Self::parse_with_ctx(matches, TxCommissionRateChange);
             let tx_change_consensus_key =
@@ -311,8 +326,15 @@ pub mod cmds {
             let bond = Self::parse_with_ctx(matches, Bond);
             let unbond = Self::parse_with_ctx(matches, Unbond);
             let withdraw = Self::parse_with_ctx(matches, Withdraw);
+            let cancel_unbond = Self::parse_with_ctx(matches, CancelUnbond);
             let redelegate = Self::parse_with_ctx(matches, Redelegate);
+            let tokenize_bond = Self::parse_with_ctx(matches, TokenizeBond);
+            let redeem_shares = Self::parse_with_ctx(matches, RedeemShares);
             let claim_rewards = Self::parse_with_ctx(matches, ClaimRewards);
+            let change_auto_compound =
+                Self::parse_with_ctx(matches, ChangeAutoCompound);
+            let change_reward_recipient =
+                Self::parse_with_ctx(matches, ChangeRewardRecipient);
             let query_epoch = Self::parse_with_ctx(matches, QueryEpoch);
             let query_next_epoch_info =
                 Self::parse_with_ctx(matches, QueryNextEpochInfo);
@@ -329,6 +351,7 @@ pub mod cmds {
             let query_bonded_stake =
                 Self::parse_with_ctx(matches, QueryBondedStake);
             let query_slashes = Self::parse_with_ctx(matches, QuerySlashes);
+            let query_liveness = Self::parse_with_ctx(matches, QueryLiveness);
             let query_rewards = Self::parse_with_ctx(matches, QueryRewards);
             let query_delegations =
                 Self::parse_with_ctx(matches, QueryDelegations);
@@ -360,6 +383,7 @@ pub mod cmds {
                 .or(tx_transfer)
                 .or(tx_ibc_transfer)
                 .or(tx_update_account)
+                .or(tx_grant_fee_allowance)
                 .or(tx_init_account)
                 .or(tx_reveal_pk)
                 .or(tx_init_proposal)
@@ -375,11 +399,18 @@ pub mod cmds {
                 .or(bond)
                 .or(unbond)
                 .or(withdraw)
+                .or(cancel_unbond)
                 .or(redelegate)
+                .or(tokenize_bond)
+                .or(redeem_shares)
                 .or(claim_rewards)
+                .or(change_auto_compound)
+                .or(change_reward_recipient)
                 .or(add_to_eth_bridge_pool)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
+                .or(tx_claim_pgf_stream)
+                .or(tx_cancel_pgf_stream)
                 .or(query_epoch)
                 .or(query_next_epoch_info)
                 .or(query_status)
@@ -391,6 +422,7 @@ pub mod cmds {
                 .or(query_bonds)
                 .or(query_bonded_stake)
                 .or(query_slashes)
+                .or(query_liveness)
                 .or(query_rewards)
                 .or(query_delegations)
                 .or(query_find_validator)
@@ -450,6 +482,7 @@ pub mod cmds {
         TxIbcTransfer(TxIbcTransfer),
         QueryResult(QueryResult),
         TxUpdateAccount(TxUpdateAccount),
+        TxGrantFeeAllowance(TxGrantFeeAllowance),
         TxInitAccount(TxInitAccount),
         TxBecomeValidator(TxBecomeValidator),
         TxInitValidator(TxInitValidator),
@@ -465,11 +498,18 @@ pub mod cmds {
         Bond(Bond),
         Unbond(Unbond),
         Withdraw(Withdraw),
+        CancelUnbond(CancelUnbond),
         ClaimRewards(ClaimRewards),
+        ChangeAutoCompound(ChangeAutoCompound),
+        ChangeRewardRecipient(ChangeRewardRecipient),
         Redelegate(Redelegate),
+        TokenizeBond(TokenizeBond),
+        RedeemShares(RedeemShares),
         AddToEthBridgePool(AddToEthBridgePool),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
         TxResignSteward(TxResignSteward),
+        TxClaimPgfStream(TxClaimPgfStream),
+        TxCancelPgfStream(TxCancelPgfStream),
         QueryEpoch(QueryEpoch),
         QueryNextEpochInfo(QueryNextEpochInfo),
         QueryStatus(QueryStatus),
@@ -484,6 +524,7 @@ pub mod cmds {
         QueryCommissionRate(QueryCommissionRate),
         QueryMetaData(QueryMetaData),
         QuerySlashes(QuerySlashes),
+        QueryLiveness(QueryLiveness),
         QueryDelegations(QueryDelegations),
         QueryFindValidator(QueryFindValidator),
         QueryRawBytes(QueryRawBytes),
@@ -522,6 +563,8 @@ pub mod cmds {
         KeyAddrAdd(WalletAddKeyAddress),
         /// Key / address remove
         KeyAddrRemove(WalletRemoveKeyAddress),
+        /// Remote signer
+        ServeSigner(WalletServeSigner),
     }
 
     impl Cmd for NamadaWallet {
@@ -536,6 +579,7 @@ pub mod cmds {
                 .subcommand(WalletImportKey::def())
                 .subcommand(WalletAddKeyAddress::def())
                 .subcommand(WalletRemoveKeyAddress::def())
+                .subcommand(WalletServeSigner::def())
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
@@ -550,6 +594,7 @@ pub mod cmds {
             let key_addr_add = SubCmd::parse(matches).map(Self::KeyAddrAdd);
             let key_addr_remove =
                 SubCmd::parse(matches).map(Self::KeyAddrRemove);
+            let serve_signer = SubCmd::parse(matches).map(Self::ServeSigner);
             gen.or(derive)
                 .or(pay_addr_gen)
                 .or(key_addr_list)
@@ -559,6 +604,7 @@ pub mod cmds {
                 .or(import)
                 .or(key_addr_add)
                 .or(key_addr_remove)
+                .or(serve_signer)
         }
     }
 
@@ -699,6 +745,38 @@ pub mod cmds {
         }
     }
 
+    /// Serve signatures with keys of the wallet as a remote signer
+    #[derive(Clone, Debug)]
+    pub struct WalletServeSigner(pub args::ServeSigner);
+
+    impl SubCmd for WalletServeSigner {
+        const CMD: &'static str = "serve-signer";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ServeSigner::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Serves signatures with the given transparent keys to \
+                     remote signer clients.",
+                )
+                .long_about(
+                    "Serves signatures with the given transparent keys of the \
+                     wallet to the clients connecting to the given address, \
+                     e.g. transactions submitted with `--remote-signer` or \
+                     validator nodes signing with their protocol key. The \
+                     signer and its clients authenticate each other with the \
+                     secret set in the NAMADA_REMOTE_SIGNER_SECRET env var, \
+                     and every signed tx or vote extension is shown.",
+                )
+                .add_args::<args::ServeSigner>()
+        }
+    }
+
     /// Export key to a file
     #[derive(Clone, Debug)]
     pub struct WalletExportKey(pub args::KeyExport);
@@ -1306,6 +1384,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxGrantFeeAllowance(pub args::GrantFeeAllowance<args::CliTypes>);
+
+    impl SubCmd for TxGrantFeeAllowance {
+        const CMD: &'static str = "grant-fee-allowance";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxGrantFeeAllowance(args::GrantFeeAllowance::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Send a signed transaction to grant, update or revoke an \
+                     allowance to pay the fees of another account.",
+                )
+                .add_args::<args::GrantFeeAllowance<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxInitAccount(pub args::TxInitAccount<args::CliTypes>);
 
@@ -1519,6 +1619,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct CancelUnbond(pub args::CancelUnbond<args::CliTypes>);
+
+    impl SubCmd for CancelUnbond {
+        const CMD: &'static str = "cancel-unbond";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| CancelUnbond(args::CancelUnbond::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Cancel an unbond that is not yet withdrawable and bond \
+                     its tokens back to the validator.",
+                )
+                .add_args::<args::CancelUnbond<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct ClaimRewards(pub args::ClaimRewards<args::CliTypes>);
 
@@ -1541,6 +1663,53 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct ChangeAutoCompound(pub args::ChangeAutoCompound<args::CliTypes>);
+
+    impl SubCmd for ChangeAutoCompound {
+        const CMD: &'static str = "auto-compound";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                ChangeAutoCompound(args::ChangeAutoCompound::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Enable or disable the automatic bonding of the rewards \
+                     of a bond back to its validator at every new epoch.",
+                )
+                .add_args::<args::ChangeAutoCompound<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ChangeRewardRecipient(
+        pub args::ChangeRewardRecipient<args::CliTypes>,
+    );
+
+    impl SubCmd for ChangeRewardRecipient {
+        const CMD: &'static str = "reward-recipient";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                ChangeRewardRecipient(args::ChangeRewardRecipient::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Change the address credited with the claimed rewards of                      all the bonds of a source.",
+                )
+                .add_args::<args::ChangeRewardRecipient<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
 
@@ -1562,6 +1731,50 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TokenizeBond(pub args::TokenizeBond<args::CliTypes>);
+
+    impl SubCmd for TokenizeBond {
+        const CMD: &'static str = "tokenize-bond";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TokenizeBond(args::TokenizeBond::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Convert bonded tokens into transferable share tokens of \
+                     the validator.",
+                )
+                .add_args::<args::TokenizeBond<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct RedeemShares(pub args::RedeemShares<args::CliTypes>);
+
+    impl SubCmd for RedeemShares {
+        const CMD: &'static str = "redeem-shares";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| RedeemShares(args::RedeemShares::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Redeem share tokens of a validator back into bonded \
+                     tokens.",
+                )
+                .add_args::<args::RedeemShares<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryEpoch(pub args::Query<args::CliTypes>);
 
@@ -1882,6 +2095,31 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryLiveness(pub args::QueryLiveness<args::CliTypes>);
+
+    impl SubCmd for QueryLiveness {
+        const CMD: &'static str = "liveness";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryLiveness(args::QueryLiveness::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Query the missed blocks of PoS validators within the \
+                     liveness window and their jail events.",
+                )
+                .add_args::<args::QueryLiveness<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryRewards(pub args::QueryRewards<args::CliTypes>);
 
@@ -2040,6 +2278,53 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxClaimPgfStream(pub args::ClaimPgfStream<args::CliTypes>);
+
+    impl SubCmd for TxClaimPgfStream {
+        const CMD: &'static str = "claim-pgf-stream";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxClaimPgfStream(args::ClaimPgfStream::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Craft a transaction to claim the vested funds of a pgf \
+                     stream.",
+                )
+                .add_args::<args::ClaimPgfStream<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxCancelPgfStream(pub args::CancelPgfStream<args::CliTypes>);
+
+    impl SubCmd for TxCancelPgfStream {
+        const CMD: &'static str = "cancel-pgf-stream";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCancelPgfStream(args::CancelPgfStream::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about("Craft a transaction to cancel a pgf stream.")
+                .add_args::<args::CancelPgfStream<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxCommissionRateChange(
         pub args::CommissionRateChange<args::CliTypes>,
@@ -3009,6 +3294,7 @@ pub mod args {
     use std::str::FromStr;
 
     use data_encoding::HEXUPPER;
+    use namada::account::VestingSchedule;
     use namada::core::address::{Address, EstablishedAddress};
     use namada::core::chain::{ChainId, ChainIdPrefix};
     use namada::core::dec::Dec;
@@ -3025,14 +3311,18 @@ pub mod args {
     pub use namada_sdk::args::*;
     pub use namada_sdk::tx::{
         TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-        TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-        TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-        TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-        TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_CANCEL_PGF_STREAM, TX_CANCEL_UNBOND_WASM,
+        TX_CHANGE_AUTO_COMPOUND_WASM, TX_CHANGE_COMMISSION_WASM,
+        TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+        TX_CHANGE_REWARD_RECIPIENT_WASM, TX_CLAIM_PGF_STREAM,
+        TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+        TX_GRANT_FEE_ALLOWANCE_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
+        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDEEM_SHARES_WASM,
+        TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK,
+        TX_TOKENIZE_BOND_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
         TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
         TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-        VP_USER_WASM,
+        VP_USER_WASM, VP_VESTING_WASM,
     };
 
     use super::context::*;
@@ -3050,7 +3340,12 @@ pub mod args {
     pub const ALIAS_FORCE: ArgFlag = flag("alias-force");
     pub const ALIAS_MANY: ArgMulti<String, GlobPlus> = arg_multi("aliases");
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
+    pub const ALLOWANCE_TOKEN: ArgDefaultFromCtx<WalletAddrOrNativeToken> =
+        arg_default_from_ctx("token", DefaultFn(|| "".parse().unwrap()));
+    pub const ALLOWED_TXS: ArgMulti<PathBuf, GlobStar> =
+        arg_multi("allowed-txs");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
+    pub const AMOUNT_OPT: ArgOpt<token::DenominatedAmount> = AMOUNT.opt();
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
     pub const AVATAR_OPT: ArgOpt<String> = arg_opt("avatar");
     pub const BALANCE_OWNER: ArgOpt<WalletBalanceOwner> = arg_opt("owner");
@@ -3117,6 +3412,7 @@ pub mod args {
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
         arg("destination-validator");
+    pub const DISABLE: ArgFlag = flag("disable");
     pub const DISCORD_OPT: ArgOpt<String> = arg_opt("discord-handle");
     pub const DO_IT: ArgFlag = flag("do-it");
     pub const DONT_ARCHIVE: ArgFlag = flag("dont-archive");
@@ -3137,6 +3433,7 @@ pub mod args {
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXPIRATION_EPOCH_OPT: ArgOpt<Epoch> = arg_opt("expiration-epoch");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_UNSHIELD_SPENDING_KEY: ArgOpt<WalletTransferSource> =
@@ -3144,10 +3441,14 @@ pub mod args {
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
         arg_opt("gas-price");
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
+    pub const FEE_GRANTER_OPT: ArgOpt<WalletAddress> = arg_opt("gas-granter");
     pub const FILE_PATH: Arg<String> = arg("file");
     pub const FORCE: ArgFlag = flag("force");
-    pub const GAS_LIMIT: ArgDefault<GasLimit> =
-        arg_default("gas-limit", DefaultFn(|| GasLimit::from(25_000)));
+    pub const GAS_LIMIT_OPT: ArgOpt<GasLimit> = arg_opt("gas-limit");
+    pub const GAS_LIMIT_MULTIPLIER: ArgDefault<Dec> = arg_default(
+        "gas-limit-multiplier",
+        DefaultFn(default_gas_limit_multiplier),
+    );
     pub const FEE_TOKEN: ArgDefaultFromCtx<WalletAddrOrNativeToken> =
         arg_default_from_ctx("gas-token", DefaultFn(|| "".parse().unwrap()));
     pub const FEE_PAYER: Arg<WalletAddress> = arg("fee-payer");
@@ -3167,6 +3468,8 @@ pub mod args {
         arg("genesis-validator").opt();
     pub const GENESIS_VALIDATOR_ADDRESS: Arg<EstablishedAddress> =
         arg("validator");
+    pub const GRANTEE: Arg<WalletAddress> = arg("grantee");
+    pub const GRANTER: Arg<WalletAddress> = arg("granter");
     pub const HALT_ACTION: ArgFlag = flag("halt");
     pub const HASH: Arg<String> = arg("hash");
     pub const HASH_LIST: Arg<String> = arg("hash-list");
@@ -3243,12 +3546,17 @@ pub mod args {
         RAW_PUBLIC_KEY_HASH.opt();
     pub const RECEIVER: Arg<String> = arg("receiver");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const REMOTE_SIGNER: Arg<SocketAddr> = arg("remote-signer");
+    pub const REMOTE_SIGNER_OPT: ArgOpt<SocketAddr> = REMOTE_SIGNER.opt();
+    pub const REWARD_RECIPIENT_OPT: ArgOpt<WalletAddress> =
+        arg_opt("recipient");
     pub const SAFE_MODE: ArgFlag = flag("safe-mode");
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
     pub const SELF_BOND_AMOUNT: Arg<token::DenominatedAmount> =
         arg("self-bond-amount");
     pub const SENDER: Arg<String> = arg("sender");
+    pub const SEQUENCE_OPT: ArgOpt<u64> = arg_opt("sequence");
     pub const SHIELDED: ArgFlag = flag("shielded");
     pub const SIGNER: ArgOpt<WalletAddress> = arg_opt("signer");
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
@@ -3258,7 +3566,10 @@ pub mod args {
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SPENDING_KEYS: ArgMulti<WalletSpendingKey, GlobStar> =
         arg_multi("spending-keys");
+    pub const SPEND_LIMIT_OPT: ArgOpt<token::DenominatedAmount> =
+        arg_opt("spend-limit");
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
+    pub const STREAM_TARGET: Arg<WalletAddress> = arg("target");
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
@@ -3292,6 +3603,11 @@ pub mod args {
     pub const VALIDATOR_ETH_HOT_KEY: ArgOpt<WalletPublicKey> =
         arg_opt("eth-hot-key");
     pub const VALUE: Arg<String> = arg("value");
+    pub const VESTING_AMOUNT: ArgOpt<token::DenominatedAmount> =
+        arg_opt("vesting-amount");
+    pub const VESTING_CLIFF_EPOCH: ArgOpt<Epoch> = arg_opt("vesting-cliff");
+    pub const VESTING_END_EPOCH: ArgOpt<Epoch> = arg_opt("vesting-end");
+    pub const VESTING_START_EPOCH: ArgOpt<Epoch> = arg_opt("vesting-start");
     pub const VOTER_OPT: ArgOpt<WalletAddress> = arg_opt("voter");
     pub const VIEWING_KEY: Arg<WalletViewingKey> = arg("key");
     pub const VIEWING_KEYS: ArgMulti<WalletViewingKey, GlobStar> =
@@ -3301,6 +3617,7 @@ pub mod args {
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
     pub const WEBSITE_OPT: ArgOpt<String> = arg_opt("website");
+    pub const WITHDRAW_EPOCH: Arg<Epoch> = arg("withdraw-epoch");
     pub const TX_PATH: Arg<PathBuf> = arg("tx-path");
     pub const TX_PATH_OPT: ArgOpt<PathBuf> = TX_PATH.opt();
 
@@ -4300,6 +4617,7 @@ pub mod args {
                     .map(|pk| chain_ctx.get(pk))
                     .collect(),
                 threshold: self.threshold,
+                vesting: self.vesting,
             }
         }
     }
@@ -4307,9 +4625,38 @@ pub mod args {
     impl Args for TxInitAccount<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let vp_code_path = CODE_PATH_OPT
-                .parse(matches)
-                .unwrap_or_else(|| PathBuf::from(VP_USER_WASM));
+            let vesting = VESTING_AMOUNT.parse(matches).map(|amount| {
+                let amount = amount
+                    .canonical()
+                    .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                    .unwrap_or_else(|e| {
+                        println!("Could not parse vesting amount: {:?}", e);
+                        safe_exit(1);
+                    })
+                    .amount();
+                let start_epoch = VESTING_START_EPOCH
+                    .parse(matches)
+                    .expect("The vesting start epoch is required");
+                let end_epoch = VESTING_END_EPOCH
+                    .parse(matches)
+                    .expect("The vesting end epoch is required");
+                let cliff_epoch =
+                    VESTING_CLIFF_EPOCH.parse(matches).unwrap_or(start_epoch);
+                VestingSchedule {
+                    amount,
+                    start_epoch,
+                    cliff_epoch,
+                    end_epoch,
+                }
+            });
+            let vp_code_path =
+                CODE_PATH_OPT.parse(matches).unwrap_or_else(|| {
+                    if vesting.is_some() {
+                        PathBuf::from(VP_VESTING_WASM)
+                    } else {
+                        PathBuf::from(VP_USER_WASM)
+                    }
+                });
             let tx_code_path = PathBuf::from(TX_INIT_ACCOUNT_WASM);
             let public_keys = PUBLIC_KEYS.parse(matches);
             let threshold = THRESHOLD.parse(matches);
@@ -4318,6 +4665,7 @@ pub mod args {
                 vp_code_path,
                 public_keys,
                 threshold,
+                vesting,
                 tx_code_path,
             }
         }
@@ -4338,6 +4686,42 @@ pub mod args {
                      authorization. Must be less then the maximum number of \
                      public keys provided.",
                 ))
+                .arg(
+                    VESTING_AMOUNT
+                        .def()
+                        .help(
+                            "The amount of native token locked by the vesting \
+                             schedule of the new account. Uses the vesting VP \
+                             if no VP is specified.",
+                        )
+                        .requires(VESTING_START_EPOCH.name)
+                        .requires(VESTING_END_EPOCH.name),
+                )
+                .arg(
+                    VESTING_START_EPOCH
+                        .def()
+                        .help(
+                            "The epoch from which the tokens start unlocking.",
+                        )
+                        .requires(VESTING_AMOUNT.name),
+                )
+                .arg(
+                    VESTING_CLIFF_EPOCH
+                        .def()
+                        .help(
+                            "The epoch before which none of the tokens are \
+                             unlocked. Defaults to the start epoch.",
+                        )
+                        .requires(VESTING_AMOUNT.name),
+                )
+                .arg(
+                    VESTING_END_EPOCH
+                        .def()
+                        .help(
+                            "The epoch from which all the tokens are unlocked.",
+                        )
+                        .requires(VESTING_AMOUNT.name),
+                )
         }
     }
 
@@ -4629,6 +5013,73 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<GrantFeeAllowance<SdkTypes>> for GrantFeeAllowance<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> GrantFeeAllowance<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+            GrantFeeAllowance::<SdkTypes> {
+                tx,
+                granter: chain_ctx.get(&self.granter),
+                grantee: chain_ctx.get(&self.grantee),
+                token: chain_ctx.get(&self.token).into(),
+                spend_limit: self.spend_limit,
+                expiration: self.expiration,
+                allowed_txs: self.allowed_txs,
+                tx_code_path: self.tx_code_path,
+            }
+        }
+    }
+
+    impl Args for GrantFeeAllowance<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let granter = GRANTER.parse(matches);
+            let grantee = GRANTEE.parse(matches);
+            let token = ALLOWANCE_TOKEN.parse(matches);
+            let spend_limit =
+                SPEND_LIMIT_OPT.parse(matches).map(InputAmount::Unvalidated);
+            let expiration = EXPIRATION_EPOCH_OPT.parse(matches);
+            let allowed_txs = ALLOWED_TXS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_GRANT_FEE_ALLOWANCE_WASM);
+            Self {
+                tx,
+                granter,
+                grantee,
+                token,
+                spend_limit,
+                expiration,
+                allowed_txs,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(GRANTER.def().help(
+                    "The account paying the fees. It's key is used to produce \
+                     the signature.",
+                ))
+                .arg(GRANTEE.def().help("The account whose fees are paid."))
+                .arg(
+                    ALLOWANCE_TOKEN
+                        .def()
+                        .help("The token in which the fees can be paid."),
+                )
+                .arg(SPEND_LIMIT_OPT.def().help(
+                    "The maximum amount of fees that can be paid. If none is \
+                     provided, the current allowance is revoked.",
+                ))
+                .arg(EXPIRATION_EPOCH_OPT.def().help(
+                    "The epoch from which the allowance can no longer be used.",
+                ))
+                .arg(ALLOWED_TXS.def().help(
+                    "The names of the WASM codes of the transactions whose \
+                     fees can be paid. If none is provided, the fees of any \
+                     transaction can be paid.",
+                ))
+        }
+    }
+
     impl Args for TxUpdateAccount<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
@@ -4842,6 +5293,74 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<ClaimPgfStream<SdkTypes>> for ClaimPgfStream<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> ClaimPgfStream<SdkTypes> {
+            ClaimPgfStream::<SdkTypes> {
+                tx: self.tx.to_sdk(ctx),
+                target: ctx.borrow_chain_or_exit().get(&self.target),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for ClaimPgfStream<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let target = STREAM_TARGET.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CLAIM_PGF_STREAM);
+            Self {
+                tx,
+                target,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                STREAM_TARGET
+                    .def()
+                    .help("The target address of the pgf stream."),
+            )
+        }
+    }
+
+    impl CliToSdk<CancelPgfStream<SdkTypes>> for CancelPgfStream<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> CancelPgfStream<SdkTypes> {
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            CancelPgfStream::<SdkTypes> {
+                steward: chain_ctx.get(&self.steward),
+                target: chain_ctx.get(&self.target),
+                tx: self.tx.to_sdk(ctx),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for CancelPgfStream<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let steward = STEWARD.parse(matches);
+            let target = STREAM_TARGET.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CANCEL_PGF_STREAM);
+            Self {
+                tx,
+                steward,
+                target,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(STEWARD.def().help("Steward address."))
+                .arg(
+                    STREAM_TARGET
+                        .def()
+                        .help("The target address of the pgf stream."),
+                )
+        }
+    }
+
     impl CliToSdk<Redelegate<SdkTypes>> for Redelegate<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> Redelegate<SdkTypes> {
             let tx = self.tx.to_sdk(ctx);
@@ -4901,6 +5420,105 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<TokenizeBond<SdkTypes>> for TokenizeBond<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> TokenizeBond<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            TokenizeBond::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: chain_ctx.get(&self.source),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for TokenizeBond<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE.parse(matches);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse bond amount: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount();
+            let tx_code_path = PathBuf::from(TX_TOKENIZE_BOND_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help("Validator address."))
+                .arg(SOURCE.def().help(
+                    "Delegator (owner) address of the bonds that are being \
+                     tokenized.",
+                ))
+                .arg(AMOUNT.def().help("Amount of bonded tokens to tokenize."))
+        }
+    }
+
+    impl CliToSdk<RedeemShares<SdkTypes>> for RedeemShares<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> RedeemShares<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            RedeemShares::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                owner: chain_ctx.get(&self.owner),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for RedeemShares<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let owner = OWNER.parse(matches);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse share amount: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount();
+            let tx_code_path = PathBuf::from(TX_REDEEM_SHARES_WASM);
+            Self {
+                tx,
+                validator,
+                owner,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(
+                    VALIDATOR
+                        .def()
+                        .help("Validator address of the share tokens."),
+                )
+                .arg(OWNER.def().help("Owner address of the share tokens."))
+                .arg(AMOUNT.def().help("Amount of share tokens to redeem."))
+        }
+    }
+
     impl CliToSdk<InitProposal<SdkTypes>> for InitProposal<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> InitProposal<SdkTypes> {
             InitProposal::<SdkTypes> {
@@ -5037,7 +5655,10 @@ pub mod args {
                         ]),
                 )
                 .arg(PROPOSAL_VOTE.def().help(
-                    "The vote for the proposal. Either yay, nay, or abstain.",
+                    "The vote for the proposal. Either yay, nay, nowithveto \
+                     or abstain. Multi-option proposals are voted with either \
+                     `option:<index>`, or a weighted split such as \
+                     `options:0=0.6,1=0.4`, or abstain.",
                 ))
                 .arg(
                     PROPOSAL_OFFLINE
@@ -5292,6 +5913,66 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<CancelUnbond<SdkTypes>> for CancelUnbond<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> CancelUnbond<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            CancelUnbond::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                withdraw_epoch: self.withdraw_epoch,
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for CancelUnbond<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let withdraw_epoch = WITHDRAW_EPOCH.parse(matches);
+            let amount = AMOUNT_OPT.parse(matches).map(|amount| {
+                amount
+                    .canonical()
+                    .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                    .unwrap_or_else(|e| {
+                        println!("Could not parse cancelled amount: {:?}", e);
+                        safe_exit(1);
+                    })
+                    .amount()
+            });
+            let tx_code_path = PathBuf::from(TX_CANCEL_UNBOND_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                withdraw_epoch,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help("Validator address."))
+                .arg(SOURCE_OPT.def().help(
+                    "Source address of the unbond. For unbonds from \
+                     self-bonds, the validator is also the source.",
+                ))
+                .arg(WITHDRAW_EPOCH.def().help(
+                    "The epoch at which the unbond to cancel becomes \
+                     withdrawable.",
+                ))
+                .arg(AMOUNT_OPT.def().help(
+                    "Amount of tokens to cancel. The whole unbond is \
+                     cancelled when not given.",
+                ))
+        }
+    }
+
     impl CliToSdk<ClaimRewards<SdkTypes>> for ClaimRewards<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> ClaimRewards<SdkTypes> {
             let tx = self.tx.to_sdk(ctx);
@@ -5329,6 +6010,92 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<ChangeAutoCompound<SdkTypes>> for ChangeAutoCompound<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> ChangeAutoCompound<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            ChangeAutoCompound::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                enabled: self.enabled,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for ChangeAutoCompound<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let enabled = !DISABLE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CHANGE_AUTO_COMPOUND_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                enabled,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help("Validator address."))
+                .arg(SOURCE_OPT.def().help(
+                    "Source address of the bond. For self-bonds, the \
+                     validator is also the source.",
+                ))
+                .arg(DISABLE.def().help(
+                    "Disable the auto-compounding of the rewards of the bond \
+                     instead of enabling it.",
+                ))
+        }
+    }
+
+    impl CliToSdk<ChangeRewardRecipient<SdkTypes>>
+        for ChangeRewardRecipient<CliTypes>
+    {
+        fn to_sdk(self, ctx: &mut Context) -> ChangeRewardRecipient<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            ChangeRewardRecipient::<SdkTypes> {
+                tx,
+                source: chain_ctx.get(&self.source),
+                recipient: self.recipient.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            }
+        }
+    }
+
+    impl Args for ChangeRewardRecipient<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let source = SOURCE.parse(matches);
+            let recipient = REWARD_RECIPIENT_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CHANGE_REWARD_RECIPIENT_WASM);
+            Self {
+                tx,
+                source,
+                recipient,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(SOURCE.def().help(
+                    "Source address of the bonds. For self-bonds, this is the \
+                     validator address.",
+                ))
+                .arg(REWARD_RECIPIENT_OPT.def().help(
+                    "The address to credit with the claimed rewards. When not \
+                     given, the rewards are credited to the source again.",
+                ))
+        }
+    }
+
     impl CliToSdk<QueryConversions<SdkTypes>> for QueryConversions<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> QueryConversions<SdkTypes> {
             QueryConversions::<SdkTypes> {
@@ -6086,6 +6853,33 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryLiveness<SdkTypes>> for QueryLiveness<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> QueryLiveness<SdkTypes> {
+            QueryLiveness::<SdkTypes> {
+                query: self.query.to_sdk(ctx),
+                validator: self
+                    .validator
+                    .map(|x| ctx.borrow_chain_or_exit().get(&x)),
+            }
+        }
+    }
+
+    impl Args for QueryLiveness<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR_OPT.parse(matches);
+            Self { query, validator }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(VALIDATOR_OPT.def().help(
+                    "The validator's address whose liveness and jail events \
+                     to query. Defaults to all the consensus validators.",
+                ))
+        }
+    }
+
     impl CliToSdk<QueryRewards<SdkTypes>> for QueryRewards<CliTypes> {
         fn to_sdk(self, ctx: &mut Context) -> QueryRewards<SdkTypes> {
             QueryRewards::<SdkTypes> {
@@ -6247,7 +7041,10 @@ pub mod args {
                 fee_unshield: self
                     .fee_unshield
                     .map(|ref fee_unshield| ctx.get_cached(fee_unshield)),
+                fee_granter: self.fee_granter.map(|x| ctx.get(&x)),
+                sequence: self.sequence,
                 gas_limit: self.gas_limit,
+                gas_limit_multiplier: self.gas_limit_multiplier,
                 signing_keys: self
                     .signing_keys
                     .iter()
@@ -6268,6 +7065,7 @@ pub mod args {
                 wrapper_fee_payer: self.wrapper_fee_payer.map(|x| ctx.get(&x)),
                 memo: self.memo,
                 use_device: self.use_device,
+                remote_signer: self.remote_signer,
             }
         }
     }
@@ -6319,9 +7117,26 @@ pub mod args {
                 "The spending key to be used for fee unshielding. If none is \
                  provided, fee will be paid from the unshielded balance only.",
             ))
-            .arg(GAS_LIMIT.def().help(
+            .arg(FEE_GRANTER_OPT.def().help(
+                "The account that granted the gas payer a fee allowance. If \
+                 provided, fees are paid by this account and charged against \
+                 the allowance.",
+            ))
+            .arg(SEQUENCE_OPT.def().help(
+                "The sequence number of the transaction among the ones whose \
+                 gas is paid by the gas payer. If provided, the transaction \
+                 is only included in a block after the ones with a lower \
+                 sequence number.",
+            ))
+            .arg(GAS_LIMIT_OPT.def().help(
                 "The multiplier of the gas limit resolution defining the \
-                 maximum amount of gas needed to run transaction.",
+                 maximum amount of gas needed to run transaction. If not \
+                 provided, the gas limit is estimated by dry running the \
+                 transaction.",
+            ))
+            .arg(GAS_LIMIT_MULTIPLIER.def().help(
+                "The safety multiplier applied to the estimated gas limit \
+                 when no --gas-limit is provided. Defaults to 1.3.",
             ))
             .arg(WALLET_ALIAS_FORCE.def().help(
                 "Override the alias without confirmation if it already exists.",
@@ -6381,6 +7196,18 @@ pub mod args {
                 "Use an attached hardware wallet device to sign the \
                  transaction.",
             ))
+            .arg(
+                REMOTE_SIGNER_OPT
+                    .def()
+                    .help(
+                        "Request the signatures that cannot be made with the \
+                         keys of the wallet from the remote signer listening \
+                         at the given address. The secret shared with the \
+                         signer must be set in the \
+                         NAMADA_REMOTE_SIGNER_SECRET env var.",
+                    )
+                    .conflicts_with(USE_DEVICE.name),
+            )
             .arg(
                 MEMO_OPT
                     .def()
@@ -6400,8 +7227,11 @@ pub mod args {
                 FEE_AMOUNT_OPT.parse(matches).map(InputAmount::Unvalidated);
             let fee_token = FEE_TOKEN.parse(matches);
             let fee_unshield = FEE_UNSHIELD_SPENDING_KEY.parse(matches);
+            let fee_granter = FEE_GRANTER_OPT.parse(matches);
+            let sequence = SEQUENCE_OPT.parse(matches);
             let _wallet_alias_force = WALLET_ALIAS_FORCE.parse(matches);
-            let gas_limit = GAS_LIMIT.parse(matches);
+            let gas_limit = GAS_LIMIT_OPT.parse(matches);
+            let gas_limit_multiplier = GAS_LIMIT_MULTIPLIER.parse(matches);
             let wallet_alias_force = WALLET_ALIAS_FORCE.parse(matches);
             let expiration = EXPIRATION_OPT.parse(matches);
             let disposable_signing_key = DISPOSABLE_SIGNING_KEY.parse(matches);
@@ -6414,6 +7244,7 @@ pub mod args {
             let wrapper_fee_payer = FEE_PAYER_OPT.parse(matches);
             let output_folder = OUTPUT_FOLDER_PATH.parse(matches);
             let use_device = USE_DEVICE.parse(matches);
+            let remote_signer = REMOTE_SIGNER_OPT.parse(matches);
             Self {
                 dry_run,
                 dry_run_wrapper,
@@ -6426,7 +7257,10 @@ pub mod args {
                 fee_amount,
                 fee_token,
                 fee_unshield,
+                fee_granter,
+                sequence,
                 gas_limit,
+                gas_limit_multiplier,
                 expiration,
                 disposable_signing_key,
                 signing_keys,
@@ -6438,6 +7272,7 @@ pub mod args {
                 output_folder,
                 memo,
                 use_device,
+                remote_signer,
             }
         }
     }
@@ -6913,6 +7748,25 @@ pub mod args {
         }
     }
 
+    impl Args for ServeSigner {
+        fn parse(matches: &ArgMatches) -> Self {
+            let address = REMOTE_SIGNER.parse(matches);
+            let aliases = ALIAS_MANY.parse(matches);
+            Self { address, aliases }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                REMOTE_SIGNER
+                    .def()
+                    .help("The address to listen at for signature requests."),
+            )
+            .arg(ALIAS_MANY.def().help(
+                "Comma separated list of aliases of the keys to sign with.",
+            ))
+        }
+    }
+
     impl Args for KeyConvert {
         fn parse(matches: &ArgMatches) -> Self {
             let alias = ALIAS.parse(matches);
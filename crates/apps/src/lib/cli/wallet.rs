@@ -27,6 +27,7 @@ use crate::cli;
 use crate::cli::api::CliApi;
 use crate::cli::args::CliToSdk;
 use crate::cli::{args, cmds, Context};
+use crate::client::remote_signer;
 use crate::client::utils::PRE_GENESIS_DIR;
 use crate::node::ledger::tendermint_node::validator_key_to_json;
 use crate::wallet::{
@@ -67,6 +68,9 @@ impl CliApi {
             cmds::NamadaWallet::KeyAddrRemove(
                 cmds::WalletRemoveKeyAddress(args),
             ) => key_address_remove(ctx, io, args),
+            cmds::NamadaWallet::ServeSigner(cmds::WalletServeSigner(args)) => {
+                serve_signer(ctx, io, args).await?
+            }
             cmds::NamadaWallet::PayAddrGen(cmds::WalletGenPaymentAddress(
                 args,
             )) => {
@@ -1249,6 +1253,31 @@ fn key_export(
         })
 }
 
+/// Serve signatures with keys of the wallet as a remote signer
+async fn serve_signer(
+    ctx: Context,
+    io: &impl Io,
+    args::ServeSigner { address, aliases }: args::ServeSigner,
+) -> Result<()> {
+    let mut wallet = load_wallet(ctx);
+    let secret_keys = aliases
+        .iter()
+        .map(|alias| {
+            wallet
+                .find_secret_key(alias.to_lowercase(), None)
+                .unwrap_or_else(|err| {
+                    edisplay_line!(io, "{}", err);
+                    cli::safe_exit(1)
+                })
+        })
+        .collect();
+    let secret = remote_signer::SharedSecret::from_env()?;
+    let listener = tokio::net::TcpListener::bind(address).await?;
+    display_line!(io, "Serving signatures at {}", address);
+    remote_signer::serve(listener, secret_keys, secret, &wallet, io).await?;
+    Ok(())
+}
+
 /// Convert a consensus key to tendermint validator key in json format
 fn key_convert(
     ctx: Context,
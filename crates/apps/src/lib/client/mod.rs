@@ -1,4 +1,5 @@
 pub mod masp;
+pub mod remote_signer;
 pub mod rpc;
 pub mod tx;
 pub mod utils;
@@ -0,0 +1,726 @@
+//! A reference implementation of the remote signer protocol, in the style of
+//! tmkms, that lets the secret keys be kept in a separate process.
+//!
+//! A client opens a TCP connection per request. Every message is Borsh
+//! encoded and prefixed with its length as a big-endian `u32`.
+//!
+//! The signer and its clients authenticate each other with a secret shared
+//! through the [`ENV_VAR_REMOTE_SIGNER_SECRET`] env var. Upon accepting a
+//! connection, the signer sends a random nonce. The client replies with its
+//! own nonce and the [`SignerRequest`], and the signer answers with the
+//! [`SignerResponse`], each authenticated with a MAC over both nonces, such
+//! that the messages can neither be forged nor replayed.
+//!
+//! The signer is handed the txs and the vote extensions to sign rather than
+//! their hashes, such that it shows what it signs and computes the signed
+//! hashes itself.
+//!
+//! A validator node requests the signatures of its protocol key from the
+//! remote signer set in its `validator_local_config.toml`. The signatures of
+//! its consensus key are requested by CometBFT from the privval signer, e.g.
+//! tmkms, listening at the `priv_validator_laddr` of the CometBFT config.
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use namada::core::hash::Hash;
+use namada::core::key::*;
+use namada::io::Io;
+use namada::tx::data::TxType;
+use namada::tx::{Section, Tx};
+use namada::vote_ext::EthereumTxData;
+use namada_sdk::display_line;
+use namada_sdk::error::Error;
+use namada_sdk::signing::{
+    to_ledger_vector, ExternalSigner, ProtocolVext, Signable, SignerRequest,
+    SignerResponse,
+};
+use namada_sdk::wallet::{Wallet, WalletIo};
+use orion::auth;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot};
+
+/// Env. var. holding the secret shared by the remote signer and its clients
+pub const ENV_VAR_REMOTE_SIGNER_SECRET: &str = "NAMADA_REMOTE_SIGNER_SECRET";
+
+/// The maximum length of a message, to avoid allocating arbitrarily large
+/// buffers for malformed messages
+const MAX_MESSAGE_LEN: u32 = 16 << 20;
+
+/// The length of the nonces exchanged to authenticate the messages
+const NONCE_LEN: usize = 32;
+
+/// The time after which a request to the remote signer is abandoned
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The domain of the MACs over the requests
+const REQUEST_DOMAIN: &[u8] = b"namada-remote-signer-request";
+
+/// The domain of the MACs over the responses
+const RESPONSE_DOMAIN: &[u8] = b"namada-remote-signer-response";
+
+/// A secret shared by the remote signer and its clients to authenticate each
+/// other
+#[derive(Clone)]
+pub struct SharedSecret([u8; 32]);
+
+impl std::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedSecret(..)")
+    }
+}
+
+impl SharedSecret {
+    /// Derive the shared secret from the given passphrase
+    pub fn new(passphrase: &str) -> Self {
+        Self(Hash::sha256(passphrase.as_bytes()).0)
+    }
+
+    /// Read the shared secret from the [`ENV_VAR_REMOTE_SIGNER_SECRET`] env
+    /// var
+    pub fn from_env() -> Result<Self, Error> {
+        match std::env::var(ENV_VAR_REMOTE_SIGNER_SECRET) {
+            Ok(passphrase) if !passphrase.is_empty() => {
+                Ok(Self::new(&passphrase))
+            }
+            _ => Err(Error::Other(format!(
+                "The secret shared with the remote signer must be set in the \
+                 {} env var",
+                ENV_VAR_REMOTE_SIGNER_SECRET
+            ))),
+        }
+    }
+
+    /// The data authenticated by a MAC over the given message
+    fn mac_data(
+        domain: &[u8],
+        signer_nonce: &[u8; NONCE_LEN],
+        client_nonce: &[u8; NONCE_LEN],
+        message: &impl BorshSerialize,
+    ) -> Vec<u8> {
+        [
+            domain,
+            signer_nonce,
+            client_nonce,
+            &message.serialize_to_vec(),
+        ]
+        .concat()
+    }
+
+    /// Compute the MAC over the given message
+    fn authenticate(
+        &self,
+        domain: &[u8],
+        signer_nonce: &[u8; NONCE_LEN],
+        client_nonce: &[u8; NONCE_LEN],
+        message: &impl BorshSerialize,
+    ) -> Vec<u8> {
+        let key = auth::SecretKey::from_slice(&self.0)
+            .expect("The shared secret should be a valid MAC key");
+        let data = Self::mac_data(domain, signer_nonce, client_nonce, message);
+        auth::authenticate(&key, &data)
+            .expect("Computing a MAC should not fail")
+            .unprotected_as_bytes()
+            .to_vec()
+    }
+
+    /// Check the MAC over the given message
+    fn verify(
+        &self,
+        domain: &[u8],
+        signer_nonce: &[u8; NONCE_LEN],
+        client_nonce: &[u8; NONCE_LEN],
+        message: &impl BorshSerialize,
+        tag: &[u8],
+    ) -> io::Result<()> {
+        let key = auth::SecretKey::from_slice(&self.0)
+            .expect("The shared secret should be a valid MAC key");
+        let data = Self::mac_data(domain, signer_nonce, client_nonce, message);
+        auth::Tag::from_slice(tag)
+            .and_then(|tag| auth::authenticate_verify(&tag, &key, &data))
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "The message could not be authenticated",
+                )
+            })
+    }
+}
+
+/// A request authenticated with the shared secret
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AuthRequest {
+    nonce: [u8; NONCE_LEN],
+    request: SignerRequest,
+    tag: Vec<u8>,
+}
+
+/// A response authenticated with the shared secret
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AuthResponse {
+    response: SignerResponse,
+    tag: Vec<u8>,
+}
+
+/// A client requesting signatures from a remote signer over TCP. The requests
+/// block the current thread, such that the client can also be used by the
+/// ledger, which isn't run in an async context.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    address: SocketAddr,
+    secret: SharedSecret,
+}
+
+impl RemoteSigner {
+    /// Create a client of the remote signer listening at the given address
+    pub fn new(address: SocketAddr, secret: SharedSecret) -> Self {
+        Self { address, secret }
+    }
+
+    /// Send a request to the remote signer and wait for its response
+    fn request(&self, request: SignerRequest) -> Result<SignerResponse, Error> {
+        match self.exchange(request) {
+            Ok(SignerResponse::Error(err)) => Err(Error::Other(format!(
+                "The remote signer at {} failed to serve the request: {}",
+                self.address, err
+            ))),
+            Ok(response) => Ok(response),
+            Err(err) => Err(Error::Other(format!(
+                "Unable to communicate with the remote signer at {}: {}",
+                self.address, err
+            ))),
+        }
+    }
+
+    /// Exchange an authenticated request and response with the remote signer
+    fn exchange(&self, request: SignerRequest) -> io::Result<SignerResponse> {
+        let mut stream =
+            std::net::TcpStream::connect_timeout(&self.address, TIMEOUT)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+
+        let mut signer_nonce = [0; NONCE_LEN];
+        stream.read_exact(&mut signer_nonce)?;
+        let mut nonce = [0; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let tag = self.secret.authenticate(
+            REQUEST_DOMAIN,
+            &signer_nonce,
+            &nonce,
+            &request,
+        );
+        let request = AuthRequest {
+            nonce,
+            request,
+            tag,
+        };
+        stream.write_all(&encode_message(&request)?)?;
+
+        let mut len = [0; 4];
+        stream.read_exact(&mut len)?;
+        let mut buf = vec![0; checked_len(u32::from_be_bytes(len))?];
+        stream.read_exact(&mut buf)?;
+        let AuthResponse { response, tag } =
+            AuthResponse::try_from_slice(&buf)?;
+        self.secret.verify(
+            RESPONSE_DOMAIN,
+            &signer_nonce,
+            &nonce,
+            &response,
+            &tag,
+        )?;
+        Ok(response)
+    }
+
+    /// The error returned when the response doesn't match the request
+    fn unexpected_response(&self, response: SignerResponse) -> Error {
+        Error::Other(format!(
+            "Unexpected response from the remote signer at {}: {:?}",
+            self.address, response
+        ))
+    }
+
+    /// Get the signature over the given part of the given tx, checked against
+    /// the signature section that it commits to
+    pub fn sign_tx_part(
+        &self,
+        pubkey: &common::PublicKey,
+        tx: &Tx,
+        part: Signable,
+    ) -> Result<common::Signature, Error> {
+        let request = SignerRequest::SignTx {
+            pubkey: pubkey.clone(),
+            tx: Box::new(tx.clone()),
+            part,
+        };
+        let signature = match self.request(request)? {
+            SignerResponse::Signature(signature) => signature,
+            response => return Err(self.unexpected_response(response)),
+        };
+        let hash = part.signature_section(tx, pubkey).get_raw_hash();
+        common::SigScheme::verify_signature(pubkey, &hash, &signature)
+            .map_err(|err| {
+                Error::Other(format!(
+                    "Invalid signature from the remote signer at {}: {}",
+                    self.address, err
+                ))
+            })?;
+        Ok(signature)
+    }
+
+    /// Sign the given protocol tx with the protocol key of the given public
+    /// key
+    pub fn sign_protocol_tx(
+        &self,
+        pubkey: &common::PublicKey,
+        mut tx: Tx,
+    ) -> Result<Tx, Error> {
+        tx.protocol_filter();
+        let signature = self.sign_tx_part(pubkey, &tx, Signable::FeeHeader)?;
+        let mut section = Signable::FeeHeader.signature_section(&tx, pubkey);
+        section.signatures.insert(0, signature);
+        tx.add_section(Section::Signature(section));
+        Ok(tx)
+    }
+
+    /// Sign the given vote extension with the protocol key of the given
+    /// public key
+    pub fn sign_vext(
+        &self,
+        pubkey: &common::PublicKey,
+        vext: ProtocolVext,
+    ) -> Result<common::Signature, Error> {
+        let request = SignerRequest::SignVext {
+            pubkey: pubkey.clone(),
+            vext,
+        };
+        match self.request(request)? {
+            SignerResponse::Signature(signature) => Ok(signature),
+            response => Err(self.unexpected_response(response)),
+        }
+    }
+}
+
+#[cfg_attr(feature = "async-send", async_trait::async_trait)]
+#[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+impl ExternalSigner for RemoteSigner {
+    async fn public_keys(&self) -> Result<Vec<common::PublicKey>, Error> {
+        let signer = self.clone();
+        let response = tokio::task::spawn_blocking(move || {
+            signer.request(SignerRequest::PublicKeys)
+        })
+        .await
+        .map_err(|err| Error::Other(err.to_string()))??;
+        match response {
+            SignerResponse::PublicKeys(pks) => Ok(pks),
+            response => Err(self.unexpected_response(response)),
+        }
+    }
+
+    async fn sign_tx(
+        &self,
+        pubkey: &common::PublicKey,
+        tx: &Tx,
+        part: Signable,
+    ) -> Result<common::Signature, Error> {
+        let signer = self.clone();
+        let pubkey = pubkey.clone();
+        let tx = tx.clone();
+        tokio::task::spawn_blocking(move || {
+            signer.sign_tx_part(&pubkey, &tx, part)
+        })
+        .await
+        .map_err(|err| Error::Other(err.to_string()))?
+    }
+}
+
+/// A request of a client waiting to be served
+type PendingRequest =
+    (SignerRequest, SocketAddr, oneshot::Sender<SignerResponse>);
+
+/// Serve the requests of the connections accepted by the given listener with
+/// the given secret keys, showing what is signed with the given IO. The
+/// clients are authenticated with the given shared secret. Only returns if
+/// the listener fails.
+pub async fn serve<U: WalletIo>(
+    listener: TcpListener,
+    secret_keys: Vec<common::SecretKey>,
+    secret: SharedSecret,
+    wallet: &Wallet<U>,
+    io: &impl Io,
+) -> io::Result<()> {
+    let secret_keys: HashMap<common::PublicKey, common::SecretKey> =
+        secret_keys
+            .into_iter()
+            .map(|secret_key| (secret_key.ref_to(), secret_key))
+            .collect();
+    // The connections are served concurrently, while the authenticated
+    // requests are handled one at a time
+    let (sender, mut receiver) = mpsc::unbounded_channel::<PendingRequest>();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, peer) = accepted?;
+                let secret = secret.clone();
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    let connection =
+                        serve_connection(stream, peer, secret, sender);
+                    let result = tokio::time::timeout(TIMEOUT, connection)
+                        .await
+                        .unwrap_or_else(|_| {
+                            Err(io::Error::from(io::ErrorKind::TimedOut))
+                        });
+                    if let Err(err) = result {
+                        tracing::warn!(
+                            "Unable to serve the remote signer client {}: {}",
+                            peer,
+                            err
+                        );
+                    }
+                });
+            }
+            Some((request, peer, respond)) = receiver.recv() => {
+                let response =
+                    handle_request(&secret_keys, request, peer, wallet, io)
+                        .await;
+                // The client may have timed out in the meantime
+                let _ = respond.send(response);
+            }
+        }
+    }
+}
+
+/// Authenticate the request of the given connection, hand it over to be
+/// handled and send back the authenticated response
+async fn serve_connection(
+    mut stream: TcpStream,
+    peer: SocketAddr,
+    secret: SharedSecret,
+    requests: mpsc::UnboundedSender<PendingRequest>,
+) -> io::Result<()> {
+    let mut nonce = [0; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    stream.write_all(&nonce).await?;
+
+    let AuthRequest {
+        nonce: client_nonce,
+        request,
+        tag,
+    } = read_message(&mut stream).await?;
+    secret.verify(REQUEST_DOMAIN, &nonce, &client_nonce, &request, &tag)?;
+
+    let (respond, response) = oneshot::channel();
+    requests
+        .send((request, peer, respond))
+        .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+    let response = response
+        .await
+        .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+
+    let tag =
+        secret.authenticate(RESPONSE_DOMAIN, &nonce, &client_nonce, &response);
+    let response = AuthResponse { response, tag };
+    stream.write_all(&encode_message(&response)?).await?;
+    stream.flush().await
+}
+
+/// Compute the response to a request with the given secret keys, showing
+/// what is signed
+async fn handle_request<U: WalletIo>(
+    secret_keys: &HashMap<common::PublicKey, common::SecretKey>,
+    request: SignerRequest,
+    peer: SocketAddr,
+    wallet: &Wallet<U>,
+    io: &impl Io,
+) -> SignerResponse {
+    match request {
+        SignerRequest::PublicKeys => {
+            SignerResponse::PublicKeys(secret_keys.keys().cloned().collect())
+        }
+        SignerRequest::SignTx {
+            pubkey,
+            mut tx,
+            part,
+        } => {
+            let secret_key = match secret_keys.get(&pubkey) {
+                Some(secret_key) => secret_key,
+                None => return unknown_key(&pubkey),
+            };
+            let description = match describe_tx(wallet, &tx).await {
+                Ok(description) => description,
+                Err(err) => {
+                    return SignerResponse::Error(format!(
+                        "Unable to show the tx to sign: {}",
+                        err
+                    ));
+                }
+            };
+            tx.protocol_filter();
+            let hash = part.signature_section(&tx, &pubkey).get_raw_hash();
+            display_line!(
+                io,
+                "Signing the {:?} of tx {} with {} for {}:",
+                part,
+                tx.header_hash(),
+                pubkey,
+                peer
+            );
+            for line in description {
+                display_line!(io, "  {}", line);
+            }
+            SignerResponse::Signature(common::SigScheme::sign(secret_key, hash))
+        }
+        SignerRequest::SignVext { pubkey, vext } => {
+            let secret_key = match secret_keys.get(&pubkey) {
+                Some(secret_key) => secret_key,
+                None => return unknown_key(&pubkey),
+            };
+            display_line!(
+                io,
+                "Signing the vote extension {:?} with {} for {}",
+                vext,
+                pubkey,
+                peer
+            );
+            SignerResponse::Signature(vext.sign(secret_key))
+        }
+    }
+}
+
+/// The response to a request to sign with a key that isn't held
+fn unknown_key(pubkey: &common::PublicKey) -> SignerResponse {
+    SignerResponse::Error(format!(
+        "No secret key found for the public key {}",
+        pubkey
+    ))
+}
+
+/// Describe the given tx with the lines shown by the hardware wallets, or the
+/// data of the protocol txs
+async fn describe_tx<U: WalletIo>(
+    wallet: &Wallet<U>,
+    tx: &Tx,
+) -> Result<Vec<String>, Error> {
+    if let TxType::Protocol(_) = tx.header().tx_type {
+        let data = EthereumTxData::try_from(tx)
+            .map_err(|err| Error::Other(err.to_string()))?;
+        return Ok(vec![format!("{:?}", data)]);
+    }
+    Ok(to_ledger_vector(wallet, tx).await?.output_expert)
+}
+
+/// Check the length of a message against the maximum length
+fn checked_len(len: u32) -> io::Result<usize> {
+    if len > MAX_MESSAGE_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Message length {len} exceeds the maximum length"),
+        ));
+    }
+    Ok(len as usize)
+}
+
+/// Read a length prefixed Borsh encoded message from the given stream
+async fn read_message<S, T>(stream: &mut S) -> io::Result<T>
+where
+    S: AsyncRead + Unpin,
+    T: BorshDeserialize,
+{
+    let len = checked_len(stream.read_u32().await?)?;
+    let mut buf = vec![0; len];
+    stream.read_exact(&mut buf).await?;
+    T::try_from_slice(&buf)
+}
+
+/// Encode a message with Borsh and prefix it with its length
+fn encode_message<T: BorshSerialize>(message: &T) -> io::Result<Vec<u8>> {
+    let buf = message.serialize_to_vec();
+    let len = u32::try_from(buf.len())
+        .ok()
+        .filter(|len| *len <= MAX_MESSAGE_LEN)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Message length exceeds the maximum length",
+            )
+        })?;
+    Ok([len.to_be_bytes().as_slice(), &buf].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::path::PathBuf;
+
+    use namada::core::key::testing::{keypair_1, keypair_2};
+    use namada_sdk::io::NullIo;
+    use namada_sdk::signing::with_external_signer;
+
+    use super::*;
+    use crate::wallet::CliWalletUtils;
+
+    /// Start a remote signer holding the given keys on a random local port
+    async fn start_signer(
+        secret_keys: Vec<common::SecretKey>,
+        secret: SharedSecret,
+    ) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let wallet = CliWalletUtils::new(PathBuf::new());
+            serve(listener, secret_keys, secret, &wallet, &NullIo).await
+        });
+        address
+    }
+
+    /// A tx with a code section, such that it can be shown by the signer
+    fn custom_tx() -> Tx {
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.add_code(vec![], None);
+        tx
+    }
+
+    /// Test that the remote signer serves its public keys and signs with the
+    /// requested key only.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_signer() {
+        let secret_key = keypair_1();
+        let pubkey = secret_key.ref_to();
+        let secret = SharedSecret::new("secret");
+        let address = start_signer(vec![secret_key], secret.clone()).await;
+        let signer = RemoteSigner::new(address, secret);
+
+        assert_eq!(signer.public_keys().await.unwrap(), vec![pubkey.clone()]);
+
+        let tx = custom_tx();
+        let signature = signer
+            .sign_tx(&pubkey, &tx, Signable::RawHeader)
+            .await
+            .unwrap();
+        let hash = Signable::RawHeader
+            .signature_section(&tx, &pubkey)
+            .get_raw_hash();
+        common::SigScheme::verify_signature(&pubkey, &hash, &signature)
+            .unwrap();
+
+        let unknown = keypair_2().ref_to();
+        assert!(
+            signer
+                .sign_tx(&unknown, &tx, Signable::RawHeader)
+                .await
+                .is_err()
+        );
+    }
+
+    /// Test that the remote signer only serves the clients holding the shared
+    /// secret.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_signer_authentication() {
+        let secret_key = keypair_1();
+        let address =
+            start_signer(vec![secret_key], SharedSecret::new("secret")).await;
+
+        let signer = RemoteSigner::new(address, SharedSecret::new("guess"));
+        assert!(signer.public_keys().await.is_err());
+    }
+
+    /// Test that the remote signer refuses to sign the txs that it cannot
+    /// show.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_signer_rejects_unknown_tx() {
+        let secret_key = keypair_1();
+        let pubkey = secret_key.ref_to();
+        let secret = SharedSecret::new("secret");
+        let address = start_signer(vec![secret_key], secret.clone()).await;
+        let signer = RemoteSigner::new(address, secret);
+
+        // A tx without code cannot be shown
+        let tx = Tx::from_type(TxType::Raw);
+        assert!(
+            signer
+                .sign_tx(&pubkey, &tx, Signable::RawHeader)
+                .await
+                .is_err()
+        );
+    }
+
+    /// Test that the protocol txs and vote extensions of a validator are
+    /// validly signed through the remote signer.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sign_protocol_data_with_remote_signer() {
+        use namada::core::address::testing::established_address_1;
+        use namada::core::chain::ChainId;
+        use namada::core::storage::BlockHeight;
+        use namada::tx::Signed;
+        use namada::vote_ext::ethereum_events;
+
+        let protocol_key = keypair_1();
+        let pubkey = protocol_key.ref_to();
+        let secret = SharedSecret::new("secret");
+        let address = start_signer(vec![protocol_key], secret.clone()).await;
+        let signer = RemoteSigner::new(address, secret);
+
+        let ext = ethereum_events::Vext::empty(
+            BlockHeight(1),
+            established_address_1(),
+        );
+        let (signed, tx) = tokio::task::spawn_blocking(move || {
+            let sig = signer
+                .sign_vext(&pubkey, ProtocolVext::EthereumEvents(ext.clone()))
+                .unwrap();
+            let signed = Signed::new_from(ext, sig);
+            signed.verify(&pubkey).unwrap();
+
+            let protocol_tx = EthereumTxData::EthEventsVext(
+                ethereum_events::SignedVext(signed.clone()),
+            );
+            let tx = protocol_tx.unsigned(pubkey.clone(), ChainId::default());
+            let tx = signer.sign_protocol_tx(&pubkey, tx).unwrap();
+            (signed, tx)
+        })
+        .await
+        .unwrap();
+
+        let pubkey = keypair_1().ref_to();
+        assert!(tx.verify_signature(&pubkey, &tx.sechashes()).is_ok());
+        let (data, _) = EthereumTxData::try_from(&tx).unwrap().serialize();
+        let (expected, _) =
+            EthereumTxData::EthEventsVext(ethereum_events::SignedVext(signed))
+                .serialize();
+        assert_eq!(data, expected);
+    }
+
+    /// Test that a tx signed through the remote signer carries valid
+    /// signatures over its raw and fee headers.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_sign_tx_with_remote_signer() {
+        let secret_key = keypair_1();
+        let pubkey = secret_key.ref_to();
+        let secret = SharedSecret::new("secret");
+        let address = start_signer(vec![secret_key], secret.clone()).await;
+        let signer = RemoteSigner::new(address, secret);
+
+        let tx = with_external_signer(
+            custom_tx(),
+            pubkey.clone(),
+            HashSet::from([Signable::RawHeader, Signable::FeeHeader]),
+            &signer,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            tx.verify_signature(&pubkey, &[tx.raw_header_hash()])
+                .is_ok()
+        );
+        assert!(tx.verify_signature(&pubkey, &tx.sechashes()).is_ok());
+    }
+}
@@ -15,6 +15,7 @@ use masp_primitives::sapling::{Node, ViewingKey};
 use masp_primitives::transaction::components::I128Sum;
 use masp_primitives::zip32::ExtendedFullViewingKey;
 use namada::core::address::{Address, InternalAddress, MASP};
+use namada::core::dec::Dec;
 use namada::core::hash::Hash;
 use namada::core::ibc::{is_ibc_denom, IbcTokenHash};
 use namada::core::key::*;
@@ -32,10 +33,11 @@ use namada::governance::pgf::parameters::PgfParameters;
 use namada::governance::pgf::storage::steward::StewardDetail;
 use namada::governance::storage::keys as governance_storage;
 use namada::governance::storage::proposal::{
-    StoragePgfFunding, StorageProposal,
+    StoragePgfFunding, StoragePgfStream, StorageProposal,
 };
 use namada::governance::utils::{
-    compute_proposal_result, ProposalVotes, TallyType, TallyVote, VotePower,
+    compute_proposal_result, DelegatorOverride, ProposalTally, ProposalVotes,
+    TallyType, TallyVote, VotePower,
 };
 use namada::io::Io;
 use namada::ledger::events::Event;
@@ -43,8 +45,9 @@ use namada::ledger::ibc::storage::{
     ibc_denom_key, ibc_denom_key_prefix, is_ibc_denom_key,
 };
 use namada::ledger::parameters::{storage as param_storage, EpochDuration};
-use namada::ledger::pos::types::{CommissionPair, Slash};
+use namada::ledger::pos::types::{CommissionPair, JailEvent, Slash};
 use namada::ledger::pos::PosParams;
+use namada::ledger::queries::vp::pos::{ClaimableRewards, ValidatorLiveness};
 use namada::ledger::queries::RPC;
 use namada::proof_of_stake::types::{ValidatorState, WeightedValidator};
 use namada::{state as storage, token};
@@ -421,6 +424,9 @@ pub async fn query_transparent_balance(
                         );
                     }
                 }
+                if token == context.native_token() {
+                    query_vesting_balance(context, &owner).await;
+                }
             }
         }
         (None, Some(owner)) => {
@@ -434,6 +440,7 @@ pub async fn query_transparent_balance(
                     display_line!(context.io(), "{}: {}", token_alias, balance);
                 }
             }
+            query_vesting_balance(context, &owner).await;
         }
         (Some(base_token), None) => {
             let tokens = query_tokens(context, Some(&base_token), None).await;
@@ -456,6 +463,49 @@ pub async fn query_transparent_balance(
     }
 }
 
+/// Print the locked and spendable native token balance of an account with a
+/// vesting schedule
+async fn query_vesting_balance(context: &impl Namada, owner: &Address) {
+    let schedule =
+        match rpc::get_vesting_schedule(context.client(), owner).await {
+            Ok(Some(schedule)) => schedule,
+            Ok(None) => return,
+            Err(e) => {
+                edisplay_line!(
+                    context.io(),
+                    "Error querying the vesting schedule of {owner}: {e}"
+                );
+                return;
+            }
+        };
+    let native_token = context.native_token();
+    let epoch = query_epoch(context.client()).await.unwrap();
+    let balance =
+        get_token_balance(context.client(), &native_token, owner).await;
+    let locked = schedule.locked(epoch);
+    let spendable = balance.checked_sub(locked).unwrap_or_default();
+    display_line!(
+        context.io(),
+        "Vesting of {} from epoch {} (cliff at epoch {}) to epoch {}",
+        context.format_amount(&native_token, schedule.amount).await,
+        schedule.start_epoch,
+        schedule.cliff_epoch,
+        schedule.end_epoch,
+    );
+    display_line!(
+        context.io(),
+        "{:4}Locked: {}",
+        "",
+        context.format_amount(&native_token, locked).await
+    );
+    display_line!(
+        context.io(),
+        "{:4}Spendable: {}",
+        "",
+        context.format_amount(&native_token, spendable).await
+    );
+}
+
 /// Query the token pinned balance(s)
 pub async fn query_pinned_balance(
     context: &impl Namada,
@@ -1247,6 +1297,63 @@ pub async fn get_token_balance<C: namada::ledger::queries::Client + Sync>(
         .unwrap()
 }
 
+/// Display the turnout of a proposal tally and the votes of the delegators
+/// that override the votes of their validators
+fn display_proposal_tally(
+    context: &impl Namada,
+    proposal_tally: &ProposalTally,
+) {
+    display_line!(
+        context.io(),
+        "{:4}Turnout: {} of the voting power at epoch {}",
+        "",
+        proposal_tally.turnout,
+        proposal_tally.tally_epoch
+    );
+
+    let mut overrides_by_validator: BTreeMap<
+        &Address,
+        Vec<&DelegatorOverride>,
+    > = BTreeMap::new();
+    for delegator_override in &proposal_tally.delegator_overrides {
+        if delegator_override.is_override() {
+            overrides_by_validator
+                .entry(&delegator_override.validator)
+                .or_default()
+                .push(delegator_override);
+        }
+    }
+    if overrides_by_validator.is_empty() {
+        return;
+    }
+
+    display_line!(context.io(), "{:4}Delegator overrides:", "");
+    for (validator, overrides) in overrides_by_validator {
+        let validator_vote = overrides[0]
+            .validator_vote
+            .as_ref()
+            .map(|vote| vote.to_string())
+            .unwrap_or_else(|| "no vote".to_string());
+        display_line!(
+            context.io(),
+            "{:8}Validator {} ({}):",
+            "",
+            validator,
+            validator_vote
+        );
+        for delegator_override in overrides {
+            display_line!(
+                context.io(),
+                "{:12}{}: {} with {}",
+                "",
+                delegator_override.delegator,
+                delegator_override.vote,
+                delegator_override.voting_power.to_string_native()
+            );
+        }
+    }
+}
+
 pub async fn query_proposal_result(
     context: &impl Namada,
     args: args::QueryProposalResult,
@@ -1280,6 +1387,18 @@ pub async fn query_proposal_result(
                     "",
                     proposal_query.voting_end_epoch
                 );
+                let proposal_tally = namada_sdk::rpc::query_proposal_tally(
+                    context.client(),
+                    proposal_id,
+                )
+                .await;
+                let proposal_result = match proposal_tally {
+                    Ok(Some(proposal_tally)) => {
+                        display_proposal_tally(context, &proposal_tally);
+                        proposal_tally.result
+                    }
+                    _ => proposal_result,
+                };
                 let res = format!("{}", proposal_result);
                 if let Some(idx) = res.find(' ') {
                     let slice = &res[idx..];
@@ -1400,6 +1519,7 @@ pub async fn query_account(context: &impl Namada, args: args::QueryAccount) {
 pub async fn query_pgf(context: &impl Namada, _args: args::QueryPgf) {
     let stewards = query_pgf_stewards(context.client()).await;
     let fundings = query_pgf_fundings(context.client()).await;
+    let streams = query_pgf_streams(context.client()).await;
 
     match stewards.is_empty() {
         true => {
@@ -1446,6 +1566,30 @@ pub async fn query_pgf(context: &impl Namada, _args: args::QueryPgf) {
             }
         }
     }
+
+    match streams.is_empty() {
+        true => {
+            display_line!(
+                context.io(),
+                "Pgf streams: no streams are currently set."
+            )
+        }
+        false => {
+            let current_epoch = query_epoch(context.client()).await.unwrap();
+            display_line!(context.io(), "Pgf streams:");
+            for stream in streams {
+                display_line!(context.io(), "{:4}- {}", "", stream.detail);
+                display_line!(
+                    context.io(),
+                    "{:4}  Claimed: {}, claimable: {}, unvested: {}",
+                    "",
+                    stream.claimed.to_string_native(),
+                    stream.claimable(current_epoch).to_string_native(),
+                    stream.remaining(current_epoch).to_string_native()
+                );
+            }
+        }
+    }
 }
 
 pub async fn query_protocol_parameters(
@@ -1473,6 +1617,12 @@ pub async fn query_protocol_parameters(
         "",
         governance_parameters.min_proposal_voting_period
     );
+    display_line!(
+        context.io(),
+        "{:4}Min. expedited proposal voting period: {}",
+        "",
+        governance_parameters.min_expedited_proposal_voting_period
+    );
     display_line!(
         context.io(),
         "{:4}Max. proposal period: {}",
@@ -1491,6 +1641,12 @@ pub async fn query_protocol_parameters(
         "",
         governance_parameters.min_proposal_grace_epochs
     );
+    display_line!(
+        context.io(),
+        "{:4}Forfeited deposit target: {}",
+        "",
+        governance_parameters.forfeited_deposit_target
+    );
 
     let pgf_parameters = query_pgf_parameters(context.client()).await;
     display_line!(context.io(), "\nPublic Goods Funding Parameters");
@@ -1594,6 +1750,21 @@ pub async fn query_protocol_parameters(
         display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_cost);
     }
 
+    let base_gas_price = rpc::query_base_gas_price(context.client())
+        .await
+        .expect("Base gas price should be defined.");
+    display_line!(context.io(), "{:4}Base gas price table:", "");
+    for (token, gas_cost) in base_gas_price {
+        display_line!(context.io(), "{:8}{}: {:?}", "", token, gas_cost);
+    }
+
+    let key = param_storage::get_fee_burn_rate_key();
+    // Chains that never set the fee burn rate don't burn any fees
+    let fee_burn_rate: Dec = query_storage_value(context.client(), &key)
+        .await
+        .unwrap_or_default();
+    display_line!(context.io(), "{:4}Fee burn rate: {}", "", fee_burn_rate);
+
     display_line!(context.io(), "PoS parameters");
     let pos_params = query_pos_parameters(context.client()).await;
     display_line!(
@@ -1748,6 +1919,12 @@ pub async fn query_pgf_fundings<C: namada::ledger::queries::Client + Sync>(
     unwrap_client_response::<C, _>(RPC.vp().pgf().funding(client).await)
 }
 
+pub async fn query_pgf_streams<C: namada::ledger::queries::Client + Sync>(
+    client: &C,
+) -> Vec<StoragePgfStream> {
+    unwrap_client_response::<C, _>(RPC.vp().pgf().streams(client).await)
+}
+
 pub async fn query_pgf_parameters<C: namada::ledger::queries::Client + Sync>(
     client: &C,
 ) -> PgfParameters {
@@ -2084,13 +2261,14 @@ pub async fn query_validator_state<
     )
 }
 
-/// Query and return the available reward tokens corresponding to the bond
+/// Query and return the available reward tokens corresponding to the bond,
+/// together with the address they would be credited to
 pub async fn query_rewards<C: namada::ledger::queries::Client + Sync>(
     client: &C,
     source: &Option<Address>,
     validator: &Address,
-) -> token::Amount {
-    unwrap_client_response::<C, token::Amount>(
+) -> ClaimableRewards {
+    unwrap_client_response::<C, ClaimableRewards>(
         RPC.vp().pos().rewards(client, validator, source).await,
     )
 }
@@ -2408,6 +2586,100 @@ pub async fn query_slashes<N: Namada>(context: &N, args: args::QuerySlashes) {
     }
 }
 
+/// Query the liveness of PoS validators
+pub async fn query_liveness<N: Namada>(context: &N, args: args::QueryLiveness) {
+    match args.validator {
+        Some(validator) => {
+            let liveness: Option<ValidatorLiveness> =
+                unwrap_client_response::<N::Client, _>(
+                    RPC.vp()
+                        .pos()
+                        .validator_liveness(context.client(), &validator)
+                        .await,
+                );
+            let Some(liveness) = liveness else {
+                display_line!(
+                    context.io(),
+                    "Address {} is not a validator",
+                    validator.encode()
+                );
+                return;
+            };
+            display_liveness(context, &validator, &liveness);
+
+            // Find the history of jail events for the given validator
+            let jail_events: Vec<JailEvent> =
+                unwrap_client_response::<N::Client, _>(
+                    RPC.vp()
+                        .pos()
+                        .validator_jail_events(context.client(), &validator)
+                        .await,
+                );
+            if !jail_events.is_empty() {
+                display_line!(context.io(), "Jail events:");
+                for event in jail_events {
+                    display_line!(
+                        context.io(),
+                        "Jailed in epoch {} starting from epoch {}, reason: {}",
+                        event.epoch,
+                        event.start_epoch,
+                        event.reason
+                    );
+                }
+            } else {
+                display_line!(
+                    context.io(),
+                    "No jail events found for {}",
+                    validator.encode()
+                )
+            }
+        }
+        None => {
+            let consensus: BTreeSet<WeightedValidator> =
+                unwrap_client_response::<N::Client, _>(
+                    RPC.vp()
+                        .pos()
+                        .consensus_validator_set(context.client(), &None)
+                        .await,
+                );
+            display_line!(context.io(), "Consensus validators liveness:");
+            for WeightedValidator { address, .. } in consensus {
+                let liveness: Option<ValidatorLiveness> =
+                    unwrap_client_response::<N::Client, _>(
+                        RPC.vp()
+                            .pos()
+                            .validator_liveness(context.client(), &address)
+                            .await,
+                    );
+                if let Some(liveness) = liveness {
+                    display_liveness(context, &address, &liveness);
+                }
+            }
+        }
+    }
+}
+
+fn display_liveness<N: Namada>(
+    context: &N,
+    validator: &Address,
+    liveness: &ValidatorLiveness,
+) {
+    let last_signed_height = liveness
+        .last_signed_height
+        .map(|height| height.to_string())
+        .unwrap_or_else(|| "none".to_string());
+    display_line!(
+        context.io(),
+        "Validator {}: missed {} of the last {} blocks (jailed at {}), last \
+         signed block height: {}",
+        validator.encode(),
+        liveness.missed_votes,
+        liveness.window_len,
+        liveness.missed_votes_threshold,
+        last_signed_height
+    );
+}
+
 pub async fn query_and_print_rewards<N: Namada>(
     context: &N,
     args: args::QueryRewards,
@@ -2418,7 +2690,12 @@ pub async fn query_and_print_rewards<N: Namada>(
     display_line!(
         context.io(),
         "Current rewards available for claim: {} NAM",
-        rewards.to_string_native()
+        rewards.amount.to_string_native()
+    );
+    display_line!(
+        context.io(),
+        "Rewards are credited to: {}",
+        rewards.recipient
     );
 }
 
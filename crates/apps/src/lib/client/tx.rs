@@ -32,9 +32,11 @@ use tokio::sync::RwLock;
 
 use super::rpc;
 use crate::cli::{args, safe_exit};
+use crate::client::remote_signer::{RemoteSigner, SharedSecret};
 use crate::client::rpc::query_wasm_code_hash;
 use crate::client::tx::signing::{
-    default_sign, init_validator_signing_data, SigningTxData,
+    default_sign, init_validator_signing_data, with_external_signer,
+    SigningTxData,
 };
 use crate::client::tx::tx::ProcessTxResponse;
 use crate::config::TendermintMode;
@@ -188,6 +190,19 @@ pub async fn sign<N: Namada>(
                 with_hw_data,
             )
             .await?;
+    } else if let Some(address) = args.remote_signer {
+        // Request the signatures that cannot be made with the wallet from the
+        // remote signer
+        let signer = RemoteSigner::new(address, SharedSecret::from_env()?);
+        context
+            .sign(
+                tx,
+                args,
+                signing_data,
+                with_external_signer::<RemoteSigner>,
+                &signer,
+            )
+            .await?;
     } else {
         // Otherwise sign without a backup procedure
         context
@@ -295,6 +310,26 @@ where
     Ok(())
 }
 
+pub async fn submit_grant_fee_allowance<N: Namada>(
+    namada: &N,
+    args: args::GrantFeeAllowance,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_init_account<N: Namada>(
     namada: &N,
     args: args::TxInitAccount,
@@ -427,12 +462,12 @@ pub async fn submit_change_consensus_key(
     .await?;
 
     tx::prepare_tx(
-        namada.client(),
+        namada,
         &tx_args,
         &mut tx,
         unshield,
         fee_amount,
-        signing_data.fee_payer.clone(),
+        &signing_data,
     )
     .await?;
 
@@ -683,7 +718,9 @@ pub async fn submit_become_validator(
         scheme,
     )
     .unwrap();
-    let protocol_sk = validator_keys.get_protocol_keypair();
+    let protocol_sk = validator_keys
+        .get_protocol_keypair()
+        .expect("The generated validator keys should hold the protocol key");
     let protocol_key = protocol_sk.to_public();
 
     // Store the protocol key in the wallet so that we can sign the tx with it
@@ -766,12 +803,12 @@ pub async fn submit_become_validator(
     .await?;
 
     tx::prepare_tx(
-        namada.client(),
+        namada,
         &tx_args,
         &mut tx,
         unshield,
         fee_amount,
-        signing_data.fee_payer.clone(),
+        &signing_data,
     )
     .await?;
 
@@ -888,6 +925,7 @@ pub async fn submit_init_validator(
             tx_code_path: tx_init_account_code_path,
             public_keys: account_keys,
             threshold,
+            vesting: None,
         },
     )
     .await?;
@@ -1398,6 +1436,26 @@ where
     Ok(())
 }
 
+pub async fn submit_cancel_unbond<N: Namada>(
+    namada: &N,
+    args: args::CancelUnbond,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_claim_rewards<N: Namada>(
     namada: &N,
     args: args::ClaimRewards,
@@ -1418,6 +1476,46 @@ where
     Ok(())
 }
 
+pub async fn submit_change_auto_compound<N: Namada>(
+    namada: &N,
+    args: args::ChangeAutoCompound,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_change_reward_recipient<N: Namada>(
+    namada: &N,
+    args: args::ChangeRewardRecipient,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_redelegate<N: Namada>(
     namada: &N,
     args: args::Redelegate,
@@ -1438,6 +1536,46 @@ where
     Ok(())
 }
 
+pub async fn submit_tokenize_bond<N: Namada>(
+    namada: &N,
+    args: args::TokenizeBond,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_redeem_shares<N: Namada>(
+    namada: &N,
+    args: args::RedeemShares,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_validator_commission_change<N: Namada>(
     namada: &N,
     args: args::CommissionRateChange,
@@ -1578,6 +1716,46 @@ where
     Ok(())
 }
 
+pub async fn submit_claim_pgf_stream<N: Namada>(
+    namada: &N,
+    args: args::ClaimPgfStream,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_cancel_pgf_stream<N: Namada>(
+    namada: &N,
+    args: args::CancelPgfStream,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada::ledger::queries::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx);
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 /// Save accounts initialized from a tx into the wallet, if any.
 pub async fn save_initialized_accounts(
     namada: &impl Namada,
@@ -318,6 +318,8 @@ pub struct Parameters {
     pub fee_unshielding_gas_limit: u64,
     /// Fee unshielding descriptions limit
     pub fee_unshielding_descriptions_limit: u64,
+    /// Share of the fees paid at the base gas price that is burned
+    pub fee_burn_rate: Dec,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: BTreeMap<Address, token::Amount>,
 }
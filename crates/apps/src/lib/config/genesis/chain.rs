@@ -292,6 +292,7 @@ impl Finalized {
             max_signatures_per_transaction,
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
+            fee_burn_rate,
             max_block_gas,
             minimum_gas_price,
             max_tx_bytes,
@@ -338,6 +339,7 @@ impl Finalized {
             max_signatures_per_transaction,
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
+            fee_burn_rate,
             max_block_gas,
             minimum_gas_price: minimum_gas_price
                 .iter()
@@ -403,9 +405,11 @@ impl Finalized {
             min_proposal_fund,
             max_proposal_code_size,
             min_proposal_voting_period,
+            min_expedited_proposal_voting_period,
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
+            forfeited_deposit_target,
         } = self.parameters.gov_params.clone();
         namada::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -414,6 +418,8 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             min_proposal_voting_period,
+            min_expedited_proposal_voting_period,
+            forfeited_deposit_target,
         }
     }
 
@@ -654,6 +660,7 @@ pub struct FinalizedTransactions {
     pub established_account: Option<Vec<FinalizedEstablishedAccountTx>>,
     pub validator_account: Option<Vec<FinalizedValidatorAccountTx>>,
     pub bond: Option<Vec<transactions::BondTx<Validated>>>,
+    pub vesting: Option<Vec<transactions::VestingTx>>,
 }
 
 impl FinalizedTransactions {
@@ -664,6 +671,7 @@ impl FinalizedTransactions {
             established_account,
             validator_account,
             bond,
+            vesting,
         } = transactions;
         let established_account = established_account.map(|txs| {
             txs.into_iter()
@@ -682,6 +690,7 @@ impl FinalizedTransactions {
             established_account,
             validator_account,
             bond,
+            vesting,
         }
     }
 
@@ -15,6 +15,7 @@ use namada::core::token::{
 use namada::eth_bridge::storage::parameters::{
     Contracts, Erc20WhitelistEntry, MinimumConfirmations,
 };
+use namada::governance::parameters::ForfeitedDepositTarget;
 use namada::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -298,6 +299,10 @@ pub struct ChainParams<T: TemplateValidation> {
     pub fee_unshielding_gas_limit: u64,
     /// Fee unshielding descriptions limit
     pub fee_unshielding_descriptions_limit: u64,
+    /// Share of the fees paid at the base gas price that is burned. Defaults
+    /// to zero, i.e. no fees are burned
+    #[serde(default)]
+    pub fee_burn_rate: Dec,
     /// Map of the cost per gas unit for every token allowed for fee payment
     pub minimum_gas_price: T::GasMinimums,
 }
@@ -321,8 +326,15 @@ impl ChainParams<Unvalidated> {
             max_block_gas,
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
+            fee_burn_rate,
             minimum_gas_price,
         } = self;
+        if fee_burn_rate.is_negative() || fee_burn_rate > Dec::one() {
+            return Err(eyre::eyre!(
+                "The fee burn rate {} must be between 0 and 1",
+                fee_burn_rate
+            ));
+        }
         let mut min_gas_prices = BTreeMap::default();
         for (token, amount) in minimum_gas_price.into_iter() {
             let denom = if let Some(TokenConfig { denom, .. }) =
@@ -366,6 +378,7 @@ impl ChainParams<Unvalidated> {
             max_block_gas,
             fee_unshielding_gas_limit,
             fee_unshielding_descriptions_limit,
+            fee_burn_rate,
             minimum_gas_price: min_gas_prices,
         })
     }
@@ -441,12 +454,17 @@ pub struct GovernanceParams {
     pub max_proposal_code_size: u64,
     /// Minimum proposal period length in epochs
     pub min_proposal_voting_period: u64,
+    /// Minimum expedited proposal period length in epochs
+    pub min_expedited_proposal_voting_period: u64,
     /// Maximum proposal period length in epochs
     pub max_proposal_period: u64,
     /// Maximum number of characters in the proposal content
     pub max_proposal_content_size: u64,
     /// Minimum number of epoch between end and grace epoch
     pub min_proposal_grace_epochs: u64,
+    /// Where the deposits of vetoed proposals and of proposals that didn't
+    /// reach quorum are sent
+    pub forfeited_deposit_target: ForfeitedDepositTarget,
 }
 
 #[derive(
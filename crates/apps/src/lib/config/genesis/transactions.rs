@@ -10,13 +10,14 @@ use itertools::{Either, Itertools};
 use ledger_namada_rs::NamadaApp;
 use ledger_transport_hid::hidapi::HidApi;
 use ledger_transport_hid::TransportNativeHID;
-use namada::account::AccountPublicKeysMap;
+use namada::account::{AccountPublicKeysMap, VestingSchedule};
 use namada::core::address::{Address, EstablishedAddress};
 use namada::core::chain::ChainId;
 use namada::core::dec::Dec;
 use namada::core::key::{
     common, ed25519, RefTo, SerializeWithBorsh, SigScheme,
 };
+use namada::core::storage::Epoch;
 use namada::core::string_encoding::StringEncoded;
 use namada::core::time::DateTimeUtc;
 use namada::core::token;
@@ -30,7 +31,7 @@ use namada::tx::{
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
-use namada_sdk::args::Tx as TxArgs;
+use namada_sdk::args::{default_gas_limit_multiplier, Tx as TxArgs};
 use namada_sdk::signing::{sign_tx, SigningTxData};
 use namada_sdk::tx::{TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM};
 use namada_sdk::wallet::alias::Alias;
@@ -50,6 +51,11 @@ use crate::wallet::CliWalletUtils;
 /// Dummy chain id used to sign [`Tx`] objects at pre-genesis.
 const NAMADA_GENESIS_TX_CHAIN_ID: &str = "namada-genesis";
 
+/// Message to be passed to `.expect()` calls on the protocol key of a
+/// pre-genesis validator wallet, which is always generated with the wallet.
+const PRE_GENESIS_PROTOCOL_KEY_EXPECT_MSG: &str =
+    "The pre-genesis validator wallet should hold the protocol key";
+
 /// Helper trait to fetch tx data to sign.
 pub trait TxToSign {
     /// Return tx data to sign.
@@ -84,7 +90,10 @@ fn get_tx_args(use_device: bool) -> TxArgs {
         wrapper_fee_payer: None,
         fee_token: genesis_fee_token_address(),
         fee_unshield: None,
-        gas_limit: Default::default(),
+        fee_granter: None,
+        sequence: None,
+        gas_limit: Some(Default::default()),
+        gas_limit_multiplier: default_gas_limit_multiplier(),
         expiration: None,
         disposable_signing_key: false,
         chain_id: None,
@@ -94,6 +103,7 @@ fn get_tx_args(use_device: bool) -> TxArgs {
         password: None,
         memo: None,
         use_device,
+        remote_signer: None,
     }
 }
 
@@ -172,6 +182,7 @@ pub async fn sign_txs(
         established_account,
         validator_account,
         bond,
+        vesting,
     } = txs;
 
     // Sign bond txs
@@ -224,6 +235,7 @@ pub async fn sign_txs(
         established_account,
         validator_account,
         bond,
+        vesting,
     }
 }
 
@@ -295,7 +307,8 @@ pub fn init_validator(
             validator_wallet
                 .store
                 .validator_keys
-                .protocol_keypair
+                .get_protocol_keypair()
+                .expect(PRE_GENESIS_PROTOCOL_KEY_EXPECT_MSG)
                 .ref_to(),
         ),
         tendermint_node_key: StringEncoded::new(
@@ -372,7 +385,11 @@ pub async fn sign_validator_account_tx(
                 sign_key(&unsigned_tx, &validator_wallet.consensus_key);
             let protocol_key_sig = sign_key(
                 &unsigned_tx,
-                &validator_wallet.store.validator_keys.protocol_keypair,
+                validator_wallet
+                    .store
+                    .validator_keys
+                    .get_protocol_keypair()
+                    .expect(PRE_GENESIS_PROTOCOL_KEY_EXPECT_MSG),
             );
             let eth_hot_key_sig =
                 sign_key(&unsigned_tx, &validator_wallet.eth_hot_key);
@@ -465,6 +482,7 @@ pub struct Transactions<T: TemplateValidation> {
     pub established_account: Option<Vec<EstablishedAccountTx>>,
     pub validator_account: Option<Vec<SignedValidatorAccountTx>>,
     pub bond: Option<Vec<T::BondTx>>,
+    pub vesting: Option<Vec<VestingTx>>,
 }
 
 impl<T: TemplateValidation> Transactions<T> {
@@ -503,6 +521,17 @@ impl<T: TemplateValidation> Transactions<T> {
             })
             .or(other.bond)
             .map(|txs| txs.into_iter().sorted().dedup().collect());
+        self.vesting = self
+            .vesting
+            .take()
+            .map(|mut txs| {
+                if let Some(new_txs) = other.vesting.as_mut() {
+                    txs.append(new_txs);
+                }
+                txs
+            })
+            .or(other.vesting)
+            .map(|txs| txs.into_iter().sorted().dedup().collect());
     }
 }
 
@@ -512,6 +541,7 @@ impl<T: TemplateValidation> Default for Transactions<T> {
             established_account: None,
             validator_account: None,
             bond: None,
+            vesting: None,
         }
     }
 }
@@ -563,6 +593,7 @@ pub struct UnsignedTransactions {
     pub established_account: Option<Vec<EstablishedAccountTx>>,
     pub validator_account: Option<Vec<UnsignedValidatorAccountTx>>,
     pub bond: Option<Vec<BondTx<Unvalidated>>>,
+    pub vesting: Option<Vec<VestingTx>>,
 }
 
 pub type UnsignedValidatorAccountTx =
@@ -696,6 +727,52 @@ impl DeriveEstablishedAddress for EstablishedAccountTx {
     const SALT: &'static str = "established-account-tx";
 }
 
+/// A vesting schedule locking the native tokens of an established account
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub struct VestingTx {
+    /// The address of the established account
+    pub address: StringEncoded<EstablishedAddress>,
+    /// The amount of native token locked by the schedule
+    pub amount: token::DenominatedAmount,
+    /// The epoch from which the tokens start unlocking
+    pub start_epoch: Epoch,
+    /// The epoch before which none of the tokens are unlocked
+    #[serde(default)]
+    pub cliff_epoch: Option<Epoch>,
+    /// The epoch from which all the tokens are unlocked
+    pub end_epoch: Epoch,
+}
+
+impl VestingTx {
+    /// Get the vesting schedule of the account, if the amount can be
+    /// represented in the native token denomination
+    pub fn schedule(&self) -> Option<VestingSchedule> {
+        let amount = self
+            .amount
+            .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+            .ok()?
+            .amount();
+        Some(VestingSchedule {
+            amount,
+            start_epoch: self.start_epoch,
+            cliff_epoch: self.cliff_epoch.unwrap_or(self.start_epoch),
+            end_epoch: self.end_epoch,
+        })
+    }
+}
+
 #[derive(
     Clone,
     Debug,
@@ -1015,6 +1092,7 @@ pub fn validate(
         ref established_account,
         ref validator_account,
         bond,
+        ref vesting,
     } = transactions;
 
     if let Some(txs) = established_account {
@@ -1030,6 +1108,25 @@ pub fn validate(
         }
     }
 
+    if let Some(txs) = vesting {
+        let established_account_vps: BTreeMap<Address, &str> =
+            established_account
+                .iter()
+                .flatten()
+                .map(|tx| (tx.derive_address(), tx.vp.as_str()))
+                .collect();
+        let mut vesting_accounts = BTreeSet::new();
+        for tx in txs {
+            if !validate_vesting(
+                tx,
+                &established_account_vps,
+                &mut vesting_accounts,
+            ) {
+                is_valid = false;
+            }
+        }
+    }
+
     if let Some(txs) = validator_account {
         for tx in txs {
             if !validate_validator_account(
@@ -1131,6 +1228,7 @@ pub fn validate(
             },
         ),
         bond: validated_bonds,
+        vesting: transactions.vesting,
     })
 }
 
@@ -1330,6 +1428,57 @@ pub fn validate_established_account(
     is_valid
 }
 
+pub fn validate_vesting(
+    tx: &VestingTx,
+    established_account_vps: &BTreeMap<Address, &str>,
+    vesting_accounts: &mut BTreeSet<Address>,
+) -> bool {
+    let mut is_valid = true;
+
+    let address = Address::Established(tx.address.raw.clone());
+    match established_account_vps.get(&address) {
+        Some(&utils::VP_VESTING) => {}
+        Some(vp) => {
+            eprintln!(
+                "A `vesting` tx address \"{address}\" has the \"{vp}\" \
+                 validity predicate, but the vesting schedule is only \
+                 enforced by \"{}\".",
+                utils::VP_VESTING
+            );
+            is_valid = false;
+        }
+        None => {
+            eprintln!(
+                "A `vesting` tx address \"{address}\" is not an established \
+                 account."
+            );
+            is_valid = false;
+        }
+    }
+    if !vesting_accounts.insert(address.clone()) {
+        eprintln!("A duplicate address \"{address}\" found in a `vesting` tx.");
+        is_valid = false;
+    }
+    match tx.schedule() {
+        Some(schedule) if schedule.is_valid() => {}
+        Some(_) => {
+            eprintln!(
+                "The `vesting` tx of \"{address}\" must lock some tokens and \
+                 its start, cliff and end epochs must be ordered."
+            );
+            is_valid = false;
+        }
+        None => {
+            eprintln!(
+                "The `vesting` tx amount of \"{address}\" has too many \
+                 decimal places for the native token."
+            );
+            is_valid = false;
+        }
+    }
+    is_valid
+}
+
 pub fn validate_validator_account(
     signed_tx: &SignedValidatorAccountTx,
     vps: Option<&ValidityPredicates>,
@@ -1548,3 +1697,66 @@ impl From<&ValidatorAccountTx<SignedPk>> for UnsignedValidatorAccountTx {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada::core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+
+    use super::*;
+
+    /// A vesting tx locking tokens of the given established account
+    fn vesting_tx(address: &Address) -> VestingTx {
+        let Address::Established(address) = address else {
+            panic!("Vesting accounts must be established accounts");
+        };
+        VestingTx {
+            address: StringEncoded::new(address.clone()),
+            amount: DenominatedAmount::new(
+                token::Amount::native_whole(100),
+                NATIVE_MAX_DECIMAL_PLACES.into(),
+            ),
+            start_epoch: Epoch(0),
+            cliff_epoch: None,
+            end_epoch: Epoch(10),
+        }
+    }
+
+    /// Test that a vesting schedule is only accepted on the established
+    /// accounts with the vesting VP, which enforces it.
+    #[test]
+    fn test_validate_vesting() {
+        let vesting_account = established_address_1();
+        let user_account = established_address_2();
+        let established_account_vps = BTreeMap::from([
+            (vesting_account.clone(), utils::VP_VESTING),
+            (user_account.clone(), utils::VP_USER),
+        ]);
+        let mut vesting_accounts = BTreeSet::new();
+
+        assert!(validate_vesting(
+            &vesting_tx(&vesting_account),
+            &established_account_vps,
+            &mut vesting_accounts,
+        ));
+        // The schedule of an account with another VP is not enforced
+        assert!(!validate_vesting(
+            &vesting_tx(&user_account),
+            &established_account_vps,
+            &mut vesting_accounts,
+        ));
+        // The account must be initialized at genesis
+        assert!(!validate_vesting(
+            &vesting_tx(&established_address_3()),
+            &established_account_vps,
+            &mut vesting_accounts,
+        ));
+        // An account may only have one schedule
+        assert!(!validate_vesting(
+            &vesting_tx(&vesting_account),
+            &established_account_vps,
+            &mut vesting_accounts,
+        ));
+    }
+}
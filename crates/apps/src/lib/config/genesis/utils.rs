@@ -17,6 +17,9 @@ use crate::wallet::CliWalletUtils;
 /// Validity predicaty assigned to established accounts.
 pub const VP_USER: &str = "vp_user";
 
+/// Validity predicate enforcing the vesting schedule of established accounts.
+pub const VP_VESTING: &str = "vp_vesting";
+
 pub fn read_toml<T: DeserializeOwned>(
     path: &Path,
     which_file: &str,
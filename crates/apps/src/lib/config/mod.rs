@@ -8,6 +8,7 @@ pub mod utils;
 use std::collections::HashMap;
 use std::fs::{create_dir_all, File};
 use std::io::Write;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 
 use directories::ProjectDirs;
@@ -35,6 +36,8 @@ pub const FILENAME: &str = "config.toml";
 pub const COMETBFT_DIR: &str = "cometbft";
 /// Chain-specific Namada DB. Nested in chain dirs.
 pub const DB_DIR: &str = "db";
+/// Chain-specific state-sync snapshots. Nested in chain dirs.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Config {
@@ -46,6 +49,28 @@ pub struct Config {
 pub struct ValidatorLocalConfig {
     pub accepted_gas_tokens:
         HashMap<namada::core::address::Address, namada::core::token::Amount>,
+    /// When set, the signatures of the validator's protocol key are
+    /// requested from the remote signer listening at this address, which
+    /// must hold the protocol key. The protocol key may then be omitted from
+    /// the validator's wallet, as only its public key is read from storage.
+    /// The secret shared with the signer is read from the
+    /// `NAMADA_REMOTE_SIGNER_SECRET` env var.
+    #[serde(default)]
+    pub remote_signer: Option<SocketAddr>,
+}
+
+/// The policy used by a block proposer to order the wrapper txs of the mempool
+#[derive(
+    Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq,
+)]
+pub enum TxOrdering {
+    /// Keep the order in which the txs were handed over by the mempool
+    Mempool,
+    /// Order the txs by decreasing effective gas price, normalized by the
+    /// minimum gas price of their fee token, keeping the relative order of
+    /// the txs of each fee payer
+    #[default]
+    FeePriority,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -111,6 +136,38 @@ pub struct Shell {
     /// When set, will limit the how many block heights in the past can the
     /// storage be queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// When set, events emitted by `FinalizeBlock` calls are pruned from the
+    /// persistent event store once they are older than this many blocks.
+    /// When not set, events are kept indefinitely.
+    pub event_store_retention_blocks: Option<u64>,
+    /// The order in which a block proposer includes the wrapper txs of the
+    /// mempool in its proposals.
+    #[serde(default)]
+    pub tx_ordering: TxOrdering,
+    /// When set, the per-key storage diffs of every applied tx are persisted
+    /// with the block results, to be queried by the block height and tx hash.
+    #[serde(default)]
+    pub index_storage_diffs: bool,
+    /// When set, every version of the storage key-vals is archived, such
+    /// that the storage can be queried at any height since the node started
+    /// archiving. The `storage_read_past_height_limit` only applies to the
+    /// heights that are not archived.
+    #[serde(default)]
+    pub archive_mode: bool,
+    /// When set, a state-sync snapshot of the state is produced at every
+    /// block height that is a multiple of this interval, to be served to the
+    /// new nodes joining the network. When not set, no snapshots are produced.
+    #[serde(default)]
+    pub snapshot_interval: Option<u64>,
+    /// The maximum number of state-sync snapshots kept on disk.
+    /// When not set, only the latest snapshot is kept.
+    #[serde(default)]
+    pub snapshots_to_keep: Option<u64>,
+    /// When set, the node serves long-polled event subscriptions over HTTP
+    /// at this address. A subscription that has caught up with the chain is
+    /// only answered once a new block is committed.
+    #[serde(default)]
+    pub events_endpoint: Option<SocketAddr>,
     /// Use the [`Ledger::db_dir()`] method to read the value.
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
@@ -143,6 +200,13 @@ impl Ledger {
                 tx_wasm_compilation_cache_bytes: None,
                 // Default corresponds to 1 hour of past blocks at 1 block/sec
                 storage_read_past_height_limit: Some(3600),
+                event_store_retention_blocks: None,
+                tx_ordering: TxOrdering::default(),
+                index_storage_diffs: false,
+                archive_mode: false,
+                snapshot_interval: None,
+                snapshots_to_keep: None,
+                events_endpoint: None,
                 db_dir: DB_DIR.into(),
                 cometbft_dir: COMETBFT_DIR.into(),
                 action_at_height: None,
@@ -181,6 +245,11 @@ impl Shell {
             .join(chain_id.as_str())
             .join(&self.cometbft_dir)
     }
+
+    /// Get the directory path to the state-sync snapshots
+    pub fn snapshots_dir(&self, chain_id: &ChainId) -> PathBuf {
+        self.base_dir.join(chain_id.as_str()).join(SNAPSHOTS_DIR)
+    }
 }
 
 #[derive(Error, Debug)]
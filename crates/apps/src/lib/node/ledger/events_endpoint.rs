@@ -0,0 +1,158 @@
+//! An HTTP endpoint serving long-polled event subscriptions.
+//!
+//! A subscription that has caught up with the chain is held by the endpoint
+//! until the shell commits a new block, rather than being answered right away
+//! and polled again by the client. The subscriptions are served outside of
+//! the ABCI info connection, such that the pending ones do not hold back the
+//! other queries forwarded by CometBFT.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use borsh::BorshDeserialize;
+use namada::core::storage::BlockHeight;
+use namada_sdk::queries::{EventBatch, RPC, SUBSCRIBE_ENDPOINT};
+use tokio::sync::{oneshot, watch};
+use tokio::time::{self, Instant};
+use warp::http::StatusCode;
+use warp::reply::{self, Reply, Response};
+use warp::Filter;
+
+use crate::facade::tendermint::v0_37::abci::{
+    request, Request as Req, Response as Resp,
+};
+use crate::node::ledger::shims::abcipp_shim::ShellSender;
+
+/// The maximum duration for which a subscription that has caught up with the
+/// chain waits for a new block before being answered with an empty batch.
+const LONG_POLL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Starts a [`warp::Server`] serving the event subscriptions with the given
+/// shell, waiting for the heights of the blocks it commits. It shuts down if a
+/// signal is sent on the `abort_recv` channel, answering the pending
+/// subscriptions right away.
+pub async fn serve(
+    listen_addr: SocketAddr,
+    shell_send: ShellSender,
+    committed_heights: watch::Receiver<BlockHeight>,
+    abort_recv: oneshot::Receiver<()>,
+) {
+    let (shutdown_send, shutdown_recv) = watch::channel(());
+    let subscribe = warp::post()
+        .and(warp::path(SUBSCRIBE_ENDPOINT))
+        .and(warp::path::param::<u64>())
+        .and(warp::path::end())
+        .and(warp::body::bytes())
+        .then(move |cursor: u64, query: bytes::Bytes| {
+            let shell_send = shell_send.clone();
+            let committed_heights = committed_heights.clone();
+            let shutdown_recv = shutdown_recv.clone();
+            async move {
+                match long_poll(
+                    &shell_send,
+                    committed_heights,
+                    shutdown_recv,
+                    BlockHeight(cursor),
+                    query.to_vec(),
+                )
+                .await
+                {
+                    Ok(batch) => Response::new(batch.into()),
+                    Err(err) => {
+                        reply::with_status(err, StatusCode::BAD_REQUEST)
+                            .into_response()
+                    }
+                }
+            }
+        });
+
+    let (_, future) = warp::serve(subscribe).bind_with_graceful_shutdown(
+        listen_addr,
+        async move {
+            tracing::info!(
+                ?listen_addr,
+                "Starting to serve the event subscriptions"
+            );
+            if abort_recv.await.is_err() {
+                tracing::warn!(
+                    "Channel for receiving signal to abort was closed \
+                     abruptly, will abort now"
+                );
+            }
+            shutdown_send.send_replace(());
+            tracing::info!(
+                ?listen_addr,
+                "Stopping serving the event subscriptions"
+            );
+        },
+    );
+    future.await
+}
+
+/// Query the shell for the events matching the given query past the cursor.
+/// Once the subscription has caught up with the chain, wait for a new block
+/// to be committed before querying the shell again, until the long-poll times
+/// out or the endpoint shuts down. Returns the Borsh encoded [`EventBatch`].
+async fn long_poll(
+    shell_send: &ShellSender,
+    mut committed_heights: watch::Receiver<BlockHeight>,
+    mut shutdown_recv: watch::Receiver<()>,
+    cursor: BlockHeight,
+    query: Vec<u8>,
+) -> Result<Vec<u8>, String> {
+    let deadline = Instant::now() + LONG_POLL_TIMEOUT;
+    loop {
+        // Mark the last committed block as seen before querying the shell,
+        // such that a block committed in the meantime is not missed
+        committed_heights.borrow_and_update();
+        let batch = query_shell(shell_send, cursor, query.clone()).await?;
+        let EventBatch {
+            events,
+            next_height,
+            last_height,
+        } = EventBatch::try_from_slice(&batch)
+            .map_err(|err| format!("Invalid events batch: {err}"))?;
+        if !events.is_empty() || next_height <= last_height {
+            return Ok(batch);
+        }
+        tokio::select! {
+            committed = time::timeout_at(
+                deadline,
+                committed_heights.changed(),
+            ) => {
+                if !matches!(committed, Ok(Ok(()))) {
+                    // Timed out, or the shell has stopped
+                    return Ok(batch);
+                }
+            }
+            _ = shutdown_recv.changed() => return Ok(batch),
+        }
+    }
+}
+
+/// Forward a subscription query to the shell, returning its response data
+async fn query_shell(
+    shell_send: &ShellSender,
+    cursor: BlockHeight,
+    query: Vec<u8>,
+) -> Result<Vec<u8>, String> {
+    let request = request::Query {
+        data: query.into(),
+        path: RPC.shell().subscribe_path(&cursor),
+        height: 0_u32.into(),
+        prove: false,
+    };
+    let (resp_send, resp_recv) = oneshot::channel();
+    shell_send
+        .send((Req::Query(request), resp_send))
+        .map_err(|_| "The shell is no longer running".to_string())?;
+    match resp_recv.await {
+        Ok(Ok(Resp::Query(response))) if response.code.is_ok() => {
+            Ok(response.value.to_vec())
+        }
+        Ok(Ok(Resp::Query(response))) => Err(response.info),
+        Ok(Ok(resp)) => Err(format!("Unexpected response {resp:?}")),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err("The shell is no longer running".to_string()),
+    }
+}
@@ -1,6 +1,8 @@
 mod abortable;
 mod broadcaster;
 pub mod ethereum_oracle;
+mod events_endpoint;
+mod protocol_signer;
 pub mod shell;
 pub mod shims;
 pub mod storage;
@@ -136,7 +138,9 @@ impl Shell {
             }
             Request::Commit => {
                 tracing::debug!("Request Commit");
-                Ok(Response::Commit(self.commit()))
+                let response = self.commit();
+                self.take_snapshot();
+                Ok(Response::Commit(response))
             }
             Request::Flush => Ok(Response::Flush),
             Request::Echo(msg) => Ok(Response::Echo(response::Echo {
@@ -151,16 +155,16 @@ impl Shell {
                 Ok(Response::CheckTx(self.mempool_validate(&tx.tx, r#type)))
             }
             Request::ListSnapshots => {
-                Ok(Response::ListSnapshots(Default::default()))
+                Ok(Response::ListSnapshots(self.list_snapshots()))
             }
-            Request::OfferSnapshot(_) => {
-                Ok(Response::OfferSnapshot(Default::default()))
+            Request::OfferSnapshot(req) => {
+                Ok(Response::OfferSnapshot(self.offer_snapshot(req)))
             }
-            Request::LoadSnapshotChunk(_) => {
-                Ok(Response::LoadSnapshotChunk(Default::default()))
+            Request::LoadSnapshotChunk(req) => {
+                Ok(Response::LoadSnapshotChunk(self.load_snapshot_chunk(req)))
             }
-            Request::ApplySnapshotChunk(_) => {
-                Ok(Response::ApplySnapshotChunk(Default::default()))
+            Request::ApplySnapshotChunk(req) => {
+                Ok(Response::ApplySnapshotChunk(self.apply_snapshot_chunk(req)))
             }
         }
     }
@@ -359,22 +363,29 @@ async fn run_aux(config: config::Ledger, wasm_dir: PathBuf) {
 
     // Start ABCI server and broadcaster (the latter only if we are a validator
     // node)
-    let (abci, broadcaster, shell_handler) = start_abci_broadcaster_shell(
-        &mut spawner,
-        eth_oracle_channels,
-        wasm_dir,
-        setup_data,
-        config,
-    );
+    let (abci, broadcaster, events_endpoint, shell_handler) =
+        start_abci_broadcaster_shell(
+            &mut spawner,
+            eth_oracle_channels,
+            wasm_dir,
+            setup_data,
+            config,
+        );
 
     // Wait for interrupt signal or abort message
     let aborted = spawner.wait_for_abort().await.child_terminated();
 
     // Wait for all managed tasks to finish.
-    let res = tokio::try_join!(tendermint_node, abci, eth_oracle, broadcaster);
+    let res = tokio::try_join!(
+        tendermint_node,
+        abci,
+        eth_oracle,
+        broadcaster,
+        events_endpoint
+    );
 
     match res {
-        Ok((tendermint_res, abci_res, _, _)) => {
+        Ok((tendermint_res, abci_res, _, _, _)) => {
             // we ignore errors on user-initiated shutdown
             if aborted {
                 if let Err(err) = tendermint_res {
@@ -503,9 +514,9 @@ async fn run_aux_setup(
     }
 }
 
-/// This function spawns an ABCI server and a [`Broadcaster`] into the
-/// asynchronous runtime. Additionally, it executes a shell in
-/// a new OS thread, to drive the ABCI server.
+/// This function spawns an ABCI server, a [`Broadcaster`] and the events
+/// endpoint (if enabled) into the asynchronous runtime. Additionally, it
+/// executes a shell in a new OS thread, to drive the ABCI server.
 fn start_abci_broadcaster_shell(
     spawner: &mut AbortableSpawner,
     eth_oracle: Option<EthereumOracleChannels>,
@@ -515,6 +526,7 @@ fn start_abci_broadcaster_shell(
 ) -> (
     task::JoinHandle<shell::Result<()>>,
     task::JoinHandle<()>,
+    task::JoinHandle<()>,
     thread::JoinHandle<()>,
 ) {
     let rpc_address =
@@ -564,6 +576,7 @@ fn start_abci_broadcaster_shell(
     let tendermint_mode = config.shell.tendermint_mode.clone();
     let proxy_app_address =
         convert_tm_addr_to_socket_addr(&config.cometbft.proxy_app);
+    let events_endpoint_address = config.shell.events_endpoint;
 
     let (shell, abci_service, service_handle) = AbcippShim::new(
         config,
@@ -575,6 +588,33 @@ fn start_abci_broadcaster_shell(
         tx_wasm_compilation_cache,
     );
 
+    // Start the events endpoint, if enabled
+    let events_endpoint = if let Some(address) = events_endpoint_address {
+        let shell_send = abci_service.shell_sender();
+        let committed_heights = shell.committed_heights();
+        let (events_abort_send, events_abort_recv) =
+            tokio::sync::oneshot::channel();
+
+        spawner
+            .spawn_abortable("Events endpoint", move |aborter| async move {
+                events_endpoint::serve(
+                    address,
+                    shell_send,
+                    committed_heights,
+                    events_abort_recv,
+                )
+                .await;
+                tracing::info!("Events endpoint is no longer running.");
+
+                drop(aborter);
+            })
+            .with_cleanup(async move {
+                let _ = events_abort_send.send(());
+            })
+    } else {
+        spawn_dummy_task(())
+    };
+
     // Channel for signalling shut down to ABCI server
     let (abci_abort_send, abci_abort_recv) = tokio::sync::oneshot::channel();
 
@@ -613,7 +653,7 @@ fn start_abci_broadcaster_shell(
         })
         .expect("Must be able to start a thread for the shell");
 
-    (abci, broadcaster, shell_handler)
+    (abci, broadcaster, events_endpoint, shell_handler)
 }
 
 /// Runs the an asynchronous ABCI server with four sub-components for consensus,
@@ -0,0 +1,232 @@
+//! A service signing the vote extensions and protocol txs of a validator
+//! with its protocol key held by a remote signer.
+//!
+//! The requests to the remote signer block until they time out, so they are
+//! served in a separate thread rather than by the shell, and the signed
+//! protocol txs are handed over to the [`Broadcaster`] from there.
+//!
+//! [`Broadcaster`]: super::broadcaster::Broadcaster
+
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread;
+
+use namada::core::chain::ChainId;
+use namada::core::key::common;
+use namada::tx::{Signed, Tx};
+use namada::vote_ext::{bridge_pool_roots, ethereum_events, EthereumTxData};
+use namada_sdk::error::Error;
+use namada_sdk::signing::ProtocolVext;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::client::remote_signer::RemoteSigner;
+
+/// The maximum number of batches of requests waiting to be served. The
+/// batches crafted while the queue is full are dropped, such that a slow
+/// remote signer cannot make the queue grow without bound.
+const QUEUE_LEN: usize = 16;
+
+/// Data to be signed with the protocol key of a validator
+#[derive(Debug, Clone)]
+pub enum SigningRequest {
+    /// A vote extension, to be signed and included in a protocol tx, which
+    /// is then signed too
+    Vext(ProtocolVext),
+    /// A protocol tx to be signed
+    Tx(EthereumTxData),
+}
+
+/// A batch of requests, with the public key of the protocol key to sign
+/// them with
+type Batch = (common::PublicKey, Vec<SigningRequest>);
+
+/// A handle to the service signing the data of a validator with its remote
+/// signer
+#[derive(Debug)]
+pub struct RemoteProtocolSigner {
+    sender: SyncSender<Batch>,
+}
+
+impl RemoteProtocolSigner {
+    /// Start the service in a new thread. The protocol txs signed with the
+    /// given remote signer are sent to the broadcaster.
+    pub fn spawn(
+        signer: RemoteSigner,
+        chain_id: ChainId,
+        broadcast_sender: UnboundedSender<Vec<u8>>,
+    ) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_LEN);
+        thread::Builder::new()
+            .name("remote-protocol-signer".into())
+            .spawn(move || run(signer, chain_id, broadcast_sender, receiver))
+            .expect("Must be able to start a thread for the protocol signer");
+        Self { sender }
+    }
+
+    /// Request the signatures of the given data with the protocol key of the
+    /// given public key, without waiting for them
+    pub fn request(
+        &self,
+        pubkey: common::PublicKey,
+        requests: Vec<SigningRequest>,
+    ) {
+        if requests.is_empty() {
+            return;
+        }
+        match self.sender.try_send((pubkey, requests)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => tracing::warn!(
+                "The remote signer is lagging behind, dropping the protocol \
+                 data to sign"
+            ),
+            Err(TrySendError::Disconnected(_)) => tracing::error!(
+                "The remote protocol signer is no longer running"
+            ),
+        }
+    }
+}
+
+/// Serve the batches of requests until either the shell or the broadcaster
+/// stops
+fn run(
+    signer: RemoteSigner,
+    chain_id: ChainId,
+    broadcast_sender: UnboundedSender<Vec<u8>>,
+    receiver: Receiver<Batch>,
+) {
+    while let Ok((pubkey, requests)) = receiver.recv() {
+        for request in requests {
+            match sign(&signer, &pubkey, &chain_id, request) {
+                Ok(tx) => {
+                    if broadcast_sender.send(tx.to_bytes()).is_err() {
+                        tracing::info!(
+                            "The broadcaster is no longer running, stopping \
+                             the remote protocol signer"
+                        );
+                        return;
+                    }
+                }
+                Err(err) => tracing::error!(
+                    "Unable to sign protocol data with the remote signer: \
+                     {err}"
+                ),
+            }
+        }
+    }
+}
+
+/// Sign the requested data with the remote signer into a protocol tx
+fn sign(
+    signer: &RemoteSigner,
+    pubkey: &common::PublicKey,
+    chain_id: &ChainId,
+    request: SigningRequest,
+) -> Result<Tx, Error> {
+    let protocol_tx = match request {
+        SigningRequest::Vext(vext) => {
+            let sig = signer.sign_vext(pubkey, vext.clone())?;
+            signed_vext_tx(vext, sig, pubkey)?
+        }
+        SigningRequest::Tx(protocol_tx) => protocol_tx,
+    };
+    let tx = protocol_tx.unsigned(pubkey.clone(), chain_id.clone());
+    signer.sign_protocol_tx(pubkey, tx)
+}
+
+/// Include the given vote extension with its signature in a protocol tx,
+/// checking the signature against the given public key
+fn signed_vext_tx(
+    vext: ProtocolVext,
+    sig: common::Signature,
+    pubkey: &common::PublicKey,
+) -> Result<EthereumTxData, Error> {
+    let invalid_sig = |err| {
+        Error::Other(format!(
+            "Invalid vote extension signature from the remote signer: {err}"
+        ))
+    };
+    match vext {
+        ProtocolVext::EthereumEvents(ext) => {
+            let signed = Signed::new_from(ext, sig);
+            signed.verify(pubkey).map_err(invalid_sig)?;
+            Ok(EthereumTxData::EthEventsVext(ethereum_events::SignedVext(
+                signed,
+            )))
+        }
+        ProtocolVext::BridgePoolRoot(ext) => {
+            let signed = Signed::new_from(ext, sig);
+            signed.verify(pubkey).map_err(invalid_sig)?;
+            Ok(EthereumTxData::BridgePoolVext(
+                bridge_pool_roots::SignedVext(signed),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use namada::core::address::testing::established_address_1;
+    use namada::core::key::testing::keypair_1;
+    use namada::core::key::RefTo;
+    use namada::core::storage::BlockHeight;
+    use namada_sdk::io::NullIo;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::client::remote_signer::{serve, SharedSecret};
+    use crate::wallet::CliWalletUtils;
+
+    /// Test that the vote extensions requested to be signed by the remote
+    /// signer are broadcast in validly signed protocol txs.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_remote_protocol_signer() {
+        let protocol_key = keypair_1();
+        let pubkey = protocol_key.ref_to();
+        let secret = SharedSecret::new("secret");
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let signer_secret = secret.clone();
+        tokio::spawn(async move {
+            let wallet = CliWalletUtils::new(PathBuf::new());
+            serve(
+                listener,
+                vec![protocol_key],
+                signer_secret,
+                &wallet,
+                &NullIo,
+            )
+            .await
+        });
+
+        let (broadcast_sender, mut broadcast_receiver) =
+            tokio::sync::mpsc::unbounded_channel();
+        let protocol_signer = RemoteProtocolSigner::spawn(
+            RemoteSigner::new(address, secret),
+            ChainId::default(),
+            broadcast_sender,
+        );
+        let ext = ethereum_events::Vext::empty(
+            BlockHeight(1),
+            established_address_1(),
+        );
+        protocol_signer.request(
+            pubkey.clone(),
+            vec![SigningRequest::Vext(ProtocolVext::EthereumEvents(
+                ext.clone(),
+            ))],
+        );
+
+        let tx_bytes = broadcast_receiver.recv().await.unwrap();
+        let tx = Tx::try_from(tx_bytes.as_slice()).unwrap();
+        assert!(tx.verify_signature(&pubkey, &tx.sechashes()).is_ok());
+        let signed = match EthereumTxData::try_from(&tx).unwrap() {
+            EthereumTxData::EthEventsVext(ethereum_events::SignedVext(
+                signed,
+            )) => signed,
+            data => panic!("Unexpected protocol tx data {data:?}"),
+        };
+        assert_eq!(signed.data, ext);
+        assert!(signed.verify(&pubkey).is_ok());
+    }
+}
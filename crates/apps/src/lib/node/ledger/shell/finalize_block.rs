@@ -144,7 +144,13 @@ where
 
         // Tracks the accepted transactions
         self.state.in_mem_mut().block.results = BlockResults::default();
+        // Tracks the storage diffs of the applied txs, only persisted with the
+        // block when enabled in the config
+        self.state.in_mem_mut().block.storage_diffs = Default::default();
         let mut changed_keys = BTreeSet::new();
+        // The gas allocated to the wrappers of this block, which drives the
+        // update of the base gas price
+        let mut block_gas_used: u64 = 0;
         for (tx_index, processed_tx) in req.txs.iter().enumerate() {
             let tx = if let Ok(tx) = Tx::try_from(processed_tx.tx.as_ref()) {
                 tx
@@ -224,6 +230,8 @@ where
             ) = match &tx_header.tx_type {
                 TxType::Wrapper(wrapper) => {
                     stats.increment_wrapper_txs();
+                    block_gas_used = block_gas_used
+                        .saturating_add(u64::from(wrapper.gas_limit));
                     let tx_event = new_tx_event(&tx, height.0);
                     let gas_meter = TxGasMeter::new(wrapper.gas_limit);
                     (
@@ -249,13 +257,15 @@ where
 
                     match inner {
                         DecryptedTx::Decrypted => {
-                            if let Some(code_sec) = tx
-                                .get_section(tx.code_sechash())
-                                .and_then(|x| Section::code_sec(x.as_ref()))
-                            {
-                                stats.increment_tx_type(
-                                    code_sec.code.hash().to_string(),
-                                );
+                            for entry in tx.batch() {
+                                if let Some(code_sec) = tx
+                                    .get_section(&entry.code_hash)
+                                    .and_then(|x| Section::code_sec(x.as_ref()))
+                                {
+                                    stats.increment_tx_type(
+                                        code_sec.code.hash().to_string(),
+                                    );
+                                }
                             }
                         }
                         DecryptedTx::Undecryptable => {
@@ -369,6 +379,7 @@ where
                         &mut self.state,
                         &mut self.vp_wasm_cache,
                         &mut self.tx_wasm_cache,
+                        self.index_storage_diffs,
                         wrapper_args.as_mut(),
                     )
                 })
@@ -411,6 +422,18 @@ where
                             }
                             changed_keys
                                 .extend(result.changed_keys.iter().cloned());
+                            if self.index_storage_diffs
+                                && embedding_wrapper.is_some()
+                            {
+                                self.state
+                                    .in_mem_mut()
+                                    .block
+                                    .storage_diffs
+                                    .insert(
+                                        tx.raw_header_hash(),
+                                        result.storage_diffs.clone(),
+                                    );
+                            }
                             stats.increment_successful_txs();
                             if let Some(wrapper) = embedding_wrapper {
                                 self.commit_inner_tx_hash(wrapper);
@@ -547,6 +570,9 @@ where
         tracing::info!("{}", stats);
         tracing::info!("{}", stats.format_tx_executed());
 
+        // Update the base gas price for the next block
+        parameters::update_base_gas_price(&mut self.state, block_gas_used)?;
+
         // Update the MASP commitment tree anchor if the tree was updated
         let tree_key = token::storage_key::masp_commitment_tree_key();
         if let Some(StorageModification::Write { value }) =
@@ -573,6 +599,7 @@ where
         )?;
 
         self.event_log_mut().log_events(response.events.clone());
+        self.pending_events = response.events.clone();
         tracing::debug!("End finalize_block {height} of epoch {current_epoch}");
 
         Ok(response)
@@ -779,7 +806,7 @@ mod test_finalize_block {
     use namada::replay_protection;
     use namada::tendermint::abci::types::{Misbehavior, MisbehaviorKind};
     use namada::token::{Amount, DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
-    use namada::tx::data::Fee;
+    use namada::tx::data::{Fee, StorageDiff};
     use namada::tx::{Code, Data, Signature};
     use namada::vote_ext::ethereum_events;
     use namada_sdk::eth_bridge::MinimumConfirmations;
@@ -1521,6 +1548,7 @@ mod test_finalize_block {
                 voting_start_epoch: Epoch::default(),
                 voting_end_epoch: Epoch::default().next(),
                 grace_epoch: Epoch::default().next(),
+                expedited: false,
                 r#type: ProposalType::Default(None),
             };
 
@@ -2858,6 +2886,98 @@ mod test_finalize_block {
         }
     }
 
+    /// Test that the storage diffs of the applied txs are persisted with the
+    /// block results when indexing them is enabled
+    #[test]
+    fn test_index_storage_diffs() {
+        let (mut shell, _, _, _) = setup();
+        shell.index_storage_diffs = true;
+        let keypair = gen_keypair();
+
+        let mut wrapper =
+            Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                Fee {
+                    amount_per_gas_unit: DenominatedAmount::native(1.into()),
+                    token: shell.state.in_mem().native_token.clone(),
+                },
+                keypair.ref_to(),
+                Epoch(0),
+                GAS_LIMIT_MULTIPLIER.into(),
+                None,
+            ))));
+        wrapper.header.chain_id = shell.chain_id.clone();
+        wrapper.set_code(Code::new(
+            TestWasms::TxWriteStorageKey.read_bytes(),
+            None,
+        ));
+        // A key without an address doesn't trigger any VP
+        let key = Key::parse("storage_diff").unwrap();
+        wrapper.set_data(Data::new(
+            borsh::to_vec(&TxWriteData {
+                key: key.clone(),
+                value: "test".as_bytes().to_owned(),
+            })
+            .unwrap(),
+        ));
+        wrapper.add_section(Section::Signature(Signature::new(
+            wrapper.sechashes(),
+            [(0, keypair)].into_iter().collect(),
+            None,
+        )));
+        shell.enqueue_tx(wrapper.clone(), GAS_LIMIT_MULTIPLIER.into());
+        let mut inner = wrapper;
+        inner.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
+
+        let event = &shell
+            .finalize_block(FinalizeBlock {
+                txs: vec![ProcessedTx {
+                    tx: inner.to_bytes().into(),
+                    result: TxResult {
+                        code: ResultCode::Ok.into(),
+                        info: "".into(),
+                    },
+                }],
+                ..Default::default()
+            })
+            .expect("Test failed")[0];
+        assert_eq!(event.event_type.to_string(), String::from("applied"));
+        let code = event.attributes.get("code").unwrap().as_str();
+        assert_eq!(code, String::from(ResultCode::Ok).as_str());
+        shell.commit();
+
+        let height = shell.state.in_mem().block.height;
+        let storage_diffs = shell
+            .state
+            .db()
+            .read_storage_diffs(height)
+            .unwrap()
+            .expect("The storage diffs of the block should be persisted");
+        let tx_diffs = storage_diffs
+            .get(&inner.raw_header_hash())
+            .expect("The storage diffs of the tx should be persisted");
+        assert_eq!(
+            tx_diffs.get(&key),
+            Some(&StorageDiff {
+                old_value: None,
+                new_value: Some("test".as_bytes().to_owned()),
+            })
+        );
+
+        // Without indexing, no storage diffs are persisted
+        shell.index_storage_diffs = false;
+        shell.finalize_block(FinalizeBlock::default()).unwrap();
+        shell.commit();
+        let height = shell.state.in_mem().block.height;
+        assert!(
+            shell
+                .state
+                .db()
+                .read_storage_diffs(height)
+                .unwrap()
+                .is_none()
+        );
+    }
+
     /// Test that if a decrypted transaction fails because of out-of-gas,
     /// undecryptable, invalid signature or wrong section commitment, its hash
     /// is not committed to storage. Also checks that a tx failing for other
@@ -3297,6 +3417,76 @@ mod test_finalize_block {
         )
     }
 
+    /// Test that the base gas price increases after a block allocating more
+    /// than the target gas, is unchanged at the target and decreases back to
+    /// the minimum gas price after empty blocks.
+    #[test]
+    fn test_base_gas_price_update() {
+        let (mut shell, _, _, _) = setup();
+        let native_token = shell.state.in_mem().native_token.clone();
+        let minimum_gas_price =
+            parameters::read_gas_cost(&shell.state, &native_token)
+                .unwrap()
+                .unwrap();
+        let max_block_gas =
+            parameters::get_max_block_gas(&shell.state).unwrap();
+        let read_base_gas_price = |shell: &TestShell| {
+            parameters::read_base_gas_price(&shell.state, &native_token)
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(read_base_gas_price(&shell), minimum_gas_price);
+
+        // Add unshielded balance for fee payment
+        let keypair = gen_keypair();
+        let balance_key = token::storage_key::balance_key(
+            &native_token,
+            &Address::from(&keypair.ref_to()),
+        );
+        shell
+            .state
+            .write(&balance_key, Amount::native_whole(1000))
+            .unwrap();
+
+        // The gas limit of the wrapper exceeds the target
+        let (_, processed_tx) = mk_wrapper_tx(&shell, &keypair);
+        shell
+            .finalize_block(FinalizeBlock {
+                txs: vec![processed_tx],
+                ..Default::default()
+            })
+            .expect("Test failed");
+        shell.commit();
+        let increased = read_base_gas_price(&shell);
+        assert!(increased > minimum_gas_price);
+        assert_eq!(
+            increased,
+            parameters::next_base_gas_price(
+                minimum_gas_price,
+                minimum_gas_price,
+                GAS_LIMIT_MULTIPLIER,
+                max_block_gas
+            )
+        );
+
+        // A block allocating exactly the target gas keeps the price
+        parameters::update_base_gas_price(
+            &mut shell.state,
+            max_block_gas / parameters::BLOCK_GAS_TARGET_DENOM,
+        )
+        .unwrap();
+        assert_eq!(read_base_gas_price(&shell), increased);
+
+        // Empty blocks bring the price back to the minimum, but not below
+        for _ in 0..10 {
+            shell
+                .finalize_block(FinalizeBlock::default())
+                .expect("Test failed");
+            shell.commit();
+        }
+        assert_eq!(read_base_gas_price(&shell), minimum_gas_price);
+    }
+
     #[test]
     fn test_ledger_slashing() -> namada::state::StorageResult<()> {
         let num_validators = 7_u64;
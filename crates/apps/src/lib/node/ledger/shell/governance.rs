@@ -1,8 +1,7 @@
-use std::collections::HashMap;
-
 use namada::core::encode;
 use namada::core::event::EmitEvents;
 use namada::core::storage::Epoch;
+use namada::governance::parameters::ForfeitedDepositTarget;
 use namada::governance::pgf::storage::keys as pgf_storage;
 use namada::governance::pgf::storage::steward::StewardDetail;
 use namada::governance::pgf::{storage as pgf, ADDRESS};
@@ -11,19 +10,15 @@ use namada::governance::storage::proposal::{
     AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
 };
 use namada::governance::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, TallyVote,
-    VotePower,
+    compute_proposal_result, TallyResult, TallyType,
 };
 use namada::governance::{storage as gov_api, ADDRESS as gov_address};
 use namada::ibc;
 use namada::ledger::governance::utils::ProposalEvent;
-use namada::ledger::pos::BondId;
-use namada::proof_of_stake::bond_amount;
-use namada::proof_of_stake::parameters::PosParams;
+use namada::proof_of_stake::queries::compute_proposal_votes;
 use namada::proof_of_stake::storage::read_total_stake;
 use namada::state::StorageWrite;
 use namada::tx::{Code, Data};
-use namada_sdk::proof_of_stake::storage::read_validator_stake;
 
 use super::utils::force_read;
 use super::*;
@@ -80,7 +75,12 @@ where
         let total_voting_power =
             read_total_stake(&shell.state, &params, proposal_end_epoch)?;
 
-        let tally_type = TallyType::from(proposal_type.clone(), is_steward);
+        let is_expedited = gov_api::is_proposal_expedited(&shell.state, id)?;
+        let tally_type = if is_expedited {
+            TallyType::Expedited
+        } else {
+            TallyType::from(proposal_type.clone(), is_steward)
+        };
         let votes = compute_proposal_votes(
             &shell.state,
             &params,
@@ -89,9 +89,37 @@ where
         )?;
         let proposal_result =
             compute_proposal_result(votes, total_voting_power, tally_type);
-        gov_api::write_proposal_result(&mut shell.state, id, proposal_result)?;
 
-        let transfer_address = match proposal_result.result {
+        // Expedited proposals that didn't reach the expedited threshold are
+        // not rejected, but are moved back to the normal voting schedule
+        if is_expedited
+            && matches!(proposal_result.result, TallyResult::Rejected)
+        {
+            let (current_epoch, _gas) =
+                shell.state.in_mem().get_current_epoch();
+            let grace_epoch = gov_api::move_to_normal_schedule(
+                &mut shell.state,
+                id,
+                current_epoch,
+            )?;
+            tracing::info!(
+                "Expedited governance proposal {} didn't pass ({}) and has \
+                 been moved back to the normal voting schedule, with grace \
+                 epoch {}.",
+                id,
+                proposal_result,
+                grace_epoch
+            );
+            continue;
+        }
+
+        gov_api::write_proposal_result(
+            &mut shell.state,
+            id,
+            proposal_result.clone(),
+        )?;
+
+        match proposal_result.result {
             TallyResult::Passed => {
                 let proposal_event = match proposal_type {
                     ProposalType::Default(_) => {
@@ -121,6 +149,37 @@ where
                         )
                         .into()
                     }
+                    ProposalType::MultiOption { .. } => {
+                        // The code reads the winning option from the proposal
+                        // result written above
+                        let proposal_code =
+                            gov_api::get_proposal_code(&shell.state, id)?;
+                        let result = execute_default_proposal(
+                            shell,
+                            id,
+                            proposal_code.clone(),
+                        )?;
+                        tracing::info!(
+                            "Governance proposal (multi-option {} wasm) {} \
+                             has been executed ({}) and passed with option \
+                             {:?}.",
+                            if proposal_code.is_some() {
+                                "with"
+                            } else {
+                                "without"
+                            },
+                            id,
+                            result,
+                            proposal_result.winning_option
+                        );
+
+                        ProposalEvent::default_proposal_event(
+                            id,
+                            proposal_code.is_some(),
+                            result,
+                        )
+                        .into()
+                    }
                     ProposalType::PGFSteward(stewards) => {
                         let result = execute_pgf_steward_proposal(
                             &mut shell.state,
@@ -167,10 +226,8 @@ where
                 };
                 events.emit(proposal_event);
                 proposals_result.passed.push(id);
-
-                gov_api::get_proposal_author(&shell.state, id)?
             }
-            TallyResult::Rejected => {
+            TallyResult::Rejected | TallyResult::Vetoed => {
                 if let ProposalType::PGFPayment(_) = proposal_type {
                     if proposal_result.two_thirds_nay_over_two_thirds_total() {
                         pgf::remove_steward(
@@ -189,33 +246,54 @@ where
                     }
                 }
                 let proposal_event =
-                    ProposalEvent::rejected_proposal_event(id).into();
-                events.emit(proposal_event);
+                    if let TallyResult::Vetoed = proposal_result.result {
+                        ProposalEvent::vetoed_proposal_event(id)
+                    } else {
+                        ProposalEvent::rejected_proposal_event(id)
+                    };
+                events.emit(proposal_event.into());
                 proposals_result.rejected.push(id);
 
                 tracing::info!(
-                    "Governance proposal {} has been executed and rejected.",
-                    id
+                    "Governance proposal {} has been executed and {}.",
+                    id,
+                    proposal_result.result
                 );
-
-                None
             }
-        };
+        }
 
+        // Refund the deposit to the author, unless the proposal was vetoed
+        // or didn't reach quorum
         let native_token = shell.state.get_native_token()?;
-        if let Some(address) = transfer_address {
-            token::transfer(
-                &mut shell.state,
-                &native_token,
-                &gov_address,
-                &address,
-                funds,
-            )?;
+        if proposal_result.is_deposit_forfeited() {
+            let gov_params = gov_api::get_parameters(&shell.state)?;
+            match gov_params.forfeited_deposit_target {
+                ForfeitedDepositTarget::Burn => token::burn_tokens(
+                    &mut shell.state,
+                    &native_token,
+                    &gov_address,
+                    funds,
+                )?,
+                ForfeitedDepositTarget::Pgf => token::transfer(
+                    &mut shell.state,
+                    &native_token,
+                    &gov_address,
+                    &ADDRESS,
+                    funds,
+                )?,
+            }
+            tracing::info!(
+                "The deposit of governance proposal {} has been forfeited \
+                 ({}).",
+                id,
+                gov_params.forfeited_deposit_target
+            );
         } else {
-            token::burn_tokens(
+            token::transfer(
                 &mut shell.state,
                 &native_token,
                 &gov_address,
+                &proposal_author,
                 funds,
             )?;
         }
@@ -224,68 +302,6 @@ where
     Ok(proposals_result)
 }
 
-fn compute_proposal_votes<S>(
-    storage: &S,
-    params: &PosParams,
-    proposal_id: u64,
-    epoch: Epoch,
-) -> namada::state::StorageResult<ProposalVotes>
-where
-    S: StorageRead,
-{
-    let votes = gov_api::get_proposal_votes(storage, proposal_id)?;
-
-    let mut validators_vote: HashMap<Address, TallyVote> = HashMap::default();
-    let mut validator_voting_power: HashMap<Address, VotePower> =
-        HashMap::default();
-    let mut delegators_vote: HashMap<Address, TallyVote> = HashMap::default();
-    let mut delegator_voting_power: HashMap<
-        Address,
-        HashMap<Address, VotePower>,
-    > = HashMap::default();
-
-    for vote in votes {
-        if vote.is_validator() {
-            let validator = vote.validator.clone();
-            let vote_data = vote.data.clone();
-
-            let validator_stake =
-                read_validator_stake(storage, params, &validator, epoch)
-                    .unwrap_or_default();
-
-            validators_vote.insert(validator.clone(), vote_data.into());
-            validator_voting_power.insert(validator, validator_stake);
-        } else {
-            let validator = vote.validator.clone();
-            let delegator = vote.delegator.clone();
-            let vote_data = vote.data.clone();
-
-            let bond_id = BondId {
-                source: delegator.clone(),
-                validator: validator.clone(),
-            };
-            let delegator_stake = bond_amount(storage, &bond_id, epoch);
-
-            if let Ok(stake) = delegator_stake {
-                delegators_vote.insert(delegator.clone(), vote_data.into());
-                delegator_voting_power
-                    .entry(delegator)
-                    .or_default()
-                    .insert(validator, stake);
-            } else {
-                continue;
-            }
-        }
-    }
-
-    Ok(ProposalVotes {
-        validators_vote,
-        validator_voting_power,
-        delegators_vote,
-        delegator_voting_power,
-    })
-}
-
 fn execute_default_proposal<D, H>(
     shell: &mut Shell<D, H>,
     id: u64,
@@ -314,6 +330,7 @@ where
             &mut shell.state,
             &mut shell.vp_wasm_cache,
             &mut shell.tx_wasm_cache,
+            false,
             None,
         );
         shell
@@ -437,8 +454,119 @@ where
                     ),
                 }
             }
+            PGFAction::Stream(stream) => {
+                let current_epoch = state.in_mem().get_current_epoch().0;
+                let target = stream.target.clone();
+                match pgf::add_stream(state, stream, proposal_id, current_epoch)
+                {
+                    Ok(()) => tracing::info!(
+                        "Added/Updated PgfStream from proposal id {}: stream \
+                         to {}.",
+                        proposal_id,
+                        target
+                    ),
+                    Err(e) => tracing::warn!(
+                        "Error in PgfStream from proposal id {} to {}: {}",
+                        proposal_id,
+                        target,
+                        e
+                    ),
+                }
+            }
+            PGFAction::CancelStream(target) => {
+                let current_epoch = state.in_mem().get_current_epoch().0;
+                match pgf::cancel_stream(state, &target, current_epoch) {
+                    Ok(()) => tracing::info!(
+                        "Cancelled PgfStream from proposal id {}: stream to \
+                         {}.",
+                        proposal_id,
+                        target
+                    ),
+                    Err(e) => tracing::warn!(
+                        "Error cancelling PgfStream from proposal id {} to \
+                         {}: {}",
+                        proposal_id,
+                        target,
+                        e
+                    ),
+                }
+            }
         }
     }
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod test {
+    use namada::core::hash::Hash;
+    use namada::governance::storage::proposal::ProposalType;
+    use namada::governance::InitProposalData;
+
+    use super::*;
+    use crate::node::ledger::shell::test_utils::setup;
+
+    /// Test that an expedited proposal that didn't reach the expedited
+    /// threshold is moved back to the normal voting schedule instead of
+    /// being rejected
+    #[test]
+    fn test_expedited_proposal_fallback() {
+        let (mut shell, _recv, _, _) = setup();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let params = gov_api::get_parameters(&shell.state).unwrap();
+
+        let proposal_id = 0;
+        let proposal = InitProposalData {
+            id: proposal_id,
+            content: Hash::default(),
+            author: validator,
+            voting_start_epoch: Epoch::default(),
+            voting_end_epoch: Epoch::default().next(),
+            grace_epoch: Epoch::default().next(),
+            expedited: true,
+            r#type: ProposalType::Default(None),
+        };
+        gov_api::init_proposal(&mut shell.state, proposal, vec![], None)
+            .unwrap();
+        assert!(
+            gov_api::is_proposal_expedited(&shell.state, proposal_id).unwrap()
+        );
+
+        // No votes were cast, so the expedited threshold isn't reached
+        shell.proposal_data.insert(proposal_id);
+        let mut events = vec![];
+        let result =
+            execute_governance_proposals(&mut shell.shell, &mut events)
+                .unwrap();
+        assert!(result.passed.is_empty());
+        assert!(result.rejected.is_empty());
+        assert!(events.is_empty());
+
+        // The proposal is not rejected, but moved to the normal schedule
+        assert!(
+            gov_api::get_proposal_result(&shell.state, proposal_id)
+                .unwrap()
+                .is_none()
+        );
+        let proposal = gov_api::get_proposal_by_id(&shell.state, proposal_id)
+            .unwrap()
+            .unwrap();
+        assert!(!proposal.expedited);
+        let voting_end_epoch =
+            Epoch::default() + params.min_proposal_voting_period;
+        assert_eq!(proposal.voting_end_epoch, voting_end_epoch);
+        assert_eq!(
+            proposal.grace_epoch,
+            voting_end_epoch + params.min_proposal_grace_epochs
+        );
+        assert!(
+            shell
+                .state
+                .has_key(&gov_storage::get_committing_proposals_key(
+                    proposal_id,
+                    proposal.grace_epoch.0
+                ))
+                .unwrap()
+        );
+    }
+}
@@ -255,6 +255,15 @@ where
             .init_genesis_epoch(initial_height, genesis_time, &parameters)
             .expect("Initializing genesis epoch must not fail");
 
+        // The genesis state is archived from the first block. The subspace
+        // is still empty, so only the seeded height is written to the DB.
+        if self.archive_mode {
+            self.state
+                .db_mut()
+                .seed_archive(initial_height)
+                .expect("Seeding the archive must not fail");
+        }
+
         // PoS system depends on epoch being initialized
         let pos_params = genesis.get_pos_params();
         let (current_epoch, _gas) = self.state.in_mem().get_current_epoch();
@@ -276,6 +285,7 @@ where
         self.init_token_accounts(&genesis);
         self.init_token_balances(&genesis);
         self.apply_genesis_txs_established_account(&genesis, &mut vp_cache);
+        self.apply_genesis_txs_vesting(&genesis);
         self.apply_genesis_txs_validator_account(
             &genesis,
             &mut vp_cache,
@@ -583,6 +593,30 @@ where
         self.proceed_with(())
     }
 
+    /// Apply genesis txs to lock the native tokens of established accounts
+    fn apply_genesis_txs_vesting(
+        &mut self,
+        genesis: &genesis::chain::Finalized,
+    ) {
+        if let Some(txs) = genesis.transactions.vesting.as_ref() {
+            for tx in txs {
+                let address = Address::Established(tx.address.raw.clone());
+                tracing::debug!(
+                    "Applying genesis tx to lock the tokens of {address}"
+                );
+                let schedule = tx
+                    .schedule()
+                    .expect("Genesis vesting schedules must be validated");
+                namada::account::write_vesting_schedule(
+                    &mut self.state,
+                    &address,
+                    schedule,
+                )
+                .unwrap();
+            }
+        }
+    }
+
     /// Apply genesis txs to initialize validator accounts
     fn apply_genesis_txs_validator_account(
         &mut self,
@@ -16,6 +16,7 @@ pub mod prepare_proposal;
 use namada::state::State;
 pub mod process_proposal;
 pub(super) mod queries;
+mod snapshots;
 mod stats;
 #[cfg(any(test, feature = "testing"))]
 #[allow(dead_code)]
@@ -73,6 +74,7 @@ use thiserror::Error;
 use tokio::sync::mpsc::{Receiver, UnboundedSender};
 
 use super::ethereum_oracle::{self as oracle, last_processed_block};
+use super::protocol_signer::RemoteProtocolSigner;
 use crate::config::{self, genesis, TendermintMode, ValidatorLocalConfig};
 use crate::facade::tendermint::v0_37::abci::{request, response};
 use crate::facade::tendermint::{self, validator};
@@ -173,6 +175,7 @@ pub(super) enum ShellMode {
         broadcast_sender: UnboundedSender<Vec<u8>>,
         eth_oracle: Option<EthereumOracleChannels>,
         local_config: Option<ValidatorLocalConfig>,
+        remote_signer: Option<RemoteProtocolSigner>,
     },
     Full,
     Seed,
@@ -262,7 +265,8 @@ impl ShellMode {
         }
     }
 
-    /// Get the protocol keypair for this validator.
+    /// Get the protocol keypair for this validator, unless it is held by a
+    /// remote signer.
     pub fn get_protocol_key(&self) -> Option<&common::SecretKey> {
         match self {
             ShellMode::Validator {
@@ -275,7 +279,18 @@ impl ShellMode {
                         ..
                     },
                 ..
-            } => Some(protocol_keypair),
+            } => protocol_keypair.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get the service signing with the remote signer holding the protocol
+    /// key of this validator, if any.
+    pub fn get_remote_signer(&self) -> Option<&RemoteProtocolSigner> {
+        match self {
+            ShellMode::Validator { remote_signer, .. } => {
+                remote_signer.as_ref()
+            }
             _ => None,
         }
     }
@@ -349,10 +364,38 @@ where
     /// limit the how many block heights in the past can the storage be
     /// queried for reading values.
     storage_read_past_height_limit: Option<u64>,
+    /// Taken from config `event_store_retention_blocks`. When set, events
+    /// older than this many blocks are pruned from the persistent event
+    /// store.
+    event_store_retention_blocks: Option<u64>,
+    /// Taken from config `tx_ordering`. The order in which the wrapper txs
+    /// of the mempool are included in the proposed blocks.
+    tx_ordering: config::TxOrdering,
+    /// Taken from config `index_storage_diffs`. When set, the storage diffs
+    /// of the applied txs are persisted with the block results.
+    index_storage_diffs: bool,
+    /// Taken from config `archive_mode`. When set, the versions of the
+    /// storage key-vals changed by every block are archived.
+    archive_mode: bool,
+    /// Taken from config `snapshot_interval`. When set, a state-sync snapshot
+    /// is produced at every block height that is a multiple of it.
+    snapshot_interval: Option<u64>,
+    /// Taken from config `snapshots_to_keep`. The maximum number of
+    /// state-sync snapshots kept on disk.
+    snapshots_to_keep: Option<u64>,
+    /// The directory of the state-sync snapshots
+    snapshots_dir: PathBuf,
+    /// The thread producing the last state-sync snapshot, if any
+    snapshot_task: Option<std::thread::JoinHandle<()>>,
+    /// The restoration of the state-sync snapshot offered by CometBFT, if any
+    snapshot_sync: Option<snapshots::SnapshotSync<H>>,
     /// Proposal execution tracking
     pub proposal_data: BTreeSet<u64>,
     /// Log of events emitted by `FinalizeBlock` ABCI calls.
     event_log: EventLog,
+    /// Events emitted by the last `FinalizeBlock` ABCI call, to be persisted
+    /// in the event store on `Commit`.
+    pending_events: Vec<Event>,
 }
 
 /// Merkle tree storage key filter. Return `false` for keys that shouldn't be
@@ -408,8 +451,16 @@ where
         let db_path = config.shell.db_dir(&chain_id);
         let base_dir = config.shell.base_dir;
         let mode = config.shell.tendermint_mode;
+        let archive_mode = config.shell.archive_mode;
         let storage_read_past_height_limit =
             config.shell.storage_read_past_height_limit;
+        let event_store_retention_blocks =
+            config.shell.event_store_retention_blocks;
+        let tx_ordering = config.shell.tx_ordering;
+        let index_storage_diffs = config.shell.index_storage_diffs;
+        let snapshot_interval = config.shell.snapshot_interval;
+        let snapshots_to_keep = config.shell.snapshots_to_keep;
+        let snapshots_dir = config.shell.snapshots_dir(&chain_id);
         if !Path::new(&base_dir).is_dir() {
             std::fs::create_dir(&base_dir)
                 .expect("Creating directory for Namada should not fail");
@@ -435,14 +486,25 @@ where
         };
 
         // load last state from storage
-        let state = FullAccessState::open(
+        let mut state = FullAccessState::open(
             db_path,
             db_cache,
             chain_id.clone(),
             native_token,
-            config.shell.storage_read_past_height_limit,
+            storage_read_past_height_limit,
             is_merklized_storage_key,
         );
+        // Seed the archive with the last committed state ahead of the next
+        // block, if it doesn't continue the archived heights. A new chain is
+        // seeded when initialized instead.
+        if archive_mode && state.in_mem().last_block.is_some() {
+            let next_height =
+                state.in_mem().get_last_block_height().next_height();
+            state
+                .db_mut()
+                .seed_archive(next_height)
+                .expect("Seeding the archive must not fail");
+        }
         let vp_wasm_cache_dir =
             base_dir.join(chain_id.as_str()).join("vp_wasm_cache");
         let tx_wasm_cache_dir =
@@ -474,19 +536,44 @@ where
                         } else {
                             None
                         };
+                    let remote_signer = validator_local_config
+                        .as_ref()
+                        .and_then(|config| config.remote_signer)
+                        .map(|address| {
+                            use crate::client::remote_signer::{
+                                RemoteSigner, SharedSecret,
+                            };
+                            let secret = SharedSecret::from_env().expect(
+                                "The secret shared with the remote signer \
+                                 should be set",
+                            );
+                            RemoteProtocolSigner::spawn(
+                                RemoteSigner::new(address, secret),
+                                chain_id.clone(),
+                                broadcast_sender.clone(),
+                            )
+                        });
 
-                    wallet
-                        .take_validator_data()
-                        .map(|data| ShellMode::Validator {
-                            data,
-                            broadcast_sender,
-                            eth_oracle,
-                            local_config: validator_local_config,
-                        })
-                        .expect(
-                            "Validator data should have been stored in the \
-                             wallet",
-                        )
+                    let mut data = wallet.take_validator_data().expect(
+                        "Validator data should have been stored in the wallet",
+                    );
+                    if remote_signer.is_some() {
+                        // Only the public protocol key is used, read from
+                        // storage when signing
+                        data.keys.protocol_keypair = None;
+                    } else if data.keys.protocol_keypair.is_none() {
+                        panic!(
+                            "The protocol key should have been stored in the \
+                             wallet, unless a remote signer is configured"
+                        );
+                    }
+                    ShellMode::Validator {
+                        data,
+                        broadcast_sender,
+                        eth_oracle,
+                        local_config: validator_local_config,
+                        remote_signer,
+                    }
                 }
                 #[cfg(test)]
                 {
@@ -497,13 +584,14 @@ where
                             address: crate::wallet::defaults::validator_address(
                             ),
                             keys: ValidatorKeys {
-                                protocol_keypair,
+                                protocol_keypair: Some(protocol_keypair),
                                 eth_bridge_keypair,
                             },
                         },
                         broadcast_sender,
                         eth_oracle,
                         local_config: None,
+                        remote_signer: None,
                     }
                 }
             }
@@ -526,9 +614,19 @@ where
                 tx_wasm_compilation_cache as usize,
             ),
             storage_read_past_height_limit,
+            event_store_retention_blocks,
+            tx_ordering,
+            index_storage_diffs,
+            archive_mode,
+            snapshot_interval,
+            snapshots_to_keep,
+            snapshots_dir,
+            snapshot_task: None,
+            snapshot_sync: None,
             proposal_data: BTreeSet::new(),
             // TODO: config event log params
             event_log: EventLog::default(),
+            pending_events: vec![],
         };
         shell.update_eth_oracle(&Default::default());
         shell
@@ -649,8 +747,18 @@ where
     pub fn commit(&mut self) -> response::Commit {
         self.bump_last_processed_eth_block();
 
+        let committing_height = self.state.in_mem().block.height;
+        let prune_events_below =
+            self.event_store_retention_blocks.and_then(|retention| {
+                committing_height.0.checked_sub(retention).map(BlockHeight)
+            });
+        let events = std::mem::take(&mut self.pending_events);
         self.state
-            .commit_block()
+            .commit_block_with_events(
+                &events,
+                prune_events_below,
+                self.archive_mode,
+            )
             .expect("Encountered a storage error while committing a block");
 
         let merkle_root = self.state.in_mem().merkle_root();
@@ -715,6 +823,12 @@ where
     fn broadcast_protocol_txs(&mut self) {
         use crate::node::ledger::shell::vote_extensions::iter_protocol_txs;
 
+        if self.mode.get_remote_signer().is_some() {
+            let requests = self.craft_signing_requests();
+            self.request_remote_signatures(requests);
+            return;
+        }
+
         let ext = self.craft_extension();
 
         let protocol_key = self
@@ -753,6 +867,14 @@ where
             // Ethereum events to retransmit
             return;
         }
+        if self.mode.get_remote_signer().is_some() {
+            let requests = self
+                .ethereum_events_signing_request(eth_events)
+                .into_iter()
+                .collect();
+            self.request_remote_signatures(requests);
+            return;
+        }
         if let Some(vote_extension) = self.sign_ethereum_events(eth_events) {
             let protocol_key = self
                 .mode
@@ -1108,14 +1230,52 @@ where
                     return response;
                 }
 
+                // Sequence check: a stale sequence number can never be
+                // included in a block anymore, while a bounded gap is held in
+                // the mempool until the missing txs get applied
+                if let Some(sequence) = wrapper.sequence {
+                    let next_sequence = namada::account::next_sequence(
+                        &self.state,
+                        &wrapper.fee_payer(),
+                    )
+                    .expect("Error while reading the sequence from storage");
+                    if sequence < next_sequence {
+                        response.code = ResultCode::InvalidOrder.into();
+                        response.log = format!(
+                            "{INVALID_MSG}: Sequence number {sequence} has \
+                             already been used, the next expected one is \
+                             {next_sequence}"
+                        );
+                        return response;
+                    }
+                    let max_sequence = next_sequence
+                        .saturating_add(namada::account::MAX_SEQUENCE_GAP);
+                    if sequence > max_sequence {
+                        response.code = ResultCode::InvalidOrder.into();
+                        response.log = format!(
+                            "{INVALID_MSG}: Sequence number {sequence} is too \
+                             far ahead, the next expected one is \
+                             {next_sequence} and at most {max_sequence} is \
+                             accepted"
+                        );
+                        return response;
+                    }
+                }
+
                 // Validate wrapper fees
-                if let Err(e) = mempool_fee_check(
-                    &wrapper,
-                    get_fee_unshielding_transaction(&tx, &wrapper),
-                    &mut self.state.with_temp_write_log(),
-                    &mut self.vp_wasm_cache.clone(),
-                    &mut self.tx_wasm_cache.clone(),
-                ) {
+                if let Err(e) =
+                    protocol::check_fee_grant(&self.state, &tx, &wrapper)
+                        .map_err(Error::TxApply)
+                        .and_then(|()| {
+                            mempool_fee_check(
+                                &wrapper,
+                                get_fee_unshielding_transaction(&tx, &wrapper),
+                                &mut self.state.with_temp_write_log(),
+                                &mut self.vp_wasm_cache.clone(),
+                                &mut self.tx_wasm_cache.clone(),
+                            )
+                        })
+                {
                     response.code = ResultCode::FeeError.into();
                     response.log = format!("{INVALID_MSG}: {e}");
                     return response;
@@ -1997,6 +2157,7 @@ mod test_utils {
             pos_inflation_amount: Default::default(),
             fee_unshielding_gas_limit: 0,
             fee_unshielding_descriptions_limit: 0,
+            fee_burn_rate: Default::default(),
             minimum_gas_price: Default::default(),
         };
         parameters::init_storage(&params, &mut shell.state)
@@ -2869,6 +3030,62 @@ mod shell_tests {
         assert_eq!(result.code, ResultCode::FeeError.into());
     }
 
+    /// Test that the mempool rejects the stale sequence numbers and the ones
+    /// too far ahead of the next expected one
+    #[test]
+    fn test_sequence_mempool_validate() {
+        let (shell, _recv, _, _) = test_utils::setup();
+        let keypair = crate::wallet::defaults::albert_keypair();
+
+        let new_tx = |sequence: u64| {
+            let mut wrapper = Tx::from_type(TxType::Wrapper(Box::new(
+                WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            100.into(),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    Epoch(0),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                    None,
+                )
+                .with_sequence(sequence),
+            )));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper
+                .set_data(Data::new("transaction data".as_bytes().to_owned()));
+            wrapper.add_section(Section::Signature(Signature::new(
+                wrapper.sechashes(),
+                [(0, keypair.clone())].into_iter().collect(),
+                None,
+            )));
+            shell.mempool_validate(
+                wrapper.to_bytes().as_ref(),
+                MempoolTxType::NewTransaction,
+            )
+        };
+
+        // The sequence of the fee payer is at zero, so any gap up to the
+        // maximum one is held in the mempool
+        for sequence in [0, 1, namada::account::MAX_SEQUENCE_GAP] {
+            let result = new_tx(sequence);
+            assert!(
+                result.code != ResultCode::InvalidOrder.into(),
+                "{}",
+                result.log
+            );
+        }
+
+        let result = new_tx(namada::account::MAX_SEQUENCE_GAP + 1);
+        assert_eq!(result.code, ResultCode::InvalidOrder.into());
+        let result = new_tx(u64::MAX);
+        assert_eq!(result.code, ResultCode::InvalidOrder.into());
+    }
+
     /// Test max tx bytes parameter in CheckTx
     #[test]
     fn test_max_tx_bytes_check_tx() {
@@ -1,14 +1,18 @@
 //! Implementation of the [`RequestPrepareProposal`] ABCI++ method for the Shell
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
 use masp_primitives::transaction::Transaction;
 use namada::core::address::Address;
+use namada::core::dec::Dec;
 use namada::core::hints;
 use namada::core::key::tm_raw_hash_to_string;
 use namada::gas::TxGasMeter;
 use namada::ledger::protocol;
 use namada::ledger::storage::tx_queue::TxInQueue;
 use namada::proof_of_stake::storage::find_validator_by_raw_hash;
-use namada::state::{DBIter, StorageHasher, TempWlState, DB};
+use namada::state::{DBIter, StorageHasher, StorageRead, TempWlState, DB};
 use namada::tx::data::{DecryptedTx, TxType, WrapperTx};
 use namada::tx::Tx;
 use namada::vm::wasm::{TxCache, VpCache};
@@ -20,7 +24,7 @@ use super::block_alloc::states::{
     EncryptedTxBatchAllocator, NextState, TryAlloc,
 };
 use super::block_alloc::{AllocFailure, BlockAllocator, BlockResources};
-use crate::config::ValidatorLocalConfig;
+use crate::config::{TxOrdering, ValidatorLocalConfig};
 use crate::facade::tendermint_proto::google::protobuf::Timestamp;
 use crate::facade::tendermint_proto::v0_37::abci::RequestPrepareProposal;
 use crate::node::ledger::shell::ShellMode;
@@ -140,55 +144,94 @@ where
         let mut vp_wasm_cache = self.vp_wasm_cache.clone();
         let mut tx_wasm_cache = self.tx_wasm_cache.clone();
 
-        let txs = txs
-            .iter()
-            .filter_map(|tx_bytes| {
-                match validate_wrapper_bytes(tx_bytes, block_time, block_proposer, proposer_local_config, &mut temp_state, &mut vp_wasm_cache, &mut tx_wasm_cache, ) {
-                    Ok(gas) => {
-                        temp_state.write_log_mut().commit_tx();
-                        Some((tx_bytes.to_owned(), gas))
-                    },
-                    Err(()) => {
-                        temp_state.write_log_mut().drop_tx();
-                        None
-                    }
+        let mut proposed_txs = vec![];
+        for tx_bytes in self.order_mempool_txs(txs, proposer_local_config) {
+            let Ok(tx_gas) = validate_wrapper_bytes(
+                tx_bytes,
+                block_time,
+                block_proposer,
+                proposer_local_config,
+                &mut temp_state,
+                &mut vp_wasm_cache,
+                &mut tx_wasm_cache,
+            ) else {
+                temp_state.write_log_mut().drop_tx();
+                continue;
+            };
+            // The changes of the wrapper, among which the sequence number of
+            // its fee payer, are only kept once it fits in the block
+            match alloc.try_alloc(BlockResources::new(&tx_bytes[..], tx_gas)) {
+                Ok(()) => {
+                    temp_state.write_log_mut().commit_tx();
+                    proposed_txs.push(tx_bytes.to_owned());
                 }
-            })
-            .take_while(|(tx_bytes, tx_gas)| {
-                alloc.try_alloc(BlockResources::new(&tx_bytes[..], tx_gas.to_owned()))
-                    .map_or_else(
-                        |status| match status {
-                            AllocFailure::Rejected { bin_resource_left} => {
-                                tracing::debug!(
-                                    ?tx_bytes,
-                                    bin_resource_left,
-                                    proposal_height =
-                                        ?self.get_current_decision_height(),
-                                    "Dropping encrypted tx from the current proposal",
-                                );
-                                false
-                            }
-                            AllocFailure::OverflowsBin { bin_resource} => {
-                                // TODO: handle tx whose size is greater
-                                // than bin size
-                                tracing::warn!(
-                                    ?tx_bytes,
-                                    bin_resource,
-                                    proposal_height =
-                                        ?self.get_current_decision_height(),
-                                    "Dropping large encrypted tx from the current proposal",
-                                );
-                                true
-                            }
-                        },
-                        |()| true,
-                    )
-            })
-            .map(|(tx, _)| tx)
-            .collect();
+                Err(AllocFailure::Rejected { bin_resource_left }) => {
+                    temp_state.write_log_mut().drop_tx();
+                    tracing::debug!(
+                        ?tx_bytes,
+                        bin_resource_left,
+                        proposal_height =
+                            ?self.get_current_decision_height(),
+                        "Dropping encrypted tx from the current proposal",
+                    );
+                    break;
+                }
+                Err(AllocFailure::OverflowsBin { bin_resource }) => {
+                    // TODO: handle tx whose size is greater
+                    // than bin size
+                    temp_state.write_log_mut().drop_tx();
+                    tracing::warn!(
+                        ?tx_bytes,
+                        bin_resource,
+                        proposal_height =
+                            ?self.get_current_decision_height(),
+                        "Dropping large encrypted tx from the current proposal",
+                    );
+                }
+            }
+        }
         let alloc = alloc.next_state();
 
-        (txs, alloc)
+        (proposed_txs, alloc)
+    }
+
+    /// Order the txs handed over by the mempool according to the configured
+    /// [`TxOrdering`] policy. With [`TxOrdering::FeePriority`], the txs that
+    /// are not wrappers are filtered out, as they would be rejected anyway.
+    /// In both cases, the txs of a fee payer carrying a sequence number are
+    /// then sorted by it.
+    fn order_mempool_txs<'a>(
+        &self,
+        txs: &'a [TxBytes],
+        proposer_local_config: Option<&ValidatorLocalConfig>,
+    ) -> Vec<&'a TxBytes> {
+        let txs = match self.tx_ordering {
+            TxOrdering::Mempool => txs.iter().collect(),
+            TxOrdering::FeePriority => {
+                let candidates = txs.iter().filter_map(|tx_bytes| {
+                    let wrapper = Tx::try_from(tx_bytes.as_ref())
+                        .ok()?
+                        .header
+                        .wrapper()?;
+                    let gas_price = effective_gas_price(
+                        &wrapper,
+                        proposer_local_config,
+                        &self.state,
+                    );
+                    Some((tx_bytes, wrapper.fee_payer(), gas_price))
+                });
+                order_by_fee_priority(candidates)
+            }
+        };
+        order_by_sequence(txs.into_iter().map(|tx_bytes| {
+            let sequence = Tx::try_from(tx_bytes.as_ref())
+                .ok()
+                .and_then(|tx| tx.header.wrapper())
+                .and_then(|wrapper| {
+                    Some((wrapper.fee_payer(), wrapper.sequence?))
+                });
+            (tx_bytes, sequence)
+        }))
     }
 
     /// Builds a batch of DKG decrypted transactions.
@@ -311,6 +354,122 @@ where
     }
 }
 
+/// The gas price of a wrapper normalized by the minimum gas price of its fee
+/// token, so that bids in different tokens can be compared. The price of a
+/// wrapper whose fee token is not accepted is zero.
+fn effective_gas_price<S>(
+    wrapper: &WrapperTx,
+    proposer_local_config: Option<&ValidatorLocalConfig>,
+    state: &S,
+) -> Dec
+where
+    S: StorageRead,
+{
+    let minimum_gas_price = match proposer_local_config {
+        Some(config) => {
+            config.accepted_gas_tokens.get(&wrapper.fee.token).copied()
+        }
+        None => {
+            namada::ledger::parameters::read_gas_cost(state, &wrapper.fee.token)
+                .ok()
+                .flatten()
+        }
+    };
+    let gas_price = namada::token::denom_to_amount(
+        wrapper.fee.amount_per_gas_unit,
+        &wrapper.fee.token,
+        state,
+    );
+    match (minimum_gas_price, gas_price) {
+        (Some(minimum_gas_price), Ok(gas_price))
+            if minimum_gas_price.is_zero() =>
+        {
+            Dec::from(gas_price)
+        }
+        (Some(minimum_gas_price), Ok(gas_price)) => Dec::from(gas_price)
+            .trunc_div(&Dec::from(minimum_gas_price))
+            .unwrap_or_default(),
+        _ => Dec::zero(),
+    }
+}
+
+/// Order the given txs by decreasing gas price, breaking ties by their
+/// original position. The txs of the same fee payer are kept in their
+/// original relative order, so a tx is never proposed ahead of an earlier tx
+/// of the same payer, even if it bids a higher gas price.
+fn order_by_fee_priority<T>(
+    txs: impl IntoIterator<Item = (T, Address, Dec)>,
+) -> Vec<T> {
+    // The queue of pending txs of each fee payer, in their original order
+    let mut queues: HashMap<Address, VecDeque<(usize, T, Dec)>> =
+        HashMap::new();
+    for (index, (tx, fee_payer, gas_price)) in txs.into_iter().enumerate() {
+        queues
+            .entry(fee_payer)
+            .or_default()
+            .push_back((index, tx, gas_price));
+    }
+    // The first pending tx of each fee payer, by decreasing gas price and
+    // increasing index
+    let mut heads: BinaryHeap<(Dec, Reverse<usize>, Address)> = queues
+        .iter()
+        .filter_map(|(fee_payer, queue)| {
+            let (index, _, gas_price) = queue.front()?;
+            Some((*gas_price, Reverse(*index), fee_payer.clone()))
+        })
+        .collect();
+
+    let mut ordered =
+        Vec::with_capacity(queues.values().map(VecDeque::len).sum());
+    while let Some((_, _, fee_payer)) = heads.pop() {
+        let queue = queues
+            .get_mut(&fee_payer)
+            .expect("The queue of a fee payer must exist");
+        let (_, tx, _) = queue
+            .pop_front()
+            .expect("The queue of a fee payer must not be empty");
+        ordered.push(tx);
+        if let Some((index, _, gas_price)) = queue.front() {
+            heads.push((*gas_price, Reverse(*index), fee_payer));
+        }
+    }
+    ordered
+}
+
+/// Sort the txs of each fee payer carrying a sequence number by increasing
+/// sequence, among the positions they occupy in the given order. The other
+/// txs are left where they are.
+fn order_by_sequence<T>(
+    txs: impl IntoIterator<Item = (T, Option<(Address, u64)>)>,
+) -> Vec<T> {
+    let (mut txs, sequences): (Vec<_>, Vec<_>) = txs
+        .into_iter()
+        .map(|(tx, sequence)| (Some(tx), sequence))
+        .unzip();
+    // The positions of the sequenced txs of each fee payer
+    let mut positions: HashMap<&Address, Vec<usize>> = HashMap::new();
+    for (index, sequence) in sequences.iter().enumerate() {
+        if let Some((fee_payer, _)) = sequence {
+            positions.entry(fee_payer).or_default().push(index);
+        }
+    }
+
+    let mut order: Vec<usize> = (0..txs.len()).collect();
+    for slots in positions.into_values() {
+        let mut sorted = slots.clone();
+        sorted.sort_by_key(|index| {
+            sequences[*index].as_ref().map(|(_, sequence)| *sequence)
+        });
+        for (slot, index) in slots.into_iter().zip(sorted) {
+            order[slot] = index;
+        }
+    }
+    order
+        .into_iter()
+        .map(|index| txs[index].take().expect("Each tx is taken only once"))
+        .collect()
+}
+
 // Validity checks on a wrapper tx
 #[allow(clippy::too_many_arguments)]
 fn validate_wrapper_bytes<D, H, CA>(
@@ -348,6 +507,10 @@ where
 
         super::replay_protection_checks(&tx, temp_state).map_err(|_| ())?;
 
+        protocol::advance_sequence(temp_state, &wrapper).map_err(|_| ())?;
+
+        protocol::check_fee_grant(temp_state, &tx, &wrapper).map_err(|_| ())?;
+
         // Check fees and extract the gas limit of this transaction
         match prepare_proposal_fee_check(
             &wrapper,
@@ -413,6 +576,10 @@ where
         vp_wasm_cache,
         tx_wasm_cache,
     )?;
+    // The base gas price is enforced regardless of the local config of the
+    // block proposer
+    protocol::check_base_gas_price(temp_state, wrapper)
+        .map_err(Error::TxApply)?;
 
     protocol::transfer_fee(temp_state, proposer, wrapper)
         .map_err(Error::TxApply)
@@ -1177,6 +1344,7 @@ mod test_prepare_proposal {
                     namada::core::address::testing::nam(),
                     Amount::from(1),
                 )]),
+                remote_signer: None,
             });
         }
 
@@ -1283,6 +1451,7 @@ mod test_prepare_proposal {
                     namada::core::address::testing::nam(),
                     Amount::from(100),
                 )]),
+                remote_signer: None,
             });
         }
 
@@ -1552,4 +1721,248 @@ mod test_prepare_proposal {
             assert_eq!(found_event, event2);
         }
     }
+
+    /// Make a signed wrapper paying the given multiple of the minimum gas
+    /// price, with an optional sequence number, and fund its fee payer
+    fn fee_priority_wrapper(
+        shell: &mut TestShell,
+        keypair: &namada::core::key::common::SecretKey,
+        price_multiplier: u64,
+        gas_limit: u64,
+        sequence: Option<u64>,
+        data: &str,
+    ) -> Tx {
+        let native_token = shell.state.in_mem().native_token.clone();
+        let minimum_gas_price = namada::ledger::parameters::read_gas_cost(
+            &shell.state,
+            &native_token,
+        )
+        .unwrap()
+        .unwrap();
+        let balance_key = token::storage_key::balance_key(
+            &native_token,
+            &Address::from(&keypair.ref_to()),
+        );
+        shell
+            .state
+            .db_write(
+                &balance_key,
+                Amount::native_whole(1_000).serialize_to_vec(),
+            )
+            .unwrap();
+
+        let mut wrapper = WrapperTx::new(
+            Fee {
+                amount_per_gas_unit: DenominatedAmount::native(
+                    minimum_gas_price * price_multiplier,
+                ),
+                token: native_token,
+            },
+            keypair.ref_to(),
+            Epoch(0),
+            gas_limit.into(),
+            None,
+        );
+        wrapper.sequence = sequence;
+        let mut tx = Tx::from_type(TxType::Wrapper(Box::new(wrapper)));
+        tx.header.chain_id = shell.chain_id.clone();
+        tx.set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+        tx.set_data(Data::new(data.as_bytes().to_owned()));
+        tx.add_section(Section::Signature(Signature::new(
+            tx.sechashes(),
+            [(0, keypair.clone())].into_iter().collect(),
+            None,
+        )));
+        tx
+    }
+
+    /// Test that when the block is full, the wrappers bidding the highest
+    /// gas prices are proposed first
+    #[test]
+    fn test_fee_priority_full_block() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let block_gas_limit =
+            namada::parameters::get_max_block_gas(&shell.state).unwrap();
+        // Only two of the wrappers fit in the block
+        let gas_limit = block_gas_limit * 2 / 5;
+
+        let low = fee_priority_wrapper(
+            &mut shell,
+            &gen_keypair(),
+            1,
+            gas_limit,
+            None,
+            "low",
+        );
+        let high = fee_priority_wrapper(
+            &mut shell,
+            &gen_keypair(),
+            3,
+            gas_limit,
+            None,
+            "high",
+        );
+        let mid = fee_priority_wrapper(
+            &mut shell,
+            &gen_keypair(),
+            2,
+            gas_limit,
+            None,
+            "mid",
+        );
+
+        let req = RequestPrepareProposal {
+            txs: vec![
+                low.to_bytes().into(),
+                high.to_bytes().into(),
+                mid.to_bytes().into(),
+            ],
+            ..Default::default()
+        };
+        let received: Vec<_> = shell
+            .prepare_proposal(req)
+            .txs
+            .into_iter()
+            .map(|tx_bytes| {
+                Tx::try_from(tx_bytes.as_ref())
+                    .expect("Test failed")
+                    .header_hash()
+            })
+            .collect();
+        assert_eq!(received, vec![high.header_hash(), mid.header_hash()]);
+    }
+
+    /// Test that the wrappers of a fee payer are proposed in their mempool
+    /// order, even if a later one bids a higher gas price, and that the
+    /// mempool order is kept with the mempool ordering policy
+    #[test]
+    fn test_fee_priority_per_fee_payer_order() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let keypair = gen_keypair();
+
+        let first = fee_priority_wrapper(
+            &mut shell,
+            &keypair,
+            1,
+            GAS_LIMIT_MULTIPLIER,
+            None,
+            "first",
+        );
+        let second = fee_priority_wrapper(
+            &mut shell,
+            &keypair,
+            3,
+            GAS_LIMIT_MULTIPLIER,
+            None,
+            "second",
+        );
+        let other = fee_priority_wrapper(
+            &mut shell,
+            &gen_keypair(),
+            2,
+            GAS_LIMIT_MULTIPLIER,
+            None,
+            "other",
+        );
+        let txs: Vec<TxBytes> = vec![
+            first.to_bytes().into(),
+            second.to_bytes().into(),
+            other.to_bytes().into(),
+        ];
+        let propose = |shell: &TestShell| -> Vec<namada::core::hash::Hash> {
+            let req = RequestPrepareProposal {
+                txs: txs.clone(),
+                ..Default::default()
+            };
+            shell
+                .prepare_proposal(req)
+                .txs
+                .into_iter()
+                .map(|tx_bytes| {
+                    Tx::try_from(tx_bytes.as_ref())
+                        .expect("Test failed")
+                        .header_hash()
+                })
+                .collect()
+        };
+
+        assert_eq!(
+            propose(&shell),
+            vec![
+                other.header_hash(),
+                first.header_hash(),
+                second.header_hash()
+            ]
+        );
+
+        shell.tx_ordering = TxOrdering::Mempool;
+        assert_eq!(
+            propose(&shell),
+            vec![
+                first.header_hash(),
+                second.header_hash(),
+                other.header_hash()
+            ]
+        );
+    }
+
+    /// Test that the wrappers of a fee payer carrying a sequence number are
+    /// proposed in sequence order, and that a wrapper following a gap in the
+    /// sequence is left out of the proposal
+    #[test]
+    fn test_sequence_ordering() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+        let keypair = gen_keypair();
+
+        let second = fee_priority_wrapper(
+            &mut shell,
+            &keypair,
+            3,
+            GAS_LIMIT_MULTIPLIER,
+            Some(1),
+            "second",
+        );
+        let gap = fee_priority_wrapper(
+            &mut shell,
+            &keypair,
+            1,
+            GAS_LIMIT_MULTIPLIER,
+            Some(3),
+            "gap",
+        );
+        let first = fee_priority_wrapper(
+            &mut shell,
+            &keypair,
+            1,
+            GAS_LIMIT_MULTIPLIER,
+            Some(0),
+            "first",
+        );
+
+        for tx_ordering in [TxOrdering::FeePriority, TxOrdering::Mempool] {
+            shell.tx_ordering = tx_ordering;
+            let req = RequestPrepareProposal {
+                txs: vec![
+                    second.to_bytes().into(),
+                    gap.to_bytes().into(),
+                    first.to_bytes().into(),
+                ],
+                ..Default::default()
+            };
+            let received: Vec<_> = shell
+                .prepare_proposal(req)
+                .txs
+                .into_iter()
+                .map(|tx_bytes| {
+                    Tx::try_from(tx_bytes.as_ref())
+                        .expect("Test failed")
+                        .header_hash()
+                })
+                .collect();
+            assert_eq!(
+                received,
+                vec![first.header_hash(), second.header_hash()]
+            );
+        }
+    }
 }
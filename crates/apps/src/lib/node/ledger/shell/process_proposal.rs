@@ -575,6 +575,26 @@ where
                     };
                 }
 
+                // Check that the sequence number, if any, follows the one of
+                // the previous wrapper of the fee payer
+                if let Err(e) = protocol::advance_sequence(temp_state, &wrapper)
+                {
+                    return TxResult {
+                        code: ResultCode::InvalidOrder.into(),
+                        info: e.to_string(),
+                    };
+                }
+
+                // Check that the fee allowance, if any, can pay the fees
+                if let Err(e) =
+                    protocol::check_fee_grant(temp_state, &tx, &wrapper)
+                {
+                    return TxResult {
+                        code: ResultCode::FeeError.into(),
+                        info: e.to_string(),
+                    };
+                }
+
                 // Check that the fee payer has sufficient balance.
                 match process_proposal_fee_check(
                     &wrapper,
@@ -645,6 +665,8 @@ where
         vp_wasm_cache,
         tx_wasm_cache,
     )?;
+    protocol::check_base_gas_price(temp_state, wrapper)
+        .map_err(Error::TxApply)?;
 
     protocol::transfer_fee(temp_state, proposer, wrapper)
         .map_err(Error::TxApply)
@@ -1270,6 +1292,8 @@ mod test_process_proposal {
             epoch: Epoch(0),
             gas_limit: GAS_LIMIT_MULTIPLIER.into(),
             unshield_section_hash: None,
+            fee_granter: None,
+            sequence: None,
         };
 
         let tx = Tx::from_type(TxType::Wrapper(Box::new(wrapper)));
@@ -1480,6 +1504,90 @@ mod test_process_proposal {
         }
     }
 
+    /// Test that a block containing the wrappers of a fee payer out of their
+    /// sequence order is rejected, while the same wrappers in order are
+    /// accepted
+    #[test]
+    fn test_wrapper_sequence_order() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+
+        let keypair = crate::wallet::defaults::daewon_keypair();
+
+        // Add unshielded balance for fee payment
+        let balance_key = token::storage_key::balance_key(
+            &shell.state.in_mem().native_token,
+            &Address::from(&keypair.ref_to()),
+        );
+        shell
+            .state
+            .write(&balance_key, Amount::native_whole(1000))
+            .unwrap();
+
+        let sequenced_wrapper = |sequence: u64| {
+            let mut wrapper = Tx::from_type(TxType::Wrapper(Box::new(
+                WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            1.into(),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    Epoch(0),
+                    GAS_LIMIT_MULTIPLIER.into(),
+                    None,
+                )
+                .with_sequence(sequence),
+            )));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper.set_data(Data::new(
+                format!("transaction data {sequence}").as_bytes().to_owned(),
+            ));
+            wrapper.add_section(Section::Signature(Signature::new(
+                wrapper.sechashes(),
+                [(0, keypair.clone())].into_iter().collect(),
+                None,
+            )));
+            wrapper
+        };
+        let first = sequenced_wrapper(0);
+        let second = sequenced_wrapper(1);
+
+        // Run validation
+        let request = ProcessProposal {
+            txs: vec![second.to_bytes(), first.to_bytes()],
+        };
+        match shell.process_proposal(request) {
+            Ok(_) => panic!("Test failed"),
+            Err(TestError::RejectProposal(response)) => {
+                assert_eq!(
+                    response[0].result.code,
+                    u32::from(ResultCode::InvalidOrder)
+                );
+                assert_eq!(
+                    response[0].result.info,
+                    format!(
+                        "Invalid sequence number: Expected sequence number 0 \
+                         from {}, found 1",
+                        Address::from(&keypair.ref_to())
+                    )
+                );
+                assert_eq!(response[1].result.code, u32::from(ResultCode::Ok));
+            }
+        }
+
+        let request = ProcessProposal {
+            txs: vec![first.to_bytes(), second.to_bytes()],
+        };
+        if let Err(TestError::RejectProposal(response)) =
+            shell.process_proposal(request)
+        {
+            panic!("Test failed: {:?}", response);
+        }
+    }
+
     /// Test that if the unsigned inner tx hash is known (replay attack), the
     /// block is rejected
     #[test]
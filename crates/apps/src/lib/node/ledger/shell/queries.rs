@@ -1,7 +1,7 @@
 //! Shell methods for querying state
 
-use namada::ledger::dry_run_tx;
 use namada::ledger::queries::{RequestCtx, ResponseQuery};
+use namada::ledger::{dry_run_tx, estimate_gas, simulate_tx};
 
 use super::*;
 
@@ -26,6 +26,10 @@ where
         // Invoke the root RPC handler - returns borsh-encoded data on success
         let result = if query.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &query)
+        } else if query.path == "/shell/estimate_gas" {
+            estimate_gas(ctx, &query)
+        } else if query.path == "/shell/simulate_tx" {
+            simulate_tx(ctx, &query)
         } else {
             namada::ledger::queries::handle_path(ctx, &query)
         };
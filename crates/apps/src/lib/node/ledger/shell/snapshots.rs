@@ -0,0 +1,790 @@
+//! Implementation of the state-sync snapshot ABCI requests, with which new
+//! nodes restore the state of a recent block instead of replaying every block
+//! from genesis.
+//!
+//! The snapshot of the state at block height `h` is stored in the `{h}`
+//! sub-directory of the snapshots directory:
+//!
+//! - `chunk_{i}`: the `i`-th chunk, a borsh encoded `Vec<SnapshotEntry>` of up
+//!   to [`CHUNK_SIZE`] bytes of key-vals
+//! - `metadata`: the borsh encoded [`SnapshotMetadata`], also sent to the peers
+//!   with the snapshot
+//!
+//! The key-vals of the account subspace, the replay protection entries, the
+//! ledger state and the last block are read from a checkpoint of the DB taken
+//! on `Commit`, in a separate thread. The merkle tree stores are taken from
+//! the tree of the committed block.
+//!
+//! A node restoring a snapshot checks every chunk against the hashes of the
+//! metadata. Once all the chunks are applied, the merkle tree restored from
+//! the snapshot is checked against the app hash trusted by CometBFT, and the
+//! restored account subspace against a merkle tree rebuilt from its key-vals.
+//! The state that isn't merklized is checked against the merklized key-vals
+//! committing to it, when there are any:
+//!
+//! - the MASP conversion state against the MASP convert anchor and assets hash
+//! - the IBC counters against the number of restored IBC clients, connections
+//!   and channels
+//!
+//! The block results and the height of the last Ethereum block processed by
+//! the oracle aren't committed to at all. They are left out of the snapshots
+//! and rebuilt by the restored node: the results of the restored block are
+//! empty and the oracle starts from its configured height. The remaining
+//! state, namely the replay protection entries, the tx and Ethereum events
+//! queues, the address generator, the predecessor epochs and the other MASP
+//! key-vals, is trusted from the peers. Any tampering with it makes the
+//! restored node diverge from the app hash of the next blocks, which halts
+//! it.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use borsh_ext::BorshSerializeExt;
+use masp_proofs::bls12_381;
+use namada::core::eth_bridge_pool::is_pending_transfer_key;
+use namada::core::hash::Hash;
+use namada::core::storage::{BlockHeight, DbKeySeg, Key};
+use namada::ibc::storage::{
+    channel_counter_key, client_counter_key, connection_counter_key, is_ibc_key,
+};
+use namada::state::{
+    update_epoch_in_merkle_tree, MerkleTree, Sha256Hasher, StorageHasher, DB,
+};
+use namada::storage::SUBSPACE_CF;
+use namada::token;
+use namada_sdk::tendermint::AppHash;
+
+use super::Shell;
+use crate::facade::tendermint::abci::response::ApplySnapshotChunkResult;
+use crate::facade::tendermint::abci::types::Snapshot;
+use crate::facade::tendermint::v0_37::abci::{request, response};
+use crate::node::ledger::storage::{self, SnapshotEntry};
+
+/// The format of the snapshots produced by this version of the ledger
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// The maximum size of the key-vals of a chunk in bytes, unless a single
+/// key-val exceeds it. CometBFT rejects the chunks larger than 16 MiB.
+pub const CHUNK_SIZE: usize = 10 << 20;
+
+/// The name of the file with the metadata of a snapshot
+const METADATA_FILE: &str = "metadata";
+
+/// The suffix of the directory of a snapshot that is being produced
+const PARTIAL_SUFFIX: &str = "partial";
+
+/// The suffix of the directory of the DB checkpoint a snapshot is produced
+/// from
+const CHECKPOINT_SUFFIX: &str = "checkpoint";
+
+/// The metadata of a snapshot
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotMetadata {
+    /// The hashes of the chunks, in order
+    pub chunk_hashes: Vec<Hash>,
+}
+
+/// The restoration of a snapshot offered by CometBFT
+#[derive(Debug)]
+pub struct SnapshotSync<H: StorageHasher> {
+    /// The height of the snapshot
+    height: BlockHeight,
+    /// The app hash at the height of the snapshot, trusted by CometBFT
+    app_hash: AppHash,
+    /// The hashes of the chunks of the snapshot
+    chunk_hashes: Vec<Hash>,
+    /// The merkle tree rebuilt from the key-vals of the account subspace
+    /// restored so far
+    tree: MerkleTree<H>,
+    /// The keys of the pending transfers of the bridge pool restored so far,
+    /// whose merkle tree leaves are taken from the restored tree
+    pending_transfers: BTreeSet<Key>,
+    /// The number of IBC clients, connections and channels restored so far,
+    /// by the key of their counter
+    ibc_counts: BTreeMap<Key, u64>,
+    /// The indices of the chunks restored so far
+    applied_chunks: BTreeSet<usize>,
+}
+
+impl Shell {
+    /// Produce a snapshot of the state of the last committed block, if its
+    /// height is a multiple of the configured snapshot interval. The snapshot
+    /// is written in a separate thread from a checkpoint of the DB, such that
+    /// it doesn't hold the next blocks back.
+    pub fn take_snapshot(&mut self) {
+        let height = self.state.in_mem().get_last_block_height();
+        match self.snapshot_interval {
+            Some(interval) if interval > 0 && height.0 % interval == 0 => {}
+            _ => return,
+        }
+        if self
+            .snapshot_task
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+        {
+            tracing::warn!(
+                "Skipping the state-sync snapshot at height {height}, the \
+                 previous snapshot is still being produced"
+            );
+            return;
+        }
+        if let Err(err) = self.spawn_snapshot_task(height) {
+            tracing::error!(
+                "Failed to start the state-sync snapshot at height {height}: \
+                 {err}"
+            );
+        }
+    }
+
+    /// Checkpoint the DB and spawn the thread writing the snapshot at the
+    /// given height from it
+    fn spawn_snapshot_task(&mut self, height: BlockHeight) -> io::Result<()> {
+        fs::create_dir_all(&self.snapshots_dir)?;
+        remove_partial_snapshots(&self.snapshots_dir)?;
+
+        let checkpoint_dir = self
+            .snapshots_dir
+            .join(format!("{height}.{CHECKPOINT_SUFFIX}"));
+        self.state
+            .db()
+            .checkpoint(&checkpoint_dir)
+            .map_err(io::Error::other)?;
+
+        let in_mem = self.state.in_mem();
+        let epoch = in_mem.block.epoch;
+        let epoch_start_height =
+            match in_mem.block.pred_epochs.get_start_height_of_epoch(epoch) {
+                Some(BlockHeight(0)) | None => BlockHeight(1),
+                Some(height) => height,
+            };
+        let tree_entries = storage::snapshot_merkle_tree_entries(
+            height,
+            epoch,
+            epoch_start_height,
+            &in_mem.block.tree.stores(),
+        );
+
+        let snapshots_dir = self.snapshots_dir.clone();
+        let snapshots_to_keep = self.snapshots_to_keep.unwrap_or(1).max(1);
+        let thread_builder =
+            std::thread::Builder::new().name("ledger-snapshot".into());
+        let task = thread_builder.spawn(move || {
+            let result = write_snapshot(
+                &snapshots_dir,
+                &checkpoint_dir,
+                height,
+                tree_entries,
+            )
+            .and_then(|()| prune_snapshots(&snapshots_dir, snapshots_to_keep));
+            match result {
+                Ok(()) => tracing::info!(
+                    "Produced the state-sync snapshot at height {height}"
+                ),
+                Err(err) => tracing::error!(
+                    "Failed to produce the state-sync snapshot at height \
+                     {height}: {err}"
+                ),
+            }
+            if let Err(err) = fs::remove_dir_all(&checkpoint_dir) {
+                tracing::warn!(
+                    "Failed to remove the DB checkpoint {}: {err}",
+                    checkpoint_dir.to_string_lossy()
+                );
+            }
+        })?;
+        self.snapshot_task = Some(task);
+        Ok(())
+    }
+
+    /// List the snapshots available to the peers
+    pub fn list_snapshots(&self) -> response::ListSnapshots {
+        let snapshots = match read_snapshots(&self.snapshots_dir) {
+            Ok(snapshots) => snapshots,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to read the state-sync snapshots: {err}"
+                );
+                vec![]
+            }
+        };
+        response::ListSnapshots {
+            snapshots: snapshots
+                .into_iter()
+                .filter_map(|(height, metadata)| {
+                    let chunks = SnapshotMetadata::try_from_slice(&metadata)
+                        .ok()?
+                        .chunk_hashes
+                        .len();
+                    Some(Snapshot {
+                        height: height.0.try_into().ok()?,
+                        format: SNAPSHOT_FORMAT,
+                        chunks: chunks.try_into().ok()?,
+                        hash: Hash::sha256(&metadata).0.to_vec().into(),
+                        metadata: metadata.into(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Load a chunk of a snapshot available to the peers. The chunk is empty
+    /// if it can't be found.
+    pub fn load_snapshot_chunk(
+        &self,
+        req: request::LoadSnapshotChunk,
+    ) -> response::LoadSnapshotChunk {
+        if req.format != SNAPSHOT_FORMAT {
+            return Default::default();
+        }
+        let snapshot_dir = self.snapshots_dir.join(req.height.to_string());
+        match fs::read(chunk_path(&snapshot_dir, req.chunk as usize)) {
+            Ok(chunk) => response::LoadSnapshotChunk {
+                chunk: chunk.into(),
+            },
+            Err(err) => {
+                tracing::warn!(
+                    "Failed to load the chunk {} of the state-sync snapshot \
+                     at height {}: {err}",
+                    req.chunk,
+                    req.height
+                );
+                Default::default()
+            }
+        }
+    }
+
+    /// Accept to restore the offered snapshot if it has the supported format
+    /// and the node has no state yet
+    pub fn offer_snapshot(
+        &mut self,
+        req: request::OfferSnapshot,
+    ) -> response::OfferSnapshot {
+        if self.snapshot_sync.take().is_some() {
+            // Delete the key-vals of the snapshot that was being restored
+            if let Err(err) = self.state.db_mut().clear_snapshot_entries() {
+                tracing::error!(
+                    "Failed to clear the previous state-sync snapshot: {err}"
+                );
+                return response::OfferSnapshot::Abort;
+            }
+        }
+        if self.state.in_mem().last_block.is_some() {
+            tracing::error!(
+                "Cannot restore a state-sync snapshot, the node already has a \
+                 state"
+            );
+            return response::OfferSnapshot::Abort;
+        }
+
+        let snapshot = req.snapshot;
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return response::OfferSnapshot::RejectFormat;
+        }
+        let metadata =
+            match SnapshotMetadata::try_from_slice(&snapshot.metadata) {
+                Ok(metadata)
+                    if Hash::sha256(&snapshot.metadata).0.as_slice()
+                        == snapshot.hash.as_ref()
+                        && !metadata.chunk_hashes.is_empty()
+                        && metadata.chunk_hashes.len()
+                            == snapshot.chunks as usize =>
+                {
+                    metadata
+                }
+                _ => return response::OfferSnapshot::Reject,
+            };
+
+        let height = BlockHeight(snapshot.height.value());
+        tracing::info!(
+            "Restoring the state-sync snapshot at height {height} in {} chunks",
+            snapshot.chunks
+        );
+        self.snapshot_sync = Some(SnapshotSync {
+            height,
+            app_hash: req.app_hash,
+            chunk_hashes: metadata.chunk_hashes,
+            tree: MerkleTree::default(),
+            pending_transfers: BTreeSet::new(),
+            ibc_counts: BTreeMap::new(),
+            applied_chunks: BTreeSet::new(),
+        });
+        response::OfferSnapshot::Accept
+    }
+
+    /// Restore a chunk of the snapshot being restored. Once the last chunk is
+    /// applied, the restored state is checked and loaded.
+    pub fn apply_snapshot_chunk(
+        &mut self,
+        req: request::ApplySnapshotChunk,
+    ) -> response::ApplySnapshotChunk {
+        let Some(mut sync) = self.snapshot_sync.take() else {
+            tracing::error!(
+                "Received a state-sync snapshot chunk without a snapshot offer"
+            );
+            return chunk_response(ApplySnapshotChunkResult::Abort);
+        };
+        let index = req.index as usize;
+        let Some(chunk_hash) = sync.chunk_hashes.get(index) else {
+            tracing::error!(
+                "The state-sync snapshot has no chunk with index {index}"
+            );
+            return self.reject_snapshot();
+        };
+        if Hash::sha256(&req.chunk) != *chunk_hash {
+            tracing::warn!(
+                "The chunk {index} of the state-sync snapshot received from \
+                 {} doesn't match its hash",
+                req.sender
+            );
+            self.snapshot_sync = Some(sync);
+            return response::ApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Retry,
+                refetch_chunks: vec![req.index],
+                reject_senders: vec![req.sender],
+            };
+        }
+
+        // A chunk applied again, e.g. after a refetch, must not be counted
+        // twice in the restored state
+        if sync.applied_chunks.insert(index) {
+            if let Err(err) = self.restore_snapshot_chunk(&mut sync, &req.chunk)
+            {
+                tracing::error!(
+                    "Failed to restore the chunk {index} of the state-sync \
+                     snapshot: {err}"
+                );
+                return self.reject_snapshot();
+            }
+        } else {
+            tracing::debug!(
+                "The chunk {index} of the state-sync snapshot was already \
+                 applied"
+            );
+        }
+        if sync.applied_chunks.len() < sync.chunk_hashes.len() {
+            self.snapshot_sync = Some(sync);
+            return chunk_response(ApplySnapshotChunkResult::Accept);
+        }
+
+        let height = sync.height;
+        match self.finish_snapshot_sync(sync) {
+            Ok(()) => {
+                tracing::info!(
+                    "Restored the state-sync snapshot at height {height}"
+                );
+                chunk_response(ApplySnapshotChunkResult::Accept)
+            }
+            Err(err) => {
+                tracing::error!(
+                    "Rejecting the state-sync snapshot at height {height}: \
+                     {err}"
+                );
+                self.reject_snapshot()
+            }
+        }
+    }
+
+    /// Write the key-vals of a chunk to the DB and add the ones of the
+    /// account subspace to the rebuilt merkle tree, counting the IBC objects
+    fn restore_snapshot_chunk(
+        &mut self,
+        sync: &mut SnapshotSync<Sha256Hasher>,
+        chunk: &[u8],
+    ) -> Result<(), String> {
+        let entries = Vec::<SnapshotEntry>::try_from_slice(chunk)
+            .map_err(|err| format!("Invalid chunk: {err}"))?;
+        for entry in &entries {
+            if entry.cf != SUBSPACE_CF {
+                continue;
+            }
+            let key = Key::parse(&entry.key).map_err(|err| err.to_string())?;
+            if let Some(counter_key) = ibc_counter_key(&key) {
+                *sync.ibc_counts.entry(counter_key).or_default() += 1;
+            }
+            if is_pending_transfer_key(&key) {
+                sync.pending_transfers.insert(key);
+            } else if (self.state.merkle_tree_key_filter)(&key) {
+                sync.tree
+                    .update(&key, &entry.value)
+                    .map_err(|err| err.to_string())?;
+            }
+        }
+        self.state
+            .db_mut()
+            .write_snapshot_entries(entries)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Rebuild the state left out of the snapshot and check the restored
+    /// state against the app hash trusted by CometBFT, then load it
+    fn finish_snapshot_sync(
+        &mut self,
+        sync: SnapshotSync<Sha256Hasher>,
+    ) -> Result<(), String> {
+        let SnapshotSync {
+            height,
+            app_hash,
+            tree: mut rebuilt,
+            pending_transfers,
+            ibc_counts,
+            ..
+        } = sync;
+        self.state
+            .db_mut()
+            .write_snapshot_local_state(height)
+            .map_err(|err| err.to_string())?;
+        let block = self
+            .state
+            .db()
+            .read_last_block()
+            .map_err(|err| err.to_string())?
+            .ok_or("The snapshot has no last block")?;
+        if block.height != height {
+            return Err(format!(
+                "The last block of the snapshot is at height {}",
+                block.height
+            ));
+        }
+        let restored =
+            MerkleTree::<Sha256Hasher>::new(block.merkle_tree_stores)
+                .map_err(|err| err.to_string())?;
+        if restored.root().0.as_slice() != app_hash.as_bytes() {
+            return Err(format!(
+                "The merkle tree root {} doesn't match the app hash",
+                restored.root()
+            ));
+        }
+
+        // The heights at which the pending transfers were added to the bridge
+        // pool are only recorded in its merkle tree
+        for key in pending_transfers {
+            let height = restored.get(&key).map_err(|err| err.to_string())?;
+            rebuilt
+                .update(&key, height)
+                .map_err(|err| err.to_string())?;
+        }
+        update_epoch_in_merkle_tree(
+            &mut rebuilt,
+            block.epoch,
+            block.next_epoch_min_start_height,
+            block.next_epoch_min_start_time,
+        )
+        .map_err(|err| err.to_string())?;
+        if rebuilt.root() != restored.root() {
+            return Err(format!(
+                "The merkle tree root {} of the account subspace doesn't \
+                 match the restored root {}",
+                rebuilt.root(),
+                restored.root()
+            ));
+        }
+
+        // The conversion state is committed to by the anchor of its tree and
+        // by the hash of its assets, which isn't written before the first
+        // conversions are computed
+        let conversion_state = &block.conversion_state;
+        let convert_anchor = Hash(
+            bls12_381::Scalar::from(conversion_state.tree.root()).to_bytes(),
+        );
+        let anchor_key = token::storage_key::masp_convert_anchor_key();
+        if self.read_restored::<Hash>(&anchor_key)? != Some(convert_anchor) {
+            return Err(
+                "The MASP conversion tree doesn't match its anchor".to_string()
+            );
+        }
+        let assets_hash =
+            Hash::sha256(conversion_state.assets.serialize_to_vec());
+        let assets_hash_key = token::storage_key::masp_assets_hash_key();
+        match self.read_restored::<Hash>(&assets_hash_key)? {
+            Some(hash) if hash == assets_hash => {}
+            None if conversion_state.assets.is_empty() => {}
+            _ => {
+                return Err("The MASP conversion assets don't match their \
+                            hash"
+                    .to_string());
+            }
+        }
+
+        for counter_key in [
+            client_counter_key(),
+            connection_counter_key(),
+            channel_counter_key(),
+        ] {
+            let count = ibc_counts.get(&counter_key).copied().unwrap_or(0);
+            let counter = self.read_restored::<u64>(&counter_key)?;
+            if counter != Some(count) {
+                return Err(format!(
+                    "The IBC counter {counter_key} is {counter:?}, but \
+                     {count} objects were restored"
+                ));
+            }
+        }
+
+        self.state.load_last_state();
+        self.update_eth_oracle(&Default::default());
+        Ok(())
+    }
+
+    /// Read and decode a restored key-val of the account subspace
+    fn read_restored<T: BorshDeserialize>(
+        &self,
+        key: &Key,
+    ) -> Result<Option<T>, String> {
+        self.state
+            .db()
+            .read_subspace_val(key)
+            .map_err(|err| err.to_string())?
+            .map(|bytes| T::try_from_slice(&bytes))
+            .transpose()
+            .map_err(|err| format!("Invalid value of {key}: {err}"))
+    }
+
+    /// Reject the snapshot being restored, deleting its restored key-vals
+    fn reject_snapshot(&mut self) -> response::ApplySnapshotChunk {
+        if let Err(err) = self.state.db_mut().clear_snapshot_entries() {
+            tracing::error!(
+                "Failed to clear the rejected state-sync snapshot: {err}"
+            );
+            return chunk_response(ApplySnapshotChunkResult::Abort);
+        }
+        chunk_response(ApplySnapshotChunkResult::RejectSnapshot)
+    }
+}
+
+/// A response to a snapshot chunk without chunks to refetch or senders to
+/// reject
+fn chunk_response(
+    result: ApplySnapshotChunkResult,
+) -> response::ApplySnapshotChunk {
+    response::ApplySnapshotChunk {
+        result,
+        refetch_chunks: vec![],
+        reject_senders: vec![],
+    }
+}
+
+/// Get the key of the IBC counter of the clients, connections or channels
+/// that counts the object stored at the given key, if any. These objects are
+/// never deleted, such that each counter is their number.
+fn ibc_counter_key(key: &Key) -> Option<Key> {
+    if !is_ibc_key(key) {
+        return None;
+    }
+    let path = key.segments[1..]
+        .iter()
+        .map(|seg| match seg {
+            DbKeySeg::StringSeg(seg) => Some(seg.as_str()),
+            DbKeySeg::AddressSeg(_) => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    match path[..] {
+        ["clients", _, "clientState"] => Some(client_counter_key()),
+        ["connections", id] if id != "counter" => {
+            Some(connection_counter_key())
+        }
+        ["channelEnds", "ports", _, "channels", _] => {
+            Some(channel_counter_key())
+        }
+        _ => None,
+    }
+}
+
+/// Get the path of the file of a chunk of the snapshot in the given directory
+fn chunk_path(snapshot_dir: &Path, index: usize) -> PathBuf {
+    snapshot_dir.join(format!("chunk_{index}"))
+}
+
+/// Write the chunks and the metadata of the snapshot at the given height
+/// from the checkpoint of the DB, then move the snapshot to its final
+/// directory
+fn write_snapshot(
+    snapshots_dir: &Path,
+    checkpoint_dir: &Path,
+    height: BlockHeight,
+    tree_entries: Vec<SnapshotEntry>,
+) -> io::Result<()> {
+    let partial_dir = snapshots_dir.join(format!("{height}.{PARTIAL_SUFFIX}"));
+    fs::create_dir_all(&partial_dir)?;
+    let db = storage::open(checkpoint_dir, None).map_err(io::Error::other)?;
+    let entries = tree_entries
+        .into_iter()
+        .chain(db.iter_snapshot_entries(height).map_err(io::Error::other)?);
+
+    let mut chunk_hashes = vec![];
+    let mut chunk = vec![];
+    let mut chunk_size = 0;
+    for entry in entries {
+        let entry_size = entry.cf.len() + entry.key.len() + entry.value.len();
+        if chunk_size + entry_size > CHUNK_SIZE && !chunk.is_empty() {
+            let index = chunk_hashes.len();
+            chunk_hashes.push(write_chunk(
+                &partial_dir,
+                index,
+                std::mem::take(&mut chunk),
+            )?);
+            chunk_size = 0;
+        }
+        chunk.push(entry);
+        chunk_size += entry_size;
+    }
+    if !chunk.is_empty() {
+        let index = chunk_hashes.len();
+        chunk_hashes.push(write_chunk(&partial_dir, index, chunk)?);
+    }
+
+    let metadata = SnapshotMetadata { chunk_hashes };
+    fs::write(partial_dir.join(METADATA_FILE), metadata.serialize_to_vec())?;
+    fs::rename(partial_dir, snapshots_dir.join(height.to_string()))
+}
+
+/// Write a chunk of the snapshot in the given directory and return its hash
+fn write_chunk(
+    snapshot_dir: &Path,
+    index: usize,
+    entries: Vec<SnapshotEntry>,
+) -> io::Result<Hash> {
+    let bytes = entries.serialize_to_vec();
+    fs::write(chunk_path(snapshot_dir, index), &bytes)?;
+    Ok(Hash::sha256(bytes))
+}
+
+/// Get the heights of the complete snapshots, in ascending order
+fn snapshot_heights(snapshots_dir: &Path) -> io::Result<Vec<BlockHeight>> {
+    if !snapshots_dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let mut heights = vec![];
+    for entry in fs::read_dir(snapshots_dir)? {
+        let name = entry?.file_name();
+        // The directories of the partial snapshots and of the checkpoints
+        // don't parse as a height
+        if let Some(height) = name.to_str().and_then(|n| u64::from_str(n).ok())
+        {
+            heights.push(BlockHeight(height));
+        }
+    }
+    heights.sort();
+    Ok(heights)
+}
+
+/// Read the metadata of the complete snapshots, in ascending order of height
+fn read_snapshots(
+    snapshots_dir: &Path,
+) -> io::Result<Vec<(BlockHeight, Vec<u8>)>> {
+    snapshot_heights(snapshots_dir)?
+        .into_iter()
+        .map(|height| {
+            let metadata_path =
+                snapshots_dir.join(height.to_string()).join(METADATA_FILE);
+            Ok((height, fs::read(metadata_path)?))
+        })
+        .collect()
+}
+
+/// Delete the oldest complete snapshots in excess of the given number
+fn prune_snapshots(snapshots_dir: &Path, to_keep: u64) -> io::Result<()> {
+    let heights = snapshot_heights(snapshots_dir)?;
+    let to_keep = usize::try_from(to_keep).unwrap_or(usize::MAX);
+    for height in &heights[..heights.len().saturating_sub(to_keep)] {
+        fs::remove_dir_all(snapshots_dir.join(height.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Delete the leftovers of the snapshots whose production was interrupted
+fn remove_partial_snapshots(snapshots_dir: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(snapshots_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.ends_with(PARTIAL_SUFFIX) || name.ends_with(CHECKPOINT_SUFFIX) {
+            fs::remove_dir_all(entry.path())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test that only the complete snapshots are listed and that the oldest
+    /// ones are pruned, together with the leftovers of partial snapshots.
+    #[test]
+    fn test_prune_snapshots() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshots_dir = dir.path();
+        for height in [3_u64, 1, 2] {
+            let snapshot_dir = snapshots_dir.join(height.to_string());
+            fs::create_dir_all(&snapshot_dir).unwrap();
+            let metadata = SnapshotMetadata {
+                chunk_hashes: vec![Hash::sha256([height as u8])],
+            };
+            fs::write(
+                snapshot_dir.join(METADATA_FILE),
+                metadata.serialize_to_vec(),
+            )
+            .unwrap();
+        }
+        fs::create_dir_all(snapshots_dir.join(format!("4.{PARTIAL_SUFFIX}")))
+            .unwrap();
+        fs::create_dir_all(
+            snapshots_dir.join(format!("4.{CHECKPOINT_SUFFIX}")),
+        )
+        .unwrap();
+
+        assert_eq!(
+            snapshot_heights(snapshots_dir).unwrap(),
+            vec![BlockHeight(1), BlockHeight(2), BlockHeight(3)]
+        );
+
+        prune_snapshots(snapshots_dir, 2).unwrap();
+        remove_partial_snapshots(snapshots_dir).unwrap();
+        let snapshots = read_snapshots(snapshots_dir).unwrap();
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|(height, _)| *height)
+                .collect::<Vec<_>>(),
+            vec![BlockHeight(2), BlockHeight(3)]
+        );
+        let metadata =
+            SnapshotMetadata::try_from_slice(&snapshots[0].1).unwrap();
+        assert_eq!(metadata.chunk_hashes, vec![Hash::sha256([2_u8])]);
+        assert_eq!(fs::read_dir(snapshots_dir).unwrap().count(), 2);
+    }
+
+    /// Test that only the keys of the IBC clients, connections and channels
+    /// are counted by the IBC counters.
+    #[test]
+    fn test_ibc_counter_key() {
+        use namada::ibc::storage::ibc_key;
+
+        for (path, counter_key) in [
+            (
+                "clients/07-tendermint-0/clientState",
+                Some(client_counter_key()),
+            ),
+            ("connections/connection-0", Some(connection_counter_key())),
+            (
+                "channelEnds/ports/transfer/channels/channel-0",
+                Some(channel_counter_key()),
+            ),
+            ("clients/counter", None),
+            ("connections/counter", None),
+            ("channelEnds/counter", None),
+            ("clients/07-tendermint-0/consensusStates/0-1", None),
+            ("clients/07-tendermint-0/connections", None),
+            ("nextSequenceSend/ports/transfer/channels/channel-0", None),
+        ] {
+            let key = ibc_key(path).unwrap();
+            assert_eq!(ibc_counter_key(&key), counter_key, "{path}");
+        }
+        let key =
+            Key::parse("not_ibc/clients/07-tendermint-0/clientState").unwrap();
+        assert_eq!(ibc_counter_key(&key), None);
+    }
+}
@@ -9,7 +9,6 @@ use std::task::Poll;
 use color_eyre::eyre::{Report, Result};
 use data_encoding::HEXUPPER;
 use itertools::Either;
-use lazy_static::lazy_static;
 use namada::control_flow::time::Duration;
 use namada::core::ethereum_events::EthereumEvent;
 use namada::core::ethereum_structs;
@@ -18,11 +17,11 @@ use namada::core::key::tm_consensus_key_raw_hash;
 use namada::core::storage::{BlockHash, BlockHeight, Epoch, Header};
 use namada::core::time::DateTimeUtc;
 use namada::eth_bridge::oracle::config::Config as OracleConfig;
-use namada::ledger::dry_run_tx;
 use namada::ledger::events::log::dumb_queries;
 use namada::ledger::queries::{
     EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
 };
+use namada::ledger::{dry_run_tx, estimate_gas, simulate_tx};
 use namada::proof_of_stake::pos_queries::PosQueries;
 use namada::proof_of_stake::storage::{
     read_consensus_validator_set_addresses_with_stake,
@@ -35,7 +34,6 @@ use namada::tendermint::abci::types::VoteInfo;
 use namada_sdk::queries::Client;
 use namada_sdk::tendermint_proto::google::protobuf::Timestamp;
 use namada_sdk::tx::data::ResultCode;
-use regex::Regex;
 use tendermint_rpc::endpoint::block;
 use tendermint_rpc::SimpleRequest;
 use tokio::sync::mpsc;
@@ -711,6 +709,10 @@ impl<'a> Client for &'a MockNode {
         };
         if request.path == "/shell/dry_run_tx" {
             dry_run_tx(ctx, &request)
+        } else if request.path == "/shell/estimate_gas" {
+            estimate_gas(ctx, &request)
+        } else if request.path == "/shell/simulate_tx" {
+            simulate_tx(ctx, &request)
         } else {
             rpc.handle(ctx, &request)
         }
@@ -971,26 +973,10 @@ impl<'a> Client for &'a MockNode {
 fn parse_tm_query(
     query: namada::tendermint_rpc::query::Query,
 ) -> dumb_queries::QueryMatcher {
-    const QUERY_PARSING_REGEX_STR: &str =
-        r"^tm\.event='NewBlock' AND (accepted|applied)\.hash='([^']+)'$";
-
-    lazy_static! {
-        /// Compiled regular expression used to parse Tendermint queries.
-        static ref QUERY_PARSING_REGEX: Regex = Regex::new(QUERY_PARSING_REGEX_STR).unwrap();
-    }
-
-    let query = query.to_string();
-    let captures = QUERY_PARSING_REGEX.captures(&query).unwrap();
-
-    match captures.get(0).unwrap().as_str() {
-        "accepted" => dumb_queries::QueryMatcher::accepted(
-            captures.get(1).unwrap().as_str().try_into().unwrap(),
-        ),
-        "applied" => dumb_queries::QueryMatcher::applied(
-            captures.get(1).unwrap().as_str().try_into().unwrap(),
-        ),
-        _ => unreachable!("We only query accepted or applied txs"),
-    }
+    query
+        .to_string()
+        .parse()
+        .expect("We only query events that Namada can match")
 }
 
 /// A Namada event log index and event type encoded as
@@ -4,16 +4,19 @@ pub mod bridge_pool_vext;
 pub mod eth_events;
 pub mod val_set_update;
 
-use namada::ethereum_bridge::protocol::transactions::bridge_pool_roots::sign_bridge_pool_root;
-use namada::ethereum_bridge::protocol::transactions::ethereum_events::sign_ethereum_events;
+use namada::ethereum_bridge::protocol::transactions::bridge_pool_roots::bridge_pool_root_vext;
+use namada::ethereum_bridge::protocol::transactions::ethereum_events::ethereum_events_vext;
 use namada::ethereum_bridge::protocol::transactions::validator_set_update::sign_validator_set_update;
 pub use namada::ethereum_bridge::protocol::validation::VoteExtensionError;
+use namada::proof_of_stake::storage::validator_protocol_key_handle;
 use namada::tx::Signed;
 use namada::vote_ext::{
     bridge_pool_roots, ethereum_events, validator_set_update, VoteExtension,
 };
+use namada_sdk::signing::ProtocolVext;
 
 use super::*;
+use crate::node::ledger::protocol_signer::SigningRequest;
 use crate::node::ledger::shims::abcipp_shim_types::shim::TxBytes;
 
 /// Message to be passed to `.expect()` calls in this module.
@@ -57,17 +60,11 @@ where
             .mode
             .get_validator_address()
             .expect(VALIDATOR_EXPECT_MSG);
-        let protocol_key = match &self.mode {
-            ShellMode::Validator { data, .. } => &data.keys.protocol_keypair,
-            _ => unreachable!("{VALIDATOR_EXPECT_MSG}"),
-        };
-        sign_ethereum_events(
-            &self.state,
-            validator_addr,
-            protocol_key,
-            ethereum_events,
-        )
-        .map(|ethereum_events::SignedVext(ext)| ext)
+        let protocol_key =
+            self.mode.get_protocol_key().expect(VALIDATOR_EXPECT_MSG);
+        let ext =
+            ethereum_events_vext(&self.state, validator_addr, ethereum_events)?;
+        Some(ext.sign(protocol_key))
     }
 
     /// Extend PreCommit votes with [`bridge_pool_roots::Vext`] instances.
@@ -82,17 +79,94 @@ where
             .mode
             .get_eth_bridge_keypair()
             .expect(VALIDATOR_EXPECT_MSG);
-        let protocol_key = match &self.mode {
-            ShellMode::Validator { data, .. } => &data.keys.protocol_keypair,
-            _ => unreachable!("{VALIDATOR_EXPECT_MSG}"),
-        };
-        sign_bridge_pool_root(
-            &self.state,
-            validator_addr,
-            eth_hot_key,
-            protocol_key,
-        )
-        .map(|bridge_pool_roots::SignedVext(ext)| ext)
+        let protocol_key =
+            self.mode.get_protocol_key().expect(VALIDATOR_EXPECT_MSG);
+        let ext =
+            bridge_pool_root_vext(&self.state, validator_addr, eth_hot_key)?;
+        let bridge_pool_roots::SignedVext(signed) = ext.sign(protocol_key);
+        Some(signed)
+    }
+
+    /// Creates the data of a vote extension to be signed with the protocol
+    /// key held by the remote signer of this validator.
+    ///
+    /// INVARIANT: This method must be stateless.
+    pub fn craft_signing_requests(&mut self) -> Vec<SigningRequest> {
+        let ethereum_events = self.new_ethereum_events();
+        let validator_addr = self
+            .mode
+            .get_validator_address()
+            .expect(VALIDATOR_EXPECT_MSG);
+        let eth_hot_key = self
+            .mode
+            .get_eth_bridge_keypair()
+            .expect(VALIDATOR_EXPECT_MSG);
+        let bridge_pool_root =
+            bridge_pool_root_vext(&self.state, validator_addr, eth_hot_key)
+                .map(|ext| {
+                    SigningRequest::Vext(ProtocolVext::BridgePoolRoot(ext))
+                });
+        let validator_set_update =
+            self.extend_vote_with_valset_update().map(|ext| {
+                SigningRequest::Tx(EthereumTxData::ValSetUpdateVext(ext))
+            });
+        [
+            self.ethereum_events_signing_request(ethereum_events),
+            bridge_pool_root,
+            validator_set_update,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Creates the request to sign a vote extension over the given Ethereum
+    /// events with the protocol key held by the remote signer of this
+    /// validator.
+    pub fn ethereum_events_signing_request(
+        &self,
+        ethereum_events: Vec<EthereumEvent>,
+    ) -> Option<SigningRequest> {
+        let validator_addr = self
+            .mode
+            .get_validator_address()
+            .expect(VALIDATOR_EXPECT_MSG);
+        ethereum_events_vext(&self.state, validator_addr, ethereum_events)
+            .map(|ext| SigningRequest::Vext(ProtocolVext::EthereumEvents(ext)))
+    }
+
+    /// Hand the given data over to the remote signer holding the protocol
+    /// key of this validator, to be signed and broadcast without blocking the
+    /// shell. The protocol key of this validator is read from storage.
+    pub fn request_remote_signatures(&self, requests: Vec<SigningRequest>) {
+        if requests.is_empty() {
+            return;
+        }
+        let remote_signer =
+            self.mode.get_remote_signer().expect(VALIDATOR_EXPECT_MSG);
+        let validator_addr = self
+            .mode
+            .get_validator_address()
+            .expect(VALIDATOR_EXPECT_MSG);
+        let epoch = self.state.in_mem().get_current_epoch().0;
+        let protocol_pk = read_pos_params(&self.state).and_then(|params| {
+            validator_protocol_key_handle(validator_addr).get(
+                &self.state,
+                epoch,
+                &params,
+            )
+        });
+        match protocol_pk {
+            Ok(Some(pubkey)) => remote_signer.request(pubkey, requests),
+            Ok(None) => tracing::error!(
+                "No protocol key found in storage for validator \
+                 {validator_addr} at epoch {epoch}"
+            ),
+            Err(err) => tracing::error!(
+                "Unable to read the protocol key of validator \
+                 {validator_addr}: {err}"
+            ),
+        }
     }
 
     /// Extend PreCommit votes with [`validator_set_update::Vext`]
@@ -11,8 +11,8 @@ use namada::proof_of_stake::storage::find_validator_by_raw_hash;
 use namada::time::{DateTimeUtc, Utc};
 use namada::tx::data::hash_tx;
 use namada::tx::Tx;
-use tokio::sync::broadcast;
 use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::{broadcast, watch};
 use tower::Service;
 
 use super::abcipp_shim_types::shim::request::{FinalizeBlock, ProcessedTx};
@@ -27,6 +27,13 @@ use crate::facade::tendermint_proto::v0_37::abci::ResponseDeliverTx;
 use crate::facade::tower_abci::BoxError;
 use crate::node::ledger::shell::{EthereumOracleChannels, Shell};
 
+/// A channel for forwarding requests to the shell, along with the channels
+/// to send their responses back
+pub type ShellSender = std::sync::mpsc::Sender<(
+    Req,
+    tokio::sync::oneshot::Sender<Result<Resp, BoxError>>,
+)>;
+
 /// The shim wraps the shell, which implements ABCI++.
 /// The shim makes a crude translation between the ABCI interface currently used
 /// by tendermint and the shell's interface.
@@ -39,6 +46,9 @@ pub struct AbcippShim {
         Req,
         tokio::sync::oneshot::Sender<Result<Resp, BoxError>>,
     )>,
+    /// The height of the last committed block, published once the shell
+    /// has committed it
+    committed_height: watch::Sender<BlockHeight>,
 }
 
 impl AbcippShim {
@@ -60,20 +70,24 @@ impl AbcippShim {
         let (shell_send, shell_recv) = std::sync::mpsc::channel();
         let (server_shutdown, _) = broadcast::channel::<()>(1);
         let action_at_height = config.shell.action_at_height.clone();
+        let service = Shell::new(
+            config,
+            wasm_dir,
+            broadcast_sender,
+            eth_oracle,
+            Some(db_cache),
+            vp_wasm_compilation_cache,
+            tx_wasm_compilation_cache,
+        );
+        let (committed_height, _) =
+            watch::channel(service.state.in_mem().get_last_block_height());
         (
             Self {
-                service: Shell::new(
-                    config,
-                    wasm_dir,
-                    broadcast_sender,
-                    eth_oracle,
-                    Some(db_cache),
-                    vp_wasm_compilation_cache,
-                    tx_wasm_compilation_cache,
-                ),
+                service,
                 begin_block_request: None,
                 delivered_txs: vec![],
                 shell_recv,
+                committed_height,
             },
             AbciService {
                 shell_send,
@@ -92,10 +106,16 @@ impl AbcippShim {
         hash_tx(bytes.as_slice())
     }
 
+    /// Get a receiver of the heights of the blocks committed by the shell
+    pub fn committed_heights(&self) -> watch::Receiver<BlockHeight> {
+        self.committed_height.subscribe()
+    }
+
     /// Run the shell's blocking loop that receives messages from the
     /// [`AbciService`].
     pub fn run(mut self) {
         while let Ok((req, resp_sender)) = self.shell_recv.recv() {
+            let is_commit = matches!(req, Req::Commit);
             let resp = match req {
                 Req::ProcessProposal(proposal) => self
                     .service
@@ -183,6 +203,12 @@ impl AbcippShim {
                 },
             };
 
+            if is_commit && resp.is_ok() {
+                // Wake up the long-polled event subscriptions
+                self.committed_height.send_replace(
+                    self.service.state.in_mem().get_last_block_height(),
+                );
+            }
             let resp = resp.map_err(|e| e.into());
             if resp_sender.send(resp).is_err() {
                 tracing::info!("ABCI response channel is closed")
@@ -207,10 +233,7 @@ enum CheckAction {
 #[derive(Debug)]
 pub struct AbciService {
     /// A channel for forwarding requests to the shell
-    shell_send: std::sync::mpsc::Sender<(
-        Req,
-        tokio::sync::oneshot::Sender<Result<Resp, BoxError>>,
-    )>,
+    shell_send: ShellSender,
     /// Indicates if the consensus connection is suspended.
     suspended: bool,
     /// This resolves the non-completing futures returned to tower-abci
@@ -221,6 +244,11 @@ pub struct AbciService {
 }
 
 impl AbciService {
+    /// Get a channel for forwarding requests to the shell
+    pub fn shell_sender(&self) -> ShellSender {
+        self.shell_send.clone()
+    }
+
     /// Check if we are at a block height with a scheduled action.
     /// If so, perform the action.
     fn maybe_take_action(
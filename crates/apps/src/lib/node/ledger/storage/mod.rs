@@ -11,7 +11,9 @@ use arse_merkle_tree::H256;
 use blake2b_rs::{Blake2b, Blake2bBuilder};
 use namada::state::StorageHasher;
 use namada_sdk::state::FullAccessState;
-pub use rocksdb::RocksDBUpdateVisitor;
+pub use rocksdb::{
+    open, snapshot_merkle_tree_entries, RocksDBUpdateVisitor, SnapshotEntry,
+};
 
 #[derive(Default)]
 pub struct PersistentStorageHasher(Blake2bHasher);
@@ -171,6 +173,7 @@ mod tests {
             pos_inflation_amount: Default::default(),
             fee_unshielding_gas_limit: 0,
             fee_unshielding_descriptions_limit: 0,
+            fee_burn_rate: Default::default(),
             minimum_gas_price: Default::default(),
         };
         parameters::init_storage(&params, &mut state).expect("Test failed");
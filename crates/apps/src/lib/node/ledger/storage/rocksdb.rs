@@ -20,6 +20,7 @@
 //!     - `next_epoch_min_start_time`
 //!     - `update_epoch_blocks_delay`
 //!   - `conversion_state`: MASP conversion state
+//!   - `archive_ranges`: the ranges of the archived block heights, if any
 //! - `subspace`: accounts sub-spaces
 //!   - `{address}/{dyn}`: any byte data associated with accounts
 //! - `diffs`: diffs in account subspaces' key-vals
@@ -27,6 +28,8 @@
 //!   - `old/{dyn}`: value from predecessor block height
 //! - `block`: block state
 //!   - `results/{h}`: block results at height `h`
+//!   - `storage_diffs/{h}`: storage diffs of the txs applied at height `h`, if
+//!     indexed
 //!   - `h`: for each block at height `h`:
 //!     - `tree`: merkle tree
 //!       - `root`: root hash
@@ -39,6 +42,14 @@
 //! - `replay_protection`: hashes of processed tx
 //!     - `all`: the hashes included up to the last block
 //!     - `last`: the hashes included in the last block
+//! - `events`: events emitted by `FinalizeBlock` calls
+//!   - `event/{h}/{i}`: the `i`-th event emitted at block height `h`
+//!   - `type/{event_type}/{h}/{i}`: index of events by their type
+//!   - `attr/{hash}/{h}/{i}`: index of events by their attributes
+//! - `archive`: every version of the account subspace key-vals, only written in
+//!   the archive mode
+//!   - `{dyn}\0{h}`: value set (or deleted) in block height `h`, or any value
+//!     at the first height `h` of an archived range
 
 use std::fs::File;
 use std::io::{BufWriter, Write};
@@ -46,10 +57,11 @@ use std::path::Path;
 use std::str::FromStr;
 use std::sync::Mutex;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use data_encoding::HEXLOWER;
 use itertools::Either;
+use namada::core::event::Event;
 use namada::core::storage::{
     BlockHeight, BlockResults, Epoch, EthEventsQueue, Header, Key, KeySeg,
     KEY_SEGMENT_SEPARATOR,
@@ -61,22 +73,27 @@ use namada::ledger::eth_bridge::storage::bridge_pool;
 use namada::ledger::storage::tx_queue::TxQueue;
 use namada::replay_protection;
 use namada::state::merkle_tree::{base_tree_key_prefix, subtree_key_prefix};
+use namada::state::storage::archive;
+use namada::state::storage::event_store::{self, EventIndex};
 use namada::state::{
     BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, DbError as Error,
-    DbResult as Result, MerkleTreeStoresRead, PatternIterator, PrefixIterator,
-    StoreType, DB,
+    DbResult as Result, MerkleTreeStoresRead, MerkleTreeStoresWrite,
+    PatternIterator, PrefixIterator, StoreType, DB,
 };
 use namada::storage::{
-    DbColFam, BLOCK_CF, DIFFS_CF, REPLAY_PROTECTION_CF, STATE_CF, SUBSPACE_CF,
+    DbColFam, ARCHIVE_CF, BLOCK_CF, DIFFS_CF, EVENTS_CF, REPLAY_PROTECTION_CF,
+    STATE_CF, SUBSPACE_CF,
 };
 use namada::token::ConversionState;
+use namada::tx::data::BlockStorageDiffs;
 use namada_sdk::migrations::DBUpdateVisitor;
 use rayon::prelude::*;
 use regex::Regex;
+use rocksdb::checkpoint::Checkpoint;
 use rocksdb::{
     BlockBasedOptions, ColumnFamily, ColumnFamilyDescriptor, DBCompactionStyle,
-    DBCompressionType, Direction, FlushOptions, IteratorMode, Options,
-    ReadOptions, WriteBatch,
+    DBCompressionType, DBRawIterator, Direction, FlushOptions, IteratorMode,
+    Options, ReadOptions, WriteBatch,
 };
 
 use crate::config::utils::num_of_threads;
@@ -90,6 +107,13 @@ const ENV_VAR_ROCKSDB_COMPACTION_THREADS: &str =
 const OLD_DIFF_PREFIX: &str = "old";
 const NEW_DIFF_PREFIX: &str = "new";
 
+/// The number of subspace key-vals seeded in the archive per write batch
+const ARCHIVE_SEED_BATCH_LEN: usize = 10_000;
+
+/// The column families of the key-vals of the state-sync snapshots
+const SNAPSHOT_CFS: [&str; 4] =
+    [SUBSPACE_CF, REPLAY_PROTECTION_CF, STATE_CF, BLOCK_CF];
+
 /// RocksDB handle
 #[derive(Debug)]
 pub struct RocksDB(rocksdb::DB);
@@ -98,6 +122,17 @@ pub struct RocksDB(rocksdb::DB);
 #[derive(Default)]
 pub struct RocksDBWriteBatch(WriteBatch);
 
+/// A key-val of a state-sync snapshot
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SnapshotEntry {
+    /// The name of the column family of the key-val
+    pub cf: String,
+    /// The key
+    pub key: String,
+    /// The value
+    pub value: Vec<u8>,
+}
+
 /// Open RocksDB for the DB
 pub fn open(
     path: impl AsRef<Path>,
@@ -189,6 +224,22 @@ pub fn open(
         replay_protection_cf_opts,
     ));
 
+    // for events (insert-intensive)
+    let mut events_cf_opts = Options::default();
+    events_cf_opts.set_compression_type(DBCompressionType::Zstd);
+    events_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    events_cf_opts.set_compaction_style(DBCompactionStyle::Universal);
+    events_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(EVENTS_CF, events_cf_opts));
+
+    // for the archive (insert-intensive)
+    let mut archive_cf_opts = Options::default();
+    archive_cf_opts.set_compression_type(DBCompressionType::Zstd);
+    archive_cf_opts.set_compression_options(0, 0, 0, 1024 * 1024);
+    archive_cf_opts.set_compaction_style(DBCompactionStyle::Universal);
+    archive_cf_opts.set_block_based_table_factory(&table_opts);
+    cfs.push(ColumnFamilyDescriptor::new(ARCHIVE_CF, archive_cf_opts));
+
     rocksdb::DB::open_cf_descriptors(&db_opts, path, cfs)
         .map(RocksDB)
         .map_err(|e| Error::DBError(e.into_string()))
@@ -207,6 +258,105 @@ impl RocksDB {
             .ok_or(Error::DBError("No {cf_name} column family".to_string()))
     }
 
+    /// Delete the events emitted between the given heights (inclusive),
+    /// together with their index entries.
+    fn delete_events(
+        &self,
+        batch: &mut WriteBatch,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let index = EventIndex::All;
+        let read_opts = make_range_read_opts(
+            index.lower_bound(from_height),
+            index.upper_bound(to_height),
+        );
+        let iter =
+            self.0
+                .iterator_cf_opt(events_cf, read_opts, IteratorMode::Start);
+        for result in iter {
+            let (key, value) =
+                result.map_err(|e| Error::DBError(e.into_string()))?;
+            let key = String::from_utf8(key.to_vec())
+                .map_err(|e| Error::DBError(e.to_string()))?;
+            let (height, position) = event_store::parse_position(&index, &key)
+                .ok_or_else(|| Error::UnknownKey { key: key.clone() })?;
+            let event = Event::try_from_slice(&value)
+                .map_err(Error::BorshCodingError)?;
+            for index_key in event_store::index_keys(&event, height, position) {
+                batch.delete_cf(events_cf, index_key);
+            }
+            batch.delete_cf(events_cf, key);
+        }
+        Ok(())
+    }
+
+    /// Read the event pointed to by an entry of the given index of the event
+    /// store.
+    fn read_event_entry(
+        &self,
+        index: &EventIndex,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<(BlockHeight, Event)> {
+        let key = String::from_utf8(key.to_vec())
+            .map_err(|e| Error::DBError(e.to_string()))?;
+        let (height, position) = event_store::parse_position(index, &key)
+            .ok_or(Error::UnknownKey { key })?;
+        // Index entries only point to the event's main key
+        let value = match index {
+            EventIndex::All => value.to_vec(),
+            _ => {
+                let events_cf = self.get_column_family(EVENTS_CF)?;
+                let event_key = event_store::event_key(height, position);
+                self.0
+                    .get_cf(events_cf, &event_key)
+                    .map_err(|e| Error::DBError(e.into_string()))?
+                    .ok_or(Error::UnknownKey { key: event_key })?
+            }
+        };
+        let event =
+            Event::try_from_slice(&value).map_err(Error::BorshCodingError)?;
+        Ok((height, event))
+    }
+
+    /// Find the range of the archive of the subspace that covers the given
+    /// height, if any.
+    fn find_archive_range(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<archive::ArchiveRange>> {
+        Ok(archive::find_range(&self.read_archive_ranges()?, height))
+    }
+
+    /// Read the value of an account subspace key at the given height, covered
+    /// by the given range, from the archive with a single reverse seek from
+    /// the version at that height.
+    fn read_archive_val(
+        &self,
+        key: &Key,
+        range: archive::ArchiveRange,
+        height: BlockHeight,
+    ) -> Result<Option<Vec<u8>>> {
+        let archive_cf = self.get_column_family(ARCHIVE_CF)?;
+        let mut iter = self
+            .0
+            .raw_iterator_cf_opt(archive_cf, make_iter_read_opts(None));
+        seek_archive_val(&mut iter, &key.to_string(), range, height)
+    }
+
+    /// Read the height seeded with all the subspace key-vals to start a new
+    /// range of the archive, if any.
+    fn read_archive_seed(&self) -> Result<Option<BlockHeight>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        self.0
+            .get_cf(state_cf, archive::ARCHIVE_SEED_KEY)
+            .map_err(|e| Error::DBError(e.into_string()))?
+            .map(|bytes| decode(bytes).map_err(Error::CodingError))
+            .transpose()
+    }
+
     /// Persist the diff of an account subspace key-val under the height where
     /// it was changed.
     fn write_subspace_diff(
@@ -550,6 +700,43 @@ impl RocksDB {
         let block_cf = self.get_column_family(BLOCK_CF)?;
         tracing::info!("Removing last block results");
         batch.delete_cf(block_cf, format!("results/{}", last_block.height));
+        batch.delete_cf(
+            block_cf,
+            format!("storage_diffs/{}", last_block.height),
+        );
+
+        // Delete the events emitted in the last block
+        tracing::info!("Removing last block events");
+        self.delete_events(&mut batch, last_block.height, last_block.height)?;
+
+        // Delete the versions archived in the last block, if any. The diffs
+        // of the last block are never pruned, so they hold all of its keys.
+        // If the last block started an archived range, the versions seeded
+        // for the other keys are left behind, but they are not read once the
+        // range is removed, and the range is seeded again when restarted.
+        let mut archive_ranges = self.read_archive_ranges()?;
+        if archive::find_range(&archive_ranges, last_block.height).is_some() {
+            tracing::info!("Removing last block archived versions");
+            let archive_cf = self.get_column_family(ARCHIVE_CF)?;
+            for is_old in [true, false] {
+                for (key, _, _) in
+                    iter_diffs_prefix(self, last_block.height, None, is_old)
+                {
+                    let key = Key::parse(key).map_err(Error::KeyError)?;
+                    batch.delete_cf(
+                        archive_cf,
+                        archive::version_key(&key, last_block.height),
+                    );
+                }
+            }
+            archive::remove_last_height(&mut archive_ranges, last_block.height);
+            batch.put_cf(
+                state_cf,
+                archive::ARCHIVE_RANGES_KEY,
+                encode(&archive_ranges),
+            );
+            batch.delete_cf(state_cf, archive::ARCHIVE_SEED_KEY);
+        }
 
         // Restore the state of replay protection to the last block
         let reprot_cf = self.get_column_family(REPLAY_PROTECTION_CF)?;
@@ -652,6 +839,172 @@ impl RocksDB {
         tracing::info!("Flushing restored state to disk");
         self.exec_batch(batch)
     }
+
+    /// Create a checkpoint of the DB in the given directory, which mustn't
+    /// exist yet. The checkpoint is a consistent copy of the DB sharing its
+    /// immutable files, that can be read from while the DB keeps being
+    /// written to.
+    pub fn checkpoint(&self, dir: impl AsRef<Path>) -> Result<()> {
+        Checkpoint::new(&self.0)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(dir))
+            .map_err(|e| Error::DBError(e.into_string()))
+    }
+
+    /// Iterate over the key-vals of the state-sync snapshot of the last block,
+    /// committed at the given height: the account subspace, the replay
+    /// protection entries, the ledger state and the data of the block. The
+    /// merkle tree stores aren't included, see
+    /// [`snapshot_merkle_tree_entries`]. The predecessor values of the ledger
+    /// state and the archive ranges are left out, as a node restored
+    /// from a snapshot can neither rollback nor read the archive. The block
+    /// results and the Ethereum height aren't committed to by the app hash,
+    /// so they are left out too and rebuilt by the restored node, see
+    /// [`RocksDB::write_snapshot_local_state`].
+    pub fn iter_snapshot_entries(
+        &self,
+        height: BlockHeight,
+    ) -> Result<impl Iterator<Item = SnapshotEntry> + '_> {
+        let block_prefix = format!("{}/", height.raw());
+        let tree_prefix = format!("{block_prefix}tree/");
+
+        let mut iters = Vec::with_capacity(SNAPSHOT_CFS.len());
+        for (cf_name, prefix) in [
+            (SUBSPACE_CF, None),
+            (REPLAY_PROTECTION_CF, None),
+            (STATE_CF, None),
+            (BLOCK_CF, Some(block_prefix)),
+        ] {
+            let cf = self.get_column_family(cf_name)?;
+            let read_opts = make_iter_read_opts(prefix.clone());
+            let mode = match &prefix {
+                Some(prefix) => {
+                    IteratorMode::From(prefix.as_bytes(), Direction::Forward)
+                }
+                None => IteratorMode::Start,
+            };
+            let iter = self.0.iterator_cf_opt(cf, read_opts, mode);
+            iters.push(
+                PersistentPrefixIterator(
+                    // Empty prefix string to prevent stripping
+                    PrefixIterator::new(iter, String::default()),
+                )
+                .map(move |(key, value, _gas)| SnapshotEntry {
+                    cf: cf_name.to_string(),
+                    key,
+                    value,
+                }),
+            );
+        }
+        Ok(iters.into_iter().flatten().filter(move |entry| {
+            match entry.cf.as_str() {
+                STATE_CF => {
+                    !(entry.key.starts_with("pred/")
+                        || entry.key == archive::ARCHIVE_RANGES_KEY
+                        || entry.key == archive::ARCHIVE_SEED_KEY
+                        || entry.key == "ethereum_height")
+                }
+                BLOCK_CF => !entry.key.starts_with(&tree_prefix),
+                _ => true,
+            }
+        }))
+    }
+
+    /// Write the key-vals of a chunk of a state-sync snapshot.
+    pub fn write_snapshot_entries(
+        &mut self,
+        entries: Vec<SnapshotEntry>,
+    ) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        for SnapshotEntry { cf, key, value } in entries {
+            if !SNAPSHOT_CFS.contains(&cf.as_str()) {
+                return Err(Error::DBError(format!(
+                    "Unexpected column family {cf} in a snapshot"
+                )));
+            }
+            batch.put_cf(self.get_column_family(&cf)?, key, value);
+        }
+        self.exec_batch(batch)
+    }
+
+    /// Write the key-vals left out of the state-sync snapshots once all its
+    /// chunks are written: empty results of the block at the given height,
+    /// and no Ethereum height, such that the oracle starts from the
+    /// configured height. Any value received from the peers is overwritten.
+    pub fn write_snapshot_local_state(
+        &mut self,
+        height: BlockHeight,
+    ) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        batch.put_cf(
+            self.get_column_family(BLOCK_CF)?,
+            format!("results/{}", height.raw()),
+            encode(&BlockResults::default()),
+        );
+        batch.put_cf(
+            self.get_column_family(STATE_CF)?,
+            "ethereum_height",
+            encode(&Option::<ethereum_structs::BlockHeight>::None),
+        );
+        self.exec_batch(batch)
+    }
+
+    /// Delete all the key-vals that may have been written from the chunks of
+    /// a rejected state-sync snapshot. This must only be used on a node that
+    /// had no state before restoring the snapshot.
+    pub fn clear_snapshot_entries(&mut self) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        for cf_name in SNAPSHOT_CFS {
+            // The keys are UTF-8 strings, which never contain the byte 0xff
+            batch.delete_range_cf(
+                self.get_column_family(cf_name)?,
+                vec![],
+                vec![u8::MAX],
+            );
+        }
+        self.exec_batch(batch)
+    }
+}
+
+/// Get the key-vals of a state-sync snapshot with the merkle tree stores of
+/// the block at the given height. The stores are keyed as if the block was
+/// the first one of its epoch, such that a node restored from the snapshot
+/// can load the full tree of the block and rebuild it from the diffs of the
+/// blocks committed after it.
+pub fn snapshot_merkle_tree_entries(
+    height: BlockHeight,
+    epoch: Epoch,
+    epoch_start_height: BlockHeight,
+    stores: &MerkleTreeStoresWrite<'_>,
+) -> Vec<SnapshotEntry> {
+    let mut key_prefixes =
+        vec![(StoreType::Base, base_tree_key_prefix(height))];
+    if epoch_start_height != height {
+        key_prefixes
+            .push((StoreType::Base, base_tree_key_prefix(epoch_start_height)));
+    }
+    key_prefixes.extend(
+        StoreType::iter_subtrees()
+            .map(|st| (*st, subtree_key_prefix(st, epoch))),
+    );
+    key_prefixes
+        .into_iter()
+        .flat_map(|(st, key_prefix)| {
+            let root_key = key_prefix.clone().with_segment("root".to_owned());
+            let store_key = key_prefix.with_segment("store".to_owned());
+            [
+                SnapshotEntry {
+                    cf: BLOCK_CF.to_string(),
+                    key: root_key.to_string(),
+                    value: encode(stores.root(&st)),
+                },
+                SnapshotEntry {
+                    cf: BLOCK_CF.to_string(),
+                    key: store_key.to_string(),
+                    value: stores.store(&st).encode(),
+                },
+            ]
+        })
+        .collect()
 }
 
 impl DB for RocksDB {
@@ -941,6 +1294,7 @@ impl DB for RocksDB {
             update_epoch_blocks_delay,
             address_gen,
             results,
+            storage_diffs,
             conversion_state,
             tx_queue,
             ethereum_height,
@@ -1104,6 +1458,13 @@ impl DB for RocksDB {
             let results_path = format!("results/{}", height.raw());
             batch.0.put_cf(block_cf, results_path, encode(&results));
         }
+        // Storage diffs of the block's txs
+        if !storage_diffs.is_empty() {
+            let storage_diffs_path = format!("storage_diffs/{}", height.raw());
+            batch
+                .0
+                .put_cf(block_cf, storage_diffs_path, encode(storage_diffs));
+        }
         // Predecessor block epochs
         {
             let key = prefix_key
@@ -1148,6 +1509,22 @@ impl DB for RocksDB {
         }
     }
 
+    fn read_storage_diffs(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<BlockStorageDiffs>> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let storage_diffs_path = format!("storage_diffs/{}", height.raw());
+        match self
+            .0
+            .get_cf(block_cf, storage_diffs_path)
+            .map_err(|e| Error::DBError(e.into_string()))?
+        {
+            Some(bytes) => Ok(Some(decode(bytes).map_err(Error::CodingError)?)),
+            None => Ok(None),
+        }
+    }
+
     fn read_merkle_tree_stores(
         &self,
         epoch: Epoch,
@@ -1249,6 +1626,10 @@ impl DB for RocksDB {
         height: BlockHeight,
         last_height: BlockHeight,
     ) -> Result<Option<Vec<u8>>> {
+        if let Some(range) = self.find_archive_range(height)? {
+            return self.read_archive_val(key, range, height);
+        }
+
         // Check if the value changed at this height
         let diffs_cf = self.get_column_family(DIFFS_CF)?;
         let (old_val_key, new_val_key) = old_and_new_diff_key(key, height)?;
@@ -1626,6 +2007,227 @@ impl DB for RocksDB {
 
         Ok(())
     }
+
+    fn add_events_to_batch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[Event],
+    ) -> Result<()> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        for (index, event) in events.iter().enumerate() {
+            let index = index as u32;
+            batch.0.put_cf(
+                events_cf,
+                event_store::event_key(height, index),
+                encode(event),
+            );
+            for key in event_store::index_keys(event, height, index) {
+                batch.0.put_cf(events_cf, key, vec![]);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_events(
+        &self,
+        index: &EventIndex,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+        limit: Option<usize>,
+    ) -> Result<Vec<(BlockHeight, Event)>> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let read_opts = make_range_read_opts(
+            index.lower_bound(from_height),
+            index.upper_bound(to_height),
+        );
+        let iter =
+            self.0
+                .iterator_cf_opt(events_cf, read_opts, IteratorMode::Start);
+        let mut events = vec![];
+        for result in iter.take(limit.unwrap_or(usize::MAX)) {
+            let (key, value) =
+                result.map_err(|e| Error::DBError(e.into_string()))?;
+            events.push(self.read_event_entry(index, &key, &value)?);
+        }
+        Ok(events)
+    }
+
+    fn find_last_event(
+        &self,
+        index: &EventIndex,
+        to_height: BlockHeight,
+        matches: &dyn Fn(&Event) -> bool,
+    ) -> Result<Option<(BlockHeight, Event)>> {
+        let events_cf = self.get_column_family(EVENTS_CF)?;
+        let read_opts =
+            make_range_read_opts(index.prefix(), index.upper_bound(to_height));
+        let iter =
+            self.0
+                .iterator_cf_opt(events_cf, read_opts, IteratorMode::End);
+        for result in iter {
+            let (key, value) =
+                result.map_err(|e| Error::DBError(e.into_string()))?;
+            let (height, event) = self.read_event_entry(index, &key, &value)?;
+            if matches(&event) {
+                return Ok(Some((height, event)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn prune_events(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        below_height: BlockHeight,
+    ) -> Result<()> {
+        match below_height.0.checked_sub(1) {
+            Some(last_pruned) => self.delete_events(
+                &mut batch.0,
+                BlockHeight(0),
+                BlockHeight(last_pruned),
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn seed_archive(&mut self, height: BlockHeight) -> Result<()> {
+        let ranges = self.read_archive_ranges()?;
+        if !archive::needs_seed(&ranges, self.read_archive_seed()?, height) {
+            return Ok(());
+        }
+        // The versions are written at the height of the next block, with the
+        // values of the last committed block. The ones changed by the next
+        // block are overwritten when it's committed.
+        tracing::info!("Seeding the archive at height {height}...");
+        let archive_cf = self.get_column_family(ARCHIVE_CF)?;
+        let mut batch = WriteBatch::default();
+        let mut seeded: u64 = 0;
+        for (key, value, _gas) in iter_subspace_prefix(self, None) {
+            batch.put_cf(
+                archive_cf,
+                archive::version_key(&key, height),
+                Some(value).serialize_to_vec(),
+            );
+            seeded += 1;
+            if batch.len() >= ARCHIVE_SEED_BATCH_LEN {
+                self.0
+                    .write(std::mem::take(&mut batch))
+                    .map_err(|e| Error::DBError(e.into_string()))?;
+                tracing::info!("Seeded {seeded} key-vals in the archive");
+            }
+        }
+        let state_cf = self.get_column_family(STATE_CF)?;
+        batch.put_cf(state_cf, archive::ARCHIVE_SEED_KEY, encode(&height));
+        self.0
+            .write(batch)
+            .map_err(|e| Error::DBError(e.into_string()))?;
+        tracing::info!(
+            "Seeded the archive at height {height} with {seeded} key-vals"
+        );
+        Ok(())
+    }
+
+    fn add_archive_to_batch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        changes: &[(Key, Option<Vec<u8>>)],
+    ) -> Result<()> {
+        let mut ranges = self.read_archive_ranges()?;
+        if archive::needs_seed(&ranges, self.read_archive_seed()?, height) {
+            return Err(Error::DBError(format!(
+                "The archive must be seeded at height {height} to start a new \
+                 range of archived heights"
+            )));
+        }
+        archive::add_height(&mut ranges, height);
+        let state_cf = self.get_column_family(STATE_CF)?;
+        batch
+            .0
+            .put_cf(state_cf, archive::ARCHIVE_RANGES_KEY, encode(&ranges));
+        let archive_cf = self.get_column_family(ARCHIVE_CF)?;
+        for (key, value) in changes {
+            batch.0.put_cf(
+                archive_cf,
+                archive::version_key(key, height),
+                value.serialize_to_vec(),
+            );
+        }
+        Ok(())
+    }
+
+    fn read_archive_ranges(&self) -> Result<Vec<archive::ArchiveRange>> {
+        let state_cf = self.get_column_family(STATE_CF)?;
+        match self
+            .0
+            .get_cf(state_cf, archive::ARCHIVE_RANGES_KEY)
+            .map_err(|e| Error::DBError(e.into_string()))?
+        {
+            Some(bytes) => decode(bytes).map_err(Error::CodingError),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn read_archive_prefix(
+        &self,
+        prefix: &Key,
+        height: BlockHeight,
+    ) -> Result<Option<Vec<(String, Vec<u8>)>>> {
+        let Some(range) = self.find_archive_range(height)? else {
+            return Ok(None);
+        };
+        let archive_cf = self.get_column_family(ARCHIVE_CF)?;
+        let db_prefix = archive::sub_keys_prefix(prefix);
+        let read_opts = make_iter_read_opts(Some(db_prefix.clone()));
+        let mut iter = self.0.raw_iterator_cf_opt(archive_cf, read_opts);
+        let mut values = vec![];
+        // Every key under the prefix is read with a reverse seek to its
+        // version at the height, followed by a seek past all of its versions
+        // to the next key, regardless of how many versions it has
+        iter.seek(&db_prefix);
+        while let Some(version_key) = iter.key() {
+            let version_key = std::str::from_utf8(version_key)
+                .map_err(|e| Error::DBError(e.to_string()))?;
+            let (key, _) =
+                archive::parse_version_key(version_key).ok_or_else(|| {
+                    Error::UnknownKey {
+                        key: version_key.to_owned(),
+                    }
+                })?;
+            let key = key.to_owned();
+            if let Some(value) =
+                seek_archive_val(&mut iter, &key, range, height)?
+            {
+                values.push((key.clone(), value));
+            }
+            iter.seek(archive::versions_end(&key));
+        }
+        iter.status().map_err(|e| Error::DBError(e.into_string()))?;
+        Ok(Some(values))
+    }
+}
+
+/// Read the value of an account subspace key at the given height, covered by
+/// the given range, with a reverse seek of the given iterator of the archive
+/// from the version at that height.
+fn seek_archive_val(
+    iter: &mut DBRawIterator<'_>,
+    key: &str,
+    range: archive::ArchiveRange,
+    height: BlockHeight,
+) -> Result<Option<Vec<u8>>> {
+    iter.seek_for_prev(archive::version_key(&key, height));
+    iter.status().map_err(|e| Error::DBError(e.into_string()))?;
+    let version = match iter.item() {
+        Some((version_key, bytes)) => Some((
+            std::str::from_utf8(version_key)
+                .map_err(|e| Error::DBError(e.to_string()))?,
+            bytes,
+        )),
+        None => None,
+    };
+    archive::value_of_version(key, version, range)
 }
 
 /// A struct that can visit a set of updates,
@@ -1957,6 +2559,17 @@ fn make_iter_read_opts(prefix: Option<String>) -> ReadOptions {
     read_opts
 }
 
+/// Make read options for RocksDB iterator over the keys in between the given
+/// lower (inclusive) and upper (exclusive) bounds
+fn make_range_read_opts(lower: String, upper: String) -> ReadOptions {
+    let mut read_opts = ReadOptions::default();
+    // don't use the prefix bloom filter
+    read_opts.set_total_order_seek(true);
+    read_opts.set_iterate_lower_bound(lower.into_bytes());
+    read_opts.set_iterate_upper_bound(upper.into_bytes());
+    read_opts
+}
+
 impl DBWriteBatch for RocksDBWriteBatch {}
 
 fn old_and_new_diff_key(
@@ -2555,6 +3168,329 @@ mod test {
         }
     }
 
+    /// Test that events written to the event store can be looked up by their
+    /// indices and pruned.
+    #[test]
+    fn test_event_store() {
+        use namada::core::event::{EventLevel, EventType};
+
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+
+        let event = |event_type: EventType, hash: &str| Event {
+            event_type,
+            level: EventLevel::Tx,
+            attributes: [("hash".to_string(), hash.to_string())]
+                .into_iter()
+                .collect(),
+        };
+        let mut batch = RocksDB::batch();
+        for height in 1..=3_u64 {
+            let hash = format!("tx{height}");
+            db.add_events_to_batch(
+                &mut batch,
+                BlockHeight(height),
+                &[
+                    event(EventType::Accepted, &hash),
+                    event(EventType::Applied, &hash),
+                ],
+            )
+            .unwrap();
+        }
+        db.exec_batch(batch.0).unwrap();
+
+        let all = db
+            .read_events(&EventIndex::All, BlockHeight(0), BlockHeight(3), None)
+            .unwrap();
+        assert_eq!(all.len(), 6);
+        assert_eq!(all[0], (BlockHeight(1), event(EventType::Accepted, "tx1")));
+        assert_eq!(all[5], (BlockHeight(3), event(EventType::Applied, "tx3")));
+
+        let applied = db
+            .read_events(
+                &EventIndex::event_type(&EventType::Applied),
+                BlockHeight(2),
+                BlockHeight(3),
+                Some(1),
+            )
+            .unwrap();
+        assert_eq!(
+            applied,
+            vec![(BlockHeight(2), event(EventType::Applied, "tx2"))]
+        );
+
+        let index = EventIndex::Attribute {
+            key: "hash".to_string(),
+            value: "tx3".to_string(),
+        };
+        let by_hash = db
+            .read_events(&index, BlockHeight(0), BlockHeight(3), None)
+            .unwrap();
+        assert_eq!(
+            by_hash,
+            vec![
+                (BlockHeight(3), event(EventType::Accepted, "tx3")),
+                (BlockHeight(3), event(EventType::Applied, "tx3")),
+            ]
+        );
+
+        // The most recent matching event is found from the end of the index
+        let last_accepted = db
+            .find_last_event(&index, BlockHeight(3), &|event| {
+                event.event_type == EventType::Accepted
+            })
+            .unwrap();
+        assert_eq!(
+            last_accepted,
+            Some((BlockHeight(3), event(EventType::Accepted, "tx3")))
+        );
+        let last_applied = db
+            .find_last_event(
+                &EventIndex::event_type(&EventType::Applied),
+                BlockHeight(2),
+                &|_| true,
+            )
+            .unwrap();
+        assert_eq!(
+            last_applied,
+            Some((BlockHeight(2), event(EventType::Applied, "tx2")))
+        );
+        let missing = db
+            .find_last_event(&index, BlockHeight(2), &|_| true)
+            .unwrap();
+        assert_eq!(missing, None);
+
+        // Prune the events below height 3
+        let mut batch = RocksDB::batch();
+        db.prune_events(&mut batch, BlockHeight(3)).unwrap();
+        db.exec_batch(batch.0).unwrap();
+
+        let all = db
+            .read_events(&EventIndex::All, BlockHeight(0), BlockHeight(3), None)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        let applied = db
+            .read_events(
+                &EventIndex::event_type(&EventType::Applied),
+                BlockHeight(0),
+                BlockHeight(3),
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            applied,
+            vec![(BlockHeight(3), event(EventType::Applied, "tx3"))]
+        );
+    }
+
+    /// Test that the archived versions of the subspace key-vals are read at
+    /// any height covered by the archive, also under a prefix.
+    #[test]
+    fn test_archive() {
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+        assert_eq!(db.read_archive_ranges().unwrap(), vec![]);
+
+        // A key written before the archive mode is enabled
+        let key = Key::parse("prefix/key").unwrap();
+        let other = Key::parse("prefix/other").unwrap();
+        let old = Key::parse("prefix/old").unwrap();
+        let mut batch = RocksDB::batch();
+        db.batch_write_subspace_val(
+            &mut batch,
+            BlockHeight(1),
+            &old,
+            [1_u8],
+            true,
+        )
+        .unwrap();
+        db.exec_batch(batch.0).unwrap();
+
+        let archive_block = |db: &mut RocksDB, height: u64, changes: &[_]| {
+            let mut batch = RocksDB::batch();
+            db.add_archive_to_batch(&mut batch, BlockHeight(height), changes)
+                .unwrap();
+            db.exec_batch(batch.0).unwrap();
+        };
+        // A new range can only be archived once seeded
+        let mut batch = RocksDB::batch();
+        assert!(
+            db.add_archive_to_batch(&mut batch, BlockHeight(2), &[])
+                .is_err()
+        );
+        db.seed_archive(BlockHeight(2)).unwrap();
+        archive_block(
+            &mut db,
+            2,
+            &[(key.clone(), Some(vec![2])), (other.clone(), Some(vec![0]))],
+        );
+        archive_block(&mut db, 3, &[]);
+        archive_block(&mut db, 4, &[]);
+        archive_block(&mut db, 5, &[(key.clone(), Some(vec![5]))]);
+        archive_block(&mut db, 6, &[]);
+        archive_block(&mut db, 7, &[(other.clone(), None)]);
+        assert_eq!(
+            db.read_archive_ranges().unwrap(),
+            vec![archive::ArchiveRange {
+                start: BlockHeight(2),
+                end: BlockHeight(7),
+            }]
+        );
+
+        let read = |db: &RocksDB, key: &Key, height: u64| {
+            db.read_subspace_val_with_height(
+                key,
+                BlockHeight(height),
+                BlockHeight(10),
+            )
+            .unwrap()
+        };
+        assert_eq!(read(&db, &key, 2), Some(vec![2]));
+        assert_eq!(read(&db, &key, 4), Some(vec![2]));
+        assert_eq!(read(&db, &key, 6), Some(vec![5]));
+        assert_eq!(read(&db, &other, 6), Some(vec![0]));
+        assert_eq!(read(&db, &other, 7), None);
+        // The key not written since the archive mode was enabled is seeded
+        assert_eq!(read(&db, &old, 4), Some(vec![1]));
+
+        let prefix = Key::parse("prefix").unwrap();
+        assert_eq!(
+            db.read_archive_prefix(&prefix, BlockHeight(1)).unwrap(),
+            None
+        );
+        assert_eq!(
+            db.read_archive_prefix(&prefix, BlockHeight(6)).unwrap(),
+            Some(vec![
+                ("prefix/key".to_string(), vec![5]),
+                ("prefix/old".to_string(), vec![1]),
+                ("prefix/other".to_string(), vec![0]),
+            ])
+        );
+        assert_eq!(
+            db.read_archive_prefix(&prefix, BlockHeight(7)).unwrap(),
+            Some(vec![
+                ("prefix/key".to_string(), vec![5]),
+                ("prefix/old".to_string(), vec![1]),
+            ])
+        );
+
+        // After the archive mode is turned off at height 8, the heights that
+        // are not archived are not served from the archive
+        assert_eq!(
+            db.read_archive_prefix(&prefix, BlockHeight(8)).unwrap(),
+            None
+        );
+        // Once turned on again, the new range is seeded with the subspace
+        // key-vals, instead of the stale versions of the previous range
+        let mut batch = RocksDB::batch();
+        db.batch_write_subspace_val(
+            &mut batch,
+            BlockHeight(8),
+            &key,
+            [8_u8],
+            true,
+        )
+        .unwrap();
+        db.exec_batch(batch.0).unwrap();
+        db.seed_archive(BlockHeight(9)).unwrap();
+        archive_block(&mut db, 9, &[]);
+        assert_eq!(
+            db.read_archive_ranges().unwrap(),
+            vec![
+                archive::ArchiveRange {
+                    start: BlockHeight(2),
+                    end: BlockHeight(7),
+                },
+                archive::ArchiveRange {
+                    start: BlockHeight(9),
+                    end: BlockHeight(9),
+                },
+            ]
+        );
+        assert_eq!(read(&db, &key, 9), Some(vec![8]));
+        assert_eq!(read(&db, &other, 9), None);
+        assert_eq!(read(&db, &key, 6), Some(vec![5]));
+    }
+
+    /// Test that the key-vals of a state-sync snapshot restore the last block
+    /// in another DB, and can be cleared from it.
+    #[test]
+    fn test_snapshot_entries() {
+        let dir = tempdir().unwrap();
+        let mut db = open(dir.path(), None).unwrap();
+
+        let key = Key::parse("test").unwrap();
+        for height in [1, 2] {
+            let height = BlockHeight(height);
+            let mut batch = RocksDB::batch();
+            db.batch_write_subspace_val(
+                &mut batch,
+                height,
+                &key,
+                vec![height.0 as u8],
+                true,
+            )
+            .unwrap();
+            add_block_to_batch(
+                &db,
+                &mut batch,
+                height,
+                Epoch::default(),
+                Epochs::default(),
+                &ConversionState::default(),
+            )
+            .unwrap();
+            db.exec_batch(batch.0).unwrap();
+        }
+
+        let height = BlockHeight(2);
+        let merkle_tree = MerkleTree::<Sha256Hasher>::default();
+        let entries: Vec<SnapshotEntry> = snapshot_merkle_tree_entries(
+            height,
+            Epoch::default(),
+            BlockHeight(1),
+            &merkle_tree.stores(),
+        )
+        .into_iter()
+        .chain(db.iter_snapshot_entries(height).unwrap())
+        .collect();
+        // Only the data of the last block is included, without the state
+        // that is rebuilt locally
+        assert!(entries.iter().all(|entry| {
+            !entry.key.starts_with("pred/")
+                && !entry.key.starts_with("results/")
+                && entry.key != "ethereum_height"
+                && entry.key != "1/hash"
+        }));
+        assert!(entries.iter().any(|entry| entry.key == "2/hash"));
+
+        let restored_dir = tempdir().unwrap();
+        let mut restored = open(restored_dir.path(), None).unwrap();
+        let unexpected = SnapshotEntry {
+            cf: EVENTS_CF.to_string(),
+            key: "event".to_string(),
+            value: vec![],
+        };
+        assert!(restored.write_snapshot_entries(vec![unexpected]).is_err());
+        restored.write_snapshot_entries(entries).unwrap();
+        assert!(restored.read_last_block().unwrap().is_none());
+        restored.write_snapshot_local_state(height).unwrap();
+
+        let last_block = restored.read_last_block().unwrap().unwrap();
+        assert_eq!(last_block.height, height);
+        assert_eq!(last_block.results, BlockResults::default());
+        assert_eq!(last_block.ethereum_height, None);
+        assert!(
+            MerkleTree::<Sha256Hasher>::new(last_block.merkle_tree_stores)
+                .is_ok()
+        );
+        assert_eq!(restored.read_subspace_val(&key).unwrap(), Some(vec![2]));
+
+        restored.clear_snapshot_entries().unwrap();
+        assert!(restored.read_last_block().unwrap().is_none());
+        assert_eq!(restored.read_subspace_val(&key).unwrap(), None);
+    }
+
     /// A test helper to write a block
     fn add_block_to_batch(
         db: &RocksDB,
@@ -2574,6 +3510,7 @@ mod test {
         let address_gen = EstablishedAddressGen::new("whatever");
         let tx_queue = TxQueue::default();
         let results = BlockResults::default();
+        let storage_diffs = BlockStorageDiffs::default();
         let eth_events_queue = EthEventsQueue::default();
         let block = BlockStateWrite {
             merkle_tree_stores,
@@ -2583,6 +3520,7 @@ mod test {
             time,
             epoch,
             results: &results,
+            storage_diffs: &storage_diffs,
             conversion_state,
             pred_epochs: &pred_epochs,
             next_epoch_min_start_height,
@@ -398,6 +398,15 @@ async fn update_tendermint_config(
 
     config.consensus.create_empty_blocks = true;
 
+    // The consensus key may be held by a privval signer, e.g. tmkms, instead
+    // of the `priv_validator_key.json` file
+    if let Some(laddr) = &config.priv_validator_laddr {
+        tracing::info!(
+            "CometBFT will request the consensus signatures from the privval \
+             signer connecting to {laddr}"
+        );
+    }
+
     // mempool config
     // https://forum.cosmos.network/t/our-understanding-of-the-cosmos-hub-mempool-issues/12040
     {
@@ -32,7 +32,8 @@ mod dev {
             .store
             .validator_keys
             .protocol_keypair
-            .clone();
+            .clone()
+            .expect("The validator wallet should hold the protocol key");
         let eth_bridge_key = VALIDATOR_WALLET.eth_hot_key.clone();
         (protocol_key, eth_bridge_key)
     }
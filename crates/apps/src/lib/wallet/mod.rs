@@ -201,7 +201,7 @@ pub fn gen_validator_keys<U: WalletIo>(
         data.keys.protocol_keypair.clone()
     })?;
     let eth_bridge_keypair = find_secret_key(wallet, eth_bridge_pk, |data| {
-        data.keys.eth_bridge_keypair.clone()
+        Some(data.keys.eth_bridge_keypair.clone())
     })?;
     Ok(store::gen_validator_keys(
         eth_bridge_keypair,
@@ -221,7 +221,7 @@ fn find_secret_key<F, U>(
     extract_key: F,
 ) -> Result<Option<common::SecretKey>, FindKeyError>
 where
-    F: Fn(&ValidatorData) -> common::SecretKey,
+    F: Fn(&ValidatorData) -> Option<common::SecretKey>,
     U: WalletIo,
 {
     maybe_pk
@@ -231,7 +231,7 @@ where
                 // TODO: optionally encrypt validator keys
                 .find_key_by_pkh(&pkh, None)
                 .ok()
-                .or_else(|| wallet.get_validator_data().map(extract_key))
+                .or_else(|| wallet.get_validator_data().and_then(extract_key))
                 .ok_or_else(|| FindKeyError::KeyNotFound(pkh.to_string()))
         })
         .transpose()
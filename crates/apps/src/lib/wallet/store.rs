@@ -52,7 +52,7 @@ pub fn gen_validator_keys(
     let protocol_keypair = protocol_keypair
         .unwrap_or_else(|| gen_secret_key(protocol_keypair_scheme, &mut OsRng));
     ValidatorKeys {
-        protocol_keypair,
+        protocol_keypair: Some(protocol_keypair),
         eth_bridge_keypair,
     }
 }
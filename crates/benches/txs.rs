@@ -11,9 +11,11 @@ use namada::core::key::{
     SecretKey as SecretKeyInterface, SigScheme,
 };
 use namada::core::masp::{TransferSource, TransferTarget};
-use namada::core::storage::Key;
+use namada::core::storage::{Epoch, Key};
 use namada::governance::pgf::storage::steward::StewardDetail;
-use namada::governance::storage::proposal::ProposalType;
+use namada::governance::storage::proposal::{
+    PGFStream, ProposalType, StoragePgfStream,
+};
 use namada::governance::storage::vote::ProposalVote;
 use namada::governance::{InitProposalData, VoteProposalData};
 use namada::ibc::core::channel::types::channel::Order;
@@ -40,14 +42,14 @@ use namada::tx::{Code, Section};
 use namada_apps::bench_utils::{
     BenchShell, BenchShieldedCtx, ALBERT_PAYMENT_ADDRESS, ALBERT_SPENDING_KEY,
     BERTHA_PAYMENT_ADDRESS, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_PGF_STREAM, TX_CHANGE_CONSENSUS_KEY_WASM,
     TX_CHANGE_VALIDATOR_COMMISSION_WASM, TX_CHANGE_VALIDATOR_METADATA_WASM,
-    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
-    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL_WASM, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK_WASM, TX_UNBOND_WASM,
-    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL_WASM, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    TX_CLAIM_PGF_STREAM, TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+    TX_IBC_WASM, TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL_WASM,
+    TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+    TX_REVEAL_PK_WASM, TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM,
+    TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_STEWARD_COMMISSION,
+    TX_VOTE_PROPOSAL_WASM, TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 use namada_apps::wallet::defaults;
 use sha2::Digest;
@@ -425,6 +427,7 @@ fn init_account(c: &mut Criterion) {
         public_keys: vec![new_account.to_public()],
         vp_code_hash: extra_hash,
         threshold: 1,
+        vesting: None,
     };
     let tx = shell.generate_tx(
         TX_INIT_ACCOUNT_WASM,
@@ -466,6 +469,7 @@ fn init_proposal(c: &mut Criterion) {
                                     voting_start_epoch: 12.into(),
                                     voting_end_epoch: 15.into(),
                                     grace_epoch: 18.into(),
+                                    expedited: false,
                                 },
                                 None,
                                 Some(vec![content_section]),
@@ -518,6 +522,7 @@ fn init_proposal(c: &mut Criterion) {
                                     voting_start_epoch: 12.into(),
                                     voting_end_epoch: 15.into(),
                                     grace_epoch: 18.into(),
+                                    expedited: false,
                                 },
                                 None,
                                 Some(vec![content_section, wasm_code_section]),
@@ -959,6 +964,103 @@ fn update_steward_commission(c: &mut Criterion) {
     });
 }
 
+fn pgf_stream() -> PGFStream {
+    PGFStream {
+        target: defaults::albert_address(),
+        amount_per_epoch: Amount::native_whole(1),
+        cap: Amount::native_whole(100),
+        start_epoch: Epoch(0),
+        cliff_epoch: Epoch(0),
+        end_epoch: Epoch(100),
+    }
+}
+
+fn claim_pgf_stream(c: &mut Criterion) {
+    c.bench_function("claim pgf stream", |b| {
+        b.iter_batched_ref(
+            || {
+                let mut shell = BenchShell::default();
+                namada::governance::pgf::storage::keys::streams_handle()
+                    .insert(
+                        &mut shell.state,
+                        defaults::albert_address(),
+                        StoragePgfStream::new(pgf_stream(), 0),
+                    )
+                    .unwrap();
+                let native_token = shell.state.in_mem().native_token.clone();
+                namada::token::credit_tokens(
+                    &mut shell.state,
+                    &native_token,
+                    &namada::governance::pgf::ADDRESS,
+                    Amount::native_whole(100),
+                )
+                .unwrap();
+
+                let data = namada::tx::data::pgf::ClaimPgfStream {
+                    target: defaults::albert_address(),
+                };
+                let tx = shell.generate_tx(
+                    TX_CLAIM_PGF_STREAM,
+                    data,
+                    None,
+                    None,
+                    vec![&defaults::albert_keypair()],
+                );
+
+                (shell, tx)
+            },
+            |(shell, tx)| shell.execute_tx(tx),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+}
+
+fn cancel_pgf_stream(c: &mut Criterion) {
+    c.bench_function("cancel pgf stream", |b| {
+        b.iter_batched_ref(
+            || {
+                let mut shell = BenchShell::default();
+                namada::governance::pgf::storage::keys::stewards_handle()
+                    .insert(
+                        &mut shell.state,
+                        defaults::albert_address(),
+                        StewardDetail::base(defaults::albert_address()),
+                    )
+                    .unwrap();
+                namada::governance::pgf::storage::keys::streams_handle()
+                    .insert(
+                        &mut shell.state,
+                        defaults::bertha_address(),
+                        StoragePgfStream::new(
+                            PGFStream {
+                                target: defaults::bertha_address(),
+                                ..pgf_stream()
+                            },
+                            0,
+                        ),
+                    )
+                    .unwrap();
+
+                let data = namada::tx::data::pgf::CancelPgfStream {
+                    steward: defaults::albert_address(),
+                    target: defaults::bertha_address(),
+                };
+                let tx = shell.generate_tx(
+                    TX_CANCEL_PGF_STREAM,
+                    data,
+                    None,
+                    None,
+                    vec![&defaults::albert_keypair()],
+                );
+
+                (shell, tx)
+            },
+            |(shell, tx)| shell.execute_tx(tx),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+}
+
 fn deactivate_validator(c: &mut Criterion) {
     let shell = BenchShell::default();
     let signed_tx = shell.generate_tx(
@@ -1093,6 +1195,8 @@ criterion_group!(
     tx_bridge_pool,
     resign_steward,
     update_steward_commission,
+    claim_pgf_stream,
+    cancel_pgf_stream,
     deactivate_validator,
     reactivate_validator,
     change_validator_metadata,
@@ -135,6 +135,11 @@ impl From<raw::Address<'_, raw::Validated>> for Address {
                 InternalAddress::IbcToken(IbcTokenHash(*raw_addr.data())),
             ),
             raw::Discriminant::Masp => Address::Internal(InternalAddress::Masp),
+            raw::Discriminant::ShareToken => Address::Internal(
+                InternalAddress::ShareToken(EstablishedAddress {
+                    hash: *raw_addr.data(),
+                }),
+            ),
         }
     }
 }
@@ -229,6 +234,14 @@ impl<'addr> From<&'addr Address> for raw::Address<'addr, raw::Validated> {
                     .validate()
                     .expect("This raw address is valid")
             }
+            Address::Internal(InternalAddress::ShareToken(
+                EstablishedAddress { hash },
+            )) => {
+                raw::Address::from_discriminant(raw::Discriminant::ShareToken)
+                    .with_data_array_ref(hash)
+                    .validate()
+                    .expect("This raw address is valid")
+            }
         }
     }
 }
@@ -557,6 +570,8 @@ pub enum InternalAddress {
     Pgf,
     /// Masp
     Masp,
+    /// Liquid staking share token of the validator with the given address
+    ShareToken(EstablishedAddress),
 }
 
 impl Display for InternalAddress {
@@ -578,6 +593,10 @@ impl Display for InternalAddress {
                 Self::Multitoken => "Multitoken".to_string(),
                 Self::Pgf => "PublicGoodFundings".to_string(),
                 Self::Masp => "MASP".to_string(),
+                Self::ShareToken(validator) => format!(
+                    "ShareToken: {}",
+                    Address::Established(validator.clone())
+                ),
             }
         )
     }
@@ -802,6 +821,7 @@ pub mod testing {
             InternalAddress::Nut(_) => {}
             InternalAddress::Pgf => {}
             InternalAddress::Masp => {}
+            InternalAddress::ShareToken(_) => {}
             InternalAddress::Multitoken => {} /* Add new addresses in the
                                                * `prop_oneof` below. */
         };
@@ -819,6 +839,7 @@ pub mod testing {
             Just(InternalAddress::Multitoken),
             Just(InternalAddress::Pgf),
             Just(InternalAddress::Masp),
+            arb_established_address().prop_map(InternalAddress::ShareToken),
         ]
     }
 
@@ -97,6 +97,10 @@ pub enum DbColFam {
     DIFFS,
     /// Replay protection
     REPLAYPROT,
+    /// Events
+    EVENTS,
+    /// Archive of the subspace
+    ARCHIVE,
 }
 
 /// Subspace column family name
@@ -109,6 +113,10 @@ pub const STATE_CF: &str = "state";
 pub const BLOCK_CF: &str = "block";
 /// Replay protection column family name
 pub const REPLAY_PROTECTION_CF: &str = "replay_protection";
+/// Events column family name
+pub const EVENTS_CF: &str = "events";
+/// Archive column family name
+pub const ARCHIVE_CF: &str = "archive";
 
 impl DbColFam {
     /// Get the name of the column family
@@ -119,6 +127,8 @@ impl DbColFam {
             DbColFam::STATE => STATE_CF,
             DbColFam::DIFFS => DIFFS_CF,
             DbColFam::REPLAYPROT => REPLAY_PROTECTION_CF,
+            DbColFam::EVENTS => EVENTS_CF,
+            DbColFam::ARCHIVE => ARCHIVE_CF,
         }
     }
 }
@@ -133,6 +143,8 @@ impl FromStr for DbColFam {
             STATE_CF => Ok(Self::STATE),
             REPLAY_PROTECTION_CF => Ok(Self::REPLAYPROT),
             BLOCK_CF => Ok(Self::BLOCK),
+            EVENTS_CF => Ok(Self::EVENTS),
+            ARCHIVE_CF => Ok(Self::ARCHIVE),
             _ => Err(Error::DbColFamily(s.to_string())),
         }
     }
@@ -30,6 +30,21 @@ pub fn sign_bridge_pool_root<D, H>(
     eth_hot_key: &common::SecretKey,
     protocol_key: &common::SecretKey,
 ) -> Option<bridge_pool_roots::SignedVext>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    bridge_pool_root_vext(state, validator_addr, eth_hot_key)
+        .map(|ext| ext.sign(protocol_key))
+}
+
+/// Build the vote extension over the latest Bridge pool root, signed with the
+/// validator's Ethereum hot key but yet to be signed with its protocol key.
+pub fn bridge_pool_root_vext<D, H>(
+    state: &WlState<D, H>,
+    validator_addr: &Address,
+    eth_hot_key: &common::SecretKey,
+) -> Option<bridge_pool_roots::Vext>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -46,7 +61,7 @@ where
         validator_addr: validator_addr.clone(),
         sig: signed.sig,
     };
-    Some(ext.sign(protocol_key))
+    Some(ext)
 }
 
 /// Applies a tally of signatures on over the Ethereum
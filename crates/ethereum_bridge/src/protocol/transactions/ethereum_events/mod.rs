@@ -48,6 +48,24 @@ pub fn sign_ethereum_events<D, H>(
     protocol_key: &common::SecretKey,
     ethereum_events: Vec<EthereumEvent>,
 ) -> Option<SignedVext>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    ethereum_events_vext(state, validator_addr, ethereum_events)
+        .map(|ext| ext.sign(protocol_key).into())
+}
+
+/// Build the vote extension over the given Ethereum events, yet to be signed
+/// with the validator's protocol key.
+///
+/// __INVARIANT__: Assume `ethereum_events` are sorted in ascending
+/// order.
+pub fn ethereum_events_vext<D, H>(
+    state: &WlState<D, H>,
+    validator_addr: &Address,
+    ethereum_events: Vec<EthereumEvent>,
+) -> Option<Vext>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -70,7 +88,7 @@ where
         tracing::debug!("New Ethereum events - {:#?}", ext.ethereum_events);
     }
 
-    Some(ext.sign(protocol_key).into())
+    Some(ext)
 }
 
 /// Applies derived state changes to storage, based on Ethereum `events` which
@@ -98,7 +98,7 @@ impl Gas {
 
     /// Converts the sub gas units to whole ones. If the sub units are not a
     /// multiple of the `SCALE` than ceil the quotient
-    fn get_whole_gas_units(&self) -> u64 {
+    pub fn get_whole_gas_units(&self) -> u64 {
         let quotient = self.sub / SCALE;
         if self.sub % SCALE == 0 {
             quotient
@@ -142,6 +142,101 @@ impl Display for Gas {
     }
 }
 
+/// The categories of operations that gas is charged for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasCategory {
+    /// Validation and inclusion in a block of a wrapper tx
+    Wrapper,
+    /// Loading, validation and compilation of wasm code
+    WasmCompile,
+    /// Runs of the validity predicates
+    Vps,
+    /// Reads from storage
+    StorageRead,
+    /// Writes to storage
+    StorageWrite,
+    /// Verification of signatures
+    Signature,
+    /// Any other operation
+    Other,
+}
+
+/// The gas consumed by a transaction, split by [`GasCategory`]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct GasBreakdown {
+    /// Gas for the validation and inclusion of the wrapper tx
+    pub wrapper: Gas,
+    /// Gas for loading, validating and compiling wasm code
+    pub wasm_compile: Gas,
+    /// Gas for running the validity predicates
+    pub vps: Gas,
+    /// Gas for reading from storage
+    pub storage_reads: Gas,
+    /// Gas for writing to storage
+    pub storage_writes: Gas,
+    /// Gas for verifying signatures
+    pub signatures: Gas,
+    /// Gas for any other operation, e.g. wasm execution and host functions
+    pub other: Gas,
+}
+
+impl GasBreakdown {
+    /// Add gas to the given category
+    pub fn add(&mut self, gas: Gas, category: GasCategory) -> Result<()> {
+        let entry = match category {
+            GasCategory::Wrapper => &mut self.wrapper,
+            GasCategory::WasmCompile => &mut self.wasm_compile,
+            GasCategory::Vps => &mut self.vps,
+            GasCategory::StorageRead => &mut self.storage_reads,
+            GasCategory::StorageWrite => &mut self.storage_writes,
+            GasCategory::Signature => &mut self.signatures,
+            GasCategory::Other => &mut self.other,
+        };
+        *entry = entry.checked_add(gas).ok_or(Error::GasOverflow)?;
+        Ok(())
+    }
+
+    /// The total gas of all the categories
+    pub fn total(&self) -> Result<Gas> {
+        [
+            self.wrapper,
+            self.wasm_compile,
+            self.vps,
+            self.storage_reads,
+            self.storage_writes,
+            self.signatures,
+            self.other,
+        ]
+        .into_iter()
+        .try_fold(Gas::default(), |acc, gas| {
+            acc.checked_add(gas).ok_or(Error::GasOverflow)
+        })
+    }
+
+    /// Merge the gas of another breakdown into this one
+    pub fn merge(&mut self, other: GasBreakdown) -> Result<()> {
+        self.add(other.wrapper, GasCategory::Wrapper)?;
+        self.add(other.wasm_compile, GasCategory::WasmCompile)?;
+        self.add(other.vps, GasCategory::Vps)?;
+        self.add(other.storage_reads, GasCategory::StorageRead)?;
+        self.add(other.storage_writes, GasCategory::StorageWrite)?;
+        self.add(other.signatures, GasCategory::Signature)?;
+        self.add(other.other, GasCategory::Other)
+    }
+}
+
 /// Trait to share gas operations for transactions and validity predicates
 pub trait GasMetering {
     /// Add gas cost. It will return error when the
@@ -149,30 +244,39 @@ pub trait GasMetering {
     /// will still be updated
     fn consume(&mut self, gas: u64) -> Result<()>;
 
+    /// Add gas cost for an operation of the given category. Defaults to
+    /// [`GasMetering::consume`] for meters that don't track a breakdown.
+    fn consume_for(&mut self, gas: u64, _category: GasCategory) -> Result<()> {
+        self.consume(gas)
+    }
+
     /// Add the compiling cost proportionate to the code length
     fn add_compiling_gas(&mut self, bytes_len: u64) -> Result<()> {
-        self.consume(
+        self.consume_for(
             bytes_len
                 .checked_mul(COMPILE_GAS_PER_BYTE)
                 .ok_or(Error::GasOverflow)?,
+            GasCategory::WasmCompile,
         )
     }
 
     /// Add the gas for loading the wasm code from storage
     fn add_wasm_load_from_storage_gas(&mut self, bytes_len: u64) -> Result<()> {
-        self.consume(
+        self.consume_for(
             bytes_len
                 .checked_mul(STORAGE_ACCESS_GAS_PER_BYTE)
                 .ok_or(Error::GasOverflow)?,
+            GasCategory::WasmCompile,
         )
     }
 
     /// Add the gas for validating untrusted wasm code
     fn add_wasm_validation_gas(&mut self, bytes_len: u64) -> Result<()> {
-        self.consume(
+        self.consume_for(
             bytes_len
                 .checked_mul(WASM_CODE_VALIDATION_GAS_PER_BYTE)
                 .ok_or(Error::GasOverflow)?,
+            GasCategory::WasmCompile,
         )
     }
 
@@ -189,6 +293,11 @@ pub struct TxGasMeter {
     /// The gas limit for a transaction
     pub tx_gas_limit: Gas,
     transaction_gas: Gas,
+    /// The consumed gas split by category, excluding the uncategorized gas
+    breakdown: GasBreakdown,
+    /// Whether the VPs only charge the gas of the signature verifications,
+    /// without checking the signatures
+    skip_sig_verification: bool,
 }
 
 /// Gas metering in a validity predicate
@@ -200,6 +309,9 @@ pub struct VpGasMeter {
     initial_gas: Gas,
     /// The current gas usage in the VP
     current_gas: Gas,
+    /// Whether the VP only charges the gas of the signature verifications,
+    /// without checking the signatures
+    skip_sig_verification: bool,
 }
 
 /// Gas meter for VPs parallel runs
@@ -233,6 +345,11 @@ impl GasMetering for TxGasMeter {
         Ok(())
     }
 
+    fn consume_for(&mut self, gas: u64, category: GasCategory) -> Result<()> {
+        self.breakdown.add(gas.into(), category)?;
+        self.consume(gas)
+    }
+
     fn get_tx_consumed_gas(&self) -> Gas {
         self.transaction_gas
     }
@@ -249,6 +366,8 @@ impl TxGasMeter {
         Self {
             tx_gas_limit: tx_gas_limit.into(),
             transaction_gas: Gas::default(),
+            breakdown: GasBreakdown::default(),
+            skip_sig_verification: false,
         }
     }
 
@@ -258,31 +377,48 @@ impl TxGasMeter {
         Self {
             tx_gas_limit,
             transaction_gas: Gas::default(),
+            breakdown: GasBreakdown::default(),
+            skip_sig_verification: false,
         }
     }
 
+    /// Make the VPs only charge the gas of the signature verifications,
+    /// without checking the signatures. Must only be used to estimate the
+    /// gas of a tx carrying placeholder signatures.
+    pub fn skip_sig_verification(mut self) -> Self {
+        self.skip_sig_verification = true;
+        self
+    }
+
+    /// Get the gas required by the given number of bytes of a transaction,
+    /// for the space it requires in the block and for downloading it over
+    /// the network
+    pub fn tx_bytes_gas(bytes_len: u64) -> Result<u64> {
+        bytes_len
+            .checked_mul(
+                STORAGE_OCCUPATION_GAS_PER_BYTE
+                    + NETWORK_TRANSMISSION_GAS_PER_BYTE,
+            )
+            .ok_or(Error::GasOverflow)
+    }
+
     /// Add the gas required by a wrapper transaction which is comprised of:
     ///  - cost of validating the wrapper tx
     ///  - space that the transaction requires in the block
     ///  - cost of downloading (as part of the block) the transaction bytes over
     ///    the network
     pub fn add_wrapper_gas(&mut self, tx_bytes: &[u8]) -> Result<()> {
-        self.consume(WRAPPER_TX_VALIDATION_GAS)?;
+        self.consume_for(WRAPPER_TX_VALIDATION_GAS, GasCategory::Wrapper)?;
 
-        let bytes_len = tx_bytes.len() as u64;
-        self.consume(
-            bytes_len
-                .checked_mul(
-                    STORAGE_OCCUPATION_GAS_PER_BYTE
-                        + NETWORK_TRANSMISSION_GAS_PER_BYTE,
-                )
-                .ok_or(Error::GasOverflow)?,
+        self.consume_for(
+            Self::tx_bytes_gas(tx_bytes.len() as u64)?,
+            GasCategory::Wrapper,
         )
     }
 
     /// Add the gas cost used in validity predicates to the current transaction.
     pub fn add_vps_gas(&mut self, vps_gas: &VpsGas) -> Result<()> {
-        self.consume(vps_gas.get_current_gas()?.into())
+        self.consume_for(vps_gas.get_current_gas()?.into(), GasCategory::Vps)
     }
 
     /// Get the amount of gas still available to the transaction
@@ -291,6 +427,26 @@ impl TxGasMeter {
             .checked_sub(self.transaction_gas)
             .unwrap_or_default()
     }
+
+    /// Get the gas consumed so far split by category. The gas that was not
+    /// charged for a specific category is reported as `other`.
+    pub fn get_breakdown(&self) -> GasBreakdown {
+        let mut breakdown = self.breakdown;
+        // The categorized gas has also been consumed by the meter, so it
+        // can't exceed the total and the sums below can't overflow
+        let uncategorized = breakdown
+            .total()
+            .ok()
+            .and_then(|categorized| {
+                self.transaction_gas.checked_sub(categorized)
+            })
+            .unwrap_or_default();
+        breakdown.other = breakdown
+            .other
+            .checked_add(uncategorized)
+            .unwrap_or(self.transaction_gas);
+        breakdown
+    }
 }
 
 impl GasMetering for VpGasMeter {
@@ -328,8 +484,15 @@ impl VpGasMeter {
             tx_gas_limit: tx_gas_meter.tx_gas_limit,
             initial_gas: tx_gas_meter.transaction_gas,
             current_gas: Gas::default(),
+            skip_sig_verification: tx_gas_meter.skip_sig_verification,
         }
     }
+
+    /// Check if the VP only charges the gas of the signature verifications,
+    /// without checking the signatures
+    pub fn skips_sig_verification(&self) -> bool {
+        self.skip_sig_verification
+    }
 }
 
 impl VpsGas {
@@ -402,6 +565,8 @@ mod tests {
         let tx_gas_meter = TxGasMeter {
             tx_gas_limit: BLOCK_GAS_LIMIT.into(),
             transaction_gas: Gas::default(),
+            breakdown: GasBreakdown::default(),
+            skip_sig_verification: false,
         };
             let mut meter = VpGasMeter::new_from_tx_meter(&tx_gas_meter);
             meter.consume(gas).expect("cannot add the gas");
@@ -414,6 +579,8 @@ mod tests {
         let tx_gas_meter = TxGasMeter {
             tx_gas_limit: BLOCK_GAS_LIMIT.into(),
             transaction_gas: (TX_GAS_LIMIT - 1).into(),
+            breakdown: GasBreakdown::default(),
+            skip_sig_verification: false,
         };
         let mut meter = VpGasMeter::new_from_tx_meter(&tx_gas_meter);
         assert_matches!(
@@ -427,6 +594,8 @@ mod tests {
         let tx_gas_meter = TxGasMeter {
             tx_gas_limit: TX_GAS_LIMIT.into(),
             transaction_gas: (TX_GAS_LIMIT - 1).into(),
+            breakdown: GasBreakdown::default(),
+            skip_sig_verification: false,
         };
         let mut meter = VpGasMeter::new_from_tx_meter(&tx_gas_meter);
         assert_matches!(
@@ -457,4 +626,37 @@ mod tests {
             Error::TransactionGasExceededError
         );
     }
+
+    #[test]
+    fn test_tx_gas_breakdown() {
+        let mut meter = TxGasMeter::new_from_sub_limit(TX_GAS_LIMIT.into());
+        meter.add_wrapper_gas(&[0; 10]).unwrap();
+        meter.add_compiling_gas(10).unwrap();
+        meter.consume_for(100, GasCategory::StorageRead).unwrap();
+        meter.consume_for(200, GasCategory::StorageWrite).unwrap();
+        meter
+            .consume_for(VERIFY_TX_SIG_GAS, GasCategory::Signature)
+            .unwrap();
+        meter.consume(50).unwrap();
+
+        let breakdown = meter.get_breakdown();
+        assert_eq!(
+            breakdown.wrapper,
+            Gas::from(
+                WRAPPER_TX_VALIDATION_GAS
+                    + 10 * (STORAGE_OCCUPATION_GAS_PER_BYTE
+                        + NETWORK_TRANSMISSION_GAS_PER_BYTE)
+            )
+        );
+        assert_eq!(
+            breakdown.wasm_compile,
+            Gas::from(10 * COMPILE_GAS_PER_BYTE)
+        );
+        assert_eq!(breakdown.vps, Gas::default());
+        assert_eq!(breakdown.storage_reads, Gas::from(100));
+        assert_eq!(breakdown.storage_writes, Gas::from(200));
+        assert_eq!(breakdown.signatures, Gas::from(VERIFY_TX_SIG_GAS));
+        assert_eq!(breakdown.other, Gas::from(50));
+        assert_eq!(breakdown.total().unwrap(), meter.get_tx_consumed_gas());
+    }
 }
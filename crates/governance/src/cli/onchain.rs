@@ -12,12 +12,13 @@ use serde::{Deserialize, Serialize};
 
 use super::validation::{
     is_valid_author_balance, is_valid_content, is_valid_default_proposal_data,
-    is_valid_end_epoch, is_valid_grace_epoch, is_valid_pgf_funding_data,
-    is_valid_pgf_stewards_data, is_valid_proposal_period, is_valid_start_epoch,
+    is_valid_end_epoch, is_valid_expedited_proposal, is_valid_grace_epoch,
+    is_valid_pgf_funding_data, is_valid_pgf_stewards_data,
+    is_valid_proposal_options, is_valid_proposal_period, is_valid_start_epoch,
     ProposalValidation,
 };
 use crate::parameters::GovernanceParameters;
-use crate::storage::proposal::PGFTarget;
+use crate::storage::proposal::{PGFStream, PGFTarget};
 
 #[derive(
     Debug,
@@ -59,6 +60,16 @@ pub struct DefaultProposal {
     pub proposal: OnChainProposal,
     /// The default proposal extra data
     pub data: Option<Vec<u8>>,
+    /// The labels of the options of a multi-option proposal. Empty for
+    /// yes/no proposals.
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Whether the proposal is expedited. Expedited proposals have a shorter
+    /// minimum voting period and grace period, but need 2/3 of the total
+    /// voting power to vote yay. If they don't pass, they are moved back to
+    /// the normal voting schedule.
+    #[serde(default)]
+    pub expedited: bool,
 }
 
 impl DefaultProposal {
@@ -73,23 +84,34 @@ impl DefaultProposal {
         if force {
             return Ok(self);
         }
+        let (min_voting_period, min_grace_epochs) = if self.expedited {
+            (
+                governance_parameters.min_expedited_proposal_voting_period,
+                1,
+            )
+        } else {
+            (
+                governance_parameters.min_proposal_voting_period,
+                governance_parameters.min_proposal_grace_epochs,
+            )
+        };
         is_valid_start_epoch(
             self.proposal.voting_start_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
+            min_voting_period,
         )?;
         is_valid_end_epoch(
             self.proposal.voting_start_epoch,
             self.proposal.voting_end_epoch,
             current_epoch,
-            governance_parameters.min_proposal_voting_period,
-            governance_parameters.min_proposal_voting_period,
+            min_voting_period,
+            min_voting_period,
             governance_parameters.max_proposal_period,
         )?;
         is_valid_grace_epoch(
             self.proposal.grace_epoch,
             self.proposal.voting_end_epoch,
-            governance_parameters.min_proposal_grace_epochs,
+            min_grace_epochs,
         )?;
         is_valid_proposal_period(
             self.proposal.voting_start_epoch,
@@ -108,6 +130,8 @@ impl DefaultProposal {
             &self.data,
             governance_parameters.max_proposal_code_size,
         )?;
+        is_valid_proposal_options(&self.options)?;
+        is_valid_expedited_proposal(self.expedited, &self.options)?;
 
         Ok(self)
     }
@@ -321,6 +345,12 @@ pub struct PgfFunding {
     pub continuous: Vec<PGFTarget>,
     /// pgf retro fundings
     pub retro: Vec<PGFTarget>,
+    /// Pgf funding streams
+    #[serde(default)]
+    pub streams: Vec<PGFStream>,
+    /// The targets of the pgf funding streams to cancel
+    #[serde(default)]
+    pub cancelled_streams: Vec<Address>,
 }
 
 impl Display for PgfFunding {
@@ -337,6 +367,18 @@ impl Display for PgfFunding {
                 write!(f, "  {}", &target)?;
             }
         }
+        if !self.streams.is_empty() {
+            write!(f, "Streams: ")?;
+            for stream in &self.streams {
+                write!(f, "  {}", &stream)?;
+            }
+        }
+        if !self.cancelled_streams.is_empty() {
+            write!(f, "Cancelled streams: ")?;
+            for target in &self.cancelled_streams {
+                write!(f, "  {}", &target)?;
+            }
+        }
         Ok(())
     }
 }
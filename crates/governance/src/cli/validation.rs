@@ -6,6 +6,7 @@ use namada_core::token;
 use thiserror::Error;
 
 use super::onchain::{PgfFunding, StewardsUpdate};
+use crate::storage::proposal::MAX_PROPOSAL_OPTIONS;
 
 /// This enum raprresent a proposal data
 #[derive(Clone, Debug, PartialEq, Error)]
@@ -69,6 +70,24 @@ pub enum ProposalValidation {
     /// The pgf funding data is not valid
     #[error("invalid proposal extra data: cannot be empty.")]
     InvalidPgfFundingExtraData,
+    /// A pgf funding stream is not valid
+    #[error(
+        "Invalid pgf stream to {0}: the amount per epoch and the cap must be \
+         positive, and the epochs must satisfy start <= cliff <= end with \
+         start < end"
+    )]
+    InvalidPgfStream(Address),
+    /// The options of a multi-option proposal are not valid
+    #[error(
+        "Invalid proposal options: a multi-option proposal must have between \
+         2 and {1} options, but found {0}"
+    )]
+    InvalidProposalOptions(u64, u64),
+    /// A multi-option proposal can't be expedited
+    #[error(
+        "Invalid expedited proposal: only yes/no proposals can be expedited"
+    )]
+    InvalidExpeditedProposal,
 }
 
 pub fn is_valid_author_balance(
@@ -221,6 +240,31 @@ pub fn is_valid_default_proposal_data(
     }
 }
 
+pub fn is_valid_proposal_options(
+    options: &[String],
+) -> Result<(), ProposalValidation> {
+    if options.is_empty() || (2..=MAX_PROPOSAL_OPTIONS).contains(&options.len())
+    {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidProposalOptions(
+            options.len() as u64,
+            MAX_PROPOSAL_OPTIONS as u64,
+        ))
+    }
+}
+
+pub fn is_valid_expedited_proposal(
+    expedited: bool,
+    options: &[String],
+) -> Result<(), ProposalValidation> {
+    if expedited && !options.is_empty() {
+        Err(ProposalValidation::InvalidExpeditedProposal)
+    } else {
+        Ok(())
+    }
+}
+
 pub fn is_valid_pgf_stewards_data(
     data: &StewardsUpdate,
     author: &Address,
@@ -244,7 +288,16 @@ pub fn is_valid_pgf_stewards_data(
 pub fn is_valid_pgf_funding_data(
     data: &PgfFunding,
 ) -> Result<(), ProposalValidation> {
-    if !data.continuous.is_empty() || !data.retro.is_empty() {
+    if let Some(stream) = data.streams.iter().find(|s| !s.is_valid()) {
+        return Err(ProposalValidation::InvalidPgfStream(
+            stream.target.clone(),
+        ));
+    }
+    if !data.continuous.is_empty()
+        || !data.retro.is_empty()
+        || !data.streams.is_empty()
+        || !data.cancelled_streams.is_empty()
+    {
         Ok(())
     } else {
         Err(ProposalValidation::InvalidPgfFundingExtraData)
@@ -1,9 +1,12 @@
+use std::fmt::Display;
+
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_storage::{Result, StorageRead, StorageWrite};
+use serde::{Deserialize, Serialize};
 
 use super::storage::keys as goverance_storage;
 
@@ -27,12 +30,53 @@ pub struct GovernanceParameters {
     pub max_proposal_code_size: u64,
     /// Minimum proposal voting period in epochs
     pub min_proposal_voting_period: u64,
+    /// Minimum expedited proposal voting period in epochs
+    pub min_expedited_proposal_voting_period: u64,
     /// Maximum proposal voting period in epochs
     pub max_proposal_period: u64,
     /// Maximum number of characters for proposal content
     pub max_proposal_content_size: u64,
     /// Minimum epochs between end and grace epochs
     pub min_proposal_grace_epochs: u64,
+    /// Where the deposits of vetoed proposals and of proposals that didn't
+    /// reach quorum are sent
+    pub forfeited_deposit_target: ForfeitedDepositTarget,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+/// The target of the deposits of proposals that were vetoed or that didn't
+/// reach quorum. Deposits of other proposals are refunded to their authors.
+pub enum ForfeitedDepositTarget {
+    /// The deposit is burned
+    #[default]
+    Burn,
+    /// The deposit is sent to the PGF account
+    Pgf,
+}
+
+impl Display for ForfeitedDepositTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForfeitedDepositTarget::Burn => write!(f, "burn"),
+            ForfeitedDepositTarget::Pgf => write!(f, "pgf"),
+        }
+    }
 }
 
 impl Default for GovernanceParameters {
@@ -41,9 +85,11 @@ impl Default for GovernanceParameters {
             min_proposal_fund: token::Amount::native_whole(500),
             max_proposal_code_size: 300_000,
             min_proposal_voting_period: 3,
+            min_expedited_proposal_voting_period: 1,
             max_proposal_period: 27,
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
+            forfeited_deposit_target: ForfeitedDepositTarget::Burn,
         }
     }
 }
@@ -58,9 +104,11 @@ impl GovernanceParameters {
             min_proposal_fund,
             max_proposal_code_size,
             min_proposal_voting_period,
+            min_expedited_proposal_voting_period,
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
+            forfeited_deposit_target,
         } = self;
 
         let min_proposal_fund_key =
@@ -78,6 +126,13 @@ impl GovernanceParameters {
             min_proposal_voting_period,
         )?;
 
+        let min_expedited_proposal_voting_period_key =
+            goverance_storage::get_min_expedited_proposal_voting_period_key();
+        storage.write(
+            &min_expedited_proposal_voting_period_key,
+            min_expedited_proposal_voting_period,
+        )?;
+
         let max_proposal_period_key =
             goverance_storage::get_max_proposal_period_key();
         storage.write(&max_proposal_period_key, max_proposal_period)?;
@@ -92,6 +147,11 @@ impl GovernanceParameters {
         storage
             .write(&min_proposal_grace_epoch_key, min_proposal_grace_epochs)?;
 
+        let forfeited_deposit_target_key =
+            goverance_storage::get_forfeited_deposit_target_key();
+        storage
+            .write(&forfeited_deposit_target_key, forfeited_deposit_target)?;
+
         let counter_key = goverance_storage::get_counter_key();
         storage.write(&counter_key, u64::MIN)
     }
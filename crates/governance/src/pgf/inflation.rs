@@ -7,7 +7,9 @@ use namada_storage::{Result, StorageRead, StorageWrite};
 use namada_trans_token::credit_tokens;
 use namada_trans_token::storage_key::minted_balance_key;
 
-use crate::pgf::storage::{get_parameters, get_payments, get_stewards};
+use crate::pgf::storage::{
+    get_parameters, get_payments, get_stewards, remove_finished_streams,
+};
 use crate::storage::proposal::{PGFIbcTarget, PGFTarget};
 
 /// Apply the PGF inflation.
@@ -84,6 +86,11 @@ where
         }
     }
 
+    // the funds of the streams are claimed by their targets, we only need to
+    // clean up the ones that are over
+    let current_epoch = storage.get_block_epoch()?;
+    remove_finished_streams(storage, current_epoch)?;
+
     // Pgf steward inflation
     let stewards = get_stewards(storage)?;
     let pgf_steward_inflation = (pgf_parameters.stewards_inflation_rate
@@ -5,13 +5,14 @@ use namada_storage::collections::{lazy_map, LazyCollection, LazyMap};
 
 use crate::pgf::storage::steward::StewardDetail;
 use crate::pgf::ADDRESS;
-use crate::storage::proposal::StoragePgfFunding;
+use crate::storage::proposal::{StoragePgfFunding, StoragePgfStream};
 
 /// Storage keys for pgf internal address.
 #[derive(StorageKeys)]
 struct Keys {
     stewards: &'static str,
     fundings: &'static str,
+    streams: &'static str,
     pgf_inflation_rate: &'static str,
     steward_inflation_rate: &'static str,
 }
@@ -72,6 +73,40 @@ pub fn is_fundings_key(key: &Key) -> bool {
                 && data.as_str() == lazy_map::DATA_SUBKEY)
 }
 
+/// Obtain a storage key for pgf funding streams.
+pub fn streams_key_prefix() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(ADDRESS.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.streams.to_string()),
+        ],
+    }
+}
+
+/// LazyMap handler for the pgf funding streams substorage
+pub fn streams_handle() -> LazyMap<Address, StoragePgfStream> {
+    LazyMap::open(streams_key_prefix())
+}
+
+/// Check if the given storage key is a pgf funding stream key. If it is,
+/// returns the target address of the stream.
+pub fn is_streams_key(key: &Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(pgf),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(data),
+            DbKeySeg::AddressSeg(target),
+        ] if pgf.eq(&ADDRESS)
+            && prefix.as_str() == Keys::VALUES.streams
+            && data.as_str() == lazy_map::DATA_SUBKEY =>
+        {
+            Some(target)
+        }
+        _ => None,
+    }
+}
+
 /// Check if key is inside governance address space
 pub fn is_pgf_key(key: &Key) -> bool {
     matches!(&key.segments[0], DbKeySeg::AddressSeg(addr) if addr == &ADDRESS)
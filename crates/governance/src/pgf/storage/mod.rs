@@ -9,12 +9,16 @@ use std::collections::HashMap;
 
 use namada_core::address::Address;
 use namada_core::dec::Dec;
-use namada_storage::{Result, StorageRead, StorageWrite};
+use namada_core::storage::Epoch;
+use namada_storage::{Error, Result, StorageRead, StorageWrite};
 
 use crate::pgf::parameters::PgfParameters;
 use crate::pgf::storage::keys as pgf_keys;
 use crate::pgf::storage::steward::StewardDetail;
-use crate::storage::proposal::StoragePgfFunding;
+use crate::pgf::ADDRESS;
+use crate::storage::proposal::{
+    PGFStream, StoragePgfFunding, StoragePgfStream,
+};
 
 /// Query the current pgf steward set
 pub fn get_stewards<S>(storage: &S) -> Result<Vec<StewardDetail>>
@@ -77,6 +81,123 @@ where
     Ok(fundings)
 }
 
+/// Query the current pgf funding streams
+pub fn get_streams<S>(storage: &S) -> Result<Vec<StoragePgfStream>>
+where
+    S: StorageRead,
+{
+    let streams = pgf_keys::streams_handle()
+        .iter(storage)?
+        .filter_map(|data| match data {
+            Ok((_, stream)) => Some(stream),
+            Err(_) => None,
+        })
+        .collect::<Vec<StoragePgfStream>>();
+
+    Ok(streams)
+}
+
+/// Query the pgf funding stream of a target
+pub fn get_stream<S>(
+    storage: &S,
+    target: &Address,
+) -> Result<Option<StoragePgfStream>>
+where
+    S: StorageRead,
+{
+    pgf_keys::streams_handle().get(storage, target)
+}
+
+/// Add a pgf funding stream. If the target already has a stream, the funds of
+/// that stream that can be claimed at the given epoch are paid to the target
+/// before the stream is replaced.
+pub fn add_stream<S>(
+    storage: &mut S,
+    stream: PGFStream,
+    proposal_id: u64,
+    epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if get_stream(storage, &stream.target)?.is_some() {
+        claim_stream(storage, &stream.target, epoch)?;
+    }
+    pgf_keys::streams_handle().insert(
+        storage,
+        stream.target.clone(),
+        StoragePgfStream::new(stream, proposal_id),
+    )?;
+
+    Ok(())
+}
+
+/// Transfer the funds of the stream of a target that can be claimed at the
+/// given epoch from the pgf account to the target. Returns the claimed
+/// amount.
+pub fn claim_stream<S>(
+    storage: &mut S,
+    target: &Address,
+    epoch: Epoch,
+) -> Result<namada_trans_token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut stream = get_stream(storage, target)?
+        .ok_or(Error::new_const("No pgf stream for the given target"))?;
+
+    let amount = stream.claimable(epoch);
+    if amount.is_zero() {
+        return Ok(amount);
+    }
+
+    let native_token = storage.get_native_token()?;
+    namada_trans_token::transfer(
+        storage,
+        &native_token,
+        &ADDRESS,
+        target,
+        amount,
+    )?;
+    stream.claimed += amount;
+    pgf_keys::streams_handle().insert(storage, target.clone(), stream)?;
+
+    Ok(amount)
+}
+
+/// Cancel the stream of a target at the given epoch
+pub fn cancel_stream<S>(
+    storage: &mut S,
+    target: &Address,
+    epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut stream = get_stream(storage, target)?
+        .ok_or(Error::new_const("No pgf stream for the given target"))?;
+    stream.cancel(epoch);
+    pgf_keys::streams_handle().insert(storage, target.clone(), stream)?;
+
+    Ok(())
+}
+
+/// Remove the streams that ended and whose funds were all claimed
+pub fn remove_finished_streams<S>(storage: &mut S, epoch: Epoch) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let finished_streams = get_streams(storage)?
+        .into_iter()
+        .filter(|stream| stream.is_finished(epoch))
+        .collect::<Vec<_>>();
+    for stream in finished_streams {
+        pgf_keys::streams_handle().remove(storage, &stream.detail.target)?;
+    }
+
+    Ok(())
+}
+
 /// Query the pgf parameters
 pub fn get_parameters<S>(storage: &S) -> Result<PgfParameters>
 where
@@ -17,13 +17,16 @@ struct Keys {
     grace_epoch: &'static str,
     funds: &'static str,
     proposal_code: &'static str,
+    expedited: &'static str,
     committing_epoch: &'static str,
     min_fund: &'static str,
     max_code_size: &'static str,
     min_period: &'static str,
+    min_expedited_period: &'static str,
     max_period: &'static str,
     max_content: &'static str,
     min_grace_epoch: &'static str,
+    forfeited_deposit_target: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
@@ -198,6 +201,24 @@ pub fn is_proposal_type_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is the expedited flag key of a proposal
+pub fn is_expedited_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(expedited),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && expedited == Keys::VALUES.expedited =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is counter key
 pub fn is_counter_key(key: &Key) -> bool {
     matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(counter)] if addr == &ADDRESS && counter == Keys::VALUES.counter)
@@ -238,6 +259,16 @@ pub fn is_min_proposal_voting_period_key(key: &Key) -> bool {
              && min_proposal_voting_period_param == Keys::VALUES.min_period)
 }
 
+/// Check if key is a min expedited proposal period param key
+pub fn is_min_expedited_proposal_voting_period_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_expedited_period_param),
+         ] if addr == &ADDRESS
+             && min_expedited_period_param
+                 == Keys::VALUES.min_expedited_period)
+}
+
 /// Check if key is a max proposal period param key
 pub fn is_max_proposal_period_key(key: &Key) -> bool {
     matches!(&key.segments[..], [
@@ -270,14 +301,26 @@ pub fn is_min_grace_epoch_key(key: &Key) -> bool {
                     && min_grace_epoch_param == Keys::VALUES.min_grace_epoch)
 }
 
+/// Check if key is the forfeited deposit target parameter key
+pub fn is_forfeited_deposit_target_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+                    DbKeySeg::AddressSeg(addr),
+                    DbKeySeg::StringSeg(forfeited_deposit_target_param),
+                ] if addr == &ADDRESS
+                    && forfeited_deposit_target_param
+                        == Keys::VALUES.forfeited_deposit_target)
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
         || is_max_content_size_key(key)
         || is_max_proposal_code_size_key(key)
         || is_min_proposal_voting_period_key(key)
+        || is_min_expedited_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epoch_key(key)
+        || is_forfeited_deposit_target_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -313,6 +356,13 @@ pub fn get_min_proposal_voting_period_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get minimum expedited proposal period key
+pub fn get_min_expedited_proposal_voting_period_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_expedited_period.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get maximum proposal period key
 pub fn get_max_proposal_period_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -334,6 +384,13 @@ pub fn get_min_proposal_grace_epoch_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the key of the target of forfeited proposal deposits
+pub fn get_forfeited_deposit_target_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.forfeited_deposit_target.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal ids counter
 pub fn get_counter_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -386,6 +443,15 @@ pub fn get_voting_end_epoch_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get key of the expedited flag of a proposal
+pub fn get_expedited_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.expedited.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal funds
 pub fn get_funds_key(id: u64) -> Key {
     proposal_prefix()
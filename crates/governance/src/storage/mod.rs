@@ -15,7 +15,7 @@ use namada_core::storage::Epoch;
 use namada_storage::{iter_prefix, Error, Result, StorageRead, StorageWrite};
 use namada_trans_token as token;
 
-use crate::parameters::GovernanceParameters;
+use crate::parameters::{ForfeitedDepositTarget, GovernanceParameters};
 use crate::storage::keys as governance_keys;
 use crate::storage::proposal::{
     InitProposalData, ProposalType, StorageProposal, VoteProposalData,
@@ -48,9 +48,10 @@ where
 
     let proposal_type_key = governance_keys::get_proposal_type_key(proposal_id);
     match data.r#type {
-        ProposalType::Default(Some(_)) => {
+        ProposalType::Default(Some(_))
+        | ProposalType::MultiOption { code: Some(_), .. } => {
             // Remove wasm code and write it under a different subkey
-            storage.write(&proposal_type_key, ProposalType::Default(None))?;
+            storage.write(&proposal_type_key, data.r#type.without_code())?;
             let proposal_code_key =
                 governance_keys::get_proposal_code_key(proposal_id);
             let proposal_code = code
@@ -72,7 +73,12 @@ where
     let grace_epoch_key = governance_keys::get_grace_epoch_key(proposal_id);
     storage.write(&grace_epoch_key, data.grace_epoch)?;
 
-    if let ProposalType::Default(Some(_)) = data.r#type {
+    if data.expedited {
+        let expedited_key = governance_keys::get_expedited_key(proposal_id);
+        storage.write(&expedited_key, true)?;
+    }
+
+    if data.get_section_code_hash().is_some() {
         let proposal_code_key =
             governance_keys::get_proposal_code_key(proposal_id);
         let proposal_code =
@@ -158,6 +164,7 @@ where
     let grace_epoch: Option<Epoch> = storage.read(&grace_epoch_key)?;
     let proposal_type: Option<ProposalType> =
         storage.read(&proposal_type_key)?;
+    let expedited = is_proposal_expedited(storage, id)?;
 
     let proposal = proposal_type.map(|proposal_type| StorageProposal {
         id,
@@ -167,11 +174,68 @@ where
         voting_start_epoch: voting_start_epoch.unwrap(),
         voting_end_epoch: voting_end_epoch.unwrap(),
         grace_epoch: grace_epoch.unwrap(),
+        expedited,
     });
 
     Ok(proposal)
 }
 
+/// Check if a proposal is still expedited
+pub fn is_proposal_expedited<S>(storage: &S, proposal_id: u64) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let expedited_key = governance_keys::get_expedited_key(proposal_id);
+    Ok(storage.read(&expedited_key)?.unwrap_or_default())
+}
+
+/// Move an expedited proposal that didn't pass back to the normal voting
+/// schedule. The voting period is extended to the minimum proposal voting
+/// period counted from the voting start epoch (or to the next epoch, if that
+/// has already elapsed), followed by the minimum grace period. Votes that
+/// were already cast are kept. Returns the new grace epoch of the proposal.
+pub fn move_to_normal_schedule<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    current_epoch: Epoch,
+) -> Result<Epoch>
+where
+    S: StorageRead + StorageWrite,
+{
+    let params = get_parameters(storage)?;
+
+    let voting_start_epoch_key =
+        governance_keys::get_voting_start_epoch_key(proposal_id);
+    let voting_start_epoch: Epoch = storage
+        .read(&voting_start_epoch_key)?
+        .ok_or(Error::new_const("Missing proposal voting start epoch"))?;
+
+    let voting_end_epoch = std::cmp::max(
+        voting_start_epoch + params.min_proposal_voting_period,
+        current_epoch.next(),
+    );
+    let grace_epoch = voting_end_epoch + params.min_proposal_grace_epochs;
+
+    let voting_end_epoch_key =
+        governance_keys::get_voting_end_epoch_key(proposal_id);
+    storage.write(&voting_end_epoch_key, voting_end_epoch)?;
+
+    let grace_epoch_key = governance_keys::get_grace_epoch_key(proposal_id);
+    storage.write(&grace_epoch_key, grace_epoch)?;
+
+    let expedited_key = governance_keys::get_expedited_key(proposal_id);
+    storage.delete(&expedited_key)?;
+
+    let committing_proposals_key =
+        governance_keys::get_committing_proposals_key(
+            proposal_id,
+            grace_epoch.0,
+        );
+    storage.write(&committing_proposals_key, ())?;
+
+    Ok(grace_epoch)
+}
+
 /// Query all the votes for a proposal_id
 pub fn get_proposal_votes<S>(storage: &S, proposal_id: u64) -> Result<Vec<Vote>>
 where
@@ -271,15 +335,25 @@ where
     let min_proposal_voting_period: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let key = governance_keys::get_min_expedited_proposal_voting_period_key();
+    let min_expedited_proposal_voting_period: u64 =
+        storage.read(&key)?.expect("Parameter should be defined.");
+
+    let key = governance_keys::get_forfeited_deposit_target_key();
+    let forfeited_deposit_target: ForfeitedDepositTarget =
+        storage.read(&key)?.expect("Parameter should be defined.");
+
     let max_proposal_period: u64 = get_max_proposal_period(storage)?;
 
     Ok(GovernanceParameters {
         min_proposal_fund,
         max_proposal_code_size,
         min_proposal_voting_period,
+        min_expedited_proposal_voting_period,
         max_proposal_period,
         max_proposal_content_size,
         min_proposal_grace_epochs,
+        forfeited_deposit_target,
     })
 }
 
@@ -306,3 +380,94 @@ where
     let proposal_result: Option<ProposalResult> = storage.read(&key)?;
     Ok(proposal_result)
 }
+
+
+#[cfg(test)]
+mod test {
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    #[test]
+    fn test_move_to_normal_schedule() {
+        let mut state = TestState::default();
+        let params = GovernanceParameters::default();
+        params.init_storage(&mut state).unwrap();
+
+        let proposal_id = 0;
+        let write_expedited_proposal = |state: &mut TestState| {
+            state
+                .write(
+                    &governance_keys::get_voting_start_epoch_key(proposal_id),
+                    Epoch(2),
+                )
+                .unwrap();
+            state
+                .write(
+                    &governance_keys::get_voting_end_epoch_key(proposal_id),
+                    Epoch(3),
+                )
+                .unwrap();
+            state
+                .write(&governance_keys::get_expedited_key(proposal_id), true)
+                .unwrap();
+        };
+        let read_epoch = |state: &TestState, key: &namada_storage::Key| {
+            state.read::<Epoch>(key).unwrap().unwrap()
+        };
+
+        // The voting period is extended to the minimum voting period counted
+        // from the voting start epoch
+        write_expedited_proposal(&mut state);
+        let grace_epoch =
+            move_to_normal_schedule(&mut state, proposal_id, Epoch(3)).unwrap();
+        let voting_end_epoch = Epoch(2) + params.min_proposal_voting_period;
+        assert_eq!(
+            grace_epoch,
+            voting_end_epoch + params.min_proposal_grace_epochs
+        );
+        assert_eq!(
+            read_epoch(
+                &state,
+                &governance_keys::get_voting_end_epoch_key(proposal_id)
+            ),
+            voting_end_epoch
+        );
+        assert_eq!(
+            read_epoch(
+                &state,
+                &governance_keys::get_grace_epoch_key(proposal_id)
+            ),
+            grace_epoch
+        );
+        assert!(!is_proposal_expedited(&state, proposal_id).unwrap());
+        assert!(
+            state
+                .has_key(&governance_keys::get_committing_proposals_key(
+                    proposal_id,
+                    grace_epoch.0
+                ))
+                .unwrap()
+        );
+
+        // If the minimum voting period has already elapsed, the voting
+        // period is extended to the next epoch
+        write_expedited_proposal(&mut state);
+        let current_epoch = Epoch(10);
+        let grace_epoch =
+            move_to_normal_schedule(&mut state, proposal_id, current_epoch)
+                .unwrap();
+        assert_eq!(
+            read_epoch(
+                &state,
+                &governance_keys::get_voting_end_epoch_key(proposal_id)
+            ),
+            current_epoch.next()
+        );
+        assert_eq!(
+            grace_epoch,
+            current_epoch.next() + params.min_proposal_grace_epochs
+        );
+        assert!(!is_proposal_expedited(&state, proposal_id).unwrap());
+    }
+}
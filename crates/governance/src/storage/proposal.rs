@@ -21,6 +21,9 @@ use crate::cli::onchain::{
 };
 use crate::utils::{ProposalStatus, TallyType};
 
+/// The maximum number of options of a multi-option proposal
+pub const MAX_PROPOSAL_OPTIONS: usize = 32;
+
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
 pub enum ProposalError {
@@ -54,13 +57,18 @@ pub struct InitProposalData {
     pub voting_end_epoch: Epoch,
     /// The epoch from which this changes are executed
     pub grace_epoch: Epoch,
+    /// Whether the proposal is expedited, i.e. tallied with a higher
+    /// threshold after a shorter voting period
+    #[serde(default)]
+    pub expedited: bool,
 }
 
 impl InitProposalData {
     /// Get the hash of the corresponding extra data section
     pub fn get_section_code_hash(&self) -> Option<Hash> {
         match self.r#type {
-            ProposalType::Default(hash) => hash,
+            ProposalType::Default(hash)
+            | ProposalType::MultiOption { code: hash, .. } => hash,
             _ => None,
         }
     }
@@ -92,14 +100,23 @@ impl TryFrom<DefaultProposal> for InitProposalData {
     type Error = ProposalError;
 
     fn try_from(value: DefaultProposal) -> Result<Self, Self::Error> {
+        let r#type = if value.options.is_empty() {
+            ProposalType::Default(None)
+        } else {
+            ProposalType::MultiOption {
+                code: None,
+                options: value.options,
+            }
+        };
         Ok(InitProposalData {
             id: value.proposal.id,
             content: Hash::default(),
             author: value.proposal.author,
-            r#type: ProposalType::Default(None),
+            r#type,
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: value.expedited,
         })
     }
 }
@@ -119,6 +136,7 @@ impl TryFrom<PgfStewardProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: false,
         })
     }
 }
@@ -149,7 +167,24 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             .map(PGFAction::Retro)
             .collect::<BTreeSet<PGFAction>>();
 
+        let stream_fundings = value
+            .data
+            .streams
+            .iter()
+            .cloned()
+            .map(PGFAction::Stream)
+            .chain(
+                value
+                    .data
+                    .cancelled_streams
+                    .iter()
+                    .cloned()
+                    .map(PGFAction::CancelStream),
+            )
+            .collect::<BTreeSet<PGFAction>>();
+
         continuous_fundings.extend(retro_fundings);
+        continuous_fundings.extend(stream_fundings);
 
         Ok(InitProposalData {
             id: value.proposal.id,
@@ -159,6 +194,7 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             grace_epoch: value.proposal.grace_epoch,
+            expedited: false,
         })
     }
 }
@@ -190,6 +226,78 @@ impl StoragePgfFunding {
     }
 }
 
+/// Storage struture for pgf funding streams
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct StoragePgfStream {
+    /// The data about the pgf stream
+    pub detail: PGFStream,
+    /// The id of the proposal that added this stream
+    pub id: u64,
+    /// The amount of token already claimed by the target
+    pub claimed: Amount,
+}
+
+impl StoragePgfStream {
+    /// Init a new pgf stream struct
+    pub fn new(detail: PGFStream, id: u64) -> Self {
+        Self {
+            detail,
+            id,
+            claimed: Amount::zero(),
+        }
+    }
+
+    /// Return the amount of token that the target can claim at the given
+    /// epoch
+    pub fn claimable(&self, epoch: Epoch) -> Amount {
+        if epoch < self.detail.cliff_epoch {
+            return Amount::zero();
+        }
+        self.detail
+            .vested(epoch)
+            .checked_sub(self.claimed)
+            .unwrap_or_default()
+    }
+
+    /// Return the amount of token that is still to be vested after the given
+    /// epoch
+    pub fn remaining(&self, epoch: Epoch) -> Amount {
+        self.detail
+            .total()
+            .checked_sub(self.detail.vested(epoch))
+            .unwrap_or_default()
+    }
+
+    /// Check if the stream has ended and all its funds were claimed
+    pub fn is_finished(&self, epoch: Epoch) -> bool {
+        epoch >= self.detail.end_epoch && self.claimed >= self.detail.total()
+    }
+
+    /// Cancel the stream at the given epoch. The funds stop vesting from the
+    /// given epoch onward. If the stream is cancelled before its cliff, none
+    /// of its funds can be claimed.
+    pub fn cancel(&mut self, epoch: Epoch) {
+        let detail = &mut self.detail;
+        if epoch < detail.cliff_epoch {
+            detail.end_epoch = detail.start_epoch;
+            detail.cliff_epoch = detail.start_epoch;
+        } else {
+            detail.end_epoch = std::cmp::min(detail.end_epoch, epoch);
+        }
+    }
+}
+
 /// The type of a Proposal
 #[derive(
     Debug,
@@ -208,6 +316,15 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Proposal voted by choosing among several options, with the optional
+    /// wasm code. The code can read the winning option from the stored
+    /// proposal result.
+    MultiOption {
+        /// The hash of the optional wasm code
+        code: Option<Hash>,
+        /// The labels of the options
+        options: Vec<String>,
+    },
 }
 
 /// An add or remove action for PGF
@@ -403,6 +520,82 @@ impl borsh::BorshSchema for PGFIbcTarget {
     }
 }
 
+/// A PGF funding stream. Funds vest to the target every epoch from the start
+/// epoch until the end epoch, up to a total cap, and can be claimed by the
+/// target from the cliff epoch onward.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Ord,
+    Eq,
+    PartialOrd,
+)]
+pub struct PGFStream {
+    /// The target address
+    pub target: Address,
+    /// The amount of token vested every epoch
+    pub amount_per_epoch: Amount,
+    /// The maximum amount of token vested by the stream
+    pub cap: Amount,
+    /// The epoch from which the funds start vesting
+    pub start_epoch: Epoch,
+    /// The epoch before which the vested funds can't be claimed
+    pub cliff_epoch: Epoch,
+    /// The epoch from which the funds stop vesting
+    pub end_epoch: Epoch,
+}
+
+impl PGFStream {
+    /// Check that the stream vests some funds and that its epochs are
+    /// ordered
+    pub fn is_valid(&self) -> bool {
+        !self.amount_per_epoch.is_zero()
+            && !self.cap.is_zero()
+            && self.start_epoch <= self.cliff_epoch
+            && self.cliff_epoch <= self.end_epoch
+            && self.start_epoch < self.end_epoch
+    }
+
+    /// Return the amount of token vested before the given epoch, ignoring
+    /// the cliff
+    pub fn vested(&self, epoch: Epoch) -> Amount {
+        let vesting_epochs = std::cmp::min(epoch, self.end_epoch)
+            .0
+            .saturating_sub(self.start_epoch.0);
+        self.amount_per_epoch
+            .checked_mul(Amount::from_u64(vesting_epochs))
+            .map(|vested| std::cmp::min(vested, self.cap))
+            .unwrap_or(self.cap)
+    }
+
+    /// Return the total amount of token vested by the stream
+    pub fn total(&self) -> Amount {
+        self.vested(self.end_epoch)
+    }
+}
+
+impl Display for PGFStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Stream to {} of {} per epoch from epoch {} to {} (cliff at epoch \
+             {}, cap {})",
+            self.target,
+            self.amount_per_epoch.to_string_native(),
+            self.start_epoch,
+            self.end_epoch,
+            self.cliff_epoch,
+            self.cap.to_string_native()
+        )
+    }
+}
+
 /// The actions that a PGF Steward can propose to execute
 #[derive(
     Debug,
@@ -422,6 +615,10 @@ pub enum PGFAction {
     Continuous(AddRemove<PGFTarget>),
     /// A retro payment
     Retro(PGFTarget),
+    /// A funding stream. Replaces the stream of the same target, if any.
+    Stream(PGFStream),
+    /// The cancellation of the funding stream of the given target
+    CancelStream(Address),
 }
 
 impl ProposalType {
@@ -430,6 +627,40 @@ impl ProposalType {
         matches!(self, ProposalType::Default(_))
     }
 
+    /// Check if the proposal type is multi-option
+    pub fn is_multi_option(&self) -> bool {
+        matches!(self, ProposalType::MultiOption { .. })
+    }
+
+    /// Check if the proposal type can carry wasm code
+    pub fn can_have_code(&self) -> bool {
+        self.is_default() || self.is_multi_option()
+    }
+
+    /// Return the same proposal type, without the hash of its wasm code
+    pub fn without_code(&self) -> Self {
+        match self {
+            ProposalType::Default(_) => ProposalType::Default(None),
+            ProposalType::MultiOption { options, .. } => {
+                ProposalType::MultiOption {
+                    code: None,
+                    options: options.clone(),
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Check if a vote can be cast on a proposal of this type
+    pub fn is_valid_vote(&self, vote: &ProposalVote) -> bool {
+        match self {
+            ProposalType::MultiOption { options, .. } => {
+                vote.is_valid_options_vote(options.len())
+            }
+            _ => !vote.is_options(),
+        }
+    }
+
     fn format_data(&self) -> String {
         match self {
             ProposalType::Default(Some(hash)) => format!("Hash: {}", &hash),
@@ -448,6 +679,16 @@ impl ProposalType {
                     .map(|action| format!("\n  {}", &action))
                     .join("")
             ),
+            ProposalType::MultiOption { code, options } => format!(
+                "{}Options:{}",
+                code.map(|hash| format!("Hash: {}\n", hash))
+                    .unwrap_or_default(),
+                options
+                    .iter()
+                    .enumerate()
+                    .map(|(index, option)| format!("\n  {}: {}", index, option))
+                    .join("")
+            ),
         }
     }
 }
@@ -458,6 +699,7 @@ impl Display for ProposalType {
             ProposalType::Default(_) => write!(f, "Default"),
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
+            ProposalType::MultiOption { .. } => write!(f, "Multi-option"),
         }
     }
 }
@@ -522,6 +764,10 @@ impl Display for PGFAction {
                 write!(f, "Continuous: {}", &add_remove)
             }
             PGFAction::Retro(target) => write!(f, "Retroactive: {}", &target),
+            PGFAction::Stream(stream) => write!(f, "{}", &stream),
+            PGFAction::CancelStream(target) => {
+                write!(f, "Cancel stream to {}", &target)
+            }
         }
     }
 }
@@ -543,6 +789,9 @@ pub struct StorageProposal {
     pub voting_end_epoch: Epoch,
     /// The epoch from which this changes are executed
     pub grace_epoch: Epoch,
+    /// Whether the proposal is still expedited. Expedited proposals that
+    /// don't pass are moved back to the normal voting schedule.
+    pub expedited: bool,
 }
 
 impl StorageProposal {
@@ -567,7 +816,11 @@ impl StorageProposal {
 
     /// Return the type of tally for the proposal
     pub fn get_tally_type(&self, is_steward: bool) -> TallyType {
-        TallyType::from(self.r#type.clone(), is_steward)
+        if self.expedited {
+            TallyType::Expedited
+        } else {
+            TallyType::from(self.r#type.clone(), is_steward)
+        }
     }
 
     /// Return the status of a proposal
@@ -593,6 +846,7 @@ Content: {:?}
 Start Epoch: {}
 End Epoch: {}
 Grace Epoch: {}
+Expedited: {}
 Status: {}
 Data: {}",
             self.id,
@@ -602,6 +856,7 @@ Data: {}",
             self.voting_start_epoch,
             self.voting_end_epoch,
             self.grace_epoch,
+            self.expedited,
             self.get_status(current_epoch),
             self.r#type.format_data()
         )
@@ -715,11 +970,34 @@ pub mod testing {
         ]
     }
 
+    prop_compose! {
+        /// Generate an arbitrary PGF stream
+        pub fn arb_pgf_stream()(
+            target in arb_non_internal_address(),
+            amount_per_epoch in arb_amount(),
+            cap in arb_amount(),
+            start_epoch in arb_epoch(),
+            cliff_epoch in arb_epoch(),
+            end_epoch in arb_epoch(),
+        ) -> PGFStream {
+            PGFStream {
+                target,
+                amount_per_epoch,
+                cap,
+                start_epoch,
+                cliff_epoch,
+                end_epoch,
+            }
+        }
+    }
+
     /// Generate an arbitrary PGF action
     pub fn arb_pgf_action() -> impl Strategy<Value = PGFAction> {
         prop_oneof![
             arb_add_remove(arb_pgf_target()).prop_map(PGFAction::Continuous),
             arb_pgf_target().prop_map(PGFAction::Retro),
+            arb_pgf_stream().prop_map(PGFAction::Stream),
+            arb_non_internal_address().prop_map(PGFAction::CancelStream),
         ]
     }
 
@@ -734,6 +1012,13 @@ pub mod testing {
             .prop_map(ProposalType::PGFSteward),
             collection::btree_set(arb_pgf_action(), 0..10)
                 .prop_map(ProposalType::PGFPayment),
+            (
+                option::of(arb_hash()),
+                collection::vec("[a-zA-Z0-9_]{1,16}", 2..MAX_PROPOSAL_OPTIONS),
+            )
+                .prop_map(|(code, options)| {
+                    ProposalType::MultiOption { code, options }
+                }),
         ]
     }
 
@@ -747,6 +1032,7 @@ pub mod testing {
             voting_start_epoch in arb_epoch(),
             voting_end_epoch in arb_epoch(),
             grace_epoch in arb_epoch(),
+            expedited: bool,
         ) -> InitProposalData {
             InitProposalData {
                 id,
@@ -756,6 +1042,7 @@ pub mod testing {
                 voting_start_epoch,
                 voting_end_epoch,
                 grace_epoch,
+                expedited,
             }
         }
     }
@@ -777,3 +1064,62 @@ pub mod testing {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use namada_core::address;
+
+    use super::*;
+
+    fn stream() -> StoragePgfStream {
+        StoragePgfStream::new(
+            PGFStream {
+                target: address::testing::established_address_1(),
+                amount_per_epoch: Amount::native_whole(10),
+                cap: Amount::native_whole(55),
+                start_epoch: Epoch(2),
+                cliff_epoch: Epoch(4),
+                end_epoch: Epoch(10),
+            },
+            0,
+        )
+    }
+
+    #[test]
+    fn test_pgf_stream_vesting() {
+        let mut stream = stream();
+        assert!(stream.detail.is_valid());
+        assert_eq!(stream.detail.total(), Amount::native_whole(55));
+
+        // nothing can be claimed before the cliff
+        assert_eq!(stream.claimable(Epoch(3)), Amount::zero());
+        assert_eq!(stream.claimable(Epoch(4)), Amount::native_whole(20));
+        assert_eq!(stream.remaining(Epoch(4)), Amount::native_whole(35));
+
+        stream.claimed = Amount::native_whole(20);
+        assert_eq!(stream.claimable(Epoch(6)), Amount::native_whole(20));
+
+        // the cap limits the vested funds
+        assert_eq!(stream.claimable(Epoch(20)), Amount::native_whole(35));
+        assert_eq!(stream.remaining(Epoch(20)), Amount::zero());
+        assert!(!stream.is_finished(Epoch(20)));
+
+        stream.claimed = Amount::native_whole(55);
+        assert!(stream.is_finished(Epoch(20)));
+    }
+
+    #[test]
+    fn test_pgf_stream_cancel() {
+        let mut stream = stream();
+        stream.cancel(Epoch(6));
+        assert_eq!(stream.detail.total(), Amount::native_whole(40));
+        assert_eq!(stream.claimable(Epoch(20)), Amount::native_whole(40));
+
+        // cancelling before the cliff forfeits all the funds
+        let mut stream = self::stream();
+        stream.cancel(Epoch(3));
+        assert_eq!(stream.detail.total(), Amount::zero());
+        assert_eq!(stream.claimable(Epoch(20)), Amount::zero());
+        assert!(stream.is_finished(Epoch(3)));
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use itertools::Itertools;
+use namada_core::dec::Dec;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -25,6 +29,13 @@ pub enum ProposalVote {
     Nay,
     /// Abstain
     Abstain,
+    /// No, vetoing the proposal. The deposit of a vetoed proposal is not
+    /// refunded to its author.
+    NoWithVeto,
+    /// Vote for the options of a multi-option proposal, by index. The voting
+    /// power is split among the options according to their weights, which
+    /// must sum up to one.
+    Options(BTreeMap<u8, Dec>),
 }
 
 impl ProposalVote {
@@ -38,10 +49,51 @@ impl ProposalVote {
         matches!(self, ProposalVote::Nay)
     }
 
+    /// Check if a vote is no with veto
+    pub fn is_no_with_veto(&self) -> bool {
+        matches!(self, ProposalVote::NoWithVeto)
+    }
+
     /// Check if a vote is abstain
     pub fn is_abstain(&self) -> bool {
         matches!(self, ProposalVote::Abstain)
     }
+
+    /// Check if a vote is for the options of a multi-option proposal
+    pub fn is_options(&self) -> bool {
+        matches!(self, ProposalVote::Options(_))
+    }
+
+    /// Vote with all the voting power for a single option of a multi-option
+    /// proposal
+    pub fn single_option(option: u8) -> Self {
+        ProposalVote::Options(BTreeMap::from([(option, Dec::one())]))
+    }
+
+    /// Check if a vote is a valid vote for a multi-option proposal with
+    /// `num_options` options. Only abstaining or voting for the options of
+    /// the proposal with positive weights summing up to one is allowed.
+    pub fn is_valid_options_vote(&self, num_options: usize) -> bool {
+        match self {
+            ProposalVote::Abstain => true,
+            ProposalVote::Options(weights) => {
+                let are_weights_valid =
+                    weights.iter().all(|(option, weight)| {
+                        usize::from(*option) < num_options
+                            && !weight.is_negative()
+                            && !weight.is_zero()
+                            && *weight <= Dec::one()
+                    });
+                // NB: the sum can't overflow, as there are at most 256
+                // weights, each of them no greater than one
+                are_weights_valid
+                    && weights.values().copied().sum::<Dec>() == Dec::one()
+            }
+            ProposalVote::Yay
+            | ProposalVote::Nay
+            | ProposalVote::NoWithVeto => false,
+        }
+    }
 }
 
 impl Display for ProposalVote {
@@ -50,6 +102,22 @@ impl Display for ProposalVote {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::NoWithVeto => write!(f, "nowithveto"),
+            ProposalVote::Options(weights) => match weights.iter().next() {
+                Some((option, weight))
+                    if weights.len() == 1 && *weight == Dec::one() =>
+                {
+                    write!(f, "option:{option}")
+                }
+                _ => write!(
+                    f,
+                    "options:{}",
+                    weights
+                        .iter()
+                        .map(|(option, weight)| format!("{option}={weight}"))
+                        .join(",")
+                ),
+            },
         }
     }
 }
@@ -58,10 +126,39 @@ impl TryFrom<String> for ProposalVote {
     type Error = String;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.trim().to_lowercase().as_str() {
+        let value = value.trim().to_lowercase();
+        if let Some(option) = value.strip_prefix("option:") {
+            let option = option
+                .trim()
+                .parse()
+                .map_err(|_| "invalid vote option".to_string())?;
+            return Ok(ProposalVote::single_option(option));
+        }
+        if let Some(weights) = value.strip_prefix("options:") {
+            return weights
+                .split(',')
+                .map(|weighted_option| {
+                    let (option, weight) = weighted_option
+                        .split_once('=')
+                        .ok_or_else(|| "invalid weighted option".to_string())?;
+                    let option = option
+                        .trim()
+                        .parse()
+                        .map_err(|_| "invalid vote option".to_string())?;
+                    let weight =
+                        Dec::from_str(weight.trim()).map_err(|_| {
+                            "invalid vote option weight".to_string()
+                        })?;
+                    Ok((option, weight))
+                })
+                .collect::<Result<_, _>>()
+                .map(ProposalVote::Options);
+        }
+        match value.as_str() {
             "yay" => Ok(ProposalVote::Yay),
             "nay" => Ok(ProposalVote::Nay),
             "abstain" => Ok(ProposalVote::Abstain),
+            "nowithveto" | "no_with_veto" => Ok(ProposalVote::NoWithVeto),
             _ => Err("invalid vote".to_string()),
         }
     }
@@ -80,6 +177,65 @@ pub mod testing {
             Just(ProposalVote::Yay),
             Just(ProposalVote::Nay),
             Just(ProposalVote::Abstain),
+            Just(ProposalVote::NoWithVeto),
+            any::<u8>().prop_map(ProposalVote::single_option),
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proposal_vote_string_roundtrip() {
+        let votes = [
+            ProposalVote::Yay,
+            ProposalVote::Nay,
+            ProposalVote::Abstain,
+            ProposalVote::NoWithVeto,
+            ProposalVote::single_option(3),
+            ProposalVote::Options(BTreeMap::from([
+                (0, Dec::from_str("0.6").unwrap()),
+                (1, Dec::from_str("0.4").unwrap()),
+            ])),
+        ];
+        for vote in votes {
+            assert_eq!(ProposalVote::try_from(vote.to_string()), Ok(vote));
+        }
+        assert!(ProposalVote::try_from("option:256".to_string()).is_err());
+        assert!(ProposalVote::try_from("options:0".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_options_vote_validation() {
+        let weighted = |weights: &[(u8, &str)]| {
+            ProposalVote::Options(
+                weights
+                    .iter()
+                    .map(|(option, weight)| {
+                        (*option, Dec::from_str(weight).unwrap())
+                    })
+                    .collect(),
+            )
+        };
+
+        assert!(ProposalVote::Abstain.is_valid_options_vote(2));
+        assert!(ProposalVote::single_option(1).is_valid_options_vote(2));
+        assert!(weighted(&[(0, "0.6"), (1, "0.4")]).is_valid_options_vote(2));
+
+        // Yes/no votes can't be cast on multi-option proposals
+        assert!(!ProposalVote::Yay.is_valid_options_vote(2));
+        assert!(!ProposalVote::Nay.is_valid_options_vote(2));
+        assert!(!ProposalVote::NoWithVeto.is_valid_options_vote(2));
+        // Out of range options
+        assert!(!ProposalVote::single_option(2).is_valid_options_vote(2));
+        // Weights must sum up to one
+        assert!(!weighted(&[(0, "0.6"), (1, "0.3")]).is_valid_options_vote(2));
+        assert!(!weighted(&[(0, "1.5"), (1, "-0.5")]).is_valid_options_vote(2));
+        assert!(!weighted(&[(0, "1"), (1, "0")]).is_valid_options_vote(2));
+        assert!(
+            !ProposalVote::Options(BTreeMap::new()).is_valid_options_vote(2)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Display;
 
 use namada_core::address::Address;
@@ -76,6 +76,12 @@ pub enum TallyType {
     /// Represent a tally type for proposal requiring less than 1/2 of nay
     /// votes over at least 1/3 of the voting power
     LessOneHalfOverOneThirdNay,
+    /// Represent a tally type for multi-option proposal requiring 2/3 of the
+    /// total voting power to vote, and a single option to get the most votes
+    MultiOption,
+    /// Represent a tally type for expedited proposal requiring 2/3 of the
+    /// total voting power to vote yay
+    Expedited,
 }
 
 impl TallyType {
@@ -90,6 +96,7 @@ impl TallyType {
             (ProposalType::PGFPayment(_), false) => {
                 TallyType::OneHalfOverOneThird
             }
+            (ProposalType::MultiOption { .. }, _) => TallyType::MultiOption,
         }
     }
 }
@@ -103,6 +110,9 @@ pub enum TallyResult {
     Passed,
     /// Proposal was rejected
     Rejected,
+    /// Proposal was rejected with more than 1/3 of the votes being no with
+    /// veto
+    Vetoed,
 }
 
 impl Display for TallyResult {
@@ -110,12 +120,17 @@ impl Display for TallyResult {
         match self {
             TallyResult::Passed => write!(f, "passed"),
             TallyResult::Rejected => write!(f, "rejected"),
+            TallyResult::Vetoed => write!(f, "vetoed"),
         }
     }
 }
 
 impl TallyResult {
     /// Create a new tally result
+    ///
+    /// For [`TallyType::MultiOption`] tallies, the yay voting power is the
+    /// voting power of the winning option, if there is one, and the nay voting
+    /// power is the voting power of all the other options.
     pub fn new(
         tally_type: &TallyType,
         yay_voting_power: VotePower,
@@ -165,6 +180,19 @@ impl TallyResult {
 
                 less_than_one_third || more_than_half_voted_yay
             }
+            TallyType::MultiOption => {
+                let at_least_two_third_voted = Self::get_total_voted_power(
+                    yay_voting_power,
+                    nay_voting_power,
+                    abstain_voting_power,
+                ) >= total_voting_power
+                    .mul_ceil(Dec::two() / 3);
+
+                at_least_two_third_voted && !yay_voting_power.is_zero()
+            }
+            TallyType::Expedited => {
+                yay_voting_power >= total_voting_power.mul_ceil(Dec::two() / 3)
+            }
         };
 
         if passed { Self::Passed } else { Self::Rejected }
@@ -180,9 +208,7 @@ impl TallyResult {
 }
 
 /// The result with votes of a proposal
-#[derive(
-    Clone, Debug, Copy, BorshDeserialize, BorshSerialize, BorshDeserializer,
-)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, BorshDeserializer)]
 pub struct ProposalResult {
     /// The result of a proposal
     pub result: TallyResult,
@@ -196,45 +222,142 @@ pub struct ProposalResult {
     pub total_nay_power: VotePower,
     /// The total voting power from abstained votes
     pub total_abstain_power: VotePower,
+    /// The total voting power from no with veto votes
+    pub total_no_with_veto_power: VotePower,
+    /// The total voting power of each option of a multi-option proposal
+    pub total_options_power: BTreeMap<u8, VotePower>,
+    /// The option of a multi-option proposal with the most voting power, if
+    /// any option got strictly more voting power than all the others
+    pub winning_option: Option<u8>,
 }
 
 impl ProposalResult {
     /// Return true if at least 2/3 of the total voting power voted and at least
-    /// two third of the non-abstained voting power voted nay
+    /// two third of the non-abstained voting power voted nay (with or without
+    /// veto)
     pub fn two_thirds_nay_over_two_thirds_total(&self) -> bool {
-        let at_least_two_third_voted = self.total_yay_power
-            + self.total_nay_power
-            + self.total_abstain_power
+        let at_least_two_third_voted = self.total_voted_power()
             >= self.total_voting_power.mul_ceil(Dec::two() / 3);
 
-        let at_least_two_thirds_voted_nay = self.total_nay_power
-            >= (self.total_yay_power + self.total_nay_power)
+        let total_nay_power =
+            self.total_nay_power + self.total_no_with_veto_power;
+        let at_least_two_thirds_voted_nay = total_nay_power
+            >= (self.total_yay_power + total_nay_power)
                 .mul_ceil(Dec::two() / 3);
 
         at_least_two_third_voted && at_least_two_thirds_voted_nay
     }
+
+    /// Return the voting power that must have voted for the proposal to
+    /// reach quorum
+    pub fn quorum_threshold(&self) -> VotePower {
+        match self.tally_type {
+            TallyType::TwoThirds
+            | TallyType::MultiOption
+            | TallyType::Expedited => {
+                self.total_voting_power.mul_ceil(Dec::two() / 3)
+            }
+            TallyType::OneHalfOverOneThird
+            | TallyType::LessOneHalfOverOneThirdNay => {
+                self.total_voting_power.mul_ceil(Dec::one() / 3)
+            }
+        }
+    }
+
+    /// Return true if enough voting power voted for the proposal to reach
+    /// quorum
+    pub fn is_quorum_reached(&self) -> bool {
+        self.total_voted_power() >= self.quorum_threshold()
+    }
+
+    /// Return true if the proposal reached quorum and more than 1/3 of the
+    /// voting power that voted did so with a no with veto
+    pub fn is_veto_threshold_reached(&self) -> bool {
+        self.is_quorum_reached()
+            && self.total_no_with_veto_power
+                > self.total_voted_power().mul_ceil(Dec::one() / 3)
+    }
+
+    /// Return the fraction of the total voting power that voted
+    pub fn turnout(&self) -> Dec {
+        if self.total_voting_power.is_zero() {
+            Dec::zero()
+        } else {
+            Dec::from(self.total_voted_power())
+                / Dec::from(self.total_voting_power)
+        }
+    }
+
+    /// Return true if the deposit of the proposal must not be refunded to its
+    /// author, i.e. if the proposal was vetoed or didn't reach quorum
+    pub fn is_deposit_forfeited(&self) -> bool {
+        match self.result {
+            TallyResult::Passed => false,
+            TallyResult::Rejected => !self.is_quorum_reached(),
+            TallyResult::Vetoed => true,
+        }
+    }
+
+    fn total_voted_power(&self) -> VotePower {
+        self.total_yay_power
+            + self.total_nay_power
+            + self.total_no_with_veto_power
+            + self.total_abstain_power
+            + self
+                .total_options_power
+                .values()
+                .copied()
+                .sum::<VotePower>()
+    }
 }
 
 impl Display for ProposalResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let threshold = match self.tally_type {
-            TallyType::TwoThirds => {
-                self.total_voting_power.mul_ceil(Dec::two() / 3)
-            }
-            _ => self.total_voting_power.mul_ceil(Dec::one() / 3),
-        };
+        let threshold = self.quorum_threshold();
 
         let thresh_frac =
             Dec::from(threshold) / Dec::from(self.total_voting_power);
 
+        if let TallyType::MultiOption = self.tally_type {
+            return write!(
+                f,
+                "{} with {} option votes ({}), {} abstain votes, winning \
+                 option: {}, total voting power: {}, threshold (fraction) of \
+                 total voting power needed to tally: {} ({})",
+                self.result,
+                self.total_options_power
+                    .values()
+                    .copied()
+                    .sum::<VotePower>()
+                    .to_string_native(),
+                self.total_options_power
+                    .iter()
+                    .map(|(option, power)| format!(
+                        "{}: {}",
+                        option,
+                        power.to_string_native()
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.total_abstain_power.to_string_native(),
+                self.winning_option
+                    .map(|option| option.to_string())
+                    .unwrap_or_else(|| "none".to_string()),
+                self.total_voting_power.to_string_native(),
+                threshold.to_string_native(),
+                thresh_frac
+            );
+        }
+
         write!(
             f,
-            "{} with {} yay votes, {} nay votes and {} abstain votes, total \
-             voting power: {}, threshold (fraction) of total voting power \
-             needed to tally: {} ({})",
+            "{} with {} yay votes, {} nay votes, {} no with veto votes and {} \
+             abstain votes, total voting power: {}, threshold (fraction) of \
+             total voting power needed to tally: {} ({})",
             self.result,
             self.total_yay_power.to_string_native(),
             self.total_nay_power.to_string_native(),
+            self.total_no_with_veto_power.to_string_native(),
             self.total_abstain_power.to_string_native(),
             self.total_voting_power.to_string_native(),
             threshold.to_string_native(),
@@ -265,6 +388,14 @@ impl From<OfflineVote> for TallyVote {
 }
 
 impl TallyVote {
+    /// Get the proposal vote
+    pub fn proposal_vote(&self) -> &ProposalVote {
+        match self {
+            TallyVote::OnChain(vote) => vote,
+            TallyVote::Offline(vote) => &vote.vote,
+        }
+    }
+
     /// Check if a vote is yay
     pub fn is_yay(&self) -> bool {
         match self {
@@ -353,6 +484,133 @@ impl ProposalVotes {
             .insert(validator_address.clone(), voting_power);
         self.delegators_vote.insert(address.clone(), vote);
     }
+
+    /// Return the votes of the delegators, for each of the validators they
+    /// delegated to, together with the vote of the validator that they
+    /// override
+    pub fn delegator_overrides(&self) -> Vec<DelegatorOverride> {
+        let mut overrides = vec![];
+        for (delegator, delegations) in &self.delegator_voting_power {
+            let vote = match self.delegators_vote.get(delegator) {
+                Some(vote) => vote.proposal_vote(),
+                None => continue,
+            };
+            for (validator, voting_power) in delegations {
+                overrides.push(DelegatorOverride {
+                    delegator: delegator.clone(),
+                    validator: validator.clone(),
+                    voting_power: *voting_power,
+                    vote: vote.clone(),
+                    validator_vote: self
+                        .validators_vote
+                        .get(validator)
+                        .map(|vote| vote.proposal_vote().clone()),
+                });
+            }
+        }
+        overrides.sort_by(|a, b| {
+            (&a.validator, &a.delegator).cmp(&(&b.validator, &b.delegator))
+        });
+        overrides
+    }
+}
+
+/// The vote of a delegator for the voting power delegated to a validator,
+/// which overrides the vote of the validator for that voting power
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct DelegatorOverride {
+    /// The address of the delegator
+    pub delegator: Address,
+    /// The address of the validator
+    pub validator: Address,
+    /// The voting power delegated to the validator
+    pub voting_power: VotePower,
+    /// The vote of the delegator
+    pub vote: ProposalVote,
+    /// The vote of the validator, if it voted
+    pub validator_vote: Option<ProposalVote>,
+}
+
+impl DelegatorOverride {
+    /// Check if the vote of the delegator changes the tally, i.e. if the
+    /// validator didn't vote or voted differently
+    pub fn is_override(&self) -> bool {
+        self.validator_vote.as_ref() != Some(&self.vote)
+    }
+}
+
+/// The tally of a proposal computed from the current votes
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer)]
+pub struct ProposalTally {
+    /// The epoch of the voting power used for the tally
+    pub tally_epoch: Epoch,
+    /// The result the proposal would have if it was tallied with the current
+    /// votes
+    pub result: ProposalResult,
+    /// The fraction of the total voting power that voted
+    pub turnout: Dec,
+    /// The votes of the delegators, by validator
+    pub delegator_overrides: Vec<DelegatorOverride>,
+}
+
+/// Voting power accumulated by each side of a proposal
+#[derive(Default)]
+struct VotesTally {
+    yay: VotePower,
+    nay: VotePower,
+    no_with_veto: VotePower,
+    abstain: VotePower,
+    options: BTreeMap<u8, VotePower>,
+}
+
+impl VotesTally {
+    /// Add the voting power of a vote to the tally
+    fn add(&mut self, vote: &ProposalVote, voting_power: VotePower) {
+        match vote {
+            ProposalVote::Yay => self.yay += voting_power,
+            ProposalVote::Nay => self.nay += voting_power,
+            ProposalVote::NoWithVeto => self.no_with_veto += voting_power,
+            ProposalVote::Abstain => self.abstain += voting_power,
+            ProposalVote::Options(weights) => {
+                for (option, weight) in weights {
+                    *self.options.entry(*option).or_default() +=
+                        *weight * voting_power;
+                }
+            }
+        }
+    }
+
+    /// Remove the voting power of a vote from the tally
+    fn remove(&mut self, vote: &ProposalVote, voting_power: VotePower) {
+        match vote {
+            ProposalVote::Yay => self.yay -= voting_power,
+            ProposalVote::Nay => self.nay -= voting_power,
+            ProposalVote::NoWithVeto => self.no_with_veto -= voting_power,
+            ProposalVote::Abstain => self.abstain -= voting_power,
+            ProposalVote::Options(weights) => {
+                for (option, weight) in weights {
+                    *self.options.entry(*option).or_default() -=
+                        *weight * voting_power;
+                }
+            }
+        }
+    }
+
+    /// Get the option with strictly more voting power than all the others
+    fn winning_option(&self) -> Option<u8> {
+        let mut options = self.options.iter();
+        let (mut winner, mut winner_power) = options.next()?;
+        let mut is_tied = false;
+        for (option, power) in options {
+            if power > winner_power {
+                (winner, winner_power) = (option, power);
+                is_tied = false;
+            } else if power == winner_power {
+                is_tied = true;
+            }
+        }
+        (!is_tied && !winner_power.is_zero()).then_some(*winner)
+    }
 }
 
 /// Compute the result of a proposal
@@ -361,20 +619,12 @@ pub fn compute_proposal_result(
     total_voting_power: VotePower,
     tally_type: TallyType,
 ) -> ProposalResult {
-    let mut yay_voting_power = VotePower::default();
-    let mut nay_voting_power = VotePower::default();
-    let mut abstain_voting_power = VotePower::default();
+    let mut tally = VotesTally::default();
 
     for (address, vote_power) in votes.validator_voting_power {
         let vote_type = votes.validators_vote.get(&address);
         if let Some(vote) = vote_type {
-            if vote.is_yay() {
-                yay_voting_power += vote_power;
-            } else if vote.is_nay() {
-                nay_voting_power += vote_power;
-            } else if vote.is_abstain() {
-                abstain_voting_power += vote_power;
-            }
+            tally.add(vote.proposal_vote(), vote_power);
         }
     }
 
@@ -389,56 +639,59 @@ pub fn compute_proposal_result(
                 let validator_vote_is_same_side =
                     validator_vote.is_same_side(delegator_vote);
 
+                // The vote of the delegator overrides the one of the
+                // validator for the delegated voting power
                 if !validator_vote_is_same_side {
-                    if delegator_vote.is_yay() {
-                        yay_voting_power += voting_power;
-                        if validator_vote.is_nay() {
-                            nay_voting_power -= voting_power;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power -= voting_power;
-                        }
-                    } else if delegator_vote.is_nay() {
-                        nay_voting_power += voting_power;
-                        if validator_vote.is_yay() {
-                            yay_voting_power -= voting_power;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power -= voting_power;
-                        }
-                    } else if delegator_vote.is_abstain() {
-                        abstain_voting_power += voting_power;
-                        if validator_vote.is_yay() {
-                            yay_voting_power -= voting_power;
-                        } else if validator_vote.is_nay() {
-                            nay_voting_power -= voting_power;
-                        }
-                    }
+                    tally.remove(validator_vote.proposal_vote(), voting_power);
+                    tally.add(delegator_vote.proposal_vote(), voting_power);
                 }
-            } else if delegator_vote.is_yay() {
-                yay_voting_power += voting_power;
-            } else if delegator_vote.is_nay() {
-                nay_voting_power += voting_power;
-            } else if delegator_vote.is_abstain() {
-                abstain_voting_power += voting_power;
+            } else {
+                tally.add(delegator_vote.proposal_vote(), voting_power);
             }
         }
     }
 
-    let tally_result = TallyResult::new(
-        &tally_type,
-        yay_voting_power,
-        nay_voting_power,
-        abstain_voting_power,
-        total_voting_power,
-    );
-
-    ProposalResult {
+    let winning_option = tally.winning_option();
+    let tally_result = match tally_type {
+        TallyType::MultiOption => {
+            let total_options_power: VotePower =
+                tally.options.values().copied().sum();
+            let winning_option_power = winning_option
+                .and_then(|option| tally.options.get(&option).copied())
+                .unwrap_or_default();
+            TallyResult::new(
+                &tally_type,
+                winning_option_power,
+                total_options_power - winning_option_power,
+                tally.abstain,
+                total_voting_power,
+            )
+        }
+        // No with veto votes count as nay votes
+        _ => TallyResult::new(
+            &tally_type,
+            tally.yay,
+            tally.nay + tally.no_with_veto,
+            tally.abstain,
+            total_voting_power,
+        ),
+    };
+
+    let mut proposal_result = ProposalResult {
         result: tally_result,
         tally_type,
         total_voting_power,
-        total_yay_power: yay_voting_power,
-        total_nay_power: nay_voting_power,
-        total_abstain_power: abstain_voting_power,
+        total_yay_power: tally.yay,
+        total_nay_power: tally.nay,
+        total_abstain_power: tally.abstain,
+        total_no_with_veto_power: tally.no_with_veto,
+        total_options_power: tally.options,
+        winning_option,
+    };
+    if proposal_result.is_veto_threshold_reached() {
+        proposal_result.result = TallyResult::Vetoed;
     }
+    proposal_result
 }
 
 /// Calculate the valid voting window for a validator given proposal epoch
@@ -463,6 +716,7 @@ pub fn is_valid_validator_voting_period(
 #[cfg(test)]
 mod test {
     use std::ops::{Add, Sub};
+    use std::str::FromStr;
 
     use namada_core::address;
 
@@ -1414,6 +1668,255 @@ mod test {
         assert!(!proposal_result.two_thirds_nay_over_two_thirds_total())
     }
 
+    #[test]
+    fn test_proposal_vetoed() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let votes = [
+            (address::testing::established_address_1(), ProposalVote::Yay),
+            (
+                address::testing::established_address_2(),
+                ProposalVote::NoWithVeto,
+            ),
+        ];
+        for (validator_address, vote) in votes {
+            proposal_votes.add_validator(
+                &validator_address,
+                token::Amount::from_u64(50),
+                vote.into(),
+            );
+        }
+
+        for tally_type in [
+            TallyType::OneHalfOverOneThird,
+            TallyType::LessOneHalfOverOneThirdNay,
+            TallyType::TwoThirds,
+        ] {
+            let proposal_result = compute_proposal_result(
+                proposal_votes.clone(),
+                token::Amount::from_u64(100),
+                tally_type,
+            );
+            assert!(
+                matches!(proposal_result.result, TallyResult::Vetoed),
+                "{tally_type:?}"
+            );
+            assert_eq!(proposal_result.total_nay_power, token::Amount::zero());
+            assert_eq!(
+                proposal_result.total_no_with_veto_power,
+                token::Amount::from_u64(50)
+            );
+            assert!(proposal_result.is_deposit_forfeited());
+        }
+    }
+
+    #[test]
+    fn test_proposal_deposit_forfeited_without_quorum() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        proposal_votes.add_validator(
+            &validator_address,
+            token::Amount::from_u64(10),
+            ProposalVote::Nay.into(),
+        );
+
+        // Rejected without reaching quorum
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(100),
+            TallyType::TwoThirds,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+        assert!(!proposal_result.is_quorum_reached());
+        assert!(proposal_result.is_deposit_forfeited());
+
+        // Rejected after reaching quorum
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            token::Amount::from_u64(10),
+            TallyType::TwoThirds,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+        assert!(proposal_result.is_quorum_reached());
+        assert!(!proposal_result.is_deposit_forfeited());
+    }
+
+    #[test]
+    fn test_proposal_tally_delegator_overrides() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_1 = address::testing::established_address_1();
+        let validator_2 = address::testing::established_address_2();
+        proposal_votes.add_validator(
+            &validator_1,
+            token::Amount::from_u64(50),
+            ProposalVote::Yay.into(),
+        );
+
+        // The delegator votes the same as its first validator, and votes on
+        // behalf of its second validator which didn't vote
+        let delegator = address::testing::established_address_3();
+        proposal_votes.add_delegator(
+            &delegator,
+            &validator_1,
+            token::Amount::from_u64(10),
+            ProposalVote::Yay.into(),
+        );
+        proposal_votes.add_delegator(
+            &delegator,
+            &validator_2,
+            token::Amount::from_u64(15),
+            ProposalVote::Yay.into(),
+        );
+
+        let overrides = proposal_votes.delegator_overrides();
+        assert_eq!(overrides.len(), 2);
+        let by_validator = |validator: &Address| {
+            overrides
+                .iter()
+                .find(|o| &o.validator == validator)
+                .unwrap()
+                .clone()
+        };
+        assert!(!by_validator(&validator_1).is_override());
+        assert!(by_validator(&validator_2).is_override());
+        assert_eq!(
+            by_validator(&validator_2).voting_power,
+            token::Amount::from_u64(15)
+        );
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            token::Amount::from_u64(100),
+            TallyType::TwoThirds,
+        );
+        assert_eq!(
+            proposal_result.total_yay_power,
+            token::Amount::from_u64(65)
+        );
+        assert_eq!(proposal_result.turnout(), Dec::from_str("0.65").unwrap());
+    }
+
+    #[test]
+    fn test_expedited_proposal_threshold() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        proposal_votes.add_validator(
+            &validator_address,
+            token::Amount::from_u64(60),
+            ProposalVote::Yay.into(),
+        );
+        let validator_address = address::testing::established_address_2();
+        proposal_votes.add_validator(
+            &validator_address,
+            token::Amount::from_u64(10),
+            ProposalVote::Nay.into(),
+        );
+
+        // 60 yay over 70 voted passes the normal threshold, but not the
+        // expedited one which requires 2/3 of the total voting power
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(100),
+            TallyType::TwoThirds,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(100),
+            TallyType::Expedited,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            token::Amount::from_u64(90),
+            TallyType::Expedited,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+    }
+
+    #[test]
+    fn test_multi_option_proposal_weighted_votes() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        let validator_voting_power = token::Amount::from_u64(100);
+        proposal_votes.add_validator(
+            &validator_address,
+            validator_voting_power,
+            ProposalVote::single_option(0).into(),
+        );
+
+        // The delegator splits its voting power between the second and third
+        // options, overriding the vote of its validator
+        let delegator_address = address::testing::established_address_2();
+        let delegator_voting_power = token::Amount::from_u64(60);
+        proposal_votes.add_delegator(
+            &delegator_address,
+            &validator_address,
+            delegator_voting_power,
+            ProposalVote::Options(BTreeMap::from([
+                (1, Dec::from_str("0.75").unwrap()),
+                (2, Dec::from_str("0.25").unwrap()),
+            ]))
+            .into(),
+        );
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            validator_voting_power,
+            TallyType::MultiOption,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+        assert_eq!(
+            proposal_result.total_options_power,
+            BTreeMap::from([
+                (0, token::Amount::from(40)),
+                (1, token::Amount::from(45)),
+                (2, token::Amount::from(15)),
+            ])
+        );
+        assert_eq!(proposal_result.winning_option, Some(1));
+        assert_eq!(proposal_result.total_yay_power, token::Amount::zero());
+
+        // Without reaching the quorum, the proposal is rejected
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            token::Amount::from_u64(200),
+            TallyType::MultiOption,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+        assert_eq!(proposal_result.winning_option, Some(1));
+    }
+
+    #[test]
+    fn test_multi_option_proposal_tie() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        for (validator_address, option) in [
+            (address::testing::established_address_1(), 0),
+            (address::testing::established_address_2(), 1),
+        ] {
+            proposal_votes.add_validator(
+                &validator_address,
+                token::Amount::from_u64(50),
+                ProposalVote::single_option(option).into(),
+            );
+        }
+
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            token::Amount::from_u64(100),
+            TallyType::MultiOption,
+        );
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+        assert_eq!(proposal_result.winning_option, None);
+    }
+
     #[test]
     fn test_validator_voting_period() {
         assert!(!is_valid_validator_voting_period(
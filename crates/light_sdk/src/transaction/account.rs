@@ -1,3 +1,4 @@
+use namada_sdk::account::{FeeAllowance, VestingSchedule};
 use namada_sdk::address::Address;
 use namada_sdk::hash::Hash;
 use namada_sdk::key::common;
@@ -12,6 +13,7 @@ use crate::transaction;
 const TX_INIT_ACCOUNT_WASM: &str = "tx_init_account.wasm";
 const TX_REVEAL_PK_WASM: &str = "tx_reveal_pk.wasm";
 const TX_UPDATE_ACCOUNT_WASM: &str = "tx_update_account.wasm";
+const TX_GRANT_FEE_ALLOWANCE_WASM: &str = "tx_grant_fee_allowance.wasm";
 
 /// Transaction to initialize an established account
 pub struct InitAccount(Tx);
@@ -22,12 +24,14 @@ impl InitAccount {
         public_keys: Vec<common::PublicKey>,
         vp_code_hash: Hash,
         threshold: u8,
+        vesting: Option<VestingSchedule>,
         args: GlobalArgs,
     ) -> Self {
         let init_account = namada_sdk::account::InitAccount {
             public_keys,
             vp_code_hash,
             threshold,
+            vesting,
         };
 
         Self(transaction::build_tx(
@@ -250,3 +254,85 @@ impl UpdateAccount {
         self.0.validate_tx()
     }
 }
+
+/// Transaction to grant, update or revoke a fee allowance
+pub struct GrantFeeAllowance(Tx);
+
+impl GrantFeeAllowance {
+    /// Build a raw GrantFeeAllowance transaction from the given parameters
+    pub fn new(
+        granter: Address,
+        grantee: Address,
+        allowance: Option<FeeAllowance>,
+        args: GlobalArgs,
+    ) -> Self {
+        let grant = namada_sdk::account::GrantFeeAllowance {
+            granter,
+            grantee,
+            allowance,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            grant,
+            TX_GRANT_FEE_ALLOWANCE_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
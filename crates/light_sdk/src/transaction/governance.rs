@@ -27,6 +27,7 @@ impl InitProposal {
         voting_start_epoch: Epoch,
         voting_end_epoch: Epoch,
         grace_epoch: Epoch,
+        expedited: bool,
         args: GlobalArgs,
     ) -> Self {
         let init_proposal = namada_sdk::governance::InitProposalData {
@@ -37,6 +38,7 @@ impl InitProposal {
             voting_start_epoch,
             voting_end_epoch,
             grace_epoch,
+            expedited,
         };
 
         Self(transaction::build_tx(
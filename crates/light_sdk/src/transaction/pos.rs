@@ -5,7 +5,10 @@ use namada_sdk::key::{common, secp256k1};
 use namada_sdk::storage::Epoch;
 use namada_sdk::token;
 use namada_sdk::token::{Amount, DenominatedAmount};
-use namada_sdk::tx::data::pos::Redelegation;
+use namada_sdk::tx::data::pos::{
+    RedeemShares as RedeemSharesData, Redelegation,
+    TokenizeBond as TokenizeBondData,
+};
 use namada_sdk::tx::data::GasLimit;
 use namada_sdk::tx::{Signature, Tx, TxError};
 
@@ -19,11 +22,16 @@ const TX_UNJAIL_VALIDATOR_WASM: &str = "tx_unjail_validator.wasm";
 const TX_DEACTIVATE_VALIDATOR_WASM: &str = "tx_deactivate_validator.wasm";
 const TX_REACTIVATE_VALIDATOR_WASM: &str = "tx_reactivate_validator.wasm";
 const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
+const TX_CHANGE_AUTO_COMPOUND_WASM: &str = "tx_change_auto_compound.wasm";
+const TX_CHANGE_REWARD_RECIPIENT_WASM: &str = "tx_change_reward_recipient.wasm";
 const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
+const TX_TOKENIZE_BOND_WASM: &str = "tx_tokenize_bond.wasm";
+const TX_REDEEM_SHARES_WASM: &str = "tx_redeem_shares.wasm";
 const TX_CHANGE_METADATA_WASM: &str = "tx_change_validator_metadata.wasm";
 const TX_CHANGE_CONSENSUS_KEY_WASM: &str = "tx_change_consensus_key.wasm";
 const TX_CHANGE_COMMISSION_WASM: &str = "tx_change_validator_commission.wasm";
 const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
+const TX_CANCEL_UNBOND_WASM: &str = "tx_cancel_unbond.wasm";
 
 /// A bond transaction
 pub struct Bond(Tx);
@@ -451,6 +459,168 @@ impl ClaimRewards {
     }
 }
 
+/// Transaction to enable or disable the auto-compounding of PoS rewards
+pub struct ChangeAutoCompound(Tx);
+
+impl ChangeAutoCompound {
+    /// Build a raw ChangeAutoCompound transaction from the given parameters
+    pub fn new(
+        validator: Address,
+        source: Option<Address>,
+        enabled: bool,
+        args: GlobalArgs,
+    ) -> Self {
+        let auto_compound = namada_sdk::tx::data::pos::AutoCompound {
+            validator,
+            source,
+            enabled,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            auto_compound,
+            TX_CHANGE_AUTO_COMPOUND_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
+/// Transaction to change the address credited with the claimed rewards of a
+/// source
+pub struct ChangeRewardRecipient(Tx);
+
+impl ChangeRewardRecipient {
+    /// Build a raw ChangeRewardRecipient transaction from the given
+    /// parameters
+    pub fn new(
+        source: Address,
+        recipient: Option<Address>,
+        args: GlobalArgs,
+    ) -> Self {
+        let reward_recipient =
+            namada_sdk::tx::data::pos::RewardRecipient { source, recipient };
+
+        Self(transaction::build_tx(
+            args,
+            reward_recipient,
+            TX_CHANGE_REWARD_RECIPIENT_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
 /// Transaction to change the validator's metadata
 pub struct ChangeMetaData(Tx);
 
@@ -776,6 +946,90 @@ impl Withdraw {
     }
 }
 
+/// Transaction to cancel a not yet withdrawable unbond back into a bond
+pub struct CancelUnbond(Tx);
+
+impl CancelUnbond {
+    /// Build a raw CancelUnbond transaction from the given parameters
+    pub fn new(
+        validator: Address,
+        source: Option<Address>,
+        withdraw_epoch: Epoch,
+        amount: Option<Amount>,
+        args: GlobalArgs,
+    ) -> Self {
+        let cancel_unbond = namada_sdk::tx::data::pos::CancelUnbond {
+            validator,
+            source,
+            withdraw_epoch,
+            amount,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            cancel_unbond,
+            TX_CANCEL_UNBOND_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
 /// Transaction to redelegate
 pub struct Redelegate(Tx);
 
@@ -859,3 +1113,167 @@ impl Redelegate {
         self.0.validate_tx()
     }
 }
+
+/// Transaction to convert bonded tokens into share tokens of the validator
+pub struct TokenizeBond(Tx);
+
+impl TokenizeBond {
+    /// Build a raw TokenizeBond transaction from the given parameters
+    pub fn new(
+        validator: Address,
+        source: Address,
+        amount: Amount,
+        args: GlobalArgs,
+    ) -> Self {
+        let tokenize = TokenizeBondData {
+            validator,
+            source,
+            amount,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            tokenize,
+            TX_TOKENIZE_BOND_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
+
+/// Transaction to redeem share tokens back into bonded tokens
+pub struct RedeemShares(Tx);
+
+impl RedeemShares {
+    /// Build a raw RedeemShares transaction from the given parameters
+    pub fn new(
+        validator: Address,
+        owner: Address,
+        amount: Amount,
+        args: GlobalArgs,
+    ) -> Self {
+        let redeem = RedeemSharesData {
+            validator,
+            owner,
+            amount,
+        };
+
+        Self(transaction::build_tx(
+            args,
+            redeem,
+            TX_REDEEM_SHARES_WASM.to_string(),
+        ))
+    }
+
+    /// Get the bytes to sign for the given transaction
+    pub fn get_sign_bytes(&self) -> Vec<Hash> {
+        transaction::get_sign_bytes(&self.0)
+    }
+
+    /// Attach the provided signatures to the tx
+    pub fn attach_signatures(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(transaction::attach_raw_signatures(
+            self.0, signer, signature,
+        ))
+    }
+
+    /// Attach the fee data to the tx
+    pub fn attach_fee(
+        self,
+        fee: DenominatedAmount,
+        token: Address,
+        fee_payer: common::PublicKey,
+        epoch: Epoch,
+        gas_limit: GasLimit,
+    ) -> Self {
+        Self(attach_fee(self.0, fee, token, fee_payer, epoch, gas_limit))
+    }
+
+    /// Get the bytes of the fee data to sign
+    pub fn get_fee_sig_bytes(&self) -> Hash {
+        transaction::get_wrapper_sign_bytes(&self.0)
+    }
+
+    /// Attach a signature of the fee to the tx
+    pub fn attach_fee_signature(
+        self,
+        signer: common::PublicKey,
+        signature: common::Signature,
+    ) -> Self {
+        Self(attach_fee_signature(self.0, signer, signature))
+    }
+
+    /// Generates the protobuf encoding of this transaction
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Gets the inner transaction without the domain wrapper
+    pub fn payload(self) -> Tx {
+        self.0
+    }
+
+    /// Validate this wrapper transaction
+    pub fn validate_tx(&self) -> Result<Option<&Signature>, TxError> {
+        self.0.validate_tx()
+    }
+}
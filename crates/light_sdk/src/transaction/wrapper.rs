@@ -1,7 +1,8 @@
+use namada_sdk::address::Address;
 use namada_sdk::hash::Hash;
 use namada_sdk::key::common;
 use namada_sdk::storage::Epoch;
-use namada_sdk::tx::data::{Fee, GasLimit};
+use namada_sdk::tx::data::{Fee, GasLimit, TxType};
 use namada_sdk::tx::{Section, Signature, Signer, Tx, TxError};
 
 #[allow(missing_docs)]
@@ -29,6 +30,26 @@ impl Wrapper {
         Self(tx)
     }
 
+    /// Let the given account pay the fees of this transaction against the
+    /// fee allowance it granted to the fee payer
+    pub fn with_fee_granter(mut self, granter: Address) -> Self {
+        if let TxType::Wrapper(wrapper) = &mut self.0.header.tx_type {
+            wrapper.fee_granter = Some(granter);
+        }
+
+        self
+    }
+
+    /// Set the sequence number of this transaction among the ones whose fees
+    /// are paid by the fee payer
+    pub fn with_sequence(mut self, sequence: u64) -> Self {
+        if let TxType::Wrapper(wrapper) = &mut self.0.header.tx_type {
+            wrapper.sequence = Some(sequence);
+        }
+
+        self
+    }
+
     /// Returns the message to be signed for this transaction
     pub fn get_sign_bytes(mut self) -> (Self, Vec<Hash>) {
         self.0.protocol_filter();
@@ -6,7 +6,7 @@ use std::collections::BTreeSet;
 
 use borsh::BorshDeserialize;
 use namada_governance::storage::proposal::{
-    AddRemove, PGFAction, ProposalType,
+    AddRemove, PGFAction, ProposalType, MAX_PROPOSAL_OPTIONS,
 };
 use namada_governance::storage::{is_proposal_accepted, keys as gov_storage};
 use namada_governance::utils::is_valid_validator_voting_period;
@@ -104,6 +104,9 @@ where
                 (KeyType::END_EPOCH, Some(proposal_id)) => {
                     self.is_valid_end_epoch(proposal_id)
                 }
+                (KeyType::EXPEDITED, Some(proposal_id)) => {
+                    self.is_valid_expedited(proposal_id)
+                }
                 (KeyType::FUNDS, Some(proposal_id)) => {
                     self.is_valid_funds(proposal_id, &native_token)
                 }
@@ -220,11 +223,20 @@ where
             delegation_address.clone(),
         );
 
-        if self
+        let vote = self
             .force_read::<ProposalVote>(&vote_key, ReadType::Post)
-            .is_err()
-        {
-            return Err(Error::InvalidVoteKey(key.to_string()));
+            .map_err(|_| Error::InvalidVoteKey(key.to_string()))?;
+
+        // The vote must be compatible with the type of the proposal
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Pre)?;
+        if !proposal_type.is_valid_vote(&vote) {
+            tracing::info!(
+                "Invalid vote {vote} for a {proposal_type} proposal {}.",
+                proposal_id
+            );
+            return Ok(false);
         }
 
         // TODO: We should refactor this by modifying the vote proposal tx
@@ -403,6 +415,34 @@ where
                     .filter(|funding| matches!(funding, PGFAction::Retro(_)))
                     .count();
 
+                // collect all the stream targets that we have to add and are
+                // unique
+                let are_stream_targets_unique = fundings
+                    .iter()
+                    .filter_map(|funding| match funding {
+                        PGFAction::Stream(stream) => {
+                            Some(stream.target.clone())
+                        }
+                        _ => None,
+                    })
+                    .collect::<BTreeSet<Address>>();
+
+                // collect all the stream targets that we have to cancel and
+                // are unique
+                let are_cancelled_stream_targets_unique = fundings
+                    .iter()
+                    .filter_map(|funding| match funding {
+                        PGFAction::CancelStream(target) => Some(target.clone()),
+                        _ => None,
+                    })
+                    .collect::<BTreeSet<Address>>();
+
+                let are_streams_valid =
+                    fundings.iter().all(|funding| match funding {
+                        PGFAction::Stream(stream) => stream.is_valid(),
+                        _ => true,
+                    });
+
                 let is_total_fundings_valid = fundings.len() < MAX_PGF_ACTIONS;
 
                 // check that they are unique by checking that the set of add
@@ -412,17 +452,27 @@ where
                     are_continuous_add_targets_unique.len()
                         + are_continuous_remove_targets_unique.len()
                         + total_retro_targerts
+                        + are_stream_targets_unique.len()
+                        + are_cancelled_stream_targets_unique.len()
                         == fundings.len();
 
                 // can't remove and add the same target in the same proposal
                 let are_targets_unique = are_continuous_add_targets_unique
                     .intersection(&are_continuous_remove_targets_unique)
                     .count() as u64
-                    == 0;
+                    == 0
+                    && are_stream_targets_unique
+                        .intersection(&are_cancelled_stream_targets_unique)
+                        .count()
+                        == 0;
 
                 Ok(is_total_fundings_valid
                     && are_continuous_fundings_unique
-                    && are_targets_unique)
+                    && are_targets_unique
+                    && are_streams_valid)
+            }
+            ProposalType::MultiOption { options, .. } => {
+                Ok((2..=MAX_PROPOSAL_OPTIONS).contains(&options.len()))
             }
             _ => Ok(true), // default proposal
         }
@@ -434,7 +484,7 @@ where
         let proposal_type: ProposalType =
             self.force_read(&proposal_type_key, ReadType::Post)?;
 
-        if !proposal_type.is_default() {
+        if !proposal_type.can_have_code() {
             return Ok(false);
         }
 
@@ -462,8 +512,6 @@ where
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
         let grace_epoch_key = gov_storage::get_grace_epoch_key(proposal_id);
         let max_proposal_period = gov_storage::get_max_proposal_period_key();
-        let min_grace_epoch_key =
-            gov_storage::get_min_proposal_grace_epoch_key();
 
         let has_pre_grace_epoch = self.ctx.has_key_pre(&grace_epoch_key)?;
         if has_pre_grace_epoch {
@@ -476,8 +524,15 @@ where
             self.force_read(&end_epoch_key, ReadType::Post)?;
         let grace_epoch: Epoch =
             self.force_read(&grace_epoch_key, ReadType::Post)?;
-        let min_grace_epoch: u64 =
-            self.force_read(&min_grace_epoch_key, ReadType::Pre)?;
+        // Expedited proposals only need the grace epoch to be after the end
+        // epoch
+        let min_grace_epoch: u64 = if self.is_expedited(proposal_id)? {
+            1
+        } else {
+            let min_grace_epoch_key =
+                gov_storage::get_min_proposal_grace_epoch_key();
+            self.force_read(&min_grace_epoch_key, ReadType::Pre)?
+        };
         let max_proposal_period: u64 =
             self.force_read(&max_proposal_period, ReadType::Pre)?;
 
@@ -523,7 +578,7 @@ where
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
         let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
+            self.get_min_voting_period_key(proposal_id)?;
 
         let current_epoch = self.ctx.get_block_epoch()?;
 
@@ -561,7 +616,7 @@ where
             gov_storage::get_voting_start_epoch_key(proposal_id);
         let end_epoch_key = gov_storage::get_voting_end_epoch_key(proposal_id);
         let min_period_parameter_key =
-            gov_storage::get_min_proposal_voting_period_key();
+            self.get_min_voting_period_key(proposal_id)?;
         let max_period_parameter_key =
             gov_storage::get_max_proposal_period_key();
 
@@ -596,6 +651,28 @@ where
             && (end_epoch - start_epoch).0 <= max_period)
     }
 
+    /// Validate an expedited key
+    pub fn is_valid_expedited(&self, proposal_id: u64) -> Result<bool> {
+        let expedited_key = gov_storage::get_expedited_key(proposal_id);
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+        let counter_key = gov_storage::get_counter_key();
+
+        // The flag can only be set when the proposal is created
+        let has_pre_expedited = self.ctx.has_key_pre(&expedited_key)?;
+        let pre_counter: u64 = self.force_read(&counter_key, ReadType::Pre)?;
+        if has_pre_expedited || proposal_id < pre_counter {
+            return Ok(false);
+        }
+
+        let expedited: bool =
+            self.force_read(&expedited_key, ReadType::Post)?;
+        let proposal_type: ProposalType =
+            self.force_read(&proposal_type_key, ReadType::Post)?;
+
+        // Only yes/no proposals can be expedited
+        Ok(expedited && matches!(proposal_type, ProposalType::Default(_)))
+    }
+
     /// Validate a funds key
     pub fn is_valid_funds(
         &self,
@@ -726,6 +803,26 @@ where
         Ok(is_validator && verifiers.contains(address))
     }
 
+    /// Check if the proposal with the given id is expedited.
+    fn is_expedited(&self, proposal_id: u64) -> Result<bool> {
+        let expedited_key = gov_storage::get_expedited_key(proposal_id);
+        Ok(self
+            .ctx
+            .post()
+            .read::<bool>(&expedited_key)?
+            .unwrap_or_default())
+    }
+
+    /// Get the key of the minimum voting period that applies to the proposal
+    /// with the given id.
+    fn get_min_voting_period_key(&self, proposal_id: u64) -> Result<Key> {
+        if self.is_expedited(proposal_id)? {
+            Ok(gov_storage::get_min_expedited_proposal_voting_period_key())
+        } else {
+            Ok(gov_storage::get_min_proposal_voting_period_key())
+        }
+    }
+
     /// Private method to read from storage data that are 100% in storage.
     fn force_read<T>(&self, key: &Key, read_type: ReadType) -> Result<T>
     where
@@ -801,6 +898,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     END_EPOCH,
     #[allow(non_camel_case_types)]
+    EXPEDITED,
+    #[allow(non_camel_case_types)]
     FUNDS,
     #[allow(non_camel_case_types)]
     BALANCE,
@@ -832,6 +931,8 @@ impl KeyType {
             KeyType::PROPOSAL_COMMIT
         } else if gov_storage::is_end_epoch_key(key) {
             KeyType::END_EPOCH
+        } else if gov_storage::is_expedited_key(key) {
+            KeyType::EXPEDITED
         } else if gov_storage::is_balance_key(key) {
             KeyType::FUNDS
         } else if gov_storage::is_author_key(key) {
@@ -83,6 +83,17 @@ impl ProposalEvent {
         )
     }
 
+    /// Create a new proposal event for vetoed proposal
+    pub fn vetoed_proposal_event(proposal_id: u64) -> Self {
+        ProposalEvent::new(
+            EventType::Proposal.to_string(),
+            TallyResult::Vetoed,
+            proposal_id,
+            false,
+            false,
+        )
+    }
+
     /// Create a new proposal event for default proposal
     pub fn default_proposal_event(
         proposal_id: u64,
@@ -13,7 +13,7 @@ pub mod storage;
 pub mod vp_host_fns;
 
 #[cfg(feature = "wasm-runtime")]
-pub use dry_run_tx::dry_run_tx;
+pub use dry_run_tx::{dry_run_tx, estimate_gas, simulate_tx};
 pub use {
     namada_gas as gas, namada_parameters as parameters,
     namada_tx_env as tx_env, namada_vp_env as vp_env,
@@ -23,11 +23,21 @@ pub use {
 mod dry_run_tx {
     use std::cell::RefCell;
 
-    use namada_sdk::queries::{EncodedResponseQuery, RequestCtx, RequestQuery};
-    use namada_state::{DBIter, ResultExt, StorageHasher, DB};
-    use namada_tx::data::GasLimit;
+    use borsh::BorshDeserialize;
+    use borsh_ext::BorshSerializeExt;
+    use namada_core::event::Event;
+    use namada_gas::{Gas, GasBreakdown, GasMetering, TxGasMeter};
+    use namada_sdk::queries::{
+        EncodedResponseQuery, GasEstimate, RequestCtx, RequestQuery,
+        SimulationRequest, TxSimulation,
+    };
+    use namada_state::{DBIter, ResultExt, StorageHasher, TempWlState, DB};
+    use namada_tx::data::{DecryptedTx, GasLimit, TxResult, TxType};
+    use namada_tx::Tx;
 
     use super::protocol;
+    use crate::ledger::protocol::ShellParams;
+    use crate::storage::TxIndex;
     use crate::vm::wasm::{TxCache, VpCache};
     use crate::vm::WasmCacheAccess;
 
@@ -41,19 +51,159 @@ mod dry_run_tx {
         H: 'static + StorageHasher + Sync,
         CA: 'static + WasmCacheAccess + Sync,
     {
-        use borsh_ext::BorshSerializeExt;
-        use namada_gas::{Gas, GasMetering, TxGasMeter};
-        use namada_tx::data::{DecryptedTx, TxType};
-        use namada_tx::Tx;
+        let mut temp_state = ctx.state.with_temp_write_log();
+        let (data, _breakdown) = run_tx(
+            &mut temp_state,
+            &mut ctx.vp_wasm_cache,
+            &mut ctx.tx_wasm_cache,
+            &request.data,
+            true,
+            false,
+        )?;
+        // NOTE: the keys changed by the wrapper transaction (if any) are
+        // not returned from this function
+        let data = data.serialize_to_vec();
+        Ok(EncodedResponseQuery {
+            data,
+            proof: None,
+            info: Default::default(),
+        })
+    }
 
-        use crate::ledger::protocol::ShellParams;
-        use crate::storage::TxIndex;
+    /// Dry run a transaction to estimate the gas it consumes, split by the
+    /// kind of operations it was charged for. The signatures checked by the
+    /// VPs are only metered, such that a tx carrying placeholder signatures
+    /// is fully validated.
+    pub fn estimate_gas<'a, D, H, CA>(
+        mut ctx: RequestCtx<'a, D, H, VpCache<CA>, TxCache<CA>>,
+        request: &RequestQuery,
+    ) -> namada_state::StorageResult<EncodedResponseQuery>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+        H: 'static + StorageHasher + Sync,
+        CA: 'static + WasmCacheAccess + Sync,
+    {
+        let tx = Tx::try_from(&request.data[..]).into_storage_result()?;
+        let mut temp_state = ctx.state.with_temp_write_log();
+        let (tx_result, mut breakdown) = run_tx(
+            &mut temp_state,
+            &mut ctx.vp_wasm_cache,
+            &mut ctx.tx_wasm_cache,
+            &request.data,
+            false,
+            true,
+        )?;
+        let mut gas_used = tx_result.gas_used;
+        // A raw tx still has to be wrapped before being submitted, so
+        // account for the gas of its wrapper too
+        if let TxType::Raw = tx.header().tx_type {
+            let mut wrapper_gas_meter =
+                TxGasMeter::new_from_sub_limit(u64::MAX.into());
+            wrapper_gas_meter
+                .add_wrapper_gas(&request.data)
+                .into_storage_result()?;
+            let wrapper_gas = wrapper_gas_meter.get_tx_consumed_gas();
+            gas_used = gas_used.checked_add(wrapper_gas).ok_or(
+                namada_state::StorageError::SimpleMessage("Overflow in gas"),
+            )?;
+            breakdown
+                .merge(wrapper_gas_meter.get_breakdown())
+                .into_storage_result()?;
+        }
+        let estimate = GasEstimate {
+            gas_used,
+            breakdown,
+            tx_result,
+        };
+        Ok(EncodedResponseQuery {
+            data: estimate.serialize_to_vec(),
+            proof: None,
+            info: Default::default(),
+        })
+    }
 
+    /// Dry run a transaction on top of the storage overrides of the request,
+    /// returning the storage diff and the events of the tx along with its
+    /// result
+    pub fn simulate_tx<'a, D, H, CA>(
+        mut ctx: RequestCtx<'a, D, H, VpCache<CA>, TxCache<CA>>,
+        request: &RequestQuery,
+    ) -> namada_state::StorageResult<EncodedResponseQuery>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+        H: 'static + StorageHasher + Sync,
+        CA: 'static + WasmCacheAccess + Sync,
+    {
+        let SimulationRequest { tx, overrides } =
+            SimulationRequest::try_from_slice(&request.data[..])
+                .into_storage_result()?;
         let mut temp_state = ctx.state.with_temp_write_log();
-        let mut tx = Tx::try_from(&request.data[..]).into_storage_result()?;
+        // The overrides are written to the block write log, so that they
+        // are seen as the state preceding the tx
+        for (key, value) in overrides {
+            match value {
+                Some(value) => {
+                    temp_state.write_log_mut().protocol_write(&key, value)
+                }
+                None => temp_state.write_log_mut().protocol_delete(&key),
+            }
+            .into_storage_result()?;
+        }
+
+        let (tx_result, _breakdown) = run_tx(
+            &mut temp_state,
+            &mut ctx.vp_wasm_cache,
+            &mut ctx.tx_wasm_cache,
+            &tx,
+            true,
+            false,
+        )?;
+
+        let storage_diff = tx_result.storage_diffs.clone();
+        let events = tx_result
+            .ibc_events
+            .iter()
+            .cloned()
+            .map(Event::from)
+            .chain(tx_result.eth_bridge_events.iter().map(Event::from))
+            .collect();
+        let simulation = TxSimulation {
+            tx_result,
+            storage_diff,
+            events,
+        };
+        Ok(EncodedResponseQuery {
+            data: simulation.serialize_to_vec(),
+            proof: None,
+            info: Default::default(),
+        })
+    }
+
+    /// Run the given transaction on the temporary write log. Returns the
+    /// result of the tx and the breakdown of the consumed gas, accounting for
+    /// both the wrapper (if any) and the inner tx. The changes of the wrapper
+    /// are committed to the block write log, while the ones of the inner tx
+    /// are left in the tx write log. The storage diffs of the inner tx are
+    /// only captured if requested, and the signatures checked by its VPs are
+    /// only metered if requested.
+    fn run_tx<D, H, CA>(
+        temp_state: &mut TempWlState<'_, D, H>,
+        vp_wasm_cache: &mut VpCache<CA>,
+        tx_wasm_cache: &mut TxCache<CA>,
+        tx_bytes: &[u8],
+        capture_storage_diffs: bool,
+        skip_sig_verification: bool,
+    ) -> namada_state::StorageResult<(TxResult, GasBreakdown)>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+        H: 'static + StorageHasher + Sync,
+        CA: 'static + WasmCacheAccess + Sync,
+    {
+        let mut tx = Tx::try_from(tx_bytes).into_storage_result()?;
         tx.validate_tx().into_storage_result()?;
 
         let mut cumulated_gas = Gas::default();
+        let mut breakdown = GasBreakdown::default();
 
         // Wrapper dry run to allow estimating the gas cost of a transaction
         let tx_gas_meter = match tx.header().tx_type {
@@ -64,12 +214,12 @@ mod dry_run_tx {
                     tx.clone(),
                     &wrapper,
                     None,
-                    &request.data,
+                    tx_bytes,
                     ShellParams::new(
                         &tx_gas_meter,
-                        &mut temp_state,
-                        &mut ctx.vp_wasm_cache,
-                        &mut ctx.tx_wasm_cache,
+                        temp_state,
+                        vp_wasm_cache,
+                        tx_wasm_cache,
                     ),
                     None,
                 )
@@ -77,6 +227,7 @@ mod dry_run_tx {
 
                 temp_state.write_log_mut().commit_tx();
                 cumulated_gas = tx_gas_meter.borrow_mut().get_tx_consumed_gas();
+                breakdown = tx_gas_meter.borrow().get_breakdown();
 
                 tx.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
                 let available_gas = tx_gas_meter.borrow().get_available_gas();
@@ -86,7 +237,7 @@ mod dry_run_tx {
                 // If dry run only the inner tx, use the max block gas as
                 // the gas limit
                 TxGasMeter::new(GasLimit::from(
-                    namada_parameters::get_max_block_gas(ctx.state).unwrap(),
+                    namada_parameters::get_max_block_gas(temp_state).unwrap(),
                 ))
             }
             TxType::Raw => {
@@ -96,21 +247,27 @@ mod dry_run_tx {
                 // If dry run only the inner tx, use the max block gas as
                 // the gas limit
                 TxGasMeter::new(GasLimit::from(
-                    namada_parameters::get_max_block_gas(ctx.state).unwrap(),
+                    namada_parameters::get_max_block_gas(temp_state).unwrap(),
                 ))
             }
         };
 
+        let tx_gas_meter = if skip_sig_verification {
+            tx_gas_meter.skip_sig_verification()
+        } else {
+            tx_gas_meter
+        };
         let tx_gas_meter = RefCell::new(tx_gas_meter);
         let mut data = protocol::apply_wasm_tx(
             tx,
             &TxIndex(0),
-            ShellParams::new(
-                &tx_gas_meter,
-                &mut temp_state,
-                &mut ctx.vp_wasm_cache,
-                &mut ctx.tx_wasm_cache,
-            ),
+            ShellParams {
+                tx_gas_meter: &tx_gas_meter,
+                state: temp_state,
+                vp_wasm_cache,
+                tx_wasm_cache,
+                capture_storage_diffs,
+            },
         )
         .into_storage_result()?;
         cumulated_gas = cumulated_gas
@@ -118,16 +275,12 @@ mod dry_run_tx {
             .ok_or(namada_state::StorageError::SimpleMessage(
                 "Overflow in gas",
             ))?;
+        breakdown
+            .merge(tx_gas_meter.borrow().get_breakdown())
+            .into_storage_result()?;
         // Account gas for both inner and wrapper (if available)
         data.gas_used = cumulated_gas;
-        // NOTE: the keys changed by the wrapper transaction (if any) are
-        // not returned from this function
-        let data = data.serialize_to_vec();
-        Ok(EncodedResponseQuery {
-            data,
-            proof: None,
-            info: Default::default(),
-        })
+        Ok((data, breakdown))
     }
 }
 
@@ -139,14 +292,16 @@ mod test {
     use namada_core::hash::Hash;
     use namada_core::storage::{BlockHeight, Key};
     use namada_sdk::queries::{
-        EncodedResponseQuery, RequestCtx, RequestQuery, Router, RPC,
+        EncodedResponseQuery, RequestCtx, RequestQuery, Router,
+        SimulationRequest, RPC,
     };
     use namada_sdk::tendermint_rpc::{Error as RpcError, Response};
     use namada_state::testing::TestState;
     use namada_state::StorageWrite;
+    use namada_test_utils::tx_data::TxWriteData;
     use namada_test_utils::TestWasms;
     use namada_tx::data::decrypted::DecryptedTx;
-    use namada_tx::data::TxType;
+    use namada_tx::data::{StorageDiff, TxType};
     use namada_tx::{Code, Data, Tx};
     use tempfile::TempDir;
 
@@ -248,6 +403,10 @@ mod test {
             // really permit error types other than [`std::io::Error`]
             if request.path == "/shell/dry_run_tx" {
                 super::dry_run_tx(ctx, &request)
+            } else if request.path == "/shell/estimate_gas" {
+                super::estimate_gas(ctx, &request)
+            } else if request.path == "/shell/simulate_tx" {
+                super::simulate_tx(ctx, &request)
             } else {
                 self.rpc.handle(ctx, &request)
             }
@@ -294,11 +453,23 @@ mod test {
         let tx_bytes = outer_tx.to_bytes();
         let result = RPC
             .shell()
-            .dry_run_tx(&client, Some(tx_bytes), None, false)
+            .dry_run_tx(&client, Some(tx_bytes.clone()), None, false)
             .await
             .unwrap();
         assert!(result.data.is_accepted());
 
+        // Request gas estimation of the same tx
+        let estimate = RPC
+            .shell()
+            .estimate_gas(&client, Some(tx_bytes), None, false)
+            .await
+            .unwrap()
+            .data;
+        assert!(estimate.tx_result.is_accepted());
+        assert_eq!(estimate.gas_used, result.data.gas_used);
+        assert_eq!(estimate.breakdown.total().unwrap(), estimate.gas_used);
+        assert_ne!(estimate.breakdown.wasm_compile, Default::default());
+
         // Request storage value for a balance key ...
         let token_addr = address::testing::established_address_1();
         let owner = address::testing::established_address_2();
@@ -364,4 +535,61 @@ mod test {
 
         Ok(())
     }
+
+    /// Test that a simulated tx runs on top of the storage overrides and
+    /// returns the values it changed
+    #[tokio::test]
+    async fn test_simulate_tx_with_overrides() -> namada_state::StorageResult<()>
+    {
+        let mut client = TestClient::new(RPC);
+        // store the wasm code
+        let tx_write = TestWasms::TxWriteStorageKey.read_bytes();
+        let tx_hash = Hash::sha256(&tx_write);
+        let key = Key::wasm_code(&tx_hash);
+        let len_key = Key::wasm_code_len(&tx_hash);
+        client.state.db_write(&key, &tx_write).unwrap();
+        client
+            .state
+            .db_write(&len_key, (tx_write.len() as u64).serialize_to_vec())
+            .unwrap();
+
+        let key = Key::parse("simulated").unwrap();
+        let old_value = "overridden".to_string().serialize_to_vec();
+        let new_value = "written".to_string().serialize_to_vec();
+        let mut outer_tx =
+            Tx::from_type(TxType::Decrypted(DecryptedTx::Decrypted));
+        outer_tx.header.chain_id = client.state.in_mem().chain_id.clone();
+        outer_tx.set_code(Code::from_hash(tx_hash, None));
+        outer_tx.set_data(Data::new(
+            TxWriteData {
+                key: key.clone(),
+                value: new_value.clone(),
+            }
+            .serialize_to_vec(),
+        ));
+        let request = SimulationRequest {
+            tx: outer_tx.to_bytes(),
+            overrides: [(key.clone(), Some(old_value.clone()))].into(),
+        };
+        let simulation = RPC
+            .shell()
+            .simulate_tx(&client, Some(request.serialize_to_vec()), None, false)
+            .await
+            .unwrap()
+            .data;
+        assert!(simulation.tx_result.is_accepted());
+        assert_eq!(
+            simulation.storage_diff.get(&key),
+            Some(&StorageDiff {
+                old_value: Some(old_value),
+                new_value: Some(new_value),
+            })
+        );
+
+        // The overrides are not persisted
+        let has_key = RPC.shell().storage_has_key(&client, &key).await.unwrap();
+        assert!(!has_key);
+
+        Ok(())
+    }
 }
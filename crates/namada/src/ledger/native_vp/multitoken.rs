@@ -3,6 +3,9 @@
 use std::collections::{BTreeSet, HashMap};
 
 use namada_governance::is_proposal_accepted;
+use namada_proof_of_stake::liquid_staking::{
+    is_valid_share_supply_change, share_token_validator,
+};
 use namada_state::StateRead;
 use namada_token::storage_key::is_any_token_parameter_key;
 use namada_tx::Tx;
@@ -192,6 +195,32 @@ where
                     _ => Ok(false),
                 }
             }
+            Address::Internal(InternalAddress::ShareToken(_)) => {
+                // Share tokens are only minted by PoS
+                let minter_key = minter_key(token);
+                match self.ctx.read_post::<Address>(&minter_key)? {
+                    Some(minter)
+                        if minter
+                            == Address::Internal(InternalAddress::PoS) =>
+                    {
+                        if !verifiers.contains(&minter) {
+                            return Ok(false);
+                        }
+                    }
+                    _ => return Ok(false),
+                }
+                // The supply change must be backed by the pool bond, as the
+                // PoS VP doesn't check the PoS storage changes
+                let validator = share_token_validator(token)
+                    .expect("The token must be a share token");
+                let current_epoch = self.ctx.get_block_epoch()?;
+                Ok(is_valid_share_supply_change(
+                    &self.ctx.pre(),
+                    &self.ctx.post(),
+                    &validator,
+                    current_epoch,
+                )?)
+            }
             _ => {
                 // ERC20 and other tokens should not be minted by a wasm
                 // transaction
@@ -228,7 +257,12 @@ mod tests {
     use crate::key::testing::keypair_1;
     use crate::ledger::gas::VpGasMeter;
     use crate::ledger::ibc::storage::ibc_token;
+    use crate::ledger::native_vp::ibc::get_dummy_genesis_validator;
+    use crate::ledger::pos::{self, OwnedPosParams};
+    use crate::proof_of_stake::liquid_staking::{share_token, tokenize_bond};
+    use crate::proof_of_stake::{bond_tokens, staking_token_address};
     use crate::storage::TxIndex;
+    use crate::token::credit_tokens;
     use crate::token::storage_key::{balance_key, minted_balance_key};
     use crate::vm::wasm::compilation_cache::common::testing::cache as wasm_cache;
 
@@ -602,6 +636,128 @@ mod tests {
         );
     }
 
+    /// Initialize PoS with a validator and a delegation to it, returning the
+    /// addresses of the validator and of the delegator
+    fn init_pos_with_delegation(state: &mut TestState) -> (Address, Address) {
+        let validator = get_dummy_genesis_validator();
+        let validator_address = validator.address.clone();
+        let current_epoch = state.in_mem().block.epoch;
+        pos::test_utils::test_init_genesis(
+            state,
+            OwnedPosParams::default(),
+            vec![validator].into_iter(),
+            current_epoch,
+        )
+        .unwrap();
+        let delegator = established_address_2();
+        let amount = Amount::native_whole(100);
+        let native_token = staking_token_address(state);
+        credit_tokens(state, &native_token, &delegator, amount).unwrap();
+        bond_tokens(
+            state,
+            Some(&delegator),
+            &validator_address,
+            amount,
+            current_epoch,
+            None,
+        )
+        .unwrap();
+        state.write_log_mut().commit_tx();
+        state.commit_block().expect("commit failed");
+        (validator_address, delegator)
+    }
+
+    /// Validate the changes in the write log of the given state
+    fn validate_write_log(
+        state: &TestState,
+        verifiers: &BTreeSet<Address>,
+    ) -> bool {
+        let keys_changed = state.write_log().get_keys();
+        let tx_index = TxIndex::default();
+        let tx = dummy_tx(state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) = wasm_cache();
+        let sentinel = RefCell::new(VpSentinel::default());
+        let ctx = Ctx::new(
+            &ADDRESS,
+            state,
+            &tx,
+            &tx_index,
+            &gas_meter,
+            &sentinel,
+            &keys_changed,
+            verifiers,
+            vp_wasm_cache,
+        );
+
+        let vp = MultitokenVp { ctx };
+        vp.validate_tx(&tx, &keys_changed, verifiers)
+            .expect("validation failed")
+    }
+
+    #[test]
+    fn test_share_token_minter() {
+        // only PoS can mint share tokens
+        for (minter, is_valid) in [
+            (Address::Internal(InternalAddress::PoS), true),
+            (Address::Internal(InternalAddress::Ibc), false),
+        ] {
+            let mut state = TestState::default();
+            let (validator, delegator) = init_pos_with_delegation(&mut state);
+
+            // mint shares by tokenizing a part of the delegation
+            let current_epoch = state.in_mem().block.epoch;
+            tokenize_bond(
+                &mut state,
+                &delegator,
+                &validator,
+                Amount::native_whole(50),
+                current_epoch,
+            )
+            .unwrap();
+
+            // minter
+            let token = share_token(&validator).unwrap();
+            let minter_key = minter_key(&token);
+            state
+                .write_log_mut()
+                .write(&minter_key, minter.serialize_to_vec())
+                .expect("write failed");
+
+            let mut verifiers = BTreeSet::new();
+            // for the minter
+            verifiers.insert(minter);
+            assert_eq!(validate_write_log(&state, &verifiers), is_valid);
+        }
+    }
+
+    #[test]
+    fn test_share_token_mint_without_bond() {
+        let mut state = TestState::default();
+        let (validator, delegator) = init_pos_with_delegation(&mut state);
+
+        // mint 100 shares without tokenizing a bond
+        let token = share_token(&validator).unwrap();
+        credit_tokens(
+            &mut state,
+            &token,
+            &delegator,
+            Amount::native_whole(100),
+        )
+        .unwrap();
+        let minter = Address::Internal(InternalAddress::PoS);
+        state
+            .write_log_mut()
+            .write(&minter_key(&token), minter.serialize_to_vec())
+            .expect("write failed");
+
+        // the minter is a verifier, but the shares are not backed by the pool
+        let verifiers = BTreeSet::from([minter]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+
     #[test]
     fn test_invalid_minter_update() {
         let mut state = TestState::default();
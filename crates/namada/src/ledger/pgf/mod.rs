@@ -7,14 +7,17 @@ use std::collections::BTreeSet;
 
 use namada_governance::pgf::storage::keys as pgf_storage;
 use namada_governance::{is_proposal_accepted, pgf};
-use namada_state::StateRead;
+use namada_state::{StateRead, StorageRead};
 use namada_tx::Tx;
+use namada_vp_env::VpEnv;
 use thiserror::Error;
 
 use crate::address::{Address, InternalAddress};
 use crate::ledger::native_vp;
 use crate::ledger::native_vp::{Ctx, NativeVp};
 use crate::storage::Key;
+use crate::token;
+use crate::token::storage_key::{balance_key, is_any_token_balance_key};
 use crate::vm::WasmCacheAccess;
 
 /// for handling Pgf NativeVP errors
@@ -97,6 +100,17 @@ where
                     Ok(is_valid)
                 }
                 KeyType::FUNDINGS => Ok(false),
+                KeyType::STREAMS(target) => self.is_valid_stream_change(
+                    &target,
+                    keys_changed,
+                    verifiers,
+                ),
+                KeyType::BALANCE(token) => self.is_valid_balance_change(
+                    tx_data,
+                    key,
+                    &token,
+                    keys_changed,
+                ),
                 KeyType::PGF_INFLATION_RATE
                 | KeyType::STEWARD_INFLATION_RATE => {
                     self.is_valid_parameter_change(tx_data)
@@ -123,6 +137,111 @@ where
             None => Ok(false),
         }
     }
+
+    /// Validate a change to a pgf stream. The target of a stream can claim
+    /// its vested funds and a steward can cancel it, streams can only be
+    /// added via governance proposals.
+    pub fn is_valid_stream_change(
+        &self,
+        target: &Address,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<bool> {
+        let stream_pre = pgf::storage::get_stream(&self.ctx.pre(), target)?;
+        let stream_post = pgf::storage::get_stream(&self.ctx.post(), target)?;
+
+        let (stream_pre, stream_post) = match (stream_pre, stream_post) {
+            (Some(pre), Some(post)) => (pre, post),
+            _ => return Ok(false),
+        };
+        if stream_pre.id != stream_post.id {
+            return Ok(false);
+        }
+
+        let current_epoch = self.ctx.get_block_epoch()?;
+        if stream_pre.detail == stream_post.detail {
+            // the target is claiming its vested funds, a claim must transfer
+            // some of them
+            let claimed =
+                match stream_post.claimed.checked_sub(stream_pre.claimed) {
+                    Some(claimed) if !claimed.is_zero() => claimed,
+                    _ => return Ok(false),
+                };
+            if claimed > stream_pre.claimable(current_epoch) {
+                return Ok(false);
+            }
+
+            // the claimed funds must be debited from the pgf account, the
+            // amount is checked by the balance validation
+            let native_token = self.ctx.pre().get_native_token()?;
+            let is_balance_debited =
+                keys_changed.contains(&balance_key(&native_token, &ADDRESS));
+
+            Ok(is_balance_debited && verifiers.contains(target))
+        } else {
+            // a steward is cancelling the stream
+            let mut stream_cancelled = stream_pre;
+            stream_cancelled.cancel(current_epoch);
+
+            let is_steward_verifier = verifiers.iter().any(|address| {
+                pgf::storage::is_steward(&self.ctx.pre(), address)
+                    .unwrap_or(false)
+            });
+
+            Ok(stream_cancelled == stream_post && is_steward_verifier)
+        }
+    }
+
+    /// Validate a change to a balance of the pgf account. Funds can only be
+    /// debited by accepted governance proposals or by claims of pgf streams.
+    pub fn is_valid_balance_change(
+        &self,
+        tx: &Tx,
+        key: &Key,
+        token: &Address,
+        keys_changed: &BTreeSet<Key>,
+    ) -> Result<bool> {
+        let pre: token::Amount = self.ctx.pre().read(key)?.unwrap_or_default();
+        let post: token::Amount =
+            self.ctx.post().read(key)?.unwrap_or_default();
+
+        let debited = match pre.checked_sub(post) {
+            Some(debited) if !debited.is_zero() => debited,
+            _ => return Ok(true),
+        };
+
+        if self.is_valid_parameter_change(tx)? {
+            return Ok(true);
+        }
+
+        let native_token = self.ctx.pre().get_native_token()?;
+        if token != &native_token {
+            return Ok(false);
+        }
+
+        // the debited amount must match what was claimed from the streams
+        let mut total_claimed = token::Amount::zero();
+        for target in
+            keys_changed.iter().filter_map(pgf_storage::is_streams_key)
+        {
+            let stream_pre = pgf::storage::get_stream(&self.ctx.pre(), target)?;
+            let stream_post =
+                pgf::storage::get_stream(&self.ctx.post(), target)?;
+            let claimed = match (stream_pre, stream_post) {
+                (Some(pre), Some(post)) => {
+                    post.claimed.checked_sub(pre.claimed)
+                }
+                _ => None,
+            };
+            match claimed.and_then(|claimed| total_claimed.checked_add(claimed))
+            {
+                Some(total) => total_claimed = total,
+                None => return Ok(false),
+            }
+        }
+
+        Ok(debited == total_claimed)
+    }
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -133,6 +252,10 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     FUNDINGS,
     #[allow(non_camel_case_types)]
+    STREAMS(Address),
+    #[allow(non_camel_case_types)]
+    BALANCE(Address),
+    #[allow(non_camel_case_types)]
     PGF_INFLATION_RATE,
     #[allow(non_camel_case_types)]
     STEWARD_INFLATION_RATE,
@@ -148,14 +271,230 @@ impl From<&Key> for KeyType {
             Self::STEWARDS
         } else if pgf_storage::is_fundings_key(key) {
             KeyType::FUNDINGS
+        } else if let Some(target) = pgf_storage::is_streams_key(key) {
+            KeyType::STREAMS(target.clone())
         } else if pgf_storage::is_pgf_inflation_rate_key(key) {
             Self::PGF_INFLATION_RATE
         } else if pgf_storage::is_steward_inflation_rate_key(key) {
             Self::STEWARD_INFLATION_RATE
         } else if pgf_storage::is_pgf_key(key) {
             KeyType::UNKNOWN_PGF
+        } else if let Some([token, owner]) = is_any_token_balance_key(key) {
+            if owner == &ADDRESS {
+                KeyType::BALANCE(token.clone())
+            } else {
+                KeyType::UNKNOWN
+            }
         } else {
             KeyType::UNKNOWN
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use namada_core::validity_predicate::VpSentinel;
+    use namada_gas::TxGasMeter;
+    use namada_governance::storage::proposal::{PGFStream, StoragePgfStream};
+    use namada_state::testing::TestState;
+    use namada_tx::data::TxType;
+    use namada_tx::{Code, Data};
+
+    use super::*;
+    use crate::core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use crate::ledger::gas::VpGasMeter;
+    use crate::storage::{Epoch, TxIndex};
+    use crate::token::{credit_tokens, transfer, Amount};
+    use crate::vm::wasm::compilation_cache::common::testing::cache as wasm_cache;
+
+    fn dummy_tx(state: &TestState) -> Tx {
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        tx
+    }
+
+    /// Fund the pgf account and add a stream vesting 10 tokens per epoch to
+    /// the returned target, from which 30 tokens can be claimed
+    fn init_stream(state: &mut TestState) -> Address {
+        let target = established_address_1();
+        let native_token = state.in_mem().native_token.clone();
+        credit_tokens(
+            state,
+            &native_token,
+            &ADDRESS,
+            Amount::native_whole(1000),
+        )
+        .unwrap();
+        let stream = PGFStream {
+            target: target.clone(),
+            amount_per_epoch: Amount::native_whole(10),
+            cap: Amount::native_whole(100),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(10),
+        };
+        pgf_storage::streams_handle()
+            .insert(state, target.clone(), StoragePgfStream::new(stream, 0))
+            .unwrap();
+        state.write_log_mut().commit_tx();
+        state.commit_block().expect("commit failed");
+        state.in_mem_mut().block.epoch = Epoch(3);
+        target
+    }
+
+    /// Record the given amount as claimed from the stream of the target and
+    /// transfer the debited amount from the pgf account to the target
+    fn claim(
+        state: &mut TestState,
+        target: &Address,
+        claimed: Amount,
+        debited: Amount,
+    ) {
+        let mut stream =
+            pgf::storage::get_stream(state, target).unwrap().unwrap();
+        stream.claimed += claimed;
+        pgf_storage::streams_handle()
+            .insert(state, target.clone(), stream)
+            .unwrap();
+        let native_token = state.in_mem().native_token.clone();
+        transfer(state, &native_token, &ADDRESS, target, debited).unwrap();
+    }
+
+    /// Validate the changes in the write log of the given state
+    fn validate_write_log(
+        state: &TestState,
+        verifiers: &BTreeSet<Address>,
+    ) -> bool {
+        let keys_changed = state.write_log().get_keys();
+        let tx_index = TxIndex::default();
+        let tx = dummy_tx(state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) = wasm_cache();
+        let sentinel = RefCell::new(VpSentinel::default());
+        let ctx = Ctx::new(
+            &ADDRESS,
+            state,
+            &tx,
+            &tx_index,
+            &gas_meter,
+            &sentinel,
+            &keys_changed,
+            verifiers,
+            vp_wasm_cache,
+        );
+
+        let vp = PgfVp { ctx };
+        vp.validate_tx(&tx, &keys_changed, verifiers)
+            .expect("validation failed")
+    }
+
+    #[test]
+    fn test_valid_stream_claim() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        let current_epoch = state.in_mem().block.epoch;
+        let claimed =
+            pgf::storage::claim_stream(&mut state, &target, current_epoch)
+                .unwrap();
+        assert_eq!(claimed, Amount::native_whole(30));
+
+        let verifiers = BTreeSet::from([target]);
+        assert!(validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_partial_stream_claim() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        let amount = Amount::native_whole(20);
+        claim(&mut state, &target, amount, amount);
+
+        let verifiers = BTreeSet::from([target]);
+        assert!(validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_stream_over_claim() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        // more than the 30 vested tokens
+        let amount = Amount::native_whole(40);
+        claim(&mut state, &target, amount, amount);
+
+        let verifiers = BTreeSet::from([target]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_zero_stream_claim() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        claim(&mut state, &target, Amount::zero(), Amount::zero());
+
+        let verifiers = BTreeSet::from([target]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_stream_claim_by_non_recipient() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        let current_epoch = state.in_mem().block.epoch;
+        pgf::storage::claim_stream(&mut state, &target, current_epoch).unwrap();
+
+        // the claim is not authorized by the target of the stream
+        let verifiers = BTreeSet::from([established_address_2()]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_balance_debit_over_claim() {
+        let mut state = TestState::default();
+        let target = init_stream(&mut state);
+
+        // more is debited from the pgf account than what is claimed
+        claim(
+            &mut state,
+            &target,
+            Amount::native_whole(20),
+            Amount::native_whole(30),
+        );
+
+        let verifiers = BTreeSet::from([target]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+
+    #[test]
+    fn test_balance_debit_by_non_recipient() {
+        let mut state = TestState::default();
+        init_stream(&mut state);
+
+        // the funds are debited to another address without any claim
+        let thief = established_address_2();
+        let native_token = state.in_mem().native_token.clone();
+        transfer(
+            &mut state,
+            &native_token,
+            &ADDRESS,
+            &thief,
+            Amount::native_whole(30),
+        )
+        .unwrap();
+
+        let verifiers = BTreeSet::from([thief]);
+        assert!(!validate_write_log(&state, &verifiers));
+    }
+}
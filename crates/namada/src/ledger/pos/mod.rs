@@ -1,5 +1,6 @@
 //! Proof-of-Stake integration as a native validity predicate
 
+pub mod share_token;
 pub mod vp;
 
 use namada_core::address;
@@ -0,0 +1,287 @@
+//! Share token native validity predicate.
+//!
+//! The share token of a validator is a multitoken, whose token keys are
+//! validated by the multitoken VP. The share token address also owns the pool
+//! bond backing the shares, together with its rewards. Any change of the PoS
+//! keys or the balances owned by the share token must be matched by a change
+//! of the supply of the shares that is backed by the change of the value of
+//! the pool, as done by tokenizing a bond or by redeeming shares.
+
+use std::collections::BTreeSet;
+
+use namada_proof_of_stake::liquid_staking::{
+    is_valid_share_supply_change, share_token_validator,
+};
+use namada_state::StateRead;
+use namada_tx::Tx;
+use namada_vp_env::VpEnv;
+use thiserror::Error;
+
+use crate::address::{Address, InternalAddress};
+use crate::ledger::native_vp::{self, Ctx, NativeVp};
+use crate::storage::Key;
+use crate::token;
+use crate::token::storage_key::{
+    is_any_minted_balance_key, is_any_minter_key, is_any_token_balance_key,
+};
+use crate::vm::WasmCacheAccess;
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Native VP error: {0}")]
+    NativeVpError(#[from] native_vp::Error),
+}
+
+/// Share token functions result
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Share token VP
+pub struct ShareTokenVp<'a, S, CA>
+where
+    S: StateRead,
+    CA: WasmCacheAccess,
+{
+    /// Context to interact with the host structures.
+    pub ctx: Ctx<'a, S, CA>,
+}
+
+impl<'a, S, CA> NativeVp for ShareTokenVp<'a, S, CA>
+where
+    S: StateRead,
+    CA: 'static + WasmCacheAccess,
+{
+    type Error = Error;
+
+    fn validate_tx(
+        &self,
+        _tx_data: &Tx,
+        keys_changed: &BTreeSet<Key>,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<bool> {
+        let share_token = self.ctx.address;
+        let Some(validator) = share_token_validator(share_token) else {
+            return Ok(false);
+        };
+
+        let mut is_pool_changed = false;
+        for key in keys_changed {
+            if !key.iter_addresses().any(|address| address == share_token) {
+                continue;
+            }
+            let is_token_key = match is_any_token_balance_key(key) {
+                Some([token, _owner]) => token == share_token,
+                None => {
+                    is_any_minted_balance_key(key) == Some(share_token)
+                        || is_any_minter_key(key) == Some(share_token)
+                }
+            };
+            if is_token_key {
+                // The share token's own keys are validated by the
+                // multitoken VP
+                if !verifiers
+                    .contains(&Address::Internal(InternalAddress::Multitoken))
+                {
+                    return Ok(false);
+                }
+            } else {
+                // The pool bond, its rewards and its balances
+                is_pool_changed = true;
+            }
+        }
+        if !is_pool_changed {
+            return Ok(true);
+        }
+
+        // The pool can only change together with the supply of the shares
+        let supply_pre =
+            token::read_total_supply(&self.ctx.pre(), share_token)?;
+        let supply_post =
+            token::read_total_supply(&self.ctx.post(), share_token)?;
+        if supply_pre == supply_post {
+            tracing::debug!(
+                "The pool of the share token {share_token} changed without a \
+                 change of the supply of the shares"
+            );
+            return Ok(false);
+        }
+        let current_epoch = self.ctx.get_block_epoch()?;
+        Ok(is_valid_share_supply_change(
+            &self.ctx.pre(),
+            &self.ctx.post(),
+            &validator,
+            current_epoch,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use namada_core::validity_predicate::VpSentinel;
+    use namada_gas::TxGasMeter;
+    use namada_proof_of_stake::liquid_staking::{share_token, tokenize_bond};
+    use namada_proof_of_stake::{bond_tokens, claim_reward_tokens};
+    use namada_state::testing::TestState;
+    use namada_tx::data::TxType;
+    use namada_tx::{Code, Data};
+
+    use super::*;
+    use crate::core::address::testing::established_address_2;
+    use crate::ledger::gas::VpGasMeter;
+    use crate::ledger::native_vp::ibc::get_dummy_genesis_validator;
+    use crate::ledger::pos::{self, bond_handle, OwnedPosParams};
+    use crate::proof_of_stake::staking_token_address;
+    use crate::storage::TxIndex;
+    use crate::token::credit_tokens;
+    use crate::vm::wasm::compilation_cache::common::testing::cache as wasm_cache;
+
+    fn dummy_tx(state: &TestState) -> Tx {
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        tx
+    }
+
+    /// Initialize PoS with a validator, a delegation to it and a tokenized
+    /// part of the delegation. Returns the addresses of the validator and of
+    /// the delegator
+    fn init_share_token_pool(state: &mut TestState) -> (Address, Address) {
+        let validator = get_dummy_genesis_validator();
+        let validator_address = validator.address.clone();
+        let current_epoch = state.in_mem().block.epoch;
+        pos::test_utils::test_init_genesis(
+            state,
+            OwnedPosParams::default(),
+            vec![validator].into_iter(),
+            current_epoch,
+        )
+        .unwrap();
+        let delegator = established_address_2();
+        let amount = token::Amount::native_whole(100);
+        let native_token = staking_token_address(state);
+        credit_tokens(state, &native_token, &delegator, amount).unwrap();
+        bond_tokens(
+            state,
+            Some(&delegator),
+            &validator_address,
+            amount,
+            current_epoch,
+            None,
+        )
+        .unwrap();
+        tokenize_bond(
+            state,
+            &delegator,
+            &validator_address,
+            token::Amount::native_whole(50),
+            current_epoch,
+        )
+        .unwrap();
+        state.write_log_mut().commit_tx();
+        state.commit_block().expect("commit failed");
+        (validator_address, delegator)
+    }
+
+    /// Validate the changes in the write log of the given state with the VP
+    /// of the share token of the given validator
+    fn validate_write_log(state: &TestState, validator: &Address) -> bool {
+        let share_token = share_token(validator).unwrap();
+        let keys_changed = state.write_log().get_keys();
+        let verifiers = BTreeSet::from([
+            Address::Internal(InternalAddress::Multitoken),
+            Address::Internal(InternalAddress::PoS),
+        ]);
+        let tx_index = TxIndex::default();
+        let tx = dummy_tx(state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new_from_sub_limit(u64::MAX.into()),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) = wasm_cache();
+        let sentinel = RefCell::new(VpSentinel::default());
+        let ctx = Ctx::new(
+            &share_token,
+            state,
+            &tx,
+            &tx_index,
+            &gas_meter,
+            &sentinel,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+
+        let vp = ShareTokenVp { ctx };
+        vp.validate_tx(&tx, &keys_changed, &verifiers)
+            .expect("validation failed")
+    }
+
+    #[test]
+    fn test_tokenize_bond() {
+        let mut state = TestState::default();
+        let (validator, delegator) = init_share_token_pool(&mut state);
+
+        let current_epoch = state.in_mem().block.epoch;
+        tokenize_bond(
+            &mut state,
+            &delegator,
+            &validator,
+            token::Amount::native_whole(20),
+            current_epoch,
+        )
+        .unwrap();
+
+        assert!(validate_write_log(&state, &validator));
+    }
+
+    #[test]
+    fn test_move_pool_bond_without_burning_shares() {
+        let mut state = TestState::default();
+        let (validator, delegator) = init_share_token_pool(&mut state);
+
+        // move all the records of the pool bond into the delegator's bond
+        let share_token = share_token(&validator).unwrap();
+        let pool_bonds = bond_handle(&share_token, &validator);
+        let delegator_bonds = bond_handle(&delegator, &validator);
+        let records: Vec<_> = pool_bonds
+            .get_data_handler()
+            .iter(&state)
+            .unwrap()
+            .map(|record| record.unwrap())
+            .collect();
+        for (epoch, amount) in records {
+            pool_bonds
+                .get_data_handler()
+                .remove(&mut state, &epoch)
+                .unwrap();
+            delegator_bonds
+                .get_data_handler()
+                .update(&mut state, epoch, |current| {
+                    current.unwrap_or_default() + amount
+                })
+                .unwrap();
+        }
+
+        assert!(!validate_write_log(&state, &validator));
+    }
+
+    #[test]
+    fn test_claim_pool_rewards_without_burning_shares() {
+        let mut state = TestState::default();
+        let (validator, _delegator) = init_share_token_pool(&mut state);
+
+        let share_token = share_token(&validator).unwrap();
+        let current_epoch = state.in_mem().block.epoch.next();
+        claim_reward_tokens(
+            &mut state,
+            Some(&share_token),
+            &validator,
+            current_epoch,
+        )
+        .unwrap();
+
+        assert!(!validate_write_log(&state, &validator));
+    }
+}
@@ -1,6 +1,6 @@
 //! The ledger's protocol
 use std::cell::RefCell;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 
 use borsh_ext::BorshSerializeExt;
@@ -11,10 +11,12 @@ use namada_core::storage::Key;
 use namada_core::validity_predicate::VpSentinel;
 use namada_gas::TxGasMeter;
 use namada_sdk::tx::TX_TRANSFER_WASM;
+use namada_state::write_log::StorageModification;
 use namada_state::StorageWrite;
 use namada_tx::data::protocol::ProtocolTxType;
 use namada_tx::data::{
-    DecryptedTx, GasLimit, TxResult, TxType, VpsResult, WrapperTx,
+    BatchedTxResult, DecryptedTx, GasLimit, StorageDiff, TxResult, TxType,
+    VpsResult, WrapperTx,
 };
 use namada_tx::{Section, Tx};
 use namada_vote_ext::EthereumTxData;
@@ -33,8 +35,11 @@ use crate::ledger::native_vp::multitoken::MultitokenVp;
 use crate::ledger::native_vp::parameters::{self, ParametersVp};
 use crate::ledger::native_vp::{self, NativeVp};
 use crate::ledger::pgf::PgfVp;
+use crate::ledger::pos::share_token::ShareTokenVp;
 use crate::ledger::pos::{self, PosVP};
-use crate::state::{DBIter, State, StorageHasher, StorageRead, WlState, DB};
+use crate::state::{
+    DBIter, State, StateRead, StorageHasher, StorageRead, WlState, DB,
+};
 use crate::storage;
 use crate::storage::TxIndex;
 use crate::token::Amount;
@@ -64,6 +69,8 @@ pub enum Error {
     FeeError(String),
     #[error("Invalid transaction signature")]
     InvalidTxSignature,
+    #[error("Invalid sequence number: {0}")]
+    InvalidSequence(String),
     #[error(
         "The decrypted transaction {0} has already been applied in this block"
     )]
@@ -78,6 +85,8 @@ pub enum Error {
     PosNativeVpError(pos::vp::Error),
     #[error("PoS native VP panicked")]
     PosNativeVpRuntime,
+    #[error("Share token native VP: {0}")]
+    ShareTokenNativeVpError(pos::share_token::Error),
     #[error("Parameters native VP: {0}")]
     ParametersNativeVpError(parameters::Error),
     #[error("IBC Token native VP: {0}")]
@@ -114,6 +123,7 @@ where
     pub state: &'a mut S,
     pub vp_wasm_cache: &'a mut VpCache<CA>,
     pub tx_wasm_cache: &'a mut TxCache<CA>,
+    pub capture_storage_diffs: bool,
 }
 
 impl<'a, S, D, H, CA> ShellParams<'a, S, D, H, CA>
@@ -135,6 +145,7 @@ where
             state,
             vp_wasm_cache,
             tx_wasm_cache,
+            capture_storage_diffs: false,
         }
     }
 }
@@ -166,6 +177,7 @@ pub fn dispatch_tx<'a, D, H, CA>(
     state: &'a mut WlState<D, H>,
     vp_wasm_cache: &'a mut VpCache<CA>,
     tx_wasm_cache: &'a mut TxCache<CA>,
+    capture_storage_diffs: bool,
     wrapper_args: Option<&mut WrapperArgs>,
 ) -> Result<TxResult>
 where
@@ -183,6 +195,7 @@ where
                 state,
                 vp_wasm_cache,
                 tx_wasm_cache,
+                capture_storage_diffs,
             },
         ),
         TxType::Protocol(protocol_tx) => {
@@ -201,6 +214,7 @@ where
                     state,
                     vp_wasm_cache,
                     tx_wasm_cache,
+                    capture_storage_diffs: false,
                 },
                 wrapper_args,
             )?;
@@ -211,6 +225,8 @@ where
                 initialized_accounts: vec![],
                 ibc_events: BTreeSet::default(),
                 eth_bridge_events: BTreeSet::default(),
+                batch_results: vec![],
+                storage_diffs: BTreeMap::new(),
             })
         }
         TxType::Decrypted(DecryptedTx::Undecryptable) => {
@@ -264,6 +280,11 @@ where
         .write_tx_hash(tx.header_hash())
         .expect("Error while writing tx hash to storage");
 
+    check_fee_grant(shell_params.state, &tx, wrapper)?;
+
+    // Advance the sequence of the fee payer together with the fee payment
+    advance_sequence(shell_params.state, wrapper)?;
+
     // Charge fee before performing any fallible operations
     charge_fee(
         wrapper,
@@ -325,6 +346,7 @@ where
         state,
         vp_wasm_cache,
         tx_wasm_cache,
+        capture_storage_diffs: _,
     } = shell_params;
 
     // Unshield funds if requested
@@ -363,6 +385,7 @@ where
                         state: *state,
                         vp_wasm_cache,
                         tx_wasm_cache,
+                        capture_storage_diffs: false,
                     },
                 ) {
                     Ok(result) => {
@@ -417,7 +440,8 @@ where
 }
 
 /// Perform the actual transfer of fess from the fee payer to the block
-/// proposer.
+/// proposer. The share of the fees paid at the base gas price given by the
+/// fee burn rate parameter is burned instead.
 pub fn transfer_fee<S>(
     state: &mut S,
     block_proposer: &Address,
@@ -429,7 +453,7 @@ where
     let balance = crate::token::read_balance(
         state,
         &wrapper.fee.token,
-        &wrapper.fee_payer(),
+        &wrapper.fee_source(),
     )
     .unwrap();
 
@@ -439,14 +463,22 @@ where
                 crate::token::denom_to_amount(fees, &wrapper.fee.token, state)
                     .map_err(|e| Error::FeeError(e.to_string()))?;
             if balance.checked_sub(fees).is_some() {
+                let burned_fees = get_burned_fees(state, wrapper, fees)?;
                 token_transfer(
                     state,
                     &wrapper.fee.token,
-                    &wrapper.fee_payer(),
+                    &wrapper.fee_source(),
                     block_proposer,
-                    fees,
+                    fees.checked_sub(burned_fees).unwrap_or_default(),
                 )
-                .map_err(|e| Error::FeeError(e.to_string()))
+                .map_err(|e| Error::FeeError(e.to_string()))?;
+                token_burn(
+                    state,
+                    &wrapper.fee.token,
+                    &wrapper.fee_source(),
+                    burned_fees,
+                )?;
+                spend_fee_allowance(state, wrapper, fees)
             } else {
                 // Balance was insufficient for fee payment, move all the
                 // available funds in the transparent balance of
@@ -461,11 +493,12 @@ where
                 token_transfer(
                     state,
                     &wrapper.fee.token,
-                    &wrapper.fee_payer(),
+                    &wrapper.fee_source(),
                     block_proposer,
                     balance,
                 )
                 .map_err(|e| Error::FeeError(e.to_string()))?;
+                spend_fee_allowance(state, wrapper, balance)?;
 
                 Err(Error::FeeError(
                     "Transparent balance of wrapper's signer was insufficient \
@@ -538,15 +571,271 @@ where
     }
 }
 
-/// Check if the fee payer has enough transparent balance to pay fees
+/// Compute the amount of the given fees that must be burned: the fee burn rate
+/// applied to the fees due at the base gas price for the gas limit of the
+/// wrapper.
+fn get_burned_fees<S>(
+    state: &S,
+    wrapper: &WrapperTx,
+    fees: Amount,
+) -> Result<Amount>
+where
+    S: StorageRead,
+{
+    let fee_burn_rate = namada_parameters::read_fee_burn_rate(state)
+        .map_err(Error::StorageError)?;
+    if fee_burn_rate.is_zero() || fee_burn_rate.is_negative() {
+        return Ok(Amount::zero());
+    }
+    let base_gas_price =
+        namada_parameters::read_base_gas_price(state, &wrapper.fee.token)
+            .map_err(Error::StorageError)?
+            .unwrap_or_default();
+    let base_fees = base_gas_price
+        .checked_mul(Amount::from(u64::from(wrapper.gas_limit)))
+        .ok_or_else(|| {
+            Error::FeeError("Overflow in base fees calculation".to_string())
+        })?;
+
+    Ok(base_fees.min(fees).mul_ceil(fee_burn_rate).min(fees))
+}
+
+/// Burn `amount` of `token` from `src`. Like [`token_transfer`], this function
+/// updates the tx write log and not the block write log.
+fn token_burn<WLS>(
+    state: &mut WLS,
+    token: &Address,
+    src: &Address,
+    amount: Amount,
+) -> Result<()>
+where
+    WLS: State + StorageRead,
+{
+    if amount.is_zero() {
+        return Ok(());
+    }
+    let src_key = crate::token::storage_key::balance_key(token, src);
+    let src_balance = crate::token::read_balance(state, token, src)
+        .expect("Token balance read in protocol must not fail");
+    let new_src_balance = src_balance.checked_sub(amount).ok_or_else(|| {
+        Error::FeeError("Insufficient source balance".to_string())
+    })?;
+    let supply_key = crate::token::storage_key::minted_balance_key(token);
+    let supply = crate::token::read_total_supply(state, token)
+        .expect("Token supply read in protocol must not fail");
+    let new_supply = supply.checked_sub(amount).ok_or_else(|| {
+        Error::FeeError("The burn would underflow the total supply".to_string())
+    })?;
+
+    state
+        .write_log_mut()
+        .write(&src_key, new_src_balance.serialize_to_vec())
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+    state
+        .write_log_mut()
+        .write(&supply_key, new_supply.serialize_to_vec())
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Deduct the given amount of fees from the allowance used by the wrapper, if
+/// any. Like [`token_transfer`], this function updates the tx write log.
+fn spend_fee_allowance<S>(
+    state: &mut S,
+    wrapper: &WrapperTx,
+    fees: Amount,
+) -> Result<()>
+where
+    S: State + StorageRead,
+{
+    let Some(granter) = &wrapper.fee_granter else {
+        return Ok(());
+    };
+    let grantee = wrapper.fee_payer();
+    let mut allowance = crate::account::fee_allowance(state, granter, &grantee)
+        .map_err(Error::StorageError)?
+        .ok_or_else(|| {
+            Error::FeeError(format!(
+                "Missing the fee allowance granted by {granter} to {grantee}"
+            ))
+        })?;
+    // The allowance is validated before charging the fees, saturate in case
+    // of a fallback transfer of a lower amount
+    allowance.spend_limit =
+        allowance.spend_limit.checked_sub(fees).unwrap_or_default();
+    state
+        .write_log_mut()
+        .write(
+            &crate::account::fee_grant_key(granter, &grantee),
+            allowance.serialize_to_vec(),
+        )
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Check that the fee allowance used by the wrapper, if any, can pay its fees.
+/// The allowance must be denominated in the fee token, must not be expired,
+/// must allow all the inner transactions of the wrapper and must cover the
+/// fees.
+pub fn check_fee_grant<S>(state: &S, tx: &Tx, wrapper: &WrapperTx) -> Result<()>
+where
+    S: StorageRead,
+{
+    let Some(granter) = &wrapper.fee_granter else {
+        return Ok(());
+    };
+    if wrapper.unshield_section_hash.is_some() {
+        return Err(Error::FeeError(
+            "Fee unshielding cannot be used together with a fee allowance"
+                .to_string(),
+        ));
+    }
+    let grantee = wrapper.fee_payer();
+    let allowance = crate::account::fee_allowance(state, granter, &grantee)
+        .map_err(Error::StorageError)?
+        .ok_or_else(|| {
+            Error::FeeError(format!(
+                "No fee allowance granted by {granter} to {grantee}"
+            ))
+        })?;
+
+    if allowance.token != wrapper.fee.token {
+        return Err(Error::FeeError(format!(
+            "The fee allowance granted by {granter} to {grantee} can only pay \
+             fees in {}",
+            allowance.token
+        )));
+    }
+    let current_epoch = state.get_block_epoch().map_err(Error::StorageError)?;
+    if allowance.is_expired(current_epoch) {
+        return Err(Error::FeeError(format!(
+            "The fee allowance granted by {granter} to {grantee} is expired"
+        )));
+    }
+    for entry in tx.batch() {
+        let is_allowed = tx
+            .get_section(&entry.code_hash)
+            .and_then(|x| Section::code_sec(&x))
+            .map_or(allowance.allowed_txs.is_none(), |code_sec| {
+                allowance.is_tx_allowed(&code_sec.code.hash())
+            });
+        if !is_allowed {
+            return Err(Error::FeeError(format!(
+                "The fee allowance granted by {granter} to {grantee} doesn't \
+                 allow the transaction {}",
+                entry.code_hash
+            )));
+        }
+    }
+
+    let fees = wrapper
+        .get_tx_fee()
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+    let fees = crate::token::denom_to_amount(fees, &wrapper.fee.token, state)
+        .map_err(|e| Error::FeeError(e.to_string()))?;
+    if allowance.spend_limit < fees {
+        return Err(Error::FeeError(format!(
+            "The fee allowance granted by {granter} to {grantee} is \
+             insufficient to pay fees"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check that the sequence number of the wrapper, if any, is the next one
+/// expected from its fee payer
+pub fn check_sequence<S>(state: &S, wrapper: &WrapperTx) -> Result<()>
+where
+    S: StorageRead,
+{
+    let Some(sequence) = wrapper.sequence else {
+        return Ok(());
+    };
+    let fee_payer = wrapper.fee_payer();
+    let expected = crate::account::next_sequence(state, &fee_payer)
+        .map_err(Error::StorageError)?;
+    if sequence != expected {
+        return Err(Error::InvalidSequence(format!(
+            "Expected sequence number {expected} from {fee_payer}, found \
+             {sequence}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check the sequence number of the wrapper, if any, and advance the next
+/// sequence number expected from its fee payer. Like [`token_transfer`], this
+/// function updates the tx write log.
+pub fn advance_sequence<S>(state: &mut S, wrapper: &WrapperTx) -> Result<()>
+where
+    S: State + StorageRead,
+{
+    check_sequence(state, wrapper)?;
+    let Some(sequence) = wrapper.sequence else {
+        return Ok(());
+    };
+    let next = sequence.checked_add(1).ok_or_else(|| {
+        Error::InvalidSequence("The sequence number overflows".to_string())
+    })?;
+    state
+        .write_log_mut()
+        .write(
+            &crate::account::sequence_key(&wrapper.fee_payer()),
+            next.serialize_to_vec(),
+        )
+        .map_err(|e| Error::InvalidSequence(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Check that the gas price of the wrapper is not lower than the base gas price
+/// computed by the protocol for its fee token
+pub fn check_base_gas_price<S>(state: &S, wrapper: &WrapperTx) -> Result<()>
+where
+    S: StorageRead,
+{
+    let base_gas_price =
+        namada_parameters::read_base_gas_price(state, &wrapper.fee.token)
+            .map_err(Error::StorageError)?
+            .ok_or_else(|| {
+                Error::FeeError(format!(
+                    "The provided {} token is not allowed for fee payment",
+                    wrapper.fee.token
+                ))
+            })?;
+    let gas_price = crate::token::denom_to_amount(
+        wrapper.fee.amount_per_gas_unit,
+        &wrapper.fee.token,
+        state,
+    )
+    .map_err(|e| Error::FeeError(e.to_string()))?;
+
+    if gas_price < base_gas_price {
+        return Err(Error::FeeError(format!(
+            "Gas price {} is lower than the base gas price {} for token {}",
+            wrapper.fee.amount_per_gas_unit, base_gas_price, wrapper.fee.token
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check if the fee payer, or the fee granter, has enough transparent balance
+/// to pay fees and if the gas price is not lower than the base gas price
 pub fn check_fees<S>(state: &S, wrapper: &WrapperTx) -> Result<()>
 where
     S: State + StorageRead,
 {
+    check_base_gas_price(state, wrapper)?;
+
     let balance = crate::token::read_balance(
         state,
         &wrapper.fee.token,
-        &wrapper.fee_payer(),
+        &wrapper.fee_source(),
     )
     .unwrap();
 
@@ -567,6 +856,10 @@ where
 
 /// Apply a transaction going via the wasm environment. Gas will be metered and
 /// validity predicates will be triggered in the normal way.
+///
+/// The entries of a batch are applied in order, each one being validated on
+/// top of the changes of the previous ones. The batch is atomic: it is
+/// rejected as a whole as soon as one of its entries fails or is rejected.
 pub fn apply_wasm_tx<'a, S, D, H, CA>(
     tx: Tx,
     tx_index: &TxIndex,
@@ -583,6 +876,7 @@ where
         state,
         vp_wasm_cache,
         tx_wasm_cache,
+        capture_storage_diffs,
     } = shell_params;
 
     let tx_hash = tx.raw_header_hash();
@@ -593,8 +887,84 @@ where
         return Err(Error::ReplayAttempt(tx_hash));
     }
 
+    if !tx.is_batch() {
+        return apply_inner_tx(
+            &tx,
+            tx_index,
+            state,
+            tx_gas_meter,
+            vp_wasm_cache,
+            tx_wasm_cache,
+            capture_storage_diffs,
+        );
+    }
+
+    let mut batch_result = TxResult::default();
+    for entry in tx.batch() {
+        let gas_before = tx_gas_meter.borrow().get_tx_consumed_gas();
+        let entry_result = apply_inner_tx(
+            &tx.batch_entry(&entry),
+            tx_index,
+            state,
+            tx_gas_meter,
+            vp_wasm_cache,
+            tx_wasm_cache,
+            capture_storage_diffs,
+        )?;
+        let is_accepted = entry_result.is_accepted();
+
+        batch_result.changed_keys.extend(entry_result.changed_keys);
+        batch_result
+            .initialized_accounts
+            .extend(entry_result.initialized_accounts);
+        batch_result.ibc_events.extend(entry_result.ibc_events);
+        merge_storage_diffs(
+            &mut batch_result.storage_diffs,
+            entry_result.storage_diffs,
+        );
+        batch_result.vps_result = merge_vp_results(
+            batch_result.vps_result,
+            entry_result.vps_result.clone(),
+            &tx_gas_meter.borrow(),
+        )?;
+        batch_result.batch_results.push(BatchedTxResult {
+            gas_used: entry_result
+                .gas_used
+                .checked_sub(gas_before)
+                .unwrap_or_default(),
+            vps_result: entry_result.vps_result,
+        });
+
+        if !is_accepted {
+            // The whole batch is rejected, skip the remaining entries
+            break;
+        }
+        state.write_log_mut().commit_batched_tx();
+    }
+    batch_result.gas_used = tx_gas_meter.borrow().get_tx_consumed_gas();
+
+    Ok(batch_result)
+}
+
+/// Execute a single inner transaction and check it with the triggered
+/// validity predicates
+fn apply_inner_tx<S, D, H, CA>(
+    tx: &Tx,
+    tx_index: &TxIndex,
+    state: &mut S,
+    tx_gas_meter: &RefCell<TxGasMeter>,
+    vp_wasm_cache: &mut VpCache<CA>,
+    tx_wasm_cache: &mut TxCache<CA>,
+    capture_storage_diffs: bool,
+) -> Result<TxResult>
+where
+    S: State<D = D, H = H> + Sync,
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    CA: 'static + WasmCacheAccess + Sync,
+{
     let verifiers = execute_tx(
-        &tx,
+        tx,
         tx_index,
         state,
         tx_gas_meter,
@@ -603,7 +973,7 @@ where
     )?;
 
     let vps_result = check_vps(CheckVps {
-        tx: &tx,
+        tx,
         tx_index,
         state,
         tx_gas_meter: &mut tx_gas_meter.borrow_mut(),
@@ -615,6 +985,11 @@ where
     let initialized_accounts = state.write_log().get_initialized_accounts();
     let changed_keys = state.write_log().get_keys();
     let ibc_events = state.write_log_mut().take_ibc_events();
+    let storage_diffs = if capture_storage_diffs {
+        tx_storage_diffs(state, &changed_keys)?
+    } else {
+        BTreeMap::new()
+    };
 
     Ok(TxResult {
         gas_used,
@@ -623,28 +998,93 @@ where
         initialized_accounts,
         ibc_events,
         eth_bridge_events: BTreeSet::default(),
+        batch_results: vec![],
+        storage_diffs,
     })
 }
 
+/// Collect the values of the given keys before and after the transaction
+/// from the write log. The reads are not metered, as they are only done on
+/// demand for indexing and simulation purposes.
+fn tx_storage_diffs<S>(
+    state: &S,
+    changed_keys: &BTreeSet<Key>,
+) -> Result<BTreeMap<Key, StorageDiff>>
+where
+    S: StateRead,
+{
+    let modified_value = |modification: &StorageModification| match modification
+    {
+        StorageModification::Write { value } => Some(Some(value.clone())),
+        StorageModification::Delete => Some(None),
+        StorageModification::InitAccount { vp_code_hash } => {
+            Some(Some(vp_code_hash.to_vec()))
+        }
+        // Temporary values never reach the storage
+        StorageModification::Temp { .. } => None,
+    };
+
+    let mut storage_diffs = BTreeMap::new();
+    for key in changed_keys {
+        let Some(new_value) =
+            state.write_log().read(key).0.and_then(modified_value)
+        else {
+            continue;
+        };
+        let old_value =
+            match state.write_log().read_pre(key).0.and_then(modified_value) {
+                Some(old_value) => old_value,
+                None => state.db_read(key).map_err(Error::StateError)?.0,
+            };
+        storage_diffs.insert(
+            key.clone(),
+            StorageDiff {
+                old_value,
+                new_value,
+            },
+        );
+    }
+    Ok(storage_diffs)
+}
+
+/// Merge the storage diffs of an entry of a batch into the ones of the
+/// previous entries, keeping the values from before the first entry that
+/// changed a key and the values after the last one
+fn merge_storage_diffs(
+    batch_diffs: &mut BTreeMap<Key, StorageDiff>,
+    entry_diffs: BTreeMap<Key, StorageDiff>,
+) {
+    for (key, diff) in entry_diffs {
+        batch_diffs
+            .entry(key)
+            .and_modify(|batch_diff| {
+                batch_diff.new_value.clone_from(&diff.new_value)
+            })
+            .or_insert(diff);
+    }
+}
+
 /// Returns [`Error::DisallowedTx`] when the given tx is inner (decrypted) tx
-/// and its code `Hash` is not included in the `tx_allowlist` parameter.
+/// and the code `Hash` of any of its batch entries is not included in the
+/// `tx_allowlist` parameter.
 pub fn check_tx_allowed<D, H>(tx: &Tx, state: &WlState<D, H>) -> Result<()>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
     if let TxType::Decrypted(DecryptedTx::Decrypted) = tx.header().tx_type {
-        if let Some(code_sec) = tx
-            .get_section(tx.code_sechash())
-            .and_then(|x| Section::code_sec(&x))
-        {
-            if crate::parameters::is_tx_allowed(state, &code_sec.code.hash())
+        // Every entry of a batch must be allowed
+        for entry in tx.batch() {
+            let code_sec = tx
+                .get_section(&entry.code_hash)
+                .and_then(|x| Section::code_sec(&x))
+                .ok_or(Error::DisallowedTx)?;
+            if !crate::parameters::is_tx_allowed(state, &code_sec.code.hash())
                 .map_err(Error::StorageError)?
             {
-                return Ok(());
+                return Err(Error::DisallowedTx);
             }
         }
-        return Err(Error::DisallowedTx);
     }
     Ok(())
 }
@@ -930,6 +1370,12 @@ where
                                 .validate_tx(tx, &keys_changed, &verifiers)
                                 .map_err(Error::NutNativeVpError)
                         }
+                        InternalAddress::ShareToken(_) => {
+                            let share_token = ShareTokenVp { ctx };
+                            share_token
+                                .validate_tx(tx, &keys_changed, &verifiers)
+                                .map_err(Error::ShareTokenNativeVpError)
+                        }
                         InternalAddress::IbcToken(_)
                         | InternalAddress::Erc20(_) => {
                             // The address should be a part of a multitoken
@@ -1032,11 +1478,12 @@ fn merge_vp_results(
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     use borsh::BorshDeserialize;
     use eyre::Result;
     use namada_core::chain::ChainId;
+    use namada_core::dec::Dec;
     use namada_core::ethereum_events::testing::DAI_ERC20_ETH_ADDRESS;
     use namada_core::ethereum_events::{EthereumEvent, TransferToNamada};
     use namada_core::keccak::keccak_hash;
@@ -1227,4 +1674,246 @@ mod tests {
             }
         }
     }
+    #[test]
+    fn test_apply_wasm_tx_allowlist_batch() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+
+        let code = vec![1_u8, 2, 3];
+        let tx_hash = Hash::sha256(&code);
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.update_header(TxType::Decrypted(DecryptedTx::Decrypted));
+        tx.set_code(namada_tx::Code::new(code, None));
+        let mut other_tx = Tx::new(ChainId::default(), None);
+        other_tx.set_code(namada_tx::Code::new(vec![4_u8, 5, 6], None));
+        tx.add_batch_entry(other_tx);
+
+        let allowlist = vec![tx_hash.to_string()];
+        crate::parameters::update_tx_allowlist_parameter(&mut state, allowlist)
+            .unwrap();
+        state.commit_tx();
+
+        // Check that a batch is disallowed if any of its entries is
+        let result = check_tx_allowed(&tx, &state);
+        assert_matches!(result.unwrap_err(), Error::DisallowedTx);
+    }
+
+    /// Test that a wrapper naming a fee granter is only accepted if the
+    /// granter's allowance covers its token, epoch, code and fees.
+    #[test]
+    fn test_check_fee_grant() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+        let token = address::testing::nam();
+        crate::token::write_denom(&mut state, &token, 0.into()).unwrap();
+        let granter = address::testing::established_address_1();
+        let keypair = key::testing::keypair_1();
+        let grantee = Address::from(&keypair.ref_to());
+
+        let code = vec![1_u8, 2, 3];
+        let code_hash = Hash::sha256(&code);
+        let wrapper = WrapperTx::new(
+            namada_tx::data::Fee {
+                amount_per_gas_unit: crate::token::DenominatedAmount::new(
+                    Amount::from(1),
+                    0.into(),
+                ),
+                token: token.clone(),
+            },
+            keypair.ref_to(),
+            Default::default(),
+            GasLimit::from(10),
+            None,
+        )
+        .with_fee_granter(granter.clone());
+        let mut tx = Tx::from_type(TxType::Wrapper(Box::new(wrapper.clone())));
+        tx.set_code(namada_tx::Code::new(code, None));
+
+        // No allowance was granted
+        let result = check_fee_grant(&state, &tx, &wrapper);
+        assert_matches!(result.unwrap_err(), Error::FeeError(_));
+
+        let current_epoch = state.in_mem().block.epoch;
+        let allowance = crate::account::FeeAllowance {
+            token: token.clone(),
+            spend_limit: Amount::from(10),
+            expiration: Some(current_epoch.next()),
+            allowed_txs: Some(vec![code_hash]),
+        };
+        let check_with = |state: &mut WlState<_, _>, allowance| {
+            crate::account::write_fee_allowance(
+                state,
+                &granter,
+                &grantee,
+                Some(allowance),
+            )
+            .unwrap();
+            check_fee_grant(&*state, &tx, &wrapper)
+        };
+        assert!(check_with(&mut state, allowance.clone()).is_ok());
+
+        // The allowance is too low to pay the fees
+        let low = crate::account::FeeAllowance {
+            spend_limit: Amount::from(9),
+            ..allowance.clone()
+        };
+        assert!(check_with(&mut state, low).is_err());
+
+        // The allowance is expired
+        let expired = crate::account::FeeAllowance {
+            expiration: Some(current_epoch),
+            ..allowance.clone()
+        };
+        assert!(check_with(&mut state, expired).is_err());
+
+        // The tx code is not allowed
+        let disallowed = crate::account::FeeAllowance {
+            allowed_txs: Some(vec![Hash::sha256(b"other code")]),
+            ..allowance
+        };
+        assert!(check_with(&mut state, disallowed).is_err());
+    }
+
+    /// Test that the gas price must cover the base gas price and that the fee
+    /// burn rate applies to the fees due at the base gas price only.
+    #[test]
+    fn test_base_gas_price_and_fee_burn() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+        let token = address::testing::nam();
+        crate::token::write_denom(&mut state, &token, 0.into()).unwrap();
+        let keypair = key::testing::keypair_1();
+        let payer = Address::from(&keypair.ref_to());
+        let proposer = address::testing::established_address_1();
+        crate::token::credit_tokens(
+            &mut state,
+            &token,
+            &payer,
+            Amount::from(1000),
+        )
+        .unwrap();
+        let supply = crate::token::read_total_supply(&state, &token).unwrap();
+        state
+            .write(
+                &namada_parameters::storage::get_base_gas_price_key(),
+                BTreeMap::from([(token.clone(), Amount::from(2))]),
+            )
+            .unwrap();
+        state
+            .write(
+                &namada_parameters::storage::get_fee_burn_rate_key(),
+                Dec::new(5, 1).unwrap(),
+            )
+            .unwrap();
+        let wrapper_with_gas_price = |gas_price: u64| {
+            WrapperTx::new(
+                namada_tx::data::Fee {
+                    amount_per_gas_unit: crate::token::DenominatedAmount::new(
+                        Amount::from(gas_price),
+                        0.into(),
+                    ),
+                    token: token.clone(),
+                },
+                keypair.ref_to(),
+                Default::default(),
+                GasLimit::from(10),
+                None,
+            )
+        };
+
+        // The gas price is lower than the base gas price
+        let result = check_base_gas_price(&state, &wrapper_with_gas_price(1));
+        assert_matches!(result.unwrap_err(), Error::FeeError(_));
+
+        // Half of the 20 units due at the base gas price are burned, the rest
+        // of the 30 units of fees goes to the block proposer
+        let wrapper = wrapper_with_gas_price(3);
+        assert!(check_base_gas_price(&state, &wrapper).is_ok());
+        transfer_fee(&mut state, &proposer, &wrapper).unwrap();
+        let balance =
+            |owner| crate::token::read_balance(&state, &token, owner).unwrap();
+        assert_eq!(balance(&payer), Amount::from(970));
+        assert_eq!(balance(&proposer), Amount::from(20));
+        assert_eq!(
+            crate::token::read_total_supply(&state, &token).unwrap(),
+            supply.checked_sub(Amount::from(10)).unwrap()
+        );
+    }
+
+    /// Test that the storage diffs of a tx hold the values of the keys it
+    /// changed from before and after its execution, without its temporary
+    /// values.
+    #[test]
+    fn test_tx_storage_diffs() {
+        let (mut state, _validators) = test_utils::setup_default_storage();
+        let updated = Key::parse("updated").unwrap();
+        let deleted = Key::parse("deleted").unwrap();
+        let created = Key::parse("created").unwrap();
+        let temp = Key::parse("temp").unwrap();
+
+        // Values written by a previous tx of the block
+        state.write_log_mut().write(&updated, vec![1]).unwrap();
+        state.write_log_mut().write(&deleted, vec![2]).unwrap();
+        state.commit_tx();
+
+        state.write_log_mut().write(&updated, vec![3]).unwrap();
+        state.write_log_mut().delete(&deleted).unwrap();
+        state.write_log_mut().write(&created, vec![4]).unwrap();
+        state.write_log_mut().write_temp(&temp, vec![5]).unwrap();
+        let changed_keys = state.write_log().get_keys();
+        let storage_diffs = tx_storage_diffs(&state, &changed_keys).unwrap();
+
+        let expected = BTreeMap::from([
+            (
+                updated,
+                StorageDiff {
+                    old_value: Some(vec![1]),
+                    new_value: Some(vec![3]),
+                },
+            ),
+            (
+                deleted,
+                StorageDiff {
+                    old_value: Some(vec![2]),
+                    new_value: None,
+                },
+            ),
+            (
+                created,
+                StorageDiff {
+                    old_value: None,
+                    new_value: Some(vec![4]),
+                },
+            ),
+        ]);
+        assert_eq!(storage_diffs, expected);
+    }
+
+    /// Test that the storage diffs of the entries of a batch are merged into
+    /// the values from before the first entry changing a key and after the
+    /// last one.
+    #[test]
+    fn test_merge_batch_storage_diffs() {
+        let first = Key::parse("first").unwrap();
+        let both = Key::parse("both").unwrap();
+        let second = Key::parse("second").unwrap();
+        let diff = |old_value: Option<u8>, new_value: Option<u8>| StorageDiff {
+            old_value: old_value.map(|value| vec![value]),
+            new_value: new_value.map(|value| vec![value]),
+        };
+
+        let mut batch_diffs = BTreeMap::from([
+            (first.clone(), diff(None, Some(1))),
+            (both.clone(), diff(Some(2), Some(3))),
+        ]);
+        let entry_diffs = BTreeMap::from([
+            (both.clone(), diff(Some(3), None)),
+            (second.clone(), diff(Some(4), Some(5))),
+        ]);
+        merge_storage_diffs(&mut batch_diffs, entry_diffs);
+
+        let expected = BTreeMap::from([
+            (first, diff(None, Some(1))),
+            (both, diff(Some(2), None)),
+            (second, diff(Some(4), Some(5))),
+        ]);
+        assert_eq!(batch_diffs, expected);
+    }
 }
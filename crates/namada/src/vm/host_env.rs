@@ -13,7 +13,7 @@ use namada_core::internal::KeyVal;
 use namada_core::storage::TX_INDEX_LENGTH;
 use namada_core::validity_predicate::VpSentinel;
 use namada_gas::{
-    self as gas, GasMetering, TxGasMeter, VpGasMeter,
+    self as gas, GasCategory, GasMetering, TxGasMeter, VpGasMeter,
     MEMORY_ACCESS_GAS_PER_BYTE,
 };
 use namada_state::write_log::{self, WriteLog};
@@ -577,6 +577,22 @@ pub fn tx_charge_gas<MEM, D, H, CA>(
     env: &TxVmEnv<MEM, D, H, CA>,
     used_gas: u64,
 ) -> TxResult<()>
+where
+    MEM: VmMemory,
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+    CA: WasmCacheAccess,
+{
+    tx_charge_gas_for::<MEM, D, H, CA>(env, used_gas, GasCategory::Other)
+}
+
+/// Add a gas cost incured in a transaction for an operation of the given
+/// category
+pub fn tx_charge_gas_for<MEM, D, H, CA>(
+    env: &TxVmEnv<MEM, D, H, CA>,
+    used_gas: u64,
+    category: GasCategory,
+) -> TxResult<()>
 where
     MEM: VmMemory,
     D: 'static + DB + for<'iter> DBIter<'iter>,
@@ -585,15 +601,18 @@ where
 {
     let (gas_meter, sentinel) = env.ctx.gas_meter_and_sentinel();
     // if we run out of gas, we need to stop the execution
-    gas_meter.borrow_mut().consume(used_gas).map_err(|err| {
-        sentinel.borrow_mut().set_out_of_gas();
-        tracing::info!(
-            "Stopping transaction execution because of gas error: {}",
-            err
-        );
+    gas_meter
+        .borrow_mut()
+        .consume_for(used_gas, category)
+        .map_err(|err| {
+            sentinel.borrow_mut().set_out_of_gas();
+            tracing::info!(
+                "Stopping transaction execution because of gas error: {}",
+                err
+            );
 
-        TxRuntimeError::OutOfGas(err)
-    })
+            TxRuntimeError::OutOfGas(err)
+        })
 }
 
 /// Called from VP wasm to request to use the given gas amount
@@ -740,7 +759,7 @@ where
     let write_log = unsafe { env.ctx.write_log.get() };
     let db = unsafe { env.ctx.db.get() };
     let (iter, gas) = namada_state::iter_prefix_post(write_log, db, &prefix);
-    tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+    tx_charge_gas_for::<MEM, D, H, CA>(env, gas, GasCategory::StorageRead)?;
 
     let iterators = unsafe { env.ctx.iterators.get() };
     Ok(iterators.insert(iter).id())
@@ -772,7 +791,11 @@ where
             &Key::parse(key.clone())
                 .map_err(TxRuntimeError::StorageDataError)?,
         );
-        tx_charge_gas::<MEM, D, H, CA>(env, iter_gas + log_gas)?;
+        tx_charge_gas_for::<MEM, D, H, CA>(
+            env,
+            iter_gas + log_gas,
+            GasCategory::StorageRead,
+        )?;
         match log_val {
             Some(write_log::StorageModification::Write { ref value }) => {
                 let key_val = borsh::to_vec(&KeyVal {
@@ -905,7 +928,7 @@ where
         .write_log_mut()
         .write_temp(&key, value)
         .map_err(TxRuntimeError::StorageModificationError)?;
-    tx_charge_gas::<MEM, D, H, CA>(env, gas)
+    tx_charge_gas_for::<MEM, D, H, CA>(env, gas, GasCategory::StorageWrite)
 }
 
 fn check_address_existence<MEM, D, H, CA>(
@@ -939,14 +962,18 @@ where
         }
         let vp_key = Key::validity_predicate(&addr);
         let (vp, gas) = state.write_log().read(&vp_key);
-        tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+        tx_charge_gas_for::<MEM, D, H, CA>(env, gas, GasCategory::StorageRead)?;
         // just check the existence because the write log should not have the
         // delete log of the VP
         if vp.is_none() {
             let (is_present, gas) = state
                 .db_has_key(&vp_key)
                 .map_err(TxRuntimeError::StateError)?;
-            tx_charge_gas::<MEM, D, H, CA>(env, gas)?;
+            tx_charge_gas_for::<MEM, D, H, CA>(
+                env,
+                gas,
+                GasCategory::StorageRead,
+            )?;
             if !is_present {
                 tracing::info!(
                     "Trying to write into storage with a key containing an \
@@ -1477,7 +1504,7 @@ where
         .write_log_mut()
         .write(&key, code_hash)
         .map_err(TxRuntimeError::StorageModificationError)?;
-    tx_charge_gas::<MEM, D, H, CA>(env, gas)
+    tx_charge_gas_for::<MEM, D, H, CA>(env, gas, GasCategory::StorageWrite)
 }
 
 /// Initialize a new account established address.
@@ -1995,14 +2022,33 @@ where
 
     let tx = unsafe { env.ctx.tx.get() };
 
-    match tx.verify_signatures(
-        &hashes,
-        public_keys_map,
-        &Some(signer),
-        threshold,
-        max_signatures,
-        || gas_meter.borrow_mut().consume(gas::VERIFY_TX_SIG_GAS),
-    ) {
+    let consume_verify_sig_gas = || {
+        gas_meter
+            .borrow_mut()
+            .consume_for(gas::VERIFY_TX_SIG_GAS, GasCategory::Signature)
+    };
+    // When estimating the gas of a tx carrying placeholder signatures, the
+    // signatures are only metered
+    let verified = if gas_meter.borrow().skips_sig_verification() {
+        tx.meter_signatures(
+            &hashes,
+            public_keys_map,
+            &Some(signer),
+            threshold,
+            max_signatures,
+            consume_verify_sig_gas,
+        )
+    } else {
+        tx.verify_signatures(
+            &hashes,
+            public_keys_map,
+            &Some(signer),
+            threshold,
+            max_signatures,
+            consume_verify_sig_gas,
+        )
+    };
+    match verified {
         Ok(_) => Ok(HostEnvResult::Success.to_i64()),
         Err(err) => match err {
             namada_tx::VerifySigError::Gas(inner) => {
@@ -2205,7 +2251,11 @@ where
         &None,
         threshold,
         max_signatures,
-        || gas_meter.borrow_mut().consume(gas::VERIFY_TX_SIG_GAS),
+        || {
+            gas_meter
+                .borrow_mut()
+                .consume_for(gas::VERIFY_TX_SIG_GAS, GasCategory::Signature)
+        },
     ) {
         Ok(_) => Ok(HostEnvResult::Success.to_i64()),
         Err(err) => match err {
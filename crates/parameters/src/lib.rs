@@ -1,6 +1,7 @@
 //! Protocol parameters
 pub mod storage;
 mod wasm_allowlist;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 
 use namada_core::address::{Address, InternalAddress};
@@ -20,6 +21,14 @@ pub use wasm_allowlist::{is_tx_allowed, is_vp_allowed};
 /// can be changed via governance.
 pub const ADDRESS: Address = Address::Internal(InternalAddress::Parameters);
 
+/// The gas used by a block that keeps the base gas price unchanged, as a
+/// fraction `1 / BLOCK_GAS_TARGET_DENOM` of the max block gas
+pub const BLOCK_GAS_TARGET_DENOM: u64 = 2;
+
+/// The base gas price changes by at most `1 / BASE_GAS_PRICE_CHANGE_DENOM` of
+/// its value between two consecutive blocks
+pub const BASE_GAS_PRICE_CHANGE_DENOM: u64 = 8;
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum ReadError {
@@ -64,6 +73,7 @@ where
         minimum_gas_price,
         fee_unshielding_gas_limit,
         fee_unshielding_descriptions_limit,
+        fee_burn_rate,
     } = parameters;
 
     // write max tx bytes parameter
@@ -147,6 +157,13 @@ where
     let gas_cost_key = storage::get_gas_cost_key();
     storage.write(&gas_cost_key, minimum_gas_price)?;
 
+    // the base gas price starts from the minimum gas price
+    let base_gas_price_key = storage::get_base_gas_price_key();
+    storage.write(&base_gas_price_key, minimum_gas_price)?;
+
+    let fee_burn_rate_key = storage::get_fee_burn_rate_key();
+    storage.write(&fee_burn_rate_key, fee_burn_rate)?;
+
     Ok(())
 }
 
@@ -320,6 +337,140 @@ where
     Ok(gas_cost_table.get(token).map(|amount| amount.to_owned()))
 }
 
+/// Read the base cost per unit of gas of every token allowed for fee payment.
+/// Falls back to the minimum gas prices if the base gas prices have never been
+/// computed.
+pub fn read_base_gas_prices<S>(
+    storage: &S,
+) -> namada_storage::Result<BTreeMap<Address, token::Amount>>
+where
+    S: StorageRead,
+{
+    match storage.read(&storage::get_base_gas_price_key())? {
+        Some(base_gas_price) => Ok(base_gas_price),
+        None => storage
+            .read(&storage::get_gas_cost_key())?
+            .ok_or(ReadError::ParametersMissing)
+            .into_storage_result(),
+    }
+}
+
+/// Read the base cost per unit of gas for the provided token
+pub fn read_base_gas_price<S>(
+    storage: &S,
+    token: &Address,
+) -> namada_storage::Result<Option<token::Amount>>
+where
+    S: StorageRead,
+{
+    let base_gas_price = read_base_gas_prices(storage)?;
+    Ok(base_gas_price.get(token).map(|amount| amount.to_owned()))
+}
+
+/// Read the share of the fees paid at the base gas price that must be burned.
+/// Chains whose parameters were initialized without it don't burn any fees.
+pub fn read_fee_burn_rate<S>(storage: &S) -> namada_storage::Result<Dec>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&storage::get_fee_burn_rate_key())?
+        .unwrap_or_default())
+}
+
+/// Compute the base gas price of the next block from the one of the current
+/// block, in the style of EIP-1559. The price moves proportionally to the
+/// distance of the gas used by the block from the target, by at most
+/// `1 / BASE_GAS_PRICE_CHANGE_DENOM` of its value. The change is rounded up so
+/// that prices of a few units can move too, and the price never goes below the
+/// minimum gas price.
+pub fn next_base_gas_price(
+    base_gas_price: token::Amount,
+    minimum_gas_price: token::Amount,
+    block_gas_used: u64,
+    max_block_gas: u64,
+) -> token::Amount {
+    let target = max_block_gas / BLOCK_GAS_TARGET_DENOM;
+    let block_gas_used = block_gas_used.min(max_block_gas);
+    if target == 0 {
+        return base_gas_price.max(minimum_gas_price);
+    }
+    let change = |gas_delta: u64| {
+        let numerator =
+            base_gas_price.checked_mul(token::Amount::from_u64(gas_delta))?;
+        let denominator = token::Amount::from_u64(target).checked_mul(
+            token::Amount::from_u64(BASE_GAS_PRICE_CHANGE_DENOM),
+        )?;
+        let change = numerator.checked_div(denominator)?;
+        if change.checked_mul(denominator)? < numerator {
+            change.checked_add(token::Amount::from_u64(1))
+        } else {
+            Some(change)
+        }
+    };
+    let next_base_gas_price = match block_gas_used.cmp(&target) {
+        Ordering::Equal => base_gas_price,
+        Ordering::Greater => {
+            // Increase the price by at least one unit, so that it can grow
+            // from zero as well
+            let increase = change(block_gas_used - target)
+                .unwrap_or_else(token::Amount::max)
+                .max(token::Amount::from_u64(1));
+            base_gas_price
+                .checked_add(increase)
+                .unwrap_or_else(token::Amount::max)
+        }
+        Ordering::Less => {
+            let decrease =
+                change(target - block_gas_used).unwrap_or(base_gas_price);
+            base_gas_price.checked_sub(decrease).unwrap_or_default()
+        }
+    };
+    next_base_gas_price.max(minimum_gas_price)
+}
+
+/// Update the base gas price of every token allowed for fee payment given the
+/// gas used by the last block. Tokens added to or removed from the minimum gas
+/// price table are added to or removed from the base gas price table too.
+pub fn update_base_gas_price<S>(
+    storage: &mut S,
+    block_gas_used: u64,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let max_block_gas = storage::get_max_block_gas(storage)?;
+    let minimum_gas_price: BTreeMap<Address, token::Amount> = storage
+        .read(&storage::get_gas_cost_key())?
+        .ok_or(ReadError::ParametersMissing)
+        .into_storage_result()?;
+    let base_gas_price = read_base_gas_prices(storage)?;
+
+    let next_base_gas_price: BTreeMap<Address, token::Amount> =
+        minimum_gas_price
+            .into_iter()
+            .map(|(token, minimum)| {
+                let base =
+                    base_gas_price.get(&token).copied().unwrap_or(minimum);
+                let next = next_base_gas_price(
+                    base,
+                    minimum,
+                    block_gas_used,
+                    max_block_gas,
+                );
+                (token, next)
+            })
+            .collect();
+
+    // Avoid rewriting the same value at every block
+    if next_base_gas_price == base_gas_price
+        && storage.has_key(&storage::get_base_gas_price_key())?
+    {
+        return Ok(());
+    }
+    storage.write(&storage::get_base_gas_price_key(), next_base_gas_price)
+}
+
 /// Read all the parameters from storage. Returns the parameters and gas
 /// cost.
 pub fn read<S>(storage: &S) -> namada_storage::Result<Parameters>
@@ -430,6 +581,9 @@ where
         .ok_or(ReadError::ParametersMissing)
         .into_storage_result()?;
 
+    // read fee burn rate
+    let fee_burn_rate = read_fee_burn_rate(storage)?;
+
     // read max tx bytes
     let max_tx_bytes_key = storage::get_max_tx_bytes_key();
     let value = storage.read(&max_tx_bytes_key)?;
@@ -453,6 +607,7 @@ where
         minimum_gas_price,
         fee_unshielding_gas_limit,
         fee_unshielding_descriptions_limit,
+        fee_burn_rate,
     })
 }
 
@@ -42,6 +42,8 @@ struct Keys {
     max_tx_bytes: &'static str,
     max_block_gas: &'static str,
     minimum_gas_price: &'static str,
+    base_gas_price: &'static str,
+    fee_burn_rate: &'static str,
     fee_unshielding_gas_limit: &'static str,
     fee_unshielding_descriptions_limit: &'static str,
     max_signatures_per_transaction: &'static str,
@@ -185,6 +187,17 @@ pub fn get_gas_cost_key() -> Key {
     get_minimum_gas_price_key_at_addr(ADDRESS)
 }
 
+/// Storage key used for the base gas price table, computed by the protocol at
+/// every block
+pub fn get_base_gas_price_key() -> Key {
+    get_base_gas_price_key_at_addr(ADDRESS)
+}
+
+/// Storage key used for the fee burn rate
+pub fn get_fee_burn_rate_key() -> Key {
+    get_fee_burn_rate_key_at_addr(ADDRESS)
+}
+
 /// Storage key used for the max signatures per transaction key
 pub fn get_max_signatures_per_transaction_key() -> Key {
     get_max_signatures_per_transaction_key_at_addr(ADDRESS)
@@ -67,6 +67,32 @@ pub enum UnbondError {
     ValidatorIsFrozen(Address),
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum LiquidStakingError {
+    #[error("The given address {0} is not a validator address")]
+    NotAValidator(Address),
+    #[error("The self-bond of the validator {0} cannot be tokenized")]
+    SelfBond(Address),
+    #[error(
+        "Trying to tokenize more tokens ({0}) than the amount bonded ({1})"
+    )]
+    TokenizeAmountGreaterThanBond(String, String),
+    #[error(
+        "The bond starting at epoch {0} contains redelegated tokens, which \
+         cannot be tokenized"
+    )]
+    RedelegatedBond(Epoch),
+    #[error("Trying to redeem more share tokens ({0}) than the balance ({1})")]
+    InsufficientShares(String, String),
+    #[error("The amount {0} is too small to be converted")]
+    AmountTooSmall(String),
+    #[error("The share token pool of the validator {0} is fully slashed")]
+    PoolFullySlashed(Address),
+    #[error("Trying to convert a bond of a frozen validator: {0}")]
+    ValidatorIsFrozen(Address),
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum WithdrawError {
@@ -76,6 +102,36 @@ pub enum WithdrawError {
     NoWithdrawableUnbond(BondId),
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum CancelUnbondError {
+    #[error("No unbond withdrawable at epoch {1} could be found for {0}")]
+    NoUnbondFound(BondId, Epoch),
+    #[error("The unbond withdrawable at epoch {0} can already be withdrawn")]
+    AlreadyWithdrawable(Epoch),
+    #[error(
+        "Trying to cancel more tokens ({0}) than the amount unbonded ({1})"
+    )]
+    CancelAmountGreaterThanUnbond(String, String),
+    #[error(
+        "The unbond of the bond starting at epoch {0} contains redelegated \
+         tokens, which cannot be bonded back"
+    )]
+    RedelegatedUnbond(Epoch),
+    #[error("Trying to cancel an unbond from a frozen validator: {0}")]
+    ValidatorIsFrozen(Address),
+    #[error(
+        "The total unbonded amount of validator {validator} ending at epoch \
+         {end_epoch} of the bonds starting at epoch {start_epoch} is lower \
+         than the cancelled amount"
+    )]
+    TotalUnbondedMismatch {
+        validator: Address,
+        end_epoch: Epoch,
+        start_epoch: Epoch,
+    },
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum SlashError {
@@ -194,12 +250,24 @@ impl From<UnbondError> for namada_storage::Error {
     }
 }
 
+impl From<LiquidStakingError> for namada_storage::Error {
+    fn from(err: LiquidStakingError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl From<WithdrawError> for namada_storage::Error {
     fn from(err: WithdrawError) -> Self {
         Self::new(err)
     }
 }
 
+impl From<CancelUnbondError> for namada_storage::Error {
+    fn from(err: CancelUnbondError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl From<CommissionRateChangeError> for namada_storage::Error {
     fn from(err: CommissionRateChangeError) -> Self {
         Self::new(err)
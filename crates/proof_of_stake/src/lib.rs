@@ -7,6 +7,7 @@
 #![deny(rustdoc::private_intra_doc_links)]
 
 pub mod epoched;
+pub mod liquid_staking;
 pub mod parameters;
 pub mod pos_queries;
 pub mod queries;
@@ -50,23 +51,25 @@ use crate::slashing::{
     compute_amount_after_slashing_withdraw, find_validator_slashes,
 };
 use crate::storage::{
-    below_capacity_validator_set_handle, bond_handle,
-    consensus_validator_set_handle, delegator_redelegated_bonds_handle,
-    delegator_redelegated_unbonds_handle, get_last_reward_claim_epoch,
+    auto_compound_bonds_handle, below_capacity_validator_set_handle,
+    bond_handle, consensus_validator_set_handle,
+    delegator_redelegated_bonds_handle, delegator_redelegated_unbonds_handle,
+    get_last_reward_claim_epoch, liveness_last_signed_heights_handle,
     liveness_missed_votes_handle, liveness_sum_missed_votes_handle,
     read_consensus_validator_set_addresses, read_non_pos_owned_params,
-    read_pos_params, read_validator_last_slash_epoch,
+    read_pos_params, read_reward_recipient, read_validator_last_slash_epoch,
     read_validator_max_commission_rate_change, read_validator_stake,
-    total_bonded_handle, total_consensus_stake_handle, total_unbonded_handle,
+    reward_recipients_handle, total_bonded_handle,
+    total_consensus_stake_handle, total_unbonded_handle,
     try_insert_consensus_key, unbond_handle, update_total_deltas,
     update_validator_deltas, validator_addresses_handle,
     validator_commission_rate_handle, validator_consensus_key_handle,
     validator_deltas_handle, validator_eth_cold_key_handle,
     validator_eth_hot_key_handle, validator_incoming_redelegations_handle,
-    validator_outgoing_redelegations_handle, validator_protocol_key_handle,
-    validator_rewards_products_handle, validator_set_positions_handle,
-    validator_slashes_handle, validator_state_handle,
-    validator_total_redelegated_bonded_handle,
+    validator_jail_events_handle, validator_outgoing_redelegations_handle,
+    validator_protocol_key_handle, validator_rewards_products_handle,
+    validator_set_positions_handle, validator_slashes_handle,
+    validator_state_handle, validator_total_redelegated_bonded_handle,
     validator_total_redelegated_unbonded_handle, write_last_reward_claim_epoch,
     write_pos_params, write_validator_address_raw_hash, write_validator_avatar,
     write_validator_description, write_validator_discord_handle,
@@ -76,9 +79,9 @@ use crate::storage::{
 use crate::storage_key::{bonds_for_source_prefix, is_bond_key};
 use crate::types::{
     BondId, ConsensusValidator, ConsensusValidatorSet,
-    EagerRedelegatedBondsMap, RedelegatedBondsOrUnbonds, RedelegatedTokens,
-    ResultSlashing, Slash, Unbonds, ValidatorMetaData, ValidatorSetUpdate,
-    ValidatorState, VoteInfo,
+    EagerRedelegatedBondsMap, JailEvent, JailReason, RedelegatedBondsOrUnbonds,
+    RedelegatedTokens, ResultSlashing, Slash, Unbonds, ValidatorMetaData,
+    ValidatorSetUpdate, ValidatorState, VoteInfo,
 };
 use crate::validator_set_update::{
     copy_validator_sets_and_positions, insert_validator_into_validator_set,
@@ -236,6 +239,29 @@ where
     let staking_token = staking_token_address(storage);
     token::transfer(storage, &staking_token, source, &ADDRESS, amount)?;
 
+    add_bond(
+        storage,
+        source,
+        validator,
+        amount,
+        current_epoch,
+        offset_opt,
+    )
+}
+
+/// Bond tokens that are already held by the PoS account from the `source` to
+/// the `validator` and update the validator sets and deltas.
+fn add_bond<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+    offset_opt: Option<u64>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
     let params = read_pos_params(storage)?;
     let offset = offset_opt.unwrap_or(params.pipeline_len);
     let offset_epoch = current_epoch + offset;
@@ -264,17 +290,44 @@ where
         tracing::debug!("\nBonds after incrementing: {bonds:#?}");
     }
 
+    add_bonded_stake(
+        storage,
+        &params,
+        validator,
+        amount,
+        current_epoch,
+        offset_opt,
+    )
+}
+
+/// Add the given bonded amount to the stake of the validator at the given
+/// offset, or at the pipeline offset if none is given, updating the
+/// validator set and the validator and total deltas.
+fn add_bonded_stake<S>(
+    storage: &mut S,
+    params: &PosParams,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+    offset_opt: Option<u64>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let offset = offset_opt.unwrap_or(params.pipeline_len);
+    let offset_epoch = current_epoch + offset;
+
     // Update the validator set
     // Allow bonding even if the validator is jailed. However, if jailed, there
     // must be no changes to the validator set. Check at the pipeline epoch.
     let is_jailed_or_inactive_at_pipeline = matches!(
-        validator_state_handle.get(storage, offset_epoch, &params)?,
+        validator_state_handle(validator).get(storage, offset_epoch, params)?,
         Some(ValidatorState::Jailed) | Some(ValidatorState::Inactive)
     );
     if !is_jailed_or_inactive_at_pipeline {
         update_validator_set(
             storage,
-            &params,
+            params,
             validator,
             amount.change(),
             current_epoch,
@@ -285,7 +338,7 @@ where
     // Update the validator and total deltas
     update_validator_deltas(
         storage,
-        &params,
+        params,
         validator,
         amount.change(),
         current_epoch,
@@ -294,7 +347,7 @@ where
 
     update_total_deltas(
         storage,
-        &params,
+        params,
         amount.change(),
         current_epoch,
         offset_opt,
@@ -414,6 +467,12 @@ where
         )
         .into());
     }
+    // Stop auto-compounding the rewards of a bond that is fully unbonded
+    if amount == remaining_at_pipeline {
+        auto_compound_bonds_handle()
+            .at(source)
+            .remove(storage, validator)?;
+    }
 
     if tracing::level_enabled!(tracing::Level::DEBUG) {
         let bonds = find_bonds(storage, source, validator)?;
@@ -1490,6 +1549,214 @@ where
     Ok(withdrawable_amount)
 }
 
+/// Cancel an unbond that cannot be withdrawn yet and bond the tokens back to
+/// the validator. The unbond is identified by the epoch at which it becomes
+/// withdrawable. Up to `amount` of it is cancelled, starting from the most
+/// recent bonds, or all of it when `amount` is `None`.
+///
+/// The bond records are restored with their original start epochs, such that
+/// they remain slashable for the infractions committed since then, and the
+/// validator's stake is increased at the pipeline offset by the cancelled
+/// amount after applying the slashes recorded so far. Unbonds of redelegated
+/// tokens cannot be cancelled. Returns the amount bonded back.
+pub fn cancel_unbond<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    withdraw_epoch: Epoch,
+    amount: Option<token::Amount>,
+    current_epoch: Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    let params = read_pos_params(storage)?;
+
+    // Make sure source is not some other validator
+    if let Some(source) = source {
+        if source != validator && is_validator(storage, source)? {
+            return Err(
+                BondError::SourceMustNotBeAValidator(source.clone()).into()
+            );
+        }
+    }
+    // Make sure the target is actually a validator
+    if !is_validator(storage, validator)? {
+        return Err(BondError::NotAValidator(validator.clone()).into());
+    }
+    // Make sure the validator is not currently frozen
+    if is_validator_frozen(storage, validator, current_epoch, &params)? {
+        return Err(
+            CancelUnbondError::ValidatorIsFrozen(validator.clone()).into()
+        );
+    }
+    if withdraw_epoch <= current_epoch {
+        return Err(
+            CancelUnbondError::AlreadyWithdrawable(withdraw_epoch).into()
+        );
+    }
+
+    let source = source.unwrap_or(validator);
+    tracing::debug!(
+        "Cancelling unbond of {source} from {validator} withdrawable at epoch \
+         {withdraw_epoch}"
+    );
+
+    let unbonds = unbond_handle(source, validator);
+    let redelegated_unbonds =
+        delegator_redelegated_unbonds_handle(source).at(validator);
+
+    // Find the unbonded amounts by their bond start epochs
+    let mut unbonded = BTreeMap::new();
+    for unbond in unbonds.iter(storage)? {
+        let (
+            lazy_map::NestedSubKey::Data {
+                key: start_epoch,
+                nested_sub_key: lazy_map::SubKey::Data(epoch),
+            },
+            amount,
+        ) = unbond?;
+        if epoch == withdraw_epoch {
+            unbonded.insert(start_epoch, amount);
+        }
+    }
+    if unbonded.is_empty() {
+        return Err(CancelUnbondError::NoUnbondFound(
+            BondId {
+                source: source.clone(),
+                validator: validator.clone(),
+            },
+            withdraw_epoch,
+        )
+        .into());
+    }
+    let total_unbonded: token::Amount = unbonded.values().copied().sum();
+    let amount = amount.unwrap_or(total_unbonded);
+    if amount > total_unbonded {
+        return Err(CancelUnbondError::CancelAmountGreaterThanUnbond(
+            amount.to_string_native(),
+            total_unbonded.to_string_native(),
+        )
+        .into());
+    }
+
+    // Cancel the unbonds of the most recent bonds first
+    let mut cancelled = BTreeMap::new();
+    let mut remaining = amount;
+    for (&start_epoch, &unbond_amount) in unbonded.iter().rev() {
+        if remaining.is_zero() {
+            break;
+        }
+        if !redelegated_unbonds
+            .at(&start_epoch)
+            .at(&withdraw_epoch)
+            .is_empty(storage)?
+        {
+            return Err(
+                CancelUnbondError::RedelegatedUnbond(start_epoch).into()
+            );
+        }
+        let to_cancel = cmp::min(remaining, unbond_amount);
+        remaining -= to_cancel;
+        cancelled.insert(start_epoch, to_cancel);
+
+        let unbonds_at_start = unbonds.at(&start_epoch);
+        if to_cancel == unbond_amount {
+            unbonds_at_start.remove(storage, &withdraw_epoch)?;
+        } else {
+            unbonds_at_start.insert(
+                storage,
+                withdraw_epoch,
+                unbond_amount - to_cancel,
+            )?;
+        }
+        if unbonds_at_start.is_empty(storage)? {
+            unbonds.remove_all(storage, &start_epoch)?;
+        }
+    }
+
+    // Revert the validator's total unbonded amounts, recorded at the pipeline
+    // epoch of the unbond, as the tokens are no longer unbonded. The bond
+    // back below is added to the validator's total bonded amounts.
+    let end_epoch = withdraw_epoch
+        - params.unbonding_len
+        - params.cubic_slashing_window_length;
+    let validator_unbonded = total_unbonded_handle(validator).at(&end_epoch);
+    for (start_epoch, to_cancel) in &cancelled {
+        let remaining = validator_unbonded
+            .get(storage, start_epoch)?
+            .unwrap_or_default()
+            .checked_sub(*to_cancel)
+            .ok_or_else(|| CancelUnbondError::TotalUnbondedMismatch {
+                validator: validator.clone(),
+                end_epoch,
+                start_epoch: *start_epoch,
+            })?;
+        if remaining.is_zero() {
+            validator_unbonded.remove(storage, start_epoch)?;
+        } else {
+            validator_unbonded.insert(storage, *start_epoch, remaining)?;
+        }
+    }
+
+    // Apply the slashes for infractions committed while the cancelled tokens
+    // were still contributing to the validator's stake
+    let slashes = find_validator_slashes(storage, validator)?
+        .into_iter()
+        .filter(|slash| slash.epoch < end_epoch)
+        .collect();
+    let result_slashing = compute_amount_after_slashing_unbond(
+        storage,
+        &params,
+        &cancelled,
+        &EagerRedelegatedUnbonds::default(),
+        slashes,
+    )?;
+    let bonded_amount = result_slashing.sum;
+    tracing::debug!(
+        "Bonding back {} of the cancelled {}",
+        bonded_amount.to_string_native(),
+        amount.to_string_native()
+    );
+
+    // Settle the source's rewards of its current bonds, such that the
+    // restored bond records only earn rewards from now on, as in
+    // `redeem_share_tokens`
+    let rewards = compute_current_rewards_from_bonds(
+        storage,
+        source,
+        validator,
+        current_epoch,
+    )?;
+    add_rewards_to_counter(storage, source, validator, rewards)?;
+    write_last_reward_claim_epoch(storage, source, validator, current_epoch)?;
+
+    // Restore the bond records with their start epochs
+    let bonds = bond_handle(source, validator).get_data_handler();
+    let validator_bonded = total_bonded_handle(validator).get_data_handler();
+    for (start_epoch, to_cancel) in cancelled {
+        bonds.update(storage, start_epoch, |current| {
+            current.unwrap_or_default() + to_cancel
+        })?;
+        validator_bonded.update(storage, start_epoch, |current| {
+            current.unwrap_or_default() + to_cancel
+        })?;
+    }
+
+    if !bonded_amount.is_zero() {
+        add_bonded_stake(
+            storage,
+            &params,
+            validator,
+            bonded_amount,
+            current_epoch,
+            None,
+        )?;
+    }
+
+    Ok(bonded_amount)
+}
+
 /// Change the commission rate of a validator
 pub fn change_validator_commission_rate<S>(
     storage: &mut S,
@@ -2363,6 +2630,7 @@ where
         read_consensus_validator_set_addresses(storage, votes_epoch)?;
     let liveness_missed_votes = liveness_missed_votes_handle();
     let liveness_sum_missed_votes = liveness_sum_missed_votes_handle();
+    let liveness_last_signed_heights = liveness_last_signed_heights_handle();
 
     // Get the addresses of the validators who voted
     let vote_addresses = votes
@@ -2418,14 +2686,40 @@ where
             // Initialize any new consensus validator who has signed the first
             // block
             if !liveness_sum_missed_votes.contains(storage, &cons_validator)? {
-                liveness_sum_missed_votes.insert(storage, cons_validator, 0)?;
+                liveness_sum_missed_votes.insert(
+                    storage,
+                    cons_validator.clone(),
+                    0,
+                )?;
             }
+
+            liveness_last_signed_heights.insert(
+                storage,
+                cons_validator,
+                votes_height.0,
+            )?;
         }
     }
 
     Ok(())
 }
 
+/// Get the number of missed votes within the liveness window at which a
+/// consensus validator gets jailed, derived from the liveness threshold
+pub fn liveness_missed_votes_threshold(
+    params: &PosParams,
+) -> namada_storage::Result<u64> {
+    Ok(((Dec::one() - params.liveness_threshold)
+        * params.liveness_window_check)
+        .to_uint()
+        .ok_or_else(|| {
+            namada_storage::Error::SimpleMessage(
+                "Found negative liveness threshold",
+            )
+        })?
+        .as_u64())
+}
+
 /// Jail validators who failed to match the liveness threshold
 pub fn jail_for_liveness<S>(
     storage: &mut S,
@@ -2437,15 +2731,7 @@ where
     S: StorageRead + StorageWrite,
 {
     // Derive the actual missing votes limit from the percentage
-    let missing_votes_threshold = ((Dec::one() - params.liveness_threshold)
-        * params.liveness_window_check)
-        .to_uint()
-        .ok_or_else(|| {
-            namada_storage::Error::SimpleMessage(
-                "Found negative liveness threshold",
-            )
-        })?
-        .as_u64();
+    let missing_votes_threshold = liveness_missed_votes_threshold(params)?;
 
     // Jail inactive validators
     let validators_to_jail = liveness_sum_missed_votes_handle()
@@ -2476,7 +2762,14 @@ where
             validator,
             jail_epoch,
         );
-        jail_validator(storage, params, validator, current_epoch, jail_epoch)?;
+        jail_validator(
+            storage,
+            params,
+            validator,
+            current_epoch,
+            jail_epoch,
+            JailReason::Liveness,
+        )?;
     }
 
     Ok(())
@@ -2618,7 +2911,8 @@ where
 }
 
 /// Claim available rewards, triggering an immediate transfer of tokens from the
-/// PoS account to the source address.
+/// PoS account to the reward recipient of the source address, which is the
+/// source itself unless another recipient is set.
 pub fn claim_reward_tokens<S>(
     storage: &mut S,
     source: Option<&Address>,
@@ -2628,31 +2922,151 @@ pub fn claim_reward_tokens<S>(
 where
     S: StorageRead + StorageWrite,
 {
-    tracing::debug!("Claiming rewards in epoch {current_epoch}");
+    let source = source.unwrap_or(validator);
+    let recipient = read_reward_recipient(storage, source)?;
+    claim_reward_tokens_to(
+        storage,
+        source,
+        validator,
+        &recipient,
+        current_epoch,
+    )
+}
 
-    let source = source.cloned().unwrap_or_else(|| validator.clone());
-    tracing::debug!("Source {} --> Validator {}", source, validator);
+/// Claim available rewards of the bond from the `source` to the `validator`,
+/// triggering an immediate transfer of tokens from the PoS account to the
+/// given `recipient`, regardless of the reward recipient set by the source.
+pub(crate) fn claim_reward_tokens_to<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    recipient: &Address,
+    current_epoch: Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!("Claiming rewards in epoch {current_epoch}");
+    tracing::debug!(
+        "Source {} --> Validator {}, recipient {}",
+        source,
+        validator,
+        recipient
+    );
 
     let mut reward_tokens = compute_current_rewards_from_bonds(
         storage,
-        &source,
+        source,
         validator,
         current_epoch,
     )?;
 
     // Add reward tokens tallied during previous withdrawals
-    reward_tokens += take_rewards_from_counter(storage, &source, validator)?;
+    reward_tokens += take_rewards_from_counter(storage, source, validator)?;
 
     // Update the last claim epoch in storage
-    write_last_reward_claim_epoch(storage, &source, validator, current_epoch)?;
+    write_last_reward_claim_epoch(storage, source, validator, current_epoch)?;
 
-    // Transfer the bonded tokens from PoS to the source
+    // Transfer the reward tokens from PoS to the recipient
     let staking_token = staking_token_address(storage);
-    token::transfer(storage, &staking_token, &ADDRESS, &source, reward_tokens)?;
+    token::transfer(
+        storage,
+        &staking_token,
+        &ADDRESS,
+        recipient,
+        reward_tokens,
+    )?;
 
     Ok(reward_tokens)
 }
 
+/// Set the address credited with the claimed rewards of all the bonds of the
+/// `source`. When the `recipient` is `None` or equal to the `source`, the
+/// rewards are credited to the source again. The rewards of the bonds with
+/// auto-compounding enabled are still bonded back from the source.
+pub fn change_reward_recipient<S>(
+    storage: &mut S,
+    source: &Address,
+    recipient: Option<&Address>,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let recipients = reward_recipients_handle();
+    match recipient {
+        Some(recipient) if recipient != source => {
+            tracing::debug!(
+                "Setting the reward recipient of {source} to {recipient}"
+            );
+            recipients.insert(storage, source.clone(), recipient.clone())?;
+        }
+        _ => {
+            tracing::debug!("Resetting the reward recipient of {source}");
+            recipients.remove(storage, source)?;
+        }
+    }
+    Ok(())
+}
+
+/// Enable or disable the automatic compounding of the rewards of the bond from
+/// the `source` to the `validator`, or of the validator's self-bond when
+/// `source` is `None` or equal to the `validator` address. The rewards of the
+/// enabled bonds are bonded back to the validator at every new epoch, until the
+/// bond is fully unbonded. It can only be enabled for a non-zero bond.
+pub fn change_auto_compound_rewards<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    enabled: bool,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let source = source.unwrap_or(validator);
+    tracing::debug!(
+        "Setting auto-compounding of rewards of {source} to {validator} to \
+         {enabled}"
+    );
+    if !is_validator(storage, validator)? {
+        return Err(BondError::NotAValidator(validator.clone()).into());
+    }
+    if source != validator && is_validator(storage, source)? {
+        return Err(BondError::SourceMustNotBeAValidator(source.clone()).into());
+    }
+
+    let bonds = auto_compound_bonds_handle().at(source);
+    if enabled {
+        let params = read_pos_params(storage)?;
+        let pipeline_epoch = storage.get_block_epoch()? + params.pipeline_len;
+        let bond = bond_handle(source, validator)
+            .get_sum(storage, pipeline_epoch, &params)?
+            .unwrap_or_default();
+        if bond.is_zero() {
+            return Err(UnbondError::NoBondFound.into());
+        }
+        bonds.insert(storage, validator.clone())?;
+    } else {
+        bonds.remove(storage, validator)?;
+    }
+    Ok(())
+}
+
+/// Check if the rewards of the bond from the `source` to the `validator` are
+/// automatically compounded.
+pub fn is_auto_compound_rewards<S>(
+    storage: &S,
+    source: Option<&Address>,
+    validator: &Address,
+) -> namada_storage::Result<bool>
+where
+    S: StorageRead,
+{
+    let source = source.unwrap_or(validator);
+    auto_compound_bonds_handle()
+        .at(source)
+        .contains(storage, validator)
+}
+
 /// Query the amount of available reward tokens for a given bond.
 pub fn query_reward_tokens<S>(
     storage: &S,
@@ -2679,13 +3093,14 @@ where
 
 /// Jail a validator by removing it from and updating the validator sets and
 /// changing a its state to `Jailed`. Validators are jailed for liveness and for
-/// misbehaving.
+/// misbehaving, which is recorded in the validator's jail events.
 pub fn jail_validator<S>(
     storage: &mut S,
     params: &PosParams,
     validator: &Address,
     current_epoch: Epoch,
     validator_set_update_epoch: Epoch,
+    reason: JailReason,
 ) -> namada_storage::Result<()>
 where
     S: StorageRead + StorageWrite,
@@ -2699,12 +3114,16 @@ where
     // Remove the validator from the set starting at the update epoch and up
     // thru the pipeline epoch.
     let pipeline_epoch = current_epoch + params.pipeline_len;
+    let mut is_newly_jailed = false;
     for epoch in
         Epoch::iter_bounds_inclusive(validator_set_update_epoch, pipeline_epoch)
     {
         let prev_state = validator_state_handle(validator)
             .get(storage, epoch, params)?
             .expect("Expected to find a valid validator.");
+        if prev_state != ValidatorState::Jailed {
+            is_newly_jailed = true;
+        }
         match prev_state {
             ValidatorState::Consensus => {
                 tracing::debug!(
@@ -2752,6 +3171,11 @@ where
         }
     }
 
+    // Only record a jail event if the validator wasn't already jailed
+    if !is_newly_jailed {
+        return Ok(());
+    }
+
     // Safe sub cause `validator_set_update_epoch > current_epoch`
     let start_offset = validator_set_update_epoch.0 - current_epoch.0;
     // Set the validator state as `Jailed` thru the pipeline epoch
@@ -2763,6 +3187,15 @@ where
             offset,
         )?;
     }
+
+    validator_jail_events_handle(validator).push(
+        storage,
+        JailEvent {
+            epoch: current_epoch,
+            start_epoch: validator_set_update_epoch,
+            reason,
+        },
+    )?;
     Ok(())
 }
 
@@ -0,0 +1,480 @@
+//! Liquid staking with share tokens.
+//!
+//! A delegator can tokenize (a part of) their bond to a validator into the
+//! share token of that validator, a multitoken minted by PoS. The records of
+//! the tokenized bond are moved, with their start epochs, into the pool bond
+//! held by the share token address itself, such that the validator's stake is
+//! unchanged and the slashes of the validator apply to the pool bond as they
+//! would have applied to the delegator's bond.
+//!
+//! The share tokens are transferable like any other token and any holder can
+//! redeem them back into a bond with the validator, taking a proportional part
+//! of every record of the pool bond. The rewards of the pool bond are bonded
+//! back into the pool whenever shares are minted or redeemed, increasing the
+//! value of every share.
+
+use std::collections::BTreeMap;
+
+use namada_core::address::{Address, InternalAddress};
+use namada_core::storage::Epoch;
+use namada_storage::collections::lazy_map::Collectable;
+use namada_storage::{StorageRead, StorageWrite};
+
+use crate::rewards::{
+    add_rewards_to_counter, compute_current_rewards_from_bonds,
+};
+use crate::slashing::{
+    compute_amount_after_slashing_unbond, find_validator_slashes,
+};
+use crate::storage::{
+    bond_handle, delegator_redelegated_bonds_handle,
+    get_last_reward_claim_epoch, read_pos_params,
+    validator_rewards_products_handle, write_last_reward_claim_epoch,
+};
+use crate::types::BondId;
+use crate::{
+    bond_amount, bond_tokens, claim_reward_tokens_to, find_bonds_to_remove,
+    is_validator, is_validator_frozen, query_reward_tokens, token, BondError,
+    LiquidStakingError, PosParams, ADDRESS,
+};
+
+/// Get the address of the share token of the given validator. Returns `None`
+/// if the address is not an established address, which all the validators
+/// have.
+pub fn share_token(validator: &Address) -> Option<Address> {
+    match validator {
+        Address::Established(validator) => Some(Address::Internal(
+            InternalAddress::ShareToken(validator.clone()),
+        )),
+        _ => None,
+    }
+}
+
+/// Get the address of the validator of the given share token, if the address
+/// is a share token.
+pub fn share_token_validator(token: &Address) -> Option<Address> {
+    match token {
+        Address::Internal(InternalAddress::ShareToken(validator)) => {
+            Some(Address::Established(validator.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Get the value of the pool bond backing the share tokens of the given
+/// validator at the given epoch, after slashing. For future epochs the value
+/// is subject to change.
+pub fn share_token_pool_value<S>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let Some(share_token) = share_token(validator) else {
+        return Ok(token::Amount::zero());
+    };
+    bond_amount(
+        storage,
+        &BondId {
+            source: share_token,
+            validator: validator.clone(),
+        },
+        epoch,
+    )
+}
+
+/// Check that a change of the supply of the share token of the given
+/// validator from the `pre` to the `post` state is backed by the same
+/// proportional change of the value of the pool bond, valued after
+/// compounding the pool's rewards as tokenizing and redeeming do. The share
+/// price may only move by the rounding of the slashed value of the pool bond
+/// records.
+pub fn is_valid_share_supply_change<Pre, Post>(
+    pre: &Pre,
+    post: &Post,
+    validator: &Address,
+    current_epoch: Epoch,
+) -> namada_storage::Result<bool>
+where
+    Pre: StorageRead,
+    Post: StorageRead,
+{
+    let Some(share_token) = share_token(validator) else {
+        return Ok(false);
+    };
+    let params = read_pos_params(pre)?;
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+
+    let supply_pre = token::read_total_supply(pre, &share_token)?;
+    let supply_post = token::read_total_supply(post, &share_token)?;
+    let rewards =
+        query_reward_tokens(pre, Some(&share_token), validator, current_epoch)?;
+    let value_pre = share_token_pool_value(pre, validator, pipeline_epoch)?
+        .checked_add(rewards)
+        .ok_or_else(|| {
+            namada_storage::Error::new_const(
+                "Overflow in share token pool value",
+            )
+        })?;
+    let value_post = share_token_pool_value(post, validator, pipeline_epoch)?;
+
+    // Allow for a rounding error of one unit per slash for every record
+    let num_records = bond_handle(&share_token, validator)
+        .get_data_handler()
+        .iter(post)?
+        .count() as u64;
+    let num_slashes = find_validator_slashes(pre, validator)?.len() as u64;
+    let tolerance =
+        token::Amount::from_u64(num_records.saturating_mul(num_slashes + 1));
+
+    match supply_post.checked_sub(supply_pre) {
+        Some(minted) if minted.is_zero() => Ok(true),
+        Some(minted) => {
+            // The minted shares must be backed by the value added to the pool
+            let Some(added) = value_post.checked_sub(value_pre) else {
+                return Ok(false);
+            };
+            let backing = added + tolerance;
+            let max_shares = if supply_pre.is_zero() {
+                backing
+            } else if value_pre.is_zero() {
+                return Ok(false);
+            } else {
+                mul_div(backing, supply_pre, value_pre)?
+            };
+            Ok(minted <= max_shares)
+        }
+        None => {
+            // The value taken from the pool must be backed by the burned
+            // shares
+            let burned = supply_pre - supply_post;
+            let taken = value_pre.checked_sub(value_post).unwrap_or_default();
+            let max_taken = mul_div(value_pre, burned, supply_pre)? + tolerance;
+            Ok(taken <= max_taken)
+        }
+    }
+}
+
+/// Tokenize the given amount of the bond from the `source` to the
+/// `validator` into the share token of the validator. The most recent bonds
+/// are tokenized first. Returns the amount of minted share tokens.
+pub fn tokenize_bond<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!(
+        "Tokenizing bond amount {} of {source} to {validator} at epoch \
+         {current_epoch}",
+        amount.to_string_native()
+    );
+    if amount.is_zero() {
+        return Err(LiquidStakingError::AmountTooSmall(
+            amount.to_string_native(),
+        )
+        .into());
+    }
+    if source == validator {
+        return Err(LiquidStakingError::SelfBond(validator.clone()).into());
+    }
+    let params = read_pos_params(storage)?;
+    let share_token = check_share_token_validator(
+        storage,
+        validator,
+        current_epoch,
+        &params,
+    )?;
+
+    let bonds_handle = bond_handle(source, validator);
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let remaining_at_pipeline = bonds_handle
+        .get_sum(storage, pipeline_epoch, &params)?
+        .unwrap_or_default();
+    if amount > remaining_at_pipeline {
+        return Err(LiquidStakingError::TokenizeAmountGreaterThanBond(
+            amount.to_string_native(),
+            remaining_at_pipeline.to_string_native(),
+        )
+        .into());
+    }
+
+    // Find the bond records to move into the pool
+    let bonds_to_move = find_bonds_to_remove(
+        storage,
+        &bonds_handle.get_data_handler(),
+        amount,
+    )?;
+    let mut moved = BTreeMap::new();
+    for &epoch in &bonds_to_move.epochs {
+        let bond = bonds_handle
+            .get_delta_val(storage, epoch)?
+            .unwrap_or_default();
+        moved.insert(epoch, bond);
+    }
+    if let Some((epoch, new_bond)) = bonds_to_move.new_entry {
+        let bond = bonds_handle
+            .get_delta_val(storage, epoch)?
+            .unwrap_or_default();
+        moved.insert(epoch, bond - new_bond);
+    }
+    let redelegated_bonds =
+        delegator_redelegated_bonds_handle(source).at(validator);
+    for epoch in moved.keys() {
+        if redelegated_bonds.contains(storage, epoch)? {
+            return Err(LiquidStakingError::RedelegatedBond(*epoch).into());
+        }
+    }
+
+    // Compound the pool's rewards before valuing it
+    compound_pool_rewards(storage, &share_token, validator, current_epoch)?;
+    let pool_value =
+        share_token_pool_value(storage, validator, pipeline_epoch)?;
+    let supply = token::read_total_supply(storage, &share_token)?;
+
+    let slashes = find_validator_slashes(storage, validator)?;
+    let result_slashing = compute_amount_after_slashing_unbond(
+        storage,
+        &params,
+        &moved,
+        &BTreeMap::new(),
+        slashes,
+    )?;
+    let shares = if supply.is_zero() {
+        result_slashing.sum
+    } else if pool_value.is_zero() {
+        return Err(
+            LiquidStakingError::PoolFullySlashed(validator.clone()).into()
+        );
+    } else {
+        mul_div(result_slashing.sum, supply, pool_value)?
+    };
+    if shares.is_zero() {
+        return Err(LiquidStakingError::AmountTooSmall(
+            amount.to_string_native(),
+        )
+        .into());
+    }
+
+    // Tally the source's rewards of the moved bonds up to now, as in
+    // `unbond_tokens`
+    if current_epoch > Epoch::default() {
+        let mut rewards = token::Amount::zero();
+        let last_claim_epoch =
+            get_last_reward_claim_epoch(storage, source, validator)?
+                .unwrap_or_default();
+        let rewards_products = validator_rewards_products_handle(validator);
+        for (start_epoch, slashed_amount) in &result_slashing.epoch_map {
+            for ep in
+                Epoch::iter_bounds_inclusive(*start_epoch, current_epoch.prev())
+            {
+                if ep < last_claim_epoch {
+                    continue;
+                }
+                let rp =
+                    rewards_products.get(storage, &ep)?.unwrap_or_default();
+                rewards += rp * (*slashed_amount);
+            }
+        }
+        add_rewards_to_counter(storage, source, validator, rewards)?;
+    }
+
+    // Move the bond records into the pool
+    for epoch in &bonds_to_move.epochs {
+        bonds_handle.get_data_handler().remove(storage, epoch)?;
+    }
+    if let Some((epoch, new_bond)) = bonds_to_move.new_entry {
+        bonds_handle.set(storage, new_bond, epoch, 0)?;
+    }
+    let pool_bonds = bond_handle(&share_token, validator).get_data_handler();
+    for (epoch, amount) in moved {
+        pool_bonds.update(storage, epoch, |current| {
+            current.unwrap_or_default() + amount
+        })?;
+    }
+
+    // Mint the shares
+    token::credit_tokens(storage, &share_token, source, shares)?;
+    storage.write(&token::storage_key::minter_key(&share_token), ADDRESS)?;
+
+    Ok(shares)
+}
+
+/// Redeem the given amount of the share token of the `validator` held by the
+/// `owner` into a bond from the `owner` to the `validator`, taking the same
+/// proportion of every record of the pool bond. Returns the redeemed bond
+/// amount after slashing.
+pub fn redeem_share_tokens<S>(
+    storage: &mut S,
+    owner: &Address,
+    validator: &Address,
+    shares: token::Amount,
+    current_epoch: Epoch,
+) -> namada_storage::Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    tracing::debug!(
+        "Redeeming share token amount {} of {owner} for {validator} at epoch \
+         {current_epoch}",
+        shares.to_string_native()
+    );
+    if shares.is_zero() {
+        return Err(LiquidStakingError::AmountTooSmall(
+            shares.to_string_native(),
+        )
+        .into());
+    }
+    let params = read_pos_params(storage)?;
+    let share_token = check_share_token_validator(
+        storage,
+        validator,
+        current_epoch,
+        &params,
+    )?;
+    if owner != validator && is_validator(storage, owner)? {
+        return Err(BondError::SourceMustNotBeAValidator(owner.clone()).into());
+    }
+    let balance = token::read_balance(storage, &share_token, owner)?;
+    if shares > balance {
+        return Err(LiquidStakingError::InsufficientShares(
+            shares.to_string_native(),
+            balance.to_string_native(),
+        )
+        .into());
+    }
+
+    // Compound the pool's rewards before splitting it
+    compound_pool_rewards(storage, &share_token, validator, current_epoch)?;
+    let supply = token::read_total_supply(storage, &share_token)?;
+    let pool_bonds = bond_handle(&share_token, validator).get_data_handler();
+    let mut moved = BTreeMap::new();
+    for (epoch, bond) in pool_bonds.collect_map(storage)? {
+        let amount = mul_div(bond, shares, supply)?;
+        if !amount.is_zero() {
+            moved.insert(epoch, amount);
+        }
+    }
+    if moved.is_empty() {
+        return Err(LiquidStakingError::AmountTooSmall(
+            shares.to_string_native(),
+        )
+        .into());
+    }
+
+    // Settle the owner's rewards of their current bonds, such that the moved
+    // bond records only earn rewards for the owner from now on
+    let rewards = compute_current_rewards_from_bonds(
+        storage,
+        owner,
+        validator,
+        current_epoch,
+    )?;
+    add_rewards_to_counter(storage, owner, validator, rewards)?;
+    write_last_reward_claim_epoch(storage, owner, validator, current_epoch)?;
+
+    // Move the bond records out of the pool
+    let owner_bonds = bond_handle(owner, validator).get_data_handler();
+    for (&epoch, &amount) in &moved {
+        let bond = pool_bonds.get(storage, &epoch)?.unwrap_or_default();
+        if bond == amount {
+            pool_bonds.remove(storage, &epoch)?;
+        } else {
+            pool_bonds.insert(storage, epoch, bond - amount)?;
+        }
+        owner_bonds.update(storage, epoch, |current| {
+            current.unwrap_or_default() + amount
+        })?;
+    }
+
+    // Burn the shares
+    token::burn_tokens(storage, &share_token, owner, shares)?;
+
+    let slashes = find_validator_slashes(storage, validator)?;
+    let result_slashing = compute_amount_after_slashing_unbond(
+        storage,
+        &params,
+        &moved,
+        &BTreeMap::new(),
+        slashes,
+    )?;
+    Ok(result_slashing.sum)
+}
+
+/// Check that the share token of the given validator can be minted or
+/// redeemed and return its address.
+fn check_share_token_validator<S>(
+    storage: &S,
+    validator: &Address,
+    current_epoch: Epoch,
+    params: &PosParams,
+) -> namada_storage::Result<Address>
+where
+    S: StorageRead,
+{
+    let share_token = match share_token(validator) {
+        Some(share_token) if is_validator(storage, validator)? => share_token,
+        _ => {
+            return Err(
+                LiquidStakingError::NotAValidator(validator.clone()).into()
+            );
+        }
+    };
+    // While a validator is frozen, the value of the shares doesn't reflect
+    // the slashes that are yet to be processed
+    if is_validator_frozen(storage, validator, current_epoch, params)? {
+        return Err(
+            LiquidStakingError::ValidatorIsFrozen(validator.clone()).into()
+        );
+    }
+    Ok(share_token)
+}
+
+/// Claim the rewards of the pool bond and bond them back into the pool.
+fn compound_pool_rewards<S>(
+    storage: &mut S,
+    share_token: &Address,
+    validator: &Address,
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let rewards = claim_reward_tokens_to(
+        storage,
+        share_token,
+        validator,
+        share_token,
+        current_epoch,
+    )?;
+    bond_tokens(
+        storage,
+        Some(share_token),
+        validator,
+        rewards,
+        current_epoch,
+        None,
+    )
+}
+
+/// Compute `amount * numerator / denominator`, rounded down.
+fn mul_div(
+    amount: token::Amount,
+    numerator: token::Amount,
+    denominator: token::Amount,
+) -> namada_storage::Result<token::Amount> {
+    amount
+        .checked_mul(numerator)
+        .and_then(|product| product.checked_div(denominator))
+        .ok_or_else(|| {
+            namada_storage::Error::new_const(
+                "Overflow or division by zero in share token conversion",
+            )
+        })
+}
@@ -8,16 +8,20 @@ use namada_core::address::Address;
 use namada_core::dec::Dec;
 use namada_core::storage::Epoch;
 use namada_core::token;
+use namada_governance::storage as gov_storage;
+use namada_governance::utils::ProposalVotes;
 use namada_storage::collections::lazy_map::{NestedSubKey, SubKey};
 use namada_storage::StorageRead;
 
 use crate::slashing::{find_validator_slashes, get_slashed_amount};
-use crate::storage::{bond_handle, read_pos_params, unbond_handle};
+use crate::storage::{
+    bond_handle, read_pos_params, read_validator_stake, unbond_handle,
+};
 use crate::types::{
     BondDetails, BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, Slash,
     UnbondDetails,
 };
-use crate::{storage_key, PosParams};
+use crate::{bond_amount, storage_key, PosParams};
 
 /// Find all validators to which a given bond `owner` (or source) has a
 /// delegation
@@ -460,3 +464,50 @@ fn make_unbond_details(
         slashed_amount,
     }
 }
+
+/// Collect the votes of a governance proposal, together with the voting power
+/// of the voters at the given epoch
+pub fn compute_proposal_votes<S>(
+    storage: &S,
+    params: &PosParams,
+    proposal_id: u64,
+    epoch: Epoch,
+) -> namada_storage::Result<ProposalVotes>
+where
+    S: StorageRead,
+{
+    let votes = gov_storage::get_proposal_votes(storage, proposal_id)?;
+
+    let mut proposal_votes = ProposalVotes::default();
+
+    for vote in votes {
+        if vote.is_validator() {
+            let validator_stake =
+                read_validator_stake(storage, params, &vote.validator, epoch)
+                    .unwrap_or_default();
+
+            proposal_votes.add_validator(
+                &vote.validator,
+                validator_stake,
+                vote.data.into(),
+            );
+        } else {
+            let bond_id = BondId {
+                source: vote.delegator.clone(),
+                validator: vote.validator.clone(),
+            };
+            let delegator_stake = bond_amount(storage, &bond_id, epoch);
+
+            if let Ok(stake) = delegator_stake {
+                proposal_votes.add_delegator(
+                    &vote.delegator,
+                    &vote.validator,
+                    stake,
+                    vote.data.into(),
+                );
+            }
+        }
+    }
+
+    Ok(proposal_votes)
+}
@@ -9,21 +9,24 @@ use namada_core::token::{self, Amount};
 use namada_core::uint::{Uint, I256};
 use namada_parameters::storage as params_storage;
 use namada_storage::collections::lazy_map::NestedSubKey;
+use namada_storage::collections::lazy_set;
 use namada_storage::{ResultExt, StorageRead, StorageWrite};
 use thiserror::Error;
 
 use crate::storage::{
-    consensus_validator_set_handle, get_last_reward_claim_epoch,
-    read_pos_params, read_total_stake, read_validator_stake,
-    rewards_accumulator_handle, validator_commission_rate_handle,
-    validator_rewards_products_handle, validator_state_handle,
+    auto_compound_bonds_handle, consensus_validator_set_handle,
+    get_last_reward_claim_epoch, read_pos_params, read_total_stake,
+    read_validator_stake, rewards_accumulator_handle,
+    validator_commission_rate_handle, validator_rewards_products_handle,
+    validator_state_handle,
 };
 use crate::token::storage_key::minted_balance_key;
 use crate::token::{credit_tokens, inflation};
 use crate::types::{into_tm_voting_power, BondId, ValidatorState, VoteInfo};
 use crate::{
-    bond_amounts_for_rewards, get_total_consensus_stake, staking_token_address,
-    storage, storage_key, InflationError, PosParams,
+    bond_amounts_for_rewards, bond_tokens, claim_reward_tokens_to,
+    get_total_consensus_stake, is_validator, staking_token_address, storage,
+    storage_key, InflationError, PosParams,
 };
 
 /// This is equal to 0.01.
@@ -363,6 +366,10 @@ where
         total_tokens,
     )?;
 
+    // Compound the rewards of the opted-in bonds, now that the rewards
+    // products of the last epoch are known
+    compound_rewards(storage, last_epoch.next())?;
+
     // Write new rewards parameters that will be used for the inflation of
     // the current new epoch
     storage
@@ -489,6 +496,65 @@ where
     Ok(())
 }
 
+/// Claim the rewards of the bonds with auto-compounding enabled and bond them
+/// back to the same validators at the pipeline offset. This is applied at the
+/// start of a new epoch, after the rewards products of the last epoch have been
+/// updated.
+pub fn compound_rewards<S>(
+    storage: &mut S,
+    current_epoch: Epoch,
+) -> namada_storage::Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let bonds = auto_compound_bonds_handle()
+        .iter(storage)?
+        .map(|entry| {
+            let (
+                NestedSubKey::Data {
+                    key: source,
+                    nested_sub_key: lazy_set::SubKey::Data(validator),
+                },
+                (),
+            ) = entry?;
+            Ok(BondId { source, validator })
+        })
+        .collect::<namada_storage::Result<Vec<_>>>()?;
+
+    for BondId { source, validator } in bonds {
+        // A source that has become a validator can no longer delegate
+        if source != validator && is_validator(storage, &source)? {
+            continue;
+        }
+        // The rewards are bonded from the source, whichever reward recipient
+        // it has set
+        let rewards = claim_reward_tokens_to(
+            storage,
+            &source,
+            &validator,
+            &source,
+            current_epoch,
+        )?;
+        if rewards.is_zero() {
+            continue;
+        }
+        tracing::debug!(
+            "Compounding rewards {} of {source} to {validator}",
+            rewards.to_string_native()
+        );
+        bond_tokens(
+            storage,
+            Some(&source),
+            &validator,
+            rewards,
+            current_epoch,
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Compute the current available rewards amount due only to existing bonds.
 /// This does not include pending rewards held in the rewards counter due to
 /// unbonds and redelegations.
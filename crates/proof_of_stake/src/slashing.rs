@@ -26,8 +26,8 @@ use crate::storage::{
     write_validator_last_slash_epoch,
 };
 use crate::types::{
-    EagerRedelegatedBondsMap, ResultSlashing, Slash, SlashType, SlashedAmount,
-    Slashes, TotalRedelegatedUnbonded, ValidatorState,
+    EagerRedelegatedBondsMap, JailReason, ResultSlashing, Slash, SlashType,
+    SlashedAmount, Slashes, TotalRedelegatedUnbonded, ValidatorState,
 };
 use crate::validator_set_update::update_validator_set;
 use crate::{
@@ -181,6 +181,7 @@ where
         validator,
         current_epoch,
         validator_set_update_epoch,
+        JailReason::Misbehavior(slash_type),
     )?;
 
     // No other actions are performed here until the epoch in which the slash is
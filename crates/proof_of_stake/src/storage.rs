@@ -17,17 +17,17 @@ use num_traits::CheckedAdd;
 
 use crate::storage_key::consensus_keys_key;
 use crate::types::{
-    BelowCapacityValidatorSets, BondId, Bonds, CommissionRates,
-    ConsensusValidatorSets, DelegatorRedelegatedBonded,
+    AutoCompoundBonds, BelowCapacityValidatorSets, BondId, Bonds,
+    CommissionRates, ConsensusValidatorSets, DelegatorRedelegatedBonded,
     DelegatorRedelegatedUnbonded, EpochedSlashes, IncomingRedelegations,
-    LivenessMissedVotes, LivenessSumMissedVotes, OutgoingRedelegations,
-    ReverseOrdTokenAmount, RewardsAccumulator, RewardsProducts, Slashes,
-    TotalConsensusStakes, TotalDeltas, TotalRedelegatedBonded,
-    TotalRedelegatedUnbonded, Unbonds, ValidatorAddresses,
-    ValidatorConsensusKeys, ValidatorDeltas, ValidatorEthColdKeys,
-    ValidatorEthHotKeys, ValidatorMetaData, ValidatorProtocolKeys,
-    ValidatorSetPositions, ValidatorState, ValidatorStates,
-    ValidatorTotalUnbonded, WeightedValidator,
+    LivenessLastSignedHeights, LivenessMissedVotes, LivenessSumMissedVotes,
+    OutgoingRedelegations, ReverseOrdTokenAmount, RewardRecipients,
+    RewardsAccumulator, RewardsProducts, Slashes, TotalConsensusStakes,
+    TotalDeltas, TotalRedelegatedBonded, TotalRedelegatedUnbonded, Unbonds,
+    ValidatorAddresses, ValidatorConsensusKeys, ValidatorDeltas,
+    ValidatorEthColdKeys, ValidatorEthHotKeys, ValidatorJailEvents,
+    ValidatorMetaData, ValidatorProtocolKeys, ValidatorSetPositions,
+    ValidatorState, ValidatorStates, ValidatorTotalUnbonded, WeightedValidator,
 };
 use crate::{storage_key, MetadataError, OwnedPosParams, PosParams};
 
@@ -162,6 +162,14 @@ pub fn validator_slashes_handle(validator: &Address) -> Slashes {
     Slashes::open(key)
 }
 
+/// Get the storage handle to the history of a PoS validator's jail events
+pub fn validator_jail_events_handle(
+    validator: &Address,
+) -> ValidatorJailEvents {
+    let key = storage_key::validator_jail_events_key(validator);
+    ValidatorJailEvents::open(key)
+}
+
 /// Get the storage handle to list of all slashes to be processed and ultimately
 /// placed in the `validator_slashes_handle`
 pub fn enqueued_slashes_handle() -> EpochedSlashes {
@@ -232,6 +240,32 @@ pub fn delegator_redelegated_unbonds_handle(
     DelegatorRedelegatedUnbonded::open(key)
 }
 
+/// Get the storage handle to the bonds with auto-compounding rewards
+pub fn auto_compound_bonds_handle() -> AutoCompoundBonds {
+    let key = storage_key::auto_compound_bonds_key();
+    AutoCompoundBonds::open(key)
+}
+
+/// Get the storage handle to the reward recipients of the bond sources
+pub fn reward_recipients_handle() -> RewardRecipients {
+    let key = storage_key::reward_recipients_key();
+    RewardRecipients::open(key)
+}
+
+/// Read the address credited with the claimed rewards of the bonds of the
+/// given source, which is the source itself unless another recipient is set.
+pub fn read_reward_recipient<S>(
+    storage: &S,
+    source: &Address,
+) -> namada_storage::Result<Address>
+where
+    S: StorageRead,
+{
+    Ok(reward_recipients_handle()
+        .get(storage, source)?
+        .unwrap_or_else(|| source.clone()))
+}
+
 /// Get the storage handle to the missed votes for liveness tracking
 pub fn liveness_missed_votes_handle() -> LivenessMissedVotes {
     let key = storage_key::liveness_missed_votes_key();
@@ -244,6 +278,13 @@ pub fn liveness_sum_missed_votes_handle() -> LivenessSumMissedVotes {
     LivenessSumMissedVotes::open(key)
 }
 
+/// Get the storage handle to the heights of the last blocks signed by the
+/// validators
+pub fn liveness_last_signed_heights_handle() -> LivenessLastSignedHeights {
+    let key = storage_key::liveness_last_signed_heights_key();
+    LivenessLastSignedHeights::open(key)
+}
+
 // ---- Storage read + write ----
 
 /// Read PoS parameters
@@ -43,6 +43,8 @@ const CONSENSUS_VALIDATOR_SET_ACCUMULATOR_STORAGE_KEY: &str =
     "validator_rewards_accumulator";
 const LAST_REWARD_CLAIM_EPOCH: &str = "last_reward_claim_epoch";
 const REWARDS_COUNTER_KEY: &str = "validator_rewards_commissions";
+const AUTO_COMPOUND_BONDS_KEY: &str = "auto_compound_bonds";
+const REWARD_RECIPIENTS_KEY: &str = "reward_recipients";
 const VALIDATOR_INCOMING_REDELEGATIONS_KEY: &str = "incoming_redelegations";
 const VALIDATOR_OUTGOING_REDELEGATIONS_KEY: &str = "outgoing_redelegations";
 const VALIDATOR_TOTAL_REDELEGATED_BONDED_KEY: &str = "total_redelegated_bonded";
@@ -58,6 +60,8 @@ const VALIDATOR_AVATAR_KEY: &str = "avatar";
 const LIVENESS_PREFIX: &str = "liveness";
 const LIVENESS_MISSED_VOTES: &str = "missed_votes";
 const LIVENESS_MISSED_VOTES_SUM: &str = "sum_missed_votes";
+const LIVENESS_LAST_SIGNED_HEIGHTS: &str = "last_signed_heights";
+const VALIDATOR_JAIL_EVENTS_KEY: &str = "jail_events";
 
 /// Is the given key a PoS storage key?
 pub fn is_pos_key(key: &Key) -> bool {
@@ -945,6 +949,43 @@ pub fn is_last_pos_reward_claim_epoch_key(key: &Key) -> Option<BondId> {
     }
 }
 
+/// Storage key for the bonds whose rewards are automatically compounded.
+pub fn auto_compound_bonds_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&AUTO_COMPOUND_BONDS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Is the storage key for the auto-compounding setting of a bond? Return the
+/// bond ID if so.
+pub fn is_auto_compound_bonds_key(key: &Key) -> Option<BondId> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(data),
+            DbKeySeg::AddressSeg(source),
+            DbKeySeg::AddressSeg(validator),
+        ] if addr == &ADDRESS
+            && prefix == AUTO_COMPOUND_BONDS_KEY
+            && data == lazy_map::DATA_SUBKEY =>
+        {
+            Some(BondId {
+                source: source.clone(),
+                validator: validator.clone(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Storage key for the reward recipients of the bond sources.
+pub fn reward_recipients_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&REWARD_RECIPIENTS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get validator address from bond key
 pub fn get_validator_address_from_bond(key: &Key) -> Option<Address> {
     match key.get_at(3) {
@@ -1044,3 +1085,17 @@ pub fn liveness_sum_missed_votes_key() -> Key {
         .push(&LIVENESS_MISSED_VOTES_SUM.to_owned())
         .expect("Cannot obtain a storage key")
 }
+
+/// Storage key for the heights of the last blocks signed by the validators.
+pub fn liveness_last_signed_heights_key() -> Key {
+    liveness_data_prefix()
+        .push(&LIVENESS_LAST_SIGNED_HEIGHTS.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the history of a validator's jail events.
+pub fn validator_jail_events_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_JAIL_EVENTS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
@@ -2,6 +2,7 @@ mod helpers;
 mod state_machine;
 mod state_machine_v2;
 mod test_helper_fns;
+mod test_liquid_staking;
 mod test_pos;
 mod test_slash_and_redel;
 mod test_validator;
@@ -0,0 +1,201 @@
+use std::ops::Deref;
+
+use assert_matches::assert_matches;
+use namada_core::address;
+use namada_state::testing::TestState;
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+// Use `RUST_LOG=info` (or another tracing level) and `--nocapture` to see
+// `tracing` logs from tests
+use test_log::test;
+
+use crate::liquid_staking::{
+    redeem_share_tokens, share_token, share_token_pool_value, tokenize_bond,
+};
+use crate::storage::read_validator_stake;
+use crate::test_utils::test_init_genesis;
+use crate::tests::helpers::{advance_epoch, arb_genesis_validators};
+use crate::token::{credit_tokens, read_balance, read_total_supply, transfer};
+use crate::types::{BondId, GenesisValidator};
+use crate::{
+    bond_amount, bond_tokens, staking_token_address, token, LiquidStakingError,
+    OwnedPosParams,
+};
+
+proptest! {
+    // Generate arb valid input for `test_tokenize_and_redeem_aux`
+    #![proptest_config(Config {
+        cases: 10,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_tokenize_and_redeem(
+
+    genesis_validators in arb_genesis_validators(1..4, None),
+
+    ) {
+        test_tokenize_and_redeem_aux(genesis_validators)
+    }
+}
+
+/// Test that tokenizing a bond keeps the validator's stake unchanged and that
+/// the share tokens can be redeemed into a bond by another holder.
+fn test_tokenize_and_redeem_aux(validators: Vec<GenesisValidator>) {
+    let validator = validators[0].address.clone();
+
+    let mut storage = TestState::default();
+    let params = OwnedPosParams::default();
+
+    // Genesis
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        validators.clone().into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    // Get a delegator with a bond to the validator
+    let staking_token = staking_token_address(&storage);
+    let delegator = address::testing::gen_implicit_address();
+    let holder = address::testing::gen_implicit_address();
+    let bond_amount_delegated = token::Amount::native_whole(1_000);
+    credit_tokens(
+        &mut storage,
+        &staking_token,
+        &delegator,
+        bond_amount_delegated,
+    )
+    .unwrap();
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bond_amount_delegated,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let stake_before =
+        read_validator_stake(&storage, &params, &validator, pipeline_epoch)
+            .unwrap();
+
+    // The self-bond and more than the bond cannot be tokenized
+    let err = tokenize_bond(
+        &mut storage,
+        &validator,
+        &validator,
+        token::Amount::native_whole(1),
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<LiquidStakingError>().unwrap().deref(),
+        LiquidStakingError::SelfBond(_)
+    );
+    let err = tokenize_bond(
+        &mut storage,
+        &delegator,
+        &validator,
+        bond_amount_delegated + token::Amount::native_whole(1),
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<LiquidStakingError>().unwrap().deref(),
+        LiquidStakingError::TokenizeAmountGreaterThanBond(_, _)
+    );
+
+    // Tokenize half of the bond
+    let tokenized = token::Amount::native_whole(500);
+    let share_token = share_token(&validator).unwrap();
+    let shares = tokenize_bond(
+        &mut storage,
+        &delegator,
+        &validator,
+        tokenized,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(shares, tokenized);
+    assert_eq!(
+        read_balance(&storage, &share_token, &delegator).unwrap(),
+        shares
+    );
+    assert_eq!(read_total_supply(&storage, &share_token).unwrap(), shares);
+    assert_eq!(
+        share_token_pool_value(&storage, &validator, pipeline_epoch).unwrap(),
+        tokenized
+    );
+    let delegation = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    assert_eq!(
+        bond_amount(&storage, &delegation, pipeline_epoch).unwrap(),
+        bond_amount_delegated - tokenized
+    );
+    assert_eq!(
+        read_validator_stake(&storage, &params, &validator, pipeline_epoch)
+            .unwrap(),
+        stake_before
+    );
+
+    // Transfer half of the shares and redeem them by the holder
+    let transferred = token::Amount::native_whole(250);
+    transfer(&mut storage, &share_token, &delegator, &holder, transferred)
+        .unwrap();
+    let err = redeem_share_tokens(
+        &mut storage,
+        &holder,
+        &validator,
+        transferred + token::Amount::native_whole(1),
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<LiquidStakingError>().unwrap().deref(),
+        LiquidStakingError::InsufficientShares(_, _)
+    );
+    let redeemed = redeem_share_tokens(
+        &mut storage,
+        &holder,
+        &validator,
+        transferred,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(redeemed, transferred);
+    assert!(
+        read_balance(&storage, &share_token, &holder)
+            .unwrap()
+            .is_zero()
+    );
+    assert_eq!(
+        read_total_supply(&storage, &share_token).unwrap(),
+        shares - transferred
+    );
+    let holder_bond = BondId {
+        source: holder,
+        validator: validator.clone(),
+    };
+    assert_eq!(
+        bond_amount(&storage, &holder_bond, pipeline_epoch).unwrap(),
+        transferred
+    );
+    assert_eq!(
+        share_token_pool_value(&storage, &validator, pipeline_epoch).unwrap(),
+        tokenized - transferred
+    );
+    assert_eq!(
+        read_validator_stake(&storage, &params, &validator, pipeline_epoch)
+            .unwrap(),
+        stake_before
+    );
+}
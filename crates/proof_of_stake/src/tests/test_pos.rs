@@ -1,6 +1,7 @@
 //! PoS system tests
 
 use std::collections::{BTreeMap, HashSet};
+use std::ops::Deref;
 
 use assert_matches::assert_matches;
 use namada_core::address::Address;
@@ -10,7 +11,7 @@ use namada_core::key::RefTo;
 use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::{address, key};
 use namada_state::testing::TestState;
-use namada_storage::collections::lazy_map::Collectable;
+use namada_storage::collections::lazy_map::{self, Collectable};
 use namada_storage::StorageRead;
 use proptest::prelude::*;
 use proptest::test_runner::Config;
@@ -23,16 +24,18 @@ use crate::parameters::testing::arb_pos_params;
 use crate::parameters::OwnedPosParams;
 use crate::queries::bonds_and_unbonds;
 use crate::rewards::{
-    log_block_rewards_aux, update_rewards_products_and_mint_inflation,
-    PosRewardsCalculator,
+    compound_rewards, log_block_rewards_aux,
+    update_rewards_products_and_mint_inflation, PosRewardsCalculator,
 };
 use crate::slashing::{process_slashes, slash};
 use crate::storage::{
-    get_consensus_key_set, liveness_sum_missed_votes_handle,
+    get_consensus_key_set, liveness_last_signed_heights_handle,
+    liveness_sum_missed_votes_handle,
     read_below_threshold_validator_set_addresses,
-    read_consensus_validator_set_addresses_with_stake, read_total_stake,
-    read_validator_deltas_value, rewards_accumulator_handle,
-    total_deltas_handle,
+    read_consensus_validator_set_addresses,
+    read_consensus_validator_set_addresses_with_stake, read_reward_recipient,
+    read_total_stake, read_validator_deltas_value, rewards_accumulator_handle,
+    total_deltas_handle, total_unbonded_handle, validator_jail_events_handle,
 };
 use crate::test_utils::test_init_genesis;
 use crate::tests::helpers::{
@@ -41,16 +44,20 @@ use crate::tests::helpers::{
 use crate::token::{credit_tokens, read_balance};
 use crate::types::{
     into_tm_voting_power, BondDetails, BondId, BondsAndUnbondsDetails,
-    GenesisValidator, SlashType, UnbondDetails, ValidatorState, VoteInfo,
-    WeightedValidator,
+    GenesisValidator, JailEvent, JailReason, SlashType, UnbondDetails,
+    ValidatorState, VoteInfo, WeightedValidator,
 };
 use crate::{
-    below_capacity_validator_set_handle, bond_handle, bond_tokens,
-    change_consensus_key, consensus_validator_set_handle, is_delegator,
-    is_validator, jail_for_liveness, read_validator_stake, redelegate_tokens,
+    below_capacity_validator_set_handle, bond_amount, bond_handle, bond_tokens,
+    cancel_unbond, change_auto_compound_rewards, change_consensus_key,
+    change_reward_recipient, claim_reward_tokens,
+    consensus_validator_set_handle, is_auto_compound_rewards, is_delegator,
+    is_validator, jail_for_liveness, jail_validator, query_reward_tokens,
+    read_validator_stake, record_liveness_data, redelegate_tokens,
     staking_token_address, token, unbond_handle, unbond_tokens,
     unjail_validator, validator_consensus_key_handle,
     validator_set_positions_handle, validator_state_handle, withdraw_tokens,
+    BondError, CancelUnbondError, UnbondError,
 };
 
 proptest! {
@@ -149,6 +156,66 @@ proptest! {
     }
 }
 
+proptest! {
+    // Generate arb valid input for `test_auto_compound_rewards_aux`
+    #![proptest_config(Config {
+        cases: 1,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_auto_compound_rewards(
+        genesis_validators in arb_genesis_validators(1..4, None),
+
+    ) {
+        test_auto_compound_rewards_aux(genesis_validators)
+    }
+}
+
+proptest! {
+    // Generate arb valid input for `test_reward_recipient_aux`
+    #![proptest_config(Config {
+        cases: 10,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_reward_recipient(
+        genesis_validators in arb_genesis_validators(1..4, None),
+
+    ) {
+        test_reward_recipient_aux(genesis_validators)
+    }
+}
+
+proptest! {
+    // Generate arb valid input for `test_cancel_unbond_aux`
+    #![proptest_config(Config {
+        cases: 10,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_cancel_unbond(
+        genesis_validators in arb_genesis_validators(1..4, None),
+
+    ) {
+        test_cancel_unbond_aux(genesis_validators)
+    }
+}
+
+proptest! {
+    // Generate arb valid input for `test_cancel_unbond_and_slash_aux`
+    #![proptest_config(Config {
+        cases: 10,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_cancel_unbond_and_slash(
+        genesis_validators in arb_genesis_validators(2..4, None),
+
+    ) {
+        test_cancel_unbond_and_slash_aux(genesis_validators)
+    }
+}
+
 proptest! {
     // Generate arb valid input for `test_consensus_key_change`
     #![proptest_config(Config {
@@ -903,6 +970,30 @@ fn test_unjail_validator_aux(
         );
     }
 
+    // Jailing the validator again doesn't record another jail event
+    jail_validator(
+        &mut s,
+        &params,
+        val_addr,
+        current_epoch,
+        current_epoch.next(),
+        JailReason::Liveness,
+    )
+    .unwrap();
+    let jail_events: Vec<_> = validator_jail_events_handle(val_addr)
+        .iter(&s)
+        .unwrap()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        jail_events,
+        vec![JailEvent {
+            epoch: current_epoch,
+            start_epoch: current_epoch.next(),
+            reason: JailReason::Misbehavior(slash_0_type),
+        }]
+    );
+
     // Advance past an epoch in which we can unbond
     let unfreeze_epoch =
         slash_0_evidence_epoch + params.slash_processing_epoch_offset();
@@ -1460,6 +1551,527 @@ fn test_update_rewards_products_aux(validators: Vec<GenesisValidator>) {
     assert!(rewards_handle.is_empty(&s).unwrap());
 }
 
+/// Test that the rewards of the bonds with auto-compounding enabled are bonded
+/// back to the validator.
+fn test_auto_compound_rewards_aux(validators: Vec<GenesisValidator>) {
+    let validator = validators[0].address.clone();
+    let mut s = TestState::default();
+    // Init genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Delegate to the validator and enable auto-compounding
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    let amount = token::Amount::native_whole(1_000);
+    credit_tokens(&mut s, &staking_token, &delegator, amount).unwrap();
+    let err = change_auto_compound_rewards(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        true,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<UnbondError>().unwrap().deref(),
+        UnbondError::NoBondFound
+    );
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    let err = change_auto_compound_rewards(&mut s, None, &delegator, true)
+        .unwrap_err();
+    assert_matches!(
+        err.downcast::<BondError>().unwrap().deref(),
+        BondError::NotAValidator(_)
+    );
+    change_auto_compound_rewards(&mut s, Some(&delegator), &validator, true)
+        .unwrap();
+    assert!(
+        is_auto_compound_rewards(&s, Some(&delegator), &validator).unwrap()
+    );
+
+    // Advance past the pipeline such that the bond earns rewards in the last
+    // epoch
+    for _ in 0..=params.pipeline_len {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    let num_blocks_in_last_epoch = 1000;
+    rewards_accumulator_handle()
+        .insert(
+            &mut s,
+            validator.clone(),
+            Dec::one() * num_blocks_in_last_epoch,
+        )
+        .unwrap();
+    let total_native_tokens: token::Amount = s
+        .read(&minted_balance_key(&staking_token))
+        .unwrap()
+        .expect("Total NAM balance should exist in storage");
+    update_rewards_products_and_mint_inflation(
+        &mut s,
+        &params,
+        current_epoch.prev(),
+        num_blocks_in_last_epoch,
+        token::Amount::native_whole(10_000_000),
+        &staking_token,
+        total_native_tokens,
+    )
+    .unwrap();
+    let rewards =
+        query_reward_tokens(&s, Some(&delegator), &validator, current_epoch)
+            .unwrap();
+    assert!(!rewards.is_zero());
+
+    // The rewards are bonded at the pipeline offset
+    compound_rewards(&mut s, current_epoch).unwrap();
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_eq!(
+        bond_amount(&s, &bond_id, pipeline_epoch).unwrap(),
+        amount + rewards
+    );
+    assert_eq!(bond_amount(&s, &bond_id, current_epoch).unwrap(), amount);
+    assert!(
+        query_reward_tokens(&s, Some(&delegator), &validator, current_epoch)
+            .unwrap()
+            .is_zero()
+    );
+    assert!(
+        read_balance(&s, &staking_token, &delegator)
+            .unwrap()
+            .is_zero()
+    );
+
+    // Without new rewards, nothing is compounded
+    compound_rewards(&mut s, current_epoch).unwrap();
+    assert_eq!(
+        bond_amount(&s, &bond_id, pipeline_epoch).unwrap(),
+        amount + rewards
+    );
+
+    // Disable auto-compounding
+    change_auto_compound_rewards(&mut s, Some(&delegator), &validator, false)
+        .unwrap();
+    assert!(
+        !is_auto_compound_rewards(&s, Some(&delegator), &validator).unwrap()
+    );
+
+    // Auto-compounding is disabled once the bond is fully unbonded
+    change_auto_compound_rewards(&mut s, Some(&delegator), &validator, true)
+        .unwrap();
+    unbond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        amount + rewards,
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    assert!(
+        !is_auto_compound_rewards(&s, Some(&delegator), &validator).unwrap()
+    );
+}
+
+/// Test that the claimed rewards are credited to the reward recipient of the
+/// source, except for the auto-compounded rewards.
+fn test_reward_recipient_aux(validators: Vec<GenesisValidator>) {
+    let validator = validators[0].address.clone();
+    let mut s = TestState::default();
+    // Init genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Delegate to the validator and set a reward recipient
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    let recipient = address::testing::gen_implicit_address();
+    let amount = token::Amount::native_whole(1_000);
+    credit_tokens(&mut s, &staking_token, &delegator, amount).unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    assert_eq!(read_reward_recipient(&s, &delegator).unwrap(), delegator);
+    change_reward_recipient(&mut s, &delegator, Some(&recipient)).unwrap();
+    assert_eq!(read_reward_recipient(&s, &delegator).unwrap(), recipient);
+
+    // Advance past the pipeline such that the bond earns rewards in the last
+    // epoch
+    for _ in 0..=params.pipeline_len {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    let earn_rewards = |s: &mut TestState, current_epoch: Epoch| {
+        let num_blocks_in_last_epoch = 1000;
+        rewards_accumulator_handle()
+            .insert(s, validator.clone(), Dec::one() * num_blocks_in_last_epoch)
+            .unwrap();
+        let total_native_tokens: token::Amount = s
+            .read(&minted_balance_key(&staking_token))
+            .unwrap()
+            .expect("Total NAM balance should exist in storage");
+        update_rewards_products_and_mint_inflation(
+            s,
+            &params,
+            current_epoch.prev(),
+            num_blocks_in_last_epoch,
+            token::Amount::native_whole(10_000_000),
+            &staking_token,
+            total_native_tokens,
+        )
+        .unwrap();
+        query_reward_tokens(s, Some(&delegator), &validator, current_epoch)
+            .unwrap()
+    };
+
+    // The claimed rewards are credited to the recipient
+    let rewards = earn_rewards(&mut s, current_epoch);
+    assert!(!rewards.is_zero());
+    let claimed = claim_reward_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(claimed, rewards);
+    assert_eq!(
+        read_balance(&s, &staking_token, &recipient).unwrap(),
+        rewards
+    );
+    assert!(
+        read_balance(&s, &staking_token, &delegator)
+            .unwrap()
+            .is_zero()
+    );
+
+    // The auto-compounded rewards are bonded from the source regardless of
+    // the recipient
+    change_auto_compound_rewards(&mut s, Some(&delegator), &validator, true)
+        .unwrap();
+    current_epoch = advance_epoch(&mut s, &params);
+    let compounded = earn_rewards(&mut s, current_epoch);
+    assert!(!compounded.is_zero());
+    compound_rewards(&mut s, current_epoch).unwrap();
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    assert_eq!(
+        bond_amount(&s, &bond_id, current_epoch + params.pipeline_len).unwrap(),
+        amount + compounded
+    );
+    assert_eq!(
+        read_balance(&s, &staking_token, &recipient).unwrap(),
+        rewards
+    );
+
+    // Resetting the recipient credits the source again
+    change_reward_recipient(&mut s, &delegator, None).unwrap();
+    assert_eq!(read_reward_recipient(&s, &delegator).unwrap(), delegator);
+}
+
+/// Test that cancelling an unbond that is not yet withdrawable bonds its
+/// tokens back to the validator.
+fn test_cancel_unbond_aux(validators: Vec<GenesisValidator>) {
+    let validator = validators[0].address.clone();
+    let mut s = TestState::default();
+    // Init genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Delegate to the validator and unbond part of the delegation
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    let amount = token::Amount::native_whole(1_000);
+    credit_tokens(&mut s, &staking_token, &delegator, amount).unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    let bond_start_epoch = current_epoch + params.pipeline_len;
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    let unbonded = token::Amount::native_whole(600);
+    unbond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        unbonded,
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    let withdraw_epoch = current_epoch + params.withdrawable_epoch_offset();
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let stake_after_unbond =
+        read_validator_stake(&s, &params, &validator, pipeline_epoch).unwrap();
+    let unbonded_at = |s: &TestState, epoch: Epoch| {
+        unbond_handle(&delegator, &validator)
+            .iter(s)
+            .unwrap()
+            .map(Result::unwrap)
+            .filter_map(|(key, amount)| match key {
+                lazy_map::NestedSubKey::Data {
+                    nested_sub_key: lazy_map::SubKey::Data(withdraw),
+                    ..
+                } if withdraw == epoch => Some(amount),
+                _ => None,
+            })
+            .sum::<token::Amount>()
+    };
+    assert_eq!(unbonded_at(&s, withdraw_epoch), unbonded);
+
+    // Neither more than the unbond nor an unknown unbond can be cancelled
+    let err = cancel_unbond(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        withdraw_epoch,
+        Some(unbonded + token::Amount::native_whole(1)),
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<CancelUnbondError>().unwrap().deref(),
+        CancelUnbondError::CancelAmountGreaterThanUnbond(_, _)
+    );
+    let err = cancel_unbond(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        withdraw_epoch.next(),
+        None,
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<CancelUnbondError>().unwrap().deref(),
+        CancelUnbondError::NoUnbondFound(_, _)
+    );
+
+    // Cancel part of the unbond
+    let cancelled = token::Amount::native_whole(200);
+    let bonded = cancel_unbond(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        withdraw_epoch,
+        Some(cancelled),
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(bonded, cancelled);
+    assert_eq!(unbonded_at(&s, withdraw_epoch), unbonded - cancelled);
+    assert_eq!(
+        bond_amount(&s, &bond_id, pipeline_epoch).unwrap(),
+        amount - unbonded + cancelled
+    );
+    assert_eq!(
+        read_validator_stake(&s, &params, &validator, pipeline_epoch).unwrap(),
+        stake_after_unbond + cancelled
+    );
+    // The bond record is restored with its start epoch
+    let bond_records = bond_handle(&delegator, &validator).get_data_handler();
+    assert_eq!(
+        bond_records.get(&s, &bond_start_epoch).unwrap(),
+        Some(amount - unbonded + cancelled)
+    );
+    assert_eq!(bond_records.get(&s, &pipeline_epoch).unwrap(), None);
+
+    // Once withdrawable, the rest of the unbond can no longer be cancelled
+    while current_epoch < withdraw_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    let err = cancel_unbond(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        withdraw_epoch,
+        None,
+        current_epoch,
+    )
+    .unwrap_err();
+    assert_matches!(
+        err.downcast::<CancelUnbondError>().unwrap().deref(),
+        CancelUnbondError::AlreadyWithdrawable(_)
+    );
+    let withdrawn =
+        withdraw_tokens(&mut s, Some(&delegator), &validator, current_epoch)
+            .unwrap();
+    assert_eq!(withdrawn, unbonded - cancelled);
+    assert_eq!(
+        read_balance(&s, &staking_token, &delegator).unwrap(),
+        unbonded - cancelled
+    );
+}
+
+fn test_cancel_unbond_and_slash_aux(mut validators: Vec<GenesisValidator>) {
+    // Find the validator with the most stake and 100x their stake to keep the
+    // cubic slash rate small
+    let num_vals = validators.len();
+    validators.sort_by_key(|a| a.tokens);
+    validators[num_vals - 1].tokens = 100 * validators[num_vals - 1].tokens;
+    // The validator with the least stake misbehaves
+    let validator = validators[0].address.clone();
+
+    let mut s = TestState::default();
+    // Init genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = OwnedPosParams::default();
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+
+    // Delegate to the validator
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    let amount = token::Amount::native_whole(1_000);
+    credit_tokens(&mut s, &staking_token, &delegator, amount).unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        amount,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+
+    // The validator misbehaves while the delegation contributes to its stake
+    let infraction_epoch = current_epoch;
+    let processing_epoch =
+        infraction_epoch + params.slash_processing_epoch_offset();
+
+    // Unbond a part of the delegation such that the unbond would take effect
+    // right after the slash is processed and cancel it right away
+    while current_epoch.next() < processing_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    unbond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        token::Amount::native_whole(600),
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    let withdraw_epoch = current_epoch + params.withdrawable_epoch_offset();
+    let unbond_end_epoch = current_epoch + params.pipeline_len;
+    cancel_unbond(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        withdraw_epoch,
+        None,
+        current_epoch,
+    )
+    .unwrap();
+    // The cancelled tokens are no longer counted as unbonded
+    assert!(
+        total_unbonded_handle(&validator)
+            .at(&unbond_end_epoch)
+            .is_empty(&s)
+            .unwrap()
+    );
+    let stake_before_slash =
+        read_validator_stake(&s, &params, &validator, unbond_end_epoch)
+            .unwrap();
+
+    // Discover and process the slash
+    slash(
+        &mut s,
+        &params,
+        current_epoch,
+        infraction_epoch,
+        BlockHeight(0),
+        SlashType::DuplicateVote,
+        &validator,
+        current_epoch.next(),
+    )
+    .unwrap();
+    while current_epoch < processing_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    process_slashes(&mut s, current_epoch).unwrap();
+
+    // The stake is slashed by the same amount in the epoch before and in the
+    // epoch in which the cancelled unbond would have taken effect
+    let stake_at_processing =
+        read_validator_stake(&s, &params, &validator, processing_epoch)
+            .unwrap();
+    let stake_at_unbond_end =
+        read_validator_stake(&s, &params, &validator, unbond_end_epoch)
+            .unwrap();
+    assert!(stake_at_unbond_end < stake_before_slash);
+    assert_eq!(stake_at_processing, stake_at_unbond_end);
+    // The unbond and its cancellation cancel out in the validator's deltas
+    assert_eq!(
+        read_validator_deltas_value(&s, &validator, &unbond_end_epoch)
+            .unwrap()
+            .unwrap_or_default(),
+        token::Change::zero()
+    );
+}
+
 fn test_consensus_key_change_aux(validators: Vec<GenesisValidator>) {
     assert_eq!(validators.len(), 1);
 
@@ -1723,6 +2335,28 @@ fn test_jail_for_liveness_aux(validators: Vec<GenesisValidator>) {
         .unwrap();
         s.commit_block().unwrap();
 
+        // Record a block signed by all the validators
+        let votes_height = BlockHeight(1);
+        let votes: Vec<_> = validators
+            .iter()
+            .map(|validator| VoteInfo {
+                validator_address: validator.address.clone(),
+                validator_vp: 1,
+            })
+            .collect();
+        record_liveness_data(s, &votes, current_epoch, votes_height, &params)
+            .unwrap();
+        let consensus_validators =
+            read_consensus_validator_set_addresses(s, current_epoch).unwrap();
+        for validator in &consensus_validators {
+            assert_eq!(
+                liveness_last_signed_heights_handle()
+                    .get(s, validator)
+                    .unwrap(),
+                Some(votes_height.0)
+            );
+        }
+
         // Add missed votes to about half of the validators
         let half_len = validators.len() / 2;
         let validators_who_missed_votes: Vec<_> =
@@ -1742,6 +2376,24 @@ fn test_jail_for_liveness_aux(validators: Vec<GenesisValidator>) {
                 .unwrap()
                 .expect("Validator should have a state for the jail epoch");
             assert_eq!(state_jail_epoch, ValidatorState::Jailed);
+
+            let jail_events: Vec<_> = validator_jail_events_handle(address)
+                .iter(s)
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+            assert_eq!(
+                jail_events,
+                vec![JailEvent {
+                    epoch: current_epoch,
+                    start_epoch: jail_epoch,
+                    reason: JailReason::Liveness,
+                }]
+            );
+        }
+        for GenesisValidator { address, .. } in validators.iter().skip(half_len)
+        {
+            assert!(validator_jail_events_handle(address).is_empty(s).unwrap());
         }
     }
 
@@ -262,6 +262,10 @@ pub type LivenessMissedVotes = NestedMap<Address, LazySet<u64>>;
 /// elements in the corresponding inner LazySet of [`LivenessMissedVotes`].
 pub type LivenessSumMissedVotes = LazyMap<Address, u64>;
 
+/// The height of the last block whose commit included a vote of the
+/// validator, kept for every validator that has been in the consensus set.
+pub type LivenessLastSignedHeights = LazyMap<Address, u64>;
+
 #[derive(
     Debug,
     Clone,
@@ -296,6 +300,14 @@ pub type RewardsProducts = LazyMap<Epoch, Dec>;
 /// rewards owed over the course of an epoch)
 pub type RewardsAccumulator = LazyMap<Address, Dec>;
 
+/// The bonds whose rewards are automatically bonded back to the validator at
+/// every new epoch, keyed by the source and then by the validator of the bond
+pub type AutoCompoundBonds = NestedMap<Address, LazySet<Address>>;
+
+/// The addresses credited with the claimed rewards of the bonds of a source,
+/// keyed by the source. A source without an entry is credited itself.
+pub type RewardRecipients = LazyMap<Address, Address>;
+
 /// Eager data for a generic redelegation
 #[derive(Debug)]
 pub struct Redelegation {
@@ -577,6 +589,48 @@ pub struct Slash {
 /// their staked tokens at and before the epoch of the slash.
 pub type Slashes = LazyVec<Slash>;
 
+/// The reason for which a validator got jailed.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub enum JailReason {
+    /// Missed too many votes within the liveness window.
+    Liveness,
+    /// Committed a slashable misbehavior.
+    Misbehavior(SlashType),
+}
+
+/// A record of a validator getting jailed.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct JailEvent {
+    /// Epoch in which the validator got jailed.
+    pub epoch: Epoch,
+    /// First epoch in which the validator is removed from the validator sets.
+    pub start_epoch: Epoch,
+    /// Reason for which the validator got jailed.
+    pub reason: JailReason,
+}
+
+/// History of the jail events of a validator, oldest first.
+pub type ValidatorJailEvents = LazyVec<JailEvent>;
+
 /// A type of slashable event.
 #[derive(
     Debug,
@@ -717,6 +771,17 @@ impl Display for SlashType {
     }
 }
 
+impl Display for JailReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JailReason::Liveness => write!(f, "Liveness"),
+            JailReason::Misbehavior(slash_type) => {
+                write!(f, "Misbehavior ({slash_type})")
+            }
+        }
+    }
+}
+
 /// Calculate voting power in the tendermint context (which is stored as i64)
 /// from the number of tokens
 pub fn into_tm_voting_power(votes_per_token: Dec, tokens: Amount) -> i64 {
@@ -1,9 +1,11 @@
 //! Structures encapsulating SDK arguments
 
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::time::Duration as StdDuration;
 
+use namada_account::VestingSchedule;
 use namada_core::address::Address;
 use namada_core::chain::ChainId;
 use namada_core::dec::Dec;
@@ -658,6 +660,8 @@ pub struct TxInitAccount<C: NamadaTypes = SdkTypes> {
     pub public_keys: Vec<C::PublicKey>,
     /// The account multisignature threshold
     pub threshold: Option<u8>,
+    /// The vesting schedule locking the native tokens of the new account
+    pub vesting: Option<VestingSchedule>,
 }
 
 impl<C: NamadaTypes> TxBuilder<C> for TxInitAccount<C> {
@@ -689,6 +693,14 @@ impl<C: NamadaTypes> TxInitAccount<C> {
         }
     }
 
+    /// A vesting schedule to lock the native tokens of the new account
+    pub fn vesting(self, vesting: VestingSchedule) -> Self {
+        Self {
+            vesting: Some(vesting),
+            ..self
+        }
+    }
+
     /// Path to the VP WASM code file
     pub fn vp_code_path(self, vp_code_path: PathBuf) -> Self {
         Self {
@@ -874,6 +886,100 @@ impl TxUpdateAccount {
     }
 }
 
+/// Fee allowance grant arguments
+#[derive(Clone, Debug)]
+pub struct GrantFeeAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The account paying the fees
+    pub granter: C::Address,
+    /// The account whose fees are paid
+    pub grantee: C::Address,
+    /// The token in which the fees can be paid
+    pub token: C::AddrOrNativeToken,
+    /// The maximum amount of fees that can be paid, or `None` to revoke the
+    /// current allowance
+    pub spend_limit: Option<InputAmount>,
+    /// The optional epoch from which the allowance can no longer be used
+    pub expiration: Option<Epoch>,
+    /// The code of the transactions whose fees can be paid, or all of them
+    /// if empty
+    pub allowed_txs: Vec<PathBuf>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for GrantFeeAllowance<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        GrantFeeAllowance {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> GrantFeeAllowance<C> {
+    /// The account paying the fees
+    pub fn granter(self, granter: C::Address) -> Self {
+        Self { granter, ..self }
+    }
+
+    /// The account whose fees are paid
+    pub fn grantee(self, grantee: C::Address) -> Self {
+        Self { grantee, ..self }
+    }
+
+    /// The token in which the fees can be paid
+    pub fn token(self, token: C::AddrOrNativeToken) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The maximum amount of fees that can be paid
+    pub fn spend_limit(self, spend_limit: InputAmount) -> Self {
+        Self {
+            spend_limit: Some(spend_limit),
+            ..self
+        }
+    }
+
+    /// The epoch from which the allowance can no longer be used
+    pub fn expiration(self, expiration: Epoch) -> Self {
+        Self {
+            expiration: Some(expiration),
+            ..self
+        }
+    }
+
+    /// The code of the transactions whose fees can be paid
+    pub fn allowed_txs(self, allowed_txs: Vec<PathBuf>) -> Self {
+        Self {
+            allowed_txs,
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl GrantFeeAllowance {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_grant_fee_allowance(context, self).await
+    }
+}
+
 /// Bond arguments
 #[derive(Clone, Debug)]
 pub struct Bond<C: NamadaTypes = SdkTypes> {
@@ -1082,6 +1188,130 @@ impl<C: NamadaTypes> TxBuilder<C> for Redelegate<C> {
     }
 }
 
+/// Bond tokenization arguments
+#[derive(Clone, Debug)]
+pub struct TokenizeBond<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Owner of the bond that is being tokenized
+    pub source: C::Address,
+    /// The amount of bonded tokens to tokenize
+    pub amount: token::Amount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl TokenizeBond {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_tokenize_bond(context, self).await
+    }
+}
+
+impl<C: NamadaTypes> TokenizeBond<C> {
+    /// Validator address
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Owner of the bond that is being tokenized
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// The amount of bonded tokens to tokenize
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TokenizeBond<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TokenizeBond {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+/// Share tokens redemption arguments
+#[derive(Clone, Debug)]
+pub struct RedeemShares<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Owner of the share tokens that are being redeemed
+    pub owner: C::Address,
+    /// The amount of share tokens to redeem
+    pub amount: token::Amount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl RedeemShares {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_redeem_shares(context, self).await
+    }
+}
+
+impl<C: NamadaTypes> RedeemShares<C> {
+    /// Validator address
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Owner of the share tokens that are being redeemed
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The amount of share tokens to redeem
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for RedeemShares<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        RedeemShares {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
 /// Reveal public key
 #[derive(Clone, Debug)]
 pub struct RevealPk<C: NamadaTypes = SdkTypes> {
@@ -1214,6 +1444,73 @@ impl Withdraw {
     }
 }
 
+/// Cancel unbond arguments
+#[derive(Clone, Debug)]
+pub struct CancelUnbond<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Source address of the unbond. For unbonds from self-bonds, the
+    /// validator is also the source
+    pub source: Option<C::Address>,
+    /// The epoch at which the unbond becomes withdrawable
+    pub withdraw_epoch: Epoch,
+    /// Amount of tokens to cancel, or the whole unbond when not set
+    pub amount: Option<token::Amount>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelUnbond<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelUnbond {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelUnbond<C> {
+    /// Source address of the unbond. For unbonds from self-bonds, the
+    /// validator is also the source
+    pub fn source(self, source: C::Address) -> Self {
+        Self {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    /// Amount of tokens to cancel
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self {
+            amount: Some(amount),
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl CancelUnbond {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_cancel_unbond(context, self).await
+    }
+}
+
 /// Claim arguments
 #[derive(Clone, Debug)]
 pub struct ClaimRewards<C: NamadaTypes = SdkTypes> {
@@ -1250,6 +1547,105 @@ impl ClaimRewards {
     }
 }
 
+/// Rewards auto-compounding change arguments
+#[derive(Clone, Debug)]
+pub struct ChangeAutoCompound<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Source address of the bond. For self-bonds, the validator is also the
+    /// source
+    pub source: Option<C::Address>,
+    /// Whether the rewards of the bond should be automatically compounded
+    pub enabled: bool,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for ChangeAutoCompound<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        ChangeAutoCompound {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> ChangeAutoCompound<C> {
+    /// Source address of the bond
+    pub fn source(self, source: C::Address) -> Self {
+        Self {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    /// Whether the rewards of the bond should be automatically compounded
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+}
+
+impl ChangeAutoCompound {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_change_auto_compound(context, self).await
+    }
+}
+
+/// Reward recipient change arguments
+#[derive(Clone, Debug)]
+pub struct ChangeRewardRecipient<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Source address of the bonds
+    pub source: C::Address,
+    /// The address credited with the claimed rewards. The source itself is
+    /// credited when not set
+    pub recipient: Option<C::Address>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for ChangeRewardRecipient<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        ChangeRewardRecipient {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> ChangeRewardRecipient<C> {
+    /// The address credited with the claimed rewards
+    pub fn recipient(self, recipient: C::Address) -> Self {
+        Self {
+            recipient: Some(recipient),
+            ..self
+        }
+    }
+}
+
+impl ChangeRewardRecipient {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_change_reward_recipient(context, self).await
+    }
+}
+
 /// Query asset conversions
 #[derive(Clone, Debug)]
 pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
@@ -1654,6 +2050,109 @@ impl ResignSteward {
     }
 }
 
+#[derive(Clone, Debug)]
+/// Pgf stream claim args
+pub struct ClaimPgfStream<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Target address of the stream
+    pub target: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for ClaimPgfStream<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        ClaimPgfStream {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> ClaimPgfStream<C> {
+    /// Target address of the stream
+    pub fn target(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl ClaimPgfStream {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_claim_pgf_stream(context, self).await
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Pgf stream cancellation args
+pub struct CancelPgfStream<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Steward address
+    pub steward: C::Address,
+    /// Target address of the stream
+    pub target: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelPgfStream<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelPgfStream {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelPgfStream<C> {
+    /// Steward address
+    pub fn steward(self, steward: C::Address) -> Self {
+        Self { steward, ..self }
+    }
+
+    /// Target address of the stream
+    pub fn target(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl CancelPgfStream {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_cancel_pgf_stream(context, self).await
+    }
+}
+
 #[derive(Clone, Debug)]
 /// Re-activate a jailed validator args
 pub struct TxUnjailValidator<C: NamadaTypes = SdkTypes> {
@@ -1857,6 +2356,15 @@ pub struct QuerySlashes<C: NamadaTypes = SdkTypes> {
     pub validator: Option<C::Address>,
 }
 
+/// Query PoS validator liveness
+#[derive(Clone, Debug)]
+pub struct QueryLiveness<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of a validator, all the consensus validators when not given
+    pub validator: Option<C::Address>,
+}
+
 /// Query PoS rewards
 #[derive(Clone, Debug)]
 pub struct QueryRewards<C: NamadaTypes = SdkTypes> {
@@ -1897,6 +2405,11 @@ pub struct QueryRawBytes<C: NamadaTypes = SdkTypes> {
     pub query: Query<C>,
 }
 
+/// The default safety multiplier applied to the estimated gas limit of a tx
+pub fn default_gas_limit_multiplier() -> Dec {
+    Dec::new(13, 1).expect("The default gas limit multiplier must be valid")
+}
+
 /// Common transaction arguments
 #[derive(Clone, Debug)]
 pub struct Tx<C: NamadaTypes = SdkTypes> {
@@ -1928,8 +2441,17 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub fee_token: C::AddrOrNativeToken,
     /// The optional spending key for fee unshielding
     pub fee_unshield: Option<C::TransferSource>,
-    /// The max amount of gas used to process tx
-    pub gas_limit: GasLimit,
+    /// The optional account that granted the fee payer an allowance to pay
+    /// the fees on its behalf
+    pub fee_granter: Option<C::Address>,
+    /// The optional sequence number of the tx among the ones whose fees are
+    /// paid by the fee payer
+    pub sequence: Option<u64>,
+    /// The max amount of gas used to process tx. If not provided, it's
+    /// estimated by dry running the tx
+    pub gas_limit: Option<GasLimit>,
+    /// The safety multiplier applied to the estimated gas limit
+    pub gas_limit_multiplier: Dec,
     /// The optional expiration of the transaction
     pub expiration: Option<DateTimeUtc>,
     /// Generate an ephimeral signing key to be used only once to sign a
@@ -1949,6 +2471,9 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub memo: Option<Memo>,
     /// Use device to sign the transaction
     pub use_device: bool,
+    /// Request the signatures that cannot be made with the wallet from the
+    /// remote signer listening at the given address
+    pub remote_signer: Option<SocketAddr>,
 }
 
 /// Builder functions for Tx
@@ -2044,9 +2569,33 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// The optional account that granted the fee payer a fee allowance
+    fn fee_granter(self, fee_granter: C::Address) -> Self {
+        self.tx(|x| Tx {
+            fee_granter: Some(fee_granter),
+            ..x
+        })
+    }
+    /// The optional sequence number of the tx among the ones of the fee payer
+    fn sequence(self, sequence: u64) -> Self {
+        self.tx(|x| Tx {
+            sequence: Some(sequence),
+            ..x
+        })
+    }
     /// The max amount of gas used to process tx
     fn gas_limit(self, gas_limit: GasLimit) -> Self {
-        self.tx(|x| Tx { gas_limit, ..x })
+        self.tx(|x| Tx {
+            gas_limit: Some(gas_limit),
+            ..x
+        })
+    }
+    /// The safety multiplier applied to the estimated gas limit
+    fn gas_limit_multiplier(self, gas_limit_multiplier: Dec) -> Self {
+        self.tx(|x| Tx {
+            gas_limit_multiplier,
+            ..x
+        })
     }
     /// The optional expiration of the transaction
     fn expiration(self, expiration: DateTimeUtc) -> Self {
@@ -2092,6 +2641,14 @@ pub trait TxBuilder<C: NamadaTypes>: Sized {
             ..x
         })
     }
+    /// Request the signatures that cannot be made with the wallet from the
+    /// remote signer listening at the given address
+    fn remote_signer(self, remote_signer: SocketAddr) -> Self {
+        self.tx(|x| Tx {
+            remote_signer: Some(remote_signer),
+            ..x
+        })
+    }
 }
 
 impl<C: NamadaTypes> TxBuilder<C> for Tx<C> {
@@ -2202,6 +2759,15 @@ pub struct KeyConvert {
     pub alias: String,
 }
 
+/// Wallet remote signer arguments
+#[derive(Clone, Debug)]
+pub struct ServeSigner {
+    /// The address to listen at for signature requests
+    pub address: SocketAddr,
+    /// The aliases of the keys to sign with
+    pub aliases: Vec<String>,
+}
+
 /// Wallet key import arguments
 #[derive(Clone, Debug)]
 pub struct KeyImport {
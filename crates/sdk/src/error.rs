@@ -156,6 +156,12 @@ pub enum TxSubmitError {
     /// The address is not a valid steward
     #[error("The address {0} is not a valid steward.")]
     InvalidSteward(Address),
+    /// The address is not the target of a pgf stream
+    #[error("The address {0} is not the target of a pgf stream.")]
+    InvalidPgfStream(Address),
+    /// The pgf stream has no funds to claim
+    #[error("The pgf stream to {0} has no funds to claim at epoch {1}.")]
+    NoPgfStreamFunds(Address, Epoch),
     /// Invalid bond pair
     #[error("Invalid bond pair: source {0} cannot bond to validator {1}.")]
     InvalidBondPair(Address, Address),
@@ -192,6 +198,18 @@ pub enum TxSubmitError {
          unbonded. Amount to unbond is {1} and the total bonds is {2}."
     )]
     LowerBondThanUnbond(Address, String, String),
+    /// No unbond to cancel at the given withdrawable epoch
+    #[error(
+        "There is no unbond withdrawable at epoch {0} that can still be \
+         cancelled."
+    )]
+    NoCancellableUnbond(Epoch),
+    /// Lower unbond amount than the cancellation
+    #[error(
+        "The unbond of the source {0} is lower than the amount to be \
+         cancelled. Amount to cancel is {1} and the unbond is {2}."
+    )]
+    LowerUnbondThanCancel(Address, String, String),
     /// Balance is too low
     #[error(
         "The balance of the source {0} of token {1} is lower than the amount \
@@ -278,6 +296,9 @@ pub enum TxSubmitError {
     /// Account threshold is not set
     #[error("Account threshold must be set.")]
     MissingAccountThreshold,
+    /// Invalid vesting schedule
+    #[error("Invalid vesting schedule: {0}.")]
+    InvalidVestingSchedule(String),
     /// Not enough signature
     #[error("Account threshold is {0} but the valid signatures are {1}.")]
     MissingSigningKeys(u8, u8),
@@ -296,6 +317,18 @@ pub enum TxSubmitError {
     /// The src and dest validators are the same
     #[error("The source and destination validators are the same")]
     RedelegationSrcEqDest,
+    /// The tokenized amount is larger than the remaining bond amount
+    #[error(
+        "The amount to tokenize is larger than the remaining bond amount. \
+         Amount to tokenize is {0} and the remaining bond amount is {1}."
+    )]
+    TokenizeAmountTooLarge(String, String),
+    /// The redeemed amount is larger than the share token balance
+    #[error(
+        "The amount of share tokens to redeem is larger than the balance. \
+         Amount to redeem is {0} and the balance is {1}."
+    )]
+    RedeemAmountTooLarge(String, String),
     /// The redelegation owner is a validator
     #[error("The redelegation owner {0} is a validator")]
     RedelegatorIsValidator(Address),
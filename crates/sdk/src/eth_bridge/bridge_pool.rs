@@ -110,12 +110,12 @@ pub async fn build_bridge_pool_tx(
     .add_data(transfer);
 
     prepare_tx(
-        context.client(),
+        context,
         &tx_args,
         &mut tx,
         unshield,
         fee_amount,
-        signing_data.fee_payer.clone(),
+        &signing_data,
     )
     .await?;
 
@@ -1,15 +1,30 @@
-//! Silly simple Tendermint query parser.
+//! Tendermint query parser.
 //!
-//! This parser will only work with simple queries of the form:
+//! This parser supports the CometBFT event query grammar, which is a list of
+//! conditions joined by `AND`, of the form:
 //!
 //! ```text
-//! tm.event='NewBlock' AND <accepted|applied>.<$attr>='<$value>'
+//! tm.event='NewBlock' AND applied.hash='<$value>' AND applied.gas_used>=1000
 //! ```
+//!
+//! Each condition compares the attribute of an event, identified by a tag
+//! `<$event_type>.<$attr>`, with `=`, `<`, `<=`, `>`, `>=` or `CONTAINS`
+//! against an operand, or checks that the attribute `EXISTS`. Operands may be
+//! single-quoted strings, numbers, times (`TIME 2024-01-01T00:00:00Z`) or
+//! dates (`DATE 2024-01-01`). Since all of Namada's events are emitted by
+//! `FinalizeBlock`, the only supported `tm.event` condition is
+//! `tm.event='NewBlock'`, and all the other conditions of a query must refer
+//! to the same event type.
 
-use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
 
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::storage::BlockHeight;
+use namada_core::time::DateTimeUtc;
+use namada_storage::event_store::EventIndex;
+use thiserror::Error;
 
 use crate::events::{Event, EventType};
 use crate::ibc::core::client::types::Height as IbcHeight;
@@ -17,47 +32,217 @@ use crate::ibc::core::host::types::identifiers::{
     ChannelId, ClientId, PortId, Sequence,
 };
 
+/// Attributes whose values identify few events, in order of preference, to
+/// look up events with in the persistent event store.
+const SELECTIVE_ATTRIBUTES: [&str; 3] =
+    ["hash", "packet_sequence", "consensus_heights"];
+
+/// The tag of the CometBFT event kind.
+const TM_EVENT_TAG: &str = "tm.event";
+
+/// The CometBFT event kind of all of Namada's events.
+const TM_NEW_BLOCK_EVENT: &str = "NewBlock";
+
+/// Errors to do with parsing Tendermint queries.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QueryParseError {
+    /// The query ended before it could be fully parsed.
+    #[error("Unexpected end of query")]
+    UnexpectedEnd,
+    /// An unexpected token was found in the query.
+    #[error("Unexpected token at position {position}: {token}")]
+    UnexpectedToken {
+        /// The position of the token in the query.
+        position: usize,
+        /// The unexpected token.
+        token: String,
+    },
+    /// An operand could not be parsed.
+    #[error("Invalid {kind} operand: {operand}")]
+    InvalidOperand {
+        /// The kind of operand that was expected.
+        kind: &'static str,
+        /// The invalid operand.
+        operand: String,
+    },
+    /// The query is valid, but it can't be matched against Namada events.
+    #[error("Unsupported query: {0}")]
+    Unsupported(String),
+}
+
+/// A value that event attributes are compared against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand {
+    /// A string.
+    String(String),
+    /// A number.
+    Number(Dec),
+    /// A date and a time.
+    Time(DateTimeUtc),
+    /// A date, compared regardless of the time of the day.
+    Date(DateTimeUtc),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(string) => write!(f, "'{string}'"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Time(time) => write!(f, "TIME {time}"),
+            Self::Date(date) => write!(f, "DATE {}", date.0.date_naive()),
+        }
+    }
+}
+
+impl Operand {
+    /// Compare the given attribute value with this operand, if the value can
+    /// be interpreted as the same kind of value.
+    fn compare(&self, value: &str) -> Option<std::cmp::Ordering> {
+        match self {
+            Self::String(string) => Some(value.cmp(string.as_str())),
+            Self::Number(number) => {
+                Some(Dec::from_str(value).ok()?.cmp(number))
+            }
+            Self::Time(time) => Some(parse_time(value)?.cmp(time)),
+            Self::Date(date) => Some(
+                parse_time(value)?.0.date_naive().cmp(&date.0.date_naive()),
+            ),
+        }
+    }
+}
+
+/// An operation applied to an event attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// The attribute is equal to the operand.
+    Equal(Operand),
+    /// The attribute is less than the operand.
+    Less(Operand),
+    /// The attribute is less than or equal to the operand.
+    LessOrEqual(Operand),
+    /// The attribute is greater than the operand.
+    Greater(Operand),
+    /// The attribute is greater than or equal to the operand.
+    GreaterOrEqual(Operand),
+    /// The attribute contains the given string.
+    Contains(String),
+    /// The attribute is present.
+    Exists,
+}
+
+impl Operation {
+    /// Check if the given attribute value satisfies this operation.
+    fn holds(&self, value: &str) -> bool {
+        use std::cmp::Ordering::*;
+
+        match self {
+            Self::Equal(operand) => operand.compare(value) == Some(Equal),
+            Self::Less(operand) => operand.compare(value) == Some(Less),
+            Self::LessOrEqual(operand) => {
+                matches!(operand.compare(value), Some(Less | Equal))
+            }
+            Self::Greater(operand) => operand.compare(value) == Some(Greater),
+            Self::GreaterOrEqual(operand) => {
+                matches!(operand.compare(value), Some(Greater | Equal))
+            }
+            Self::Contains(string) => value.contains(string.as_str()),
+            Self::Exists => true,
+        }
+    }
+}
+
+/// A condition on an event attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    /// The key of the attribute.
+    pub key: String,
+    /// The operation applied to the attribute.
+    pub operation: Operation,
+}
+
+impl Condition {
+    /// Check if the given event satisfies this condition.
+    fn holds(&self, event: &Event) -> bool {
+        event
+            .attributes
+            .get(&self.key)
+            .map(|value| self.operation.holds(value))
+            .unwrap_or_default()
+    }
+
+    /// Construct a condition of equality of the given attribute with a
+    /// string.
+    fn string_eq(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            operation: Operation::Equal(Operand::String(value.into())),
+        }
+    }
+}
+
 /// A [`QueryMatcher`] verifies if a Namada event matches a
 /// given Tendermint query.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryMatcher {
-    event_type: EventType,
-    attributes: HashMap<String, String>,
+    /// The type of the matched events, if any.
+    event_type: Option<String>,
+    /// The conditions that the matched events' attributes must satisfy.
+    conditions: Vec<Condition>,
 }
 
 impl QueryMatcher {
     /// Checks if this [`QueryMatcher`] validates the
     /// given [`Event`].
     pub fn matches(&self, event: &Event) -> bool {
-        if event.event_type != self.event_type {
-            return false;
+        if let Some(event_type) = &self.event_type {
+            if event.event_type.to_string() != *event_type {
+                return false;
+            }
         }
 
-        self.attributes.iter().all(|(key, value)| {
-            match event.attributes.get(key) {
-                Some(v) => v == value,
-                None => false,
+        self.conditions
+            .iter()
+            .all(|condition| condition.holds(event))
+    }
+
+    /// Returns the index of the persistent event store that narrows down the
+    /// events which may be matched by this [`QueryMatcher`] the most.
+    pub fn event_store_index(&self) -> EventIndex {
+        let string_eq = |condition: &Condition| match &condition.operation {
+            Operation::Equal(Operand::String(value)) => {
+                Some(EventIndex::Attribute {
+                    key: condition.key.clone(),
+                    value: value.clone(),
+                })
             }
-        })
+            _ => None,
+        };
+        SELECTIVE_ATTRIBUTES
+            .iter()
+            .find_map(|&key| {
+                self.conditions
+                    .iter()
+                    .filter(|condition| condition.key == key)
+                    .find_map(string_eq)
+            })
+            .or_else(|| self.conditions.iter().find_map(string_eq))
+            .or_else(|| self.event_type.clone().map(EventIndex::Type))
+            .unwrap_or(EventIndex::All)
     }
 
     /// Returns a query matching the given accepted transaction hash.
     pub fn accepted(tx_hash: Hash) -> Self {
-        let mut attributes = HashMap::new();
-        attributes.insert("hash".to_string(), tx_hash.to_string());
         Self {
-            event_type: EventType::Accepted,
-            attributes,
+            event_type: Some(EventType::Accepted.to_string()),
+            conditions: vec![Condition::string_eq("hash", tx_hash.to_string())],
         }
     }
 
     /// Returns a query matching the given applied transaction hash.
     pub fn applied(tx_hash: Hash) -> Self {
-        let mut attributes = HashMap::new();
-        attributes.insert("hash".to_string(), tx_hash.to_string());
         Self {
-            event_type: EventType::Applied,
-            attributes,
+            event_type: Some(EventType::Applied.to_string()),
+            conditions: vec![Condition::string_eq("hash", tx_hash.to_string())],
         }
     }
 
@@ -71,18 +256,20 @@ impl QueryMatcher {
             UPDATE_CLIENT_EVENT,
         };
 
-        let mut attributes = HashMap::new();
-        attributes
-            .insert(CLIENT_ID_ATTRIBUTE_KEY.to_string(), client_id.to_string());
-        attributes.insert(
-            CONSENSUS_HEIGHTS_ATTRIBUTE_KEY.to_string(),
-            IbcHeight::new(0, consensus_height.0)
-                .expect("invalid height")
-                .to_string(),
-        );
         Self {
-            event_type: EventType::Ibc(UPDATE_CLIENT_EVENT.to_string()),
-            attributes,
+            event_type: Some(UPDATE_CLIENT_EVENT.to_string()),
+            conditions: vec![
+                Condition::string_eq(
+                    CLIENT_ID_ATTRIBUTE_KEY,
+                    client_id.to_string(),
+                ),
+                Condition::string_eq(
+                    CONSENSUS_HEIGHTS_ATTRIBUTE_KEY,
+                    IbcHeight::new(0, consensus_height.0)
+                        .expect("invalid height")
+                        .to_string(),
+                ),
+            ],
         }
     }
 
@@ -95,29 +282,239 @@ impl QueryMatcher {
         destination_channel: ChannelId,
         sequence: Sequence,
     ) -> Self {
-        let mut attributes = HashMap::new();
-        attributes
-            .insert("packet_src_port".to_string(), source_port.to_string());
-        attributes.insert(
-            "packet_src_channel".to_string(),
-            source_channel.to_string(),
-        );
-        attributes.insert(
-            "packet_dst_port".to_string(),
-            destination_port.to_string(),
-        );
-        attributes.insert(
-            "packet_dst_channel".to_string(),
-            destination_channel.to_string(),
-        );
-        attributes.insert("packet_sequence".to_string(), sequence.to_string());
         Self {
-            event_type,
-            attributes,
+            event_type: Some(event_type.to_string()),
+            conditions: vec![
+                Condition::string_eq(
+                    "packet_src_port",
+                    source_port.to_string(),
+                ),
+                Condition::string_eq(
+                    "packet_src_channel",
+                    source_channel.to_string(),
+                ),
+                Condition::string_eq(
+                    "packet_dst_port",
+                    destination_port.to_string(),
+                ),
+                Condition::string_eq(
+                    "packet_dst_channel",
+                    destination_channel.to_string(),
+                ),
+                Condition::string_eq("packet_sequence", sequence.to_string()),
+            ],
+        }
+    }
+}
+
+impl FromStr for QueryMatcher {
+    type Err = QueryParseError;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        let mut lexer = Lexer::new(query);
+        let mut matcher = Self {
+            event_type: None,
+            conditions: vec![],
+        };
+        loop {
+            let (tag_position, tag) = lexer.expect_word()?;
+            let operation = lexer.parse_operation()?;
+            if tag == TM_EVENT_TAG {
+                match operation {
+                    Operation::Equal(Operand::String(kind))
+                        if kind == TM_NEW_BLOCK_EVENT => {}
+                    _ => {
+                        return Err(QueryParseError::Unsupported(format!(
+                            "Namada events can only be matched with \
+                             {TM_EVENT_TAG}='{TM_NEW_BLOCK_EVENT}'"
+                        )));
+                    }
+                }
+            } else {
+                let (event_type, key) =
+                    tag.split_once('.').ok_or_else(|| {
+                        QueryParseError::UnexpectedToken {
+                            position: tag_position,
+                            token: tag.to_string(),
+                        }
+                    })?;
+                match &matcher.event_type {
+                    Some(matched_type) if matched_type != event_type => {
+                        return Err(QueryParseError::Unsupported(format!(
+                            "Conditions on distinct event types \
+                             {matched_type} and {event_type}"
+                        )));
+                    }
+                    _ => matcher.event_type = Some(event_type.to_string()),
+                }
+                matcher.conditions.push(Condition {
+                    key: key.to_string(),
+                    operation,
+                });
+            }
+            match lexer.next_word() {
+                None => return Ok(matcher),
+                Some((_, "AND")) => continue,
+                Some((position, token)) => {
+                    return Err(QueryParseError::UnexpectedToken {
+                        position,
+                        token: token.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Splits a query into its tokens.
+struct Lexer<'query> {
+    query: &'query str,
+    position: usize,
+}
+
+impl<'query> Lexer<'query> {
+    fn new(query: &'query str) -> Self {
+        Self { query, position: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.query[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Take the longest prefix of the rest of the query whose characters
+    /// satisfy the given predicate, along with its position.
+    fn take_while(
+        &mut self,
+        predicate: impl Fn(char) -> bool,
+    ) -> (usize, &'query str) {
+        self.skip_whitespace();
+        let start = self.position;
+        let query = self.query;
+        let rest = &query[start..];
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.position += len;
+        (start, &rest[..len])
+    }
+
+    /// Get the next word, i.e. a sequence of characters other than
+    /// whitespace, operators and quotes.
+    fn next_word(&mut self) -> Option<(usize, &'query str)> {
+        let (position, word) = self.take_while(|c| {
+            !c.is_whitespace() && !matches!(c, '=' | '<' | '>' | '\'')
+        });
+        (!word.is_empty()).then_some((position, word))
+    }
+
+    fn expect_word(&mut self) -> Result<(usize, &'query str), QueryParseError> {
+        match self.next_word() {
+            Some(word) => Ok(word),
+            None => Err(self.unexpected()),
+        }
+    }
+
+    /// Build an error for the token found at the current position.
+    fn unexpected(&self) -> QueryParseError {
+        match self.query[self.position..].chars().next() {
+            Some(c) => QueryParseError::UnexpectedToken {
+                position: self.position,
+                token: c.to_string(),
+            },
+            None => QueryParseError::UnexpectedEnd,
+        }
+    }
+
+    fn parse_operation(&mut self) -> Result<Operation, QueryParseError> {
+        let (_, symbol) = self.take_while(|c| matches!(c, '=' | '<' | '>'));
+        let operation = match symbol {
+            "=" => Operation::Equal,
+            "<" => Operation::Less,
+            "<=" => Operation::LessOrEqual,
+            ">" => Operation::Greater,
+            ">=" => Operation::GreaterOrEqual,
+            "" => {
+                return match self.expect_word()? {
+                    (_, "EXISTS") => Ok(Operation::Exists),
+                    (_, "CONTAINS") => match self.parse_operand()? {
+                        Operand::String(string) => {
+                            Ok(Operation::Contains(string))
+                        }
+                        operand => Err(QueryParseError::InvalidOperand {
+                            kind: "string",
+                            operand: operand.to_string(),
+                        }),
+                    },
+                    (position, token) => {
+                        Err(QueryParseError::UnexpectedToken {
+                            position,
+                            token: token.to_string(),
+                        })
+                    }
+                };
+            }
+            _ => {
+                return Err(QueryParseError::UnexpectedToken {
+                    position: self.position - symbol.len(),
+                    token: symbol.to_string(),
+                });
+            }
+        };
+        let operand = self.parse_operand()?;
+        if matches!(operand, Operand::String(_)) && !matches!(symbol, "=") {
+            return Err(QueryParseError::InvalidOperand {
+                kind: "number, time or date",
+                operand: operand.to_string(),
+            });
+        }
+        Ok(operation(operand))
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, QueryParseError> {
+        self.skip_whitespace();
+        if let Some(rest) = self.query[self.position..].strip_prefix('\'') {
+            let len = rest.find('\'').ok_or(QueryParseError::UnexpectedEnd)?;
+            // Skip both quotes
+            self.position += len + 2;
+            return Ok(Operand::String(rest[..len].to_string()));
+        }
+        match self.expect_word()? {
+            (_, "TIME") => {
+                let (_, time) = self.expect_word()?;
+                parse_time(time).map(Operand::Time).ok_or_else(|| {
+                    QueryParseError::InvalidOperand {
+                        kind: "time",
+                        operand: time.to_string(),
+                    }
+                })
+            }
+            (_, "DATE") => {
+                let (_, date) = self.expect_word()?;
+                parse_time(date).map(Operand::Date).ok_or_else(|| {
+                    QueryParseError::InvalidOperand {
+                        kind: "date",
+                        operand: date.to_string(),
+                    }
+                })
+            }
+            (_, number) => {
+                Dec::from_str(number).map(Operand::Number).map_err(|_| {
+                    QueryParseError::InvalidOperand {
+                        kind: "number",
+                        operand: number.to_string(),
+                    }
+                })
+            }
         }
     }
 }
 
+/// Parse an RFC 3339 date and time, or a date at midnight UTC.
+fn parse_time(value: &str) -> Option<DateTimeUtc> {
+    DateTimeUtc::from_str(value)
+        .or_else(|_| DateTimeUtc::from_str(&format!("{value}T00:00:00Z")))
+        .ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,12 +526,14 @@ mod tests {
         const HASH: &str =
             "DEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEF";
 
-        let mut attributes = HashMap::new();
-        attributes.insert("hash".to_string(), HASH.to_string());
-        let matcher = QueryMatcher {
-            event_type: EventType::Accepted,
-            attributes,
-        };
+        let matcher: QueryMatcher =
+            format!("tm.event='NewBlock' AND accepted.hash='{HASH}'")
+                .parse()
+                .unwrap();
+        assert_eq!(
+            matcher,
+            QueryMatcher::accepted(Hash::try_from(HASH).unwrap())
+        );
 
         let tests = {
             let event_1 = Event {
@@ -168,4 +567,86 @@ mod tests {
             }
         }
     }
+
+    /// Test matching events against comparisons of numbers, times and
+    /// dates, and attribute existence.
+    #[test]
+    fn test_tm_query_comparisons() {
+        let event = Event {
+            event_type: EventType::Applied,
+            level: EventLevel::Tx,
+            attributes: [
+                ("gas_used", "1500"),
+                ("time", "2024-03-01T12:00:00Z"),
+                ("info", "Transaction was applied"),
+            ]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect(),
+        };
+
+        let tests = [
+            ("applied.gas_used >= 1500", true),
+            ("applied.gas_used > 1500", false),
+            (
+                "applied.gas_used < 2000.5 AND applied.gas_used > 1000",
+                true,
+            ),
+            ("applied.gas_used = 1500.0", true),
+            ("applied.time > TIME 2024-03-01T11:59:59Z", true),
+            ("applied.time < TIME 2024-03-01T11:59:59Z", false),
+            ("applied.time = DATE 2024-03-01", true),
+            ("applied.time <= DATE 2024-02-29", false),
+            ("applied.info CONTAINS 'applied'", true),
+            ("applied.info CONTAINS 'rejected'", false),
+            ("applied.info EXISTS", true),
+            ("applied.hash EXISTS", false),
+            ("tm.event='NewBlock' AND applied.gas_used=1500", true),
+            ("accepted.gas_used=1500", false),
+        ];
+        for (query, expected) in tests {
+            let matcher: QueryMatcher = query.parse().unwrap();
+            assert_eq!(matcher.matches(&event), expected, "query: {query}");
+        }
+    }
+
+    /// Test that invalid or unsupported queries are rejected.
+    #[test]
+    fn test_tm_query_parse_errors() {
+        let tests = [
+            ("applied.hash=", QueryParseError::UnexpectedEnd),
+            (
+                "applied.hash='abc' OR applied.code=0",
+                QueryParseError::UnexpectedToken {
+                    position: 19,
+                    token: "OR".to_string(),
+                },
+            ),
+            (
+                "applied.hash < 'abc'",
+                QueryParseError::InvalidOperand {
+                    kind: "number, time or date",
+                    operand: "'abc'".to_string(),
+                },
+            ),
+            (
+                "applied.time > TIME yesterday",
+                QueryParseError::InvalidOperand {
+                    kind: "time",
+                    operand: "yesterday".to_string(),
+                },
+            ),
+        ];
+        for (query, expected) in tests {
+            assert_eq!(query.parse::<QueryMatcher>(), Err(expected));
+        }
+        assert!(matches!(
+            "tm.event='Tx' AND applied.code=0".parse::<QueryMatcher>(),
+            Err(QueryParseError::Unsupported(_))
+        ));
+        assert!(matches!(
+            "applied.code=0 AND accepted.code=0".parse::<QueryMatcher>(),
+            Err(QueryParseError::Unsupported(_))
+        ));
+    }
 }
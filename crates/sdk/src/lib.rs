@@ -44,7 +44,7 @@ use namada_core::ethereum_events::EthAddress;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::key::*;
 use namada_core::masp::{TransferSource, TransferTarget};
-use namada_tx::data::wrapper::GasLimit;
+use namada_core::storage::Epoch;
 use namada_tx::Tx;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
@@ -57,14 +57,17 @@ use crate::signing::SigningTxData;
 use crate::token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
 use crate::tx::{
     ProcessTxResponse, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_PGF_STREAM, TX_CANCEL_UNBOND_WASM,
+    TX_CHANGE_AUTO_COMPOUND_WASM, TX_CHANGE_COMMISSION_WASM,
     TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
-    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
-    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM,
-    TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    TX_CHANGE_REWARD_RECIPIENT_WASM, TX_CLAIM_PGF_STREAM,
+    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+    TX_GRANT_FEE_ALLOWANCE_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
+    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDEEM_SHARES_WASM,
+    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TOKENIZE_BOND_WASM,
+    TX_TRANSFER_WASM, TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM,
+    TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL,
+    TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 use crate::wallet::{Wallet, WalletIo, WalletStorage};
 
@@ -138,7 +141,10 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             wrapper_fee_payer: None,
             fee_token: self.native_token(),
             fee_unshield: None,
-            gas_limit: GasLimit::from(20_000),
+            fee_granter: None,
+            sequence: None,
+            gas_limit: None,
+            gas_limit_multiplier: args::default_gas_limit_multiplier(),
             expiration: None,
             disposable_signing_key: false,
             chain_id: None,
@@ -148,6 +154,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             password: None,
             memo: None,
             use_device: false,
+            remote_signer: None,
         }
     }
 
@@ -181,6 +188,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             tx_code_path: PathBuf::from(TX_INIT_ACCOUNT_WASM),
             public_keys,
             threshold,
+            vesting: None,
         }
     }
 
@@ -245,6 +253,38 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a TokenizeBond builder from the given minimum set of arguments
+    fn new_tokenize_bond(
+        &self,
+        source: Address,
+        validator: Address,
+        amount: token::Amount,
+    ) -> args::TokenizeBond {
+        args::TokenizeBond {
+            validator,
+            source,
+            amount,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_TOKENIZE_BOND_WASM),
+        }
+    }
+
+    /// Make a RedeemShares builder from the given minimum set of arguments
+    fn new_redeem_shares(
+        &self,
+        owner: Address,
+        validator: Address,
+        amount: token::Amount,
+    ) -> args::RedeemShares {
+        args::RedeemShares {
+            validator,
+            owner,
+            amount,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_REDEEM_SHARES_WASM),
+        }
+    }
+
     /// Make a TxIbcTransfer builder from the given minimum set of arguments
     fn new_ibc_transfer(
         &self,
@@ -293,6 +333,25 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a GrantFeeAllowance builder from the given minimum set of
+    /// arguments
+    fn new_grant_fee_allowance(
+        &self,
+        granter: Address,
+        grantee: Address,
+    ) -> args::GrantFeeAllowance {
+        args::GrantFeeAllowance {
+            granter,
+            grantee,
+            token: self.native_token(),
+            spend_limit: None,
+            expiration: None,
+            allowed_txs: vec![],
+            tx_code_path: PathBuf::from(TX_GRANT_FEE_ALLOWANCE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a VoteProposal builder from the given minimum set of arguments
     fn new_vote_prposal(
         &self,
@@ -463,6 +522,22 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a CancelUnbond builder from the given minimum set of arguments
+    fn new_cancel_unbond(
+        &self,
+        validator: Address,
+        withdraw_epoch: Epoch,
+    ) -> args::CancelUnbond {
+        args::CancelUnbond {
+            validator,
+            source: None,
+            withdraw_epoch,
+            amount: None,
+            tx_code_path: PathBuf::from(TX_CANCEL_UNBOND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a Claim-rewards builder from the given minimum set of arguments
     fn new_claim_rewards(&self, validator: Address) -> args::ClaimRewards {
         args::ClaimRewards {
@@ -473,6 +548,36 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a ChangeAutoCompound builder from the given minimum set of
+    /// arguments
+    fn new_change_auto_compound(
+        &self,
+        validator: Address,
+        enabled: bool,
+    ) -> args::ChangeAutoCompound {
+        args::ChangeAutoCompound {
+            validator,
+            source: None,
+            enabled,
+            tx_code_path: PathBuf::from(TX_CHANGE_AUTO_COMPOUND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a ChangeRewardRecipient builder from the given minimum set of
+    /// arguments
+    fn new_change_reward_recipient(
+        &self,
+        source: Address,
+    ) -> args::ChangeRewardRecipient {
+        args::ChangeRewardRecipient {
+            source,
+            recipient: None,
+            tx_code_path: PathBuf::from(TX_CHANGE_REWARD_RECIPIENT_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a Withdraw builder from the given minimum set of arguments
     fn new_add_erc20_transfer(
         &self,
@@ -524,6 +629,29 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
         }
     }
 
+    /// Make a ClaimPgfStream builder from the given minimum set of arguments
+    fn new_claim_pgf_stream(&self, target: Address) -> args::ClaimPgfStream {
+        args::ClaimPgfStream {
+            target,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_CLAIM_PGF_STREAM),
+        }
+    }
+
+    /// Make a CancelPgfStream builder from the given minimum set of arguments
+    fn new_cancel_pgf_stream(
+        &self,
+        steward: Address,
+        target: Address,
+    ) -> args::CancelPgfStream {
+        args::CancelPgfStream {
+            steward,
+            target,
+            tx: self.tx_builder(),
+            tx_code_path: PathBuf::from(TX_CANCEL_PGF_STREAM),
+        }
+    }
+
     /// Make a TxCustom builder from the given minimum set of arguments
     fn new_custom(&self, owner: Address) -> args::TxCustom {
         args::TxCustom {
@@ -653,7 +781,10 @@ where
                 wrapper_fee_payer: None,
                 fee_token: native_token,
                 fee_unshield: None,
-                gas_limit: GasLimit::from(20_000),
+                fee_granter: None,
+                sequence: None,
+                gas_limit: None,
+                gas_limit_multiplier: args::default_gas_limit_multiplier(),
                 expiration: None,
                 disposable_signing_key: false,
                 chain_id: None,
@@ -663,6 +794,7 @@ where
                 password: None,
                 memo: None,
                 use_device: false,
+                remote_signer: None,
             },
         }
     }
@@ -788,7 +920,7 @@ pub mod testing {
         BecomeValidator, Bond, CommissionChange, ConsensusKeyChange,
         MetaDataChange, Redelegation, Unbond, Withdraw,
     };
-    use namada_tx::data::{DecryptedTx, Fee, TxType, WrapperTx};
+    use namada_tx::data::{DecryptedTx, Fee, GasLimit, TxType, WrapperTx};
     use proptest::prelude::{Just, Strategy};
     use proptest::{arbitrary, collection, option, prop_compose, prop_oneof};
     use prost::Message;
@@ -942,6 +1074,8 @@ pub mod testing {
             pk in arb_common_pk(),
             gas_limit in arb_gas_limit(),
             unshield_section_hash in option::of(arb_hash()),
+            fee_granter in option::of(arb_non_internal_address()),
+            sequence in option::of(arbitrary::any::<u64>()),
         ) -> WrapperTx {
             WrapperTx {
                 fee,
@@ -949,6 +1083,8 @@ pub mod testing {
                 pk,
                 gas_limit,
                 unshield_section_hash,
+                fee_granter,
+                sequence,
             }
         }
     }
@@ -18,6 +18,8 @@ use namada_migrations::TypeHash;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_storage::DbColFam;
+#[cfg(feature = "migrations")]
+use namada_tx::data::StorageDiff;
 use regex::Regex;
 use serde::de::{Error, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -429,6 +431,37 @@ impl Display for UpdateStatus {
     }
 }
 
+/// The values of a storage diff of a transaction, formatted by the
+/// deserializer of their type from the migrations type registry. A missing
+/// value means that the key is not present in storage.
+#[cfg(feature = "migrations")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedStorageDiff {
+    /// The formatted value before the execution of the transaction
+    pub old_value: Option<String>,
+    /// The formatted value after the execution of the transaction
+    pub new_value: Option<String>,
+}
+
+/// Decode the values of a storage diff with the deserializer registered for
+/// the given type hash. Returns `None` if no deserializer is registered for
+/// the type, or if any of the values doesn't deserialize as that type.
+#[cfg(feature = "migrations")]
+pub fn decode_storage_diff(
+    diff: &StorageDiff,
+    type_hash: &[u8; 32],
+) -> Option<DecodedStorageDiff> {
+    let deserializer = namada_migrations::get_deserializer(type_hash)?;
+    let decode = |value: &Option<Vec<u8>>| match value {
+        Some(bytes) => deserializer(bytes.clone()).map(Some),
+        None => Some(None),
+    };
+    Some(DecodedStorageDiff {
+        old_value: decode(&diff.old_value)?,
+        new_value: decode(&diff.new_value)?,
+    })
+}
+
 #[cfg(feature = "migrations")]
 derive_borshdeserializer!(Vec::<u8>);
 #[cfg(feature = "migrations")]
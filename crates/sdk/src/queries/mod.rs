@@ -4,8 +4,11 @@
 // Re-export to show in rustdoc!
 use namada_core::storage::BlockHeight;
 use namada_state::{DBIter, StorageHasher, DB};
-pub use shell::Shell;
 use shell::SHELL;
+pub use shell::{
+    BlockEvent, EventBatch, GasEstimate, Shell, SimulationRequest,
+    TxSimulation, SUBSCRIBE_ENDPOINT,
+};
 pub use types::{
     EncodedResponseQuery, Error, RequestCtx, RequestQuery, ResponseQuery,
     Router,
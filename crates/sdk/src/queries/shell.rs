@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 pub(super) mod eth_bridge;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
@@ -16,13 +16,14 @@ use namada_core::masp::TokenMap;
 use namada_core::storage::{
     self, BlockHeight, BlockResults, Epoch, KeySeg, PrefixValue,
 };
-use namada_core::token::{Denomination, MaspDigitPos};
+use namada_core::token::{self, Denomination, MaspDigitPos};
 use namada_core::uint::Uint;
+use namada_gas::{Gas, GasBreakdown};
 use namada_state::{DBIter, LastBlock, StateRead, StorageHasher, DB};
-use namada_storage::{ResultExt, StorageRead};
+use namada_storage::event_store::EventIndex;
+use namada_storage::{archive, ResultExt, StorageRead};
 use namada_token::storage_key::masp_token_map_key;
-#[cfg(any(test, feature = "async-client"))]
-use namada_tx::data::TxResult;
+use namada_tx::data::{StorageDiff, TxResult};
 
 use self::eth_bridge::{EthBridge, ETH_BRIDGE};
 use crate::events::log::dumb_queries;
@@ -32,7 +33,9 @@ use crate::ibc::core::host::types::identifiers::{
 };
 use crate::masp::MaspTokenRewardData;
 use crate::queries::types::{RequestCtx, RequestQuery};
-use crate::queries::{require_latest_height, EncodedResponseQuery};
+use crate::queries::{
+    require_latest_height, require_no_proof, EncodedResponseQuery,
+};
 use crate::tendermint::merkle::proof::ProofOps;
 
 type ConversionWithoutPath = (
@@ -43,6 +46,73 @@ type ConversionWithoutPath = (
     masp_primitives::transaction::components::I128Sum,
 );
 
+/// An event, along with the height of the block in which it was emitted.
+pub type BlockEvent = (BlockHeight, Event);
+
+/// The maximum number of events returned by an event search query.
+pub const MAX_SEARCHED_EVENTS: usize = 10_000;
+
+/// The maximum number of block heights covered by an event search query.
+pub const MAX_SEARCHED_HEIGHTS: u64 = 100_000;
+
+/// The number of block heights whose events are read from the store at once
+/// when searching events.
+const SEARCH_WINDOW_HEIGHTS: u64 = 100;
+
+/// The path of the HTTP endpoint of a node serving the long-polled event
+/// subscriptions, followed by the block height cursor of a subscription. The
+/// subscription query is sent in the body of a POST request, and the
+/// endpoint replies with a Borsh encoded [`EventBatch`].
+pub const SUBSCRIBE_ENDPOINT: &str = "subscribe";
+
+/// A batch of events returned by an event subscription query.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventBatch {
+    /// The matching events, in the order in which they were emitted.
+    pub events: Vec<BlockEvent>,
+    /// The cursor from which the subscription should be resumed, i.e. the
+    /// first block height whose events have not been returned yet.
+    pub next_height: BlockHeight,
+    /// The last committed block height when the batch was read. The
+    /// subscription has caught up with the chain once `next_height` is past
+    /// it.
+    pub last_height: BlockHeight,
+}
+
+/// The gas estimated for a transaction by dry running it.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct GasEstimate {
+    /// The total gas consumed by the tx, including the wrapper (if any).
+    pub gas_used: Gas,
+    /// The consumed gas split by the kind of operations it was charged for.
+    pub breakdown: GasBreakdown,
+    /// The result of the dry run.
+    pub tx_result: TxResult,
+}
+
+/// A request to dry run a transaction on top of some overridden storage
+/// values.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct SimulationRequest {
+    /// The encoded transaction to simulate.
+    pub tx: Vec<u8>,
+    /// The Borsh encoded values written on top of the last committed state
+    /// before running the tx. A missing value deletes the key.
+    pub overrides: BTreeMap<storage::Key, Option<Vec<u8>>>,
+}
+
+/// The outcome of a simulated transaction.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct TxSimulation {
+    /// The result of the tx.
+    pub tx_result: TxResult,
+    /// The values of the storage keys changed by the tx, before and after its
+    /// execution. The changes of the wrapper (if any) are not included.
+    pub storage_diff: BTreeMap<storage::Key, StorageDiff>,
+    /// The events emitted by the tx.
+    pub events: Vec<Event>,
+}
+
 type Conversion = (
     Address,
     Denomination,
@@ -73,6 +143,9 @@ router! {SHELL,
     // First block height of the current epoch
     ( "first_block_height_of_current_epoch" ) -> BlockHeight = first_block_height_of_current_epoch,
 
+    // Base cost per unit of gas of every token allowed for fee payment
+    ( "base_gas_price" ) -> BTreeMap<Address, token::Amount> = base_gas_price,
+
     // Raw storage access - read value
     ( "value" / [storage_key: storage::Key] )
         -> Vec<u8> = (with_options storage_value),
@@ -80,6 +153,13 @@ router! {SHELL,
     // Dry run a transaction
     ( "dry_run_tx" ) -> TxResult = (with_options dry_run_tx),
 
+    // Dry run a transaction to estimate its gas
+    ( "estimate_gas" ) -> GasEstimate = (with_options estimate_gas),
+
+    // Dry run a transaction on top of the storage overrides attached in the
+    // request data
+    ( "simulate_tx" ) -> TxSimulation = (with_options simulate_tx),
+
     // Raw storage access - prefix iterator
     ( "prefix" / [storage_key: storage::Key] )
         -> Vec<PrefixValue> = (with_options storage_prefix),
@@ -106,6 +186,26 @@ router! {SHELL,
     // was the transaction applied?
     ( "applied" / [tx_hash: Hash] ) -> Option<Event> = applied,
 
+    // The storage values changed by a transaction applied at the given block
+    // height, only available from the nodes indexing the storage diffs
+    ( "storage_diff" / [height: BlockHeight] / [tx_hash: Hash] )
+        -> Option<BTreeMap<storage::Key, StorageDiff>> = storage_diff,
+
+    // Events emitted at the given block height
+    ( "block_events" / [height: BlockHeight] ) -> Vec<Event> = block_events,
+
+    // Search events emitted between the given heights (inclusive) with a
+    // Tendermint query attached in the request data, over at most
+    // `MAX_SEARCHED_HEIGHTS` heights and returning at most
+    // `MAX_SEARCHED_EVENTS` events
+    ( "events" / [from_height: BlockHeight] / [to_height: BlockHeight] )
+        -> Vec<BlockEvent> = (with_options search_events),
+
+    // Poll the events matching the query in the request data, starting at
+    // the given block height cursor
+    ( "subscribe" / [cursor: BlockHeight] )
+        -> EventBatch = (with_options subscribe_events),
+
     // Query account subspace
     ( "account" / [owner: Address] ) -> Option<Account> = account,
 
@@ -132,6 +232,32 @@ where
     unimplemented!("Dry running tx requires \"wasm-runtime\" feature.")
 }
 
+fn estimate_gas<D, H, V, T>(
+    _ctx: RequestCtx<'_, D, H, V, T>,
+    _request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Err(namada_storage::Error::new_const(
+        "Estimating gas requires \"wasm-runtime\" feature.",
+    ))
+}
+
+fn simulate_tx<D, H, V, T>(
+    _ctx: RequestCtx<'_, D, H, V, T>,
+    _request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Err(namada_storage::Error::new_const(
+        "Simulating tx requires \"wasm-runtime\" feature.",
+    ))
+}
+
 /// Query to read block results from storage
 pub fn read_results<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -346,6 +472,16 @@ where
     Ok(ctx.state.in_mem().last_block.clone())
 }
 
+fn base_gas_price<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<BTreeMap<Address, token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_parameters::read_base_gas_prices(ctx.state)
+}
+
 fn first_block_height_of_current_epoch<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
 ) -> namada_storage::Result<BlockHeight>
@@ -392,7 +528,15 @@ where
     };
 
     if let Some(past_height_limit) = ctx.storage_read_past_height_limit {
-        if queried_height + past_height_limit < last_committed_height {
+        // The heights served from the archive are not limited
+        let is_archived = || -> namada_storage::Result<bool> {
+            let ranges =
+                ctx.state.db().read_archive_ranges().into_storage_result()?;
+            Ok(archive::find_range(&ranges, queried_height).is_some())
+        };
+        if queried_height + past_height_limit < last_committed_height
+            && !is_archived()?
+        {
             return Err(namada_storage::Error::new(std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
                 format!(
@@ -453,30 +597,48 @@ where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
-    require_latest_height(&ctx, request)?;
+    let last_committed_height = ctx.state.in_mem().get_last_block_height();
+    let queried_height = {
+        let height: BlockHeight = request.height.into();
+        let is_last_height_query = height.0 == 0;
 
-    let iter = namada_storage::iter_prefix_bytes(ctx.state, &storage_key)?;
-    let data: namada_storage::Result<Vec<PrefixValue>> = iter
-        .map(|iter_result| {
+        if hints::likely(is_last_height_query) {
+            last_committed_height
+        } else {
+            height
+        }
+    };
+
+    let data = if queried_height == last_committed_height {
+        let iter = namada_storage::iter_prefix_bytes(ctx.state, &storage_key)?;
+        iter.map(|iter_result| {
             let (key, value) = iter_result?;
             Ok(PrefixValue { key, value })
         })
-        .collect();
-    let data = data?;
-    let proof = if request.prove {
-        let queried_height = {
-            let last_committed_height =
-                ctx.state.in_mem().get_last_block_height();
-
-            let height: BlockHeight = request.height.into();
-            let is_last_height_query = height.0 == 0;
-
-            if hints::likely(is_last_height_query) {
-                last_committed_height
-            } else {
-                height
-            }
+        .collect::<namada_storage::Result<Vec<PrefixValue>>>()?
+    } else {
+        // Past heights can only be served from the archive, if any
+        let key_vals = ctx
+            .state
+            .db()
+            .read_archive_prefix(&storage_key, queried_height)
+            .into_storage_result()?;
+        let Some(key_vals) = key_vals else {
+            return Err(namada_storage::Error::new_const(
+                "The storage prefix can only be queried at the latest block \
+                 height, or at the heights archived by nodes in the archive \
+                 mode (configured via `shell.archive_mode`)",
+            ));
         };
+        key_vals
+            .into_iter()
+            .map(|(key, value)| {
+                let key = storage::Key::parse(key).into_storage_result()?;
+                Ok(PrefixValue { key, value })
+            })
+            .collect::<namada_storage::Result<Vec<PrefixValue>>>()?
+    };
+    let proof = if request.prove {
         let mut ops = vec![];
         for PrefixValue { key, value } in &data {
             let mut proof = ctx
@@ -511,6 +673,174 @@ where
     Ok(data)
 }
 
+/// Find the most recent event matched by the given query. Events are looked
+/// up in the in-memory event log first, and then in the persistent event
+/// store, through the index of the query's most selective attribute (e.g. the
+/// tx hash), from the most recent events backwards.
+fn find_event<D, H, V, T>(
+    ctx: &RequestCtx<'_, D, H, V, T>,
+    matcher: dumb_queries::QueryMatcher,
+) -> namada_storage::Result<Option<Event>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if let Some(event) = ctx.event_log.iter_with_matcher(matcher.clone()).next()
+    {
+        return Ok(Some(event.clone()));
+    }
+    let last_height = ctx.state.in_mem().get_last_block_height();
+    let event = ctx
+        .state
+        .db()
+        .find_last_event(&matcher.event_store_index(), last_height, &|event| {
+            matcher.matches(event)
+        })
+        .into_storage_result()?;
+    Ok(event.map(|(_height, event)| event))
+}
+
+fn block_events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    height: BlockHeight,
+) -> namada_storage::Result<Vec<Event>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let events = ctx
+        .state
+        .db()
+        .read_events(&EventIndex::All, height, height, None)
+        .into_storage_result()?;
+    Ok(events.into_iter().map(|(_height, event)| event).collect())
+}
+
+fn search_events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    from_height: BlockHeight,
+    to_height: BlockHeight,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    require_latest_height(&ctx, request)?;
+    require_no_proof(request)?;
+
+    let query = std::str::from_utf8(&request.data).into_storage_result()?;
+    let matcher: dumb_queries::QueryMatcher =
+        query.parse().into_storage_result()?;
+    let last_height = ctx.state.in_mem().get_last_block_height();
+    let to_height = std::cmp::min(to_height, last_height);
+    if to_height.0.saturating_sub(from_height.0) >= MAX_SEARCHED_HEIGHTS {
+        return Err(namada_storage::Error::new(format!(
+            "The searched range of heights must not span more than \
+             {MAX_SEARCHED_HEIGHTS} blocks"
+        )));
+    }
+
+    // Read the events a window of heights at a time, such that the search
+    // stops as soon as enough matching events are found
+    let index = matcher.event_store_index();
+    let mut events: Vec<BlockEvent> = vec![];
+    let mut window_start = from_height;
+    while window_start <= to_height && events.len() < MAX_SEARCHED_EVENTS {
+        let window_end = std::cmp::min(
+            window_start + (SEARCH_WINDOW_HEIGHTS - 1),
+            to_height,
+        );
+        let window = ctx
+            .state
+            .db()
+            .read_events(&index, window_start, window_end, None)
+            .into_storage_result()?;
+        events.extend(
+            window
+                .into_iter()
+                .filter(|(_height, event)| matcher.matches(event))
+                .take(MAX_SEARCHED_EVENTS - events.len()),
+        );
+        window_start = window_end.next_height();
+    }
+    let info = if events.len() == MAX_SEARCHED_EVENTS {
+        format!(
+            "The results were truncated to the first {MAX_SEARCHED_EVENTS} \
+             events"
+        )
+    } else {
+        Default::default()
+    };
+    Ok(EncodedResponseQuery {
+        data: events.serialize_to_vec(),
+        proof: None,
+        info,
+    })
+}
+
+fn subscribe_events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    cursor: BlockHeight,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    require_latest_height(&ctx, request)?;
+    require_no_proof(request)?;
+
+    let query = std::str::from_utf8(&request.data).into_storage_result()?;
+    let matcher: dumb_queries::QueryMatcher =
+        query.parse().into_storage_result()?;
+    let index = matcher.event_store_index();
+    let db = ctx.state.db();
+    let last_height = ctx.state.in_mem().get_last_block_height();
+    if cursor > last_height {
+        let batch = EventBatch {
+            events: vec![],
+            next_height: cursor,
+            last_height,
+        };
+        return Ok(EncodedResponseQuery {
+            data: batch.serialize_to_vec(),
+            ..Default::default()
+        });
+    }
+
+    let mut events = db
+        .read_events(&index, cursor, last_height, Some(MAX_SEARCHED_EVENTS))
+        .into_storage_result()?;
+    let next_height = match events.last() {
+        Some(&(last_read, _)) if events.len() == MAX_SEARCHED_EVENTS => {
+            // Only return whole blocks, such that resuming from the
+            // returned cursor never skips any events
+            if events[0].0 == last_read {
+                events = db
+                    .read_events(&index, last_read, last_read, None)
+                    .into_storage_result()?;
+                last_read.next_height()
+            } else {
+                events.retain(|(height, _)| *height < last_read);
+                last_read
+            }
+        }
+        _ => last_height.next_height(),
+    };
+    events.retain(|(_height, event)| matcher.matches(event));
+
+    let batch = EventBatch {
+        events,
+        next_height,
+        last_height,
+    };
+    Ok(EncodedResponseQuery {
+        data: batch.serialize_to_vec(),
+        ..Default::default()
+    })
+}
+
 fn accepted<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     tx_hash: Hash,
@@ -520,12 +850,7 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let matcher = dumb_queries::QueryMatcher::accepted(tx_hash);
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_event(&ctx, matcher)
 }
 
 fn applied<D, H, V, T>(
@@ -537,12 +862,25 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let matcher = dumb_queries::QueryMatcher::applied(tx_hash);
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_event(&ctx, matcher)
+}
+
+fn storage_diff<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    height: BlockHeight,
+    tx_hash: Hash,
+) -> namada_storage::Result<Option<BTreeMap<storage::Key, StorageDiff>>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let storage_diffs = ctx
+        .state
+        .db()
+        .read_storage_diffs(height)
+        .into_storage_result()?;
+    Ok(storage_diffs
+        .and_then(|mut storage_diffs| storage_diffs.remove(&tx_hash)))
 }
 
 fn ibc_client_update<D, H, V, T>(
@@ -558,12 +896,7 @@ where
         client_id,
         consensus_height,
     );
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_event(&ctx, matcher)
 }
 
 fn ibc_packet<D, H, V, T>(
@@ -587,12 +920,7 @@ where
         destination_channel,
         sequence,
     );
-    Ok(ctx
-        .event_log
-        .iter_with_matcher(matcher)
-        .by_ref()
-        .next()
-        .cloned())
+    find_event(&ctx, matcher)
 }
 
 fn account<D, H, V, T>(
@@ -635,6 +963,8 @@ where
 #[cfg(test)]
 mod test {
     use namada_core::address;
+    use namada_core::hash::Hash;
+    use namada_core::storage::BlockHeight;
     use namada_token::storage_key::balance_key;
 
     use crate::queries::RPC;
@@ -653,10 +983,29 @@ mod test {
         let path = RPC.shell().dry_run_tx_path();
         assert_eq!("/shell/dry_run_tx", path);
 
+        let path = RPC.shell().estimate_gas_path();
+        assert_eq!("/shell/estimate_gas", path);
+
+        let path = RPC.shell().simulate_tx_path();
+        assert_eq!("/shell/simulate_tx", path);
+
+        let path = RPC.shell().base_gas_price_path();
+        assert_eq!("/shell/base_gas_price", path);
+
         let path = RPC.shell().storage_prefix_path(&key);
         assert_eq!(format!("/shell/prefix/{}", key), path);
 
         let path = RPC.shell().storage_has_key_path(&key);
         assert_eq!(format!("/shell/has_key/{}", key), path);
+
+        let path = RPC.shell().events_path(&BlockHeight(1), &BlockHeight(10));
+        assert_eq!("/shell/events/1/10", path);
+
+        let path = RPC.shell().subscribe_path(&BlockHeight(42));
+        assert_eq!("/shell/subscribe/42", path);
+
+        let tx_hash = Hash::sha256(b"tx");
+        let path = RPC.shell().storage_diff_path(&BlockHeight(42), &tx_hash);
+        assert_eq!(format!("/shell/storage_diff/42/{}", tx_hash), path);
     }
 }
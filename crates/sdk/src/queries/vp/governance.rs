@@ -1,8 +1,13 @@
 // cd namada && cargo expand ledger::queries::vp::governance
 
 use namada_governance::parameters::GovernanceParameters;
+use namada_governance::pgf::storage::is_steward;
 use namada_governance::storage::proposal::StorageProposal;
-use namada_governance::utils::{ProposalResult, Vote};
+use namada_governance::utils::{
+    compute_proposal_result, ProposalResult, ProposalTally, Vote,
+};
+use namada_proof_of_stake::queries::compute_proposal_votes;
+use namada_proof_of_stake::storage::{read_pos_params, read_total_stake};
 use namada_state::{DBIter, StorageHasher, DB};
 
 use crate::queries::types::RequestCtx;
@@ -11,6 +16,7 @@ use crate::queries::types::RequestCtx;
 router! {GOV,
     ( "proposal" / [id: u64 ] ) -> Option<StorageProposal> = proposal_id,
     ( "proposal" / [id: u64 ] / "votes" ) -> Vec<Vote> = proposal_id_votes,
+    ( "proposal" / [id: u64 ] / "tally" ) -> Option<ProposalTally> = proposal_tally,
     ( "parameters" ) -> GovernanceParameters = parameters,
     ( "stored_proposal_result" / [id: u64] ) -> Option<ProposalResult> = proposal_result,
 }
@@ -39,6 +45,43 @@ where
     namada_governance::storage::get_proposal_votes(ctx.state, id)
 }
 
+/// Compute the tally of the given proposal id from its current votes, with
+/// the voting power of the stake snapshot at the voting start epoch of the
+/// proposal
+fn proposal_tally<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<Option<ProposalTally>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let proposal =
+        match namada_governance::storage::get_proposal_by_id(ctx.state, id)? {
+            Some(proposal) => proposal,
+            None => return Ok(None),
+        };
+
+    let tally_epoch = proposal.voting_start_epoch;
+
+    let params = read_pos_params(ctx.state)?;
+    let votes = compute_proposal_votes(ctx.state, &params, id, tally_epoch)?;
+    let total_voting_power = read_total_stake(ctx.state, &params, tally_epoch)?;
+
+    let is_author_steward = is_steward(ctx.state, &proposal.author)?;
+    let tally_type = proposal.get_tally_type(is_author_steward);
+
+    let delegator_overrides = votes.delegator_overrides();
+    let result = compute_proposal_result(votes, total_voting_power, tally_type);
+
+    Ok(Some(ProposalTally {
+        tally_epoch,
+        turnout: result.turnout(),
+        result,
+        delegator_overrides,
+    }))
+}
+
 /// Get the governance parameters
 fn parameters<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -1,7 +1,9 @@
 use namada_core::address::Address;
 use namada_governance::pgf::parameters::PgfParameters;
 use namada_governance::pgf::storage::steward::StewardDetail;
-use namada_governance::storage::proposal::StoragePgfFunding;
+use namada_governance::storage::proposal::{
+    StoragePgfFunding, StoragePgfStream,
+};
 use namada_state::{DBIter, StorageHasher, DB};
 
 use crate::queries::types::RequestCtx;
@@ -11,6 +13,7 @@ router! {PGF,
     ( "stewards" / [ address: Address ] ) -> bool = is_steward,
     ( "stewards" ) -> Vec<StewardDetail> = stewards,
     ( "fundings" ) -> Vec<StoragePgfFunding> = funding,
+    ( "streams" ) -> Vec<StoragePgfStream> = streams,
     ( "parameters" ) -> PgfParameters = parameters,
 }
 
@@ -48,6 +51,17 @@ where
     namada_governance::pgf::storage::get_payments(ctx.state)
 }
 
+/// Query the pgf funding streams
+fn streams<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<Vec<StoragePgfStream>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::pgf::storage::get_streams(ctx.state)
+}
+
 /// Query the PGF parameters
 fn parameters<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
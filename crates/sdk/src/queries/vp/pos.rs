@@ -5,7 +5,7 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::address::Address;
 use namada_core::key::common;
-use namada_core::storage::Epoch;
+use namada_core::storage::{BlockHeight, Epoch};
 use namada_core::token;
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::queries::{
@@ -15,21 +15,25 @@ use namada_proof_of_stake::slashing::{
     find_all_enqueued_slashes, find_all_slashes,
 };
 use namada_proof_of_stake::storage::{
-    bond_handle, read_all_validator_addresses,
+    bond_handle, liveness_last_signed_heights_handle,
+    liveness_sum_missed_votes_handle, read_all_validator_addresses,
     read_below_capacity_validator_set_addresses_with_stake,
     read_consensus_validator_set_addresses_with_stake, read_pos_params,
-    read_total_stake, read_validator_avatar, read_validator_description,
-    read_validator_discord_handle, read_validator_email,
-    read_validator_last_slash_epoch, read_validator_max_commission_rate_change,
-    read_validator_stake, read_validator_website, unbond_handle,
-    validator_commission_rate_handle, validator_incoming_redelegations_handle,
+    read_reward_recipient, read_total_stake, read_validator_avatar,
+    read_validator_description, read_validator_discord_handle,
+    read_validator_email, read_validator_last_slash_epoch,
+    read_validator_max_commission_rate_change, read_validator_stake,
+    read_validator_website, unbond_handle, validator_commission_rate_handle,
+    validator_incoming_redelegations_handle, validator_jail_events_handle,
     validator_slashes_handle, validator_state_handle,
 };
 use namada_proof_of_stake::types::{
     BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, CommissionPair,
-    Slash, ValidatorMetaData, ValidatorState, WeightedValidator,
+    JailEvent, Slash, ValidatorMetaData, ValidatorState, WeightedValidator,
+};
+use namada_proof_of_stake::{
+    bond_amount, liveness_missed_votes_threshold, query_reward_tokens,
 };
-use namada_proof_of_stake::{bond_amount, query_reward_tokens};
 use namada_state::{DBIter, StorageHasher, DB};
 use namada_storage::collections::lazy_map;
 use namada_storage::OptionExt;
@@ -66,6 +70,12 @@ router! {POS,
 
         ( "last_infraction_epoch" / [validator: Address] )
             -> Option<Epoch> = validator_last_infraction_epoch,
+
+        ( "liveness" / [validator: Address] )
+            -> Option<ValidatorLiveness> = validator_liveness,
+
+        ( "jail_events" / [validator: Address] )
+            -> Vec<JailEvent> = validator_jail_events,
     },
 
     ( "validator_set" ) = {
@@ -96,7 +106,7 @@ router! {POS,
         -> token::Amount = bond,
 
     ( "rewards" / [validator: Address] / [source: opt Address] )
-        -> token::Amount = rewards,
+        -> ClaimableRewards = rewards,
 
     ( "bond_with_slashing" / [source: Address] / [validator: Address] / [epoch: opt Epoch] )
         -> token::Amount = bond_with_slashing,
@@ -134,6 +144,31 @@ router! {POS,
 
 }
 
+/// The rewards of a bond that are available for claim, together with the
+/// address they would be credited to.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ClaimableRewards {
+    /// The amount of reward tokens available for claim
+    pub amount: token::Amount,
+    /// The reward recipient of the bond source
+    pub recipient: Address,
+}
+
+/// The liveness of a validator within the current liveness window. The missed
+/// votes are only tracked while the validator is in the consensus set.
+#[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
+pub struct ValidatorLiveness {
+    /// Number of blocks missed by the validator within the window
+    pub missed_votes: u64,
+    /// Number of missed blocks within the window at which the validator gets
+    /// jailed
+    pub missed_votes_threshold: u64,
+    /// Length of the liveness window in blocks
+    pub window_len: u64,
+    /// Height of the last block signed by the validator, if any
+    pub last_signed_height: Option<BlockHeight>,
+}
+
 /// Enriched bonds data with extra information calculated from the data queried
 /// from the node.
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshSchema)]
@@ -328,6 +363,48 @@ where
     read_validator_last_slash_epoch(ctx.state, &validator)
 }
 
+/// Get the liveness of a validator within the current liveness window.
+/// Returns `None` when the given address is not a validator address.
+fn validator_liveness<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<Option<ValidatorLiveness>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if !namada_proof_of_stake::is_validator(ctx.state, &validator)? {
+        return Ok(None);
+    }
+    let params = read_pos_params(ctx.state)?;
+    let missed_votes = liveness_sum_missed_votes_handle()
+        .get(ctx.state, &validator)?
+        .unwrap_or_default();
+    let last_signed_height = liveness_last_signed_heights_handle()
+        .get(ctx.state, &validator)?
+        .map(BlockHeight);
+    Ok(Some(ValidatorLiveness {
+        missed_votes,
+        missed_votes_threshold: liveness_missed_votes_threshold(&params)?,
+        window_len: params.liveness_window_check,
+        last_signed_height,
+    }))
+}
+
+/// Get the history of a validator's jail events, oldest first
+fn validator_jail_events<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+) -> namada_storage::Result<Vec<JailEvent>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    validator_jail_events_handle(&validator)
+        .iter(ctx.state)?
+        .collect()
+}
+
 /// Get the total stake of a validator at the given epoch or current when
 /// `None`. The total stake is a sum of validator's self-bonds and delegations
 /// to their address.
@@ -542,13 +619,21 @@ fn rewards<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     validator: Address,
     source: Option<Address>,
-) -> namada_storage::Result<token::Amount>
+) -> namada_storage::Result<ClaimableRewards>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
 {
     let current_epoch = ctx.state.in_mem().last_epoch;
-    query_reward_tokens(ctx.state, source.as_ref(), &validator, current_epoch)
+    let source = source.unwrap_or_else(|| validator.clone());
+    let amount = query_reward_tokens(
+        ctx.state,
+        Some(&source),
+        &validator,
+        current_epoch,
+    )?;
+    let recipient = read_reward_recipient(ctx.state, &source)?;
+    Ok(ClaimableRewards { amount, recipient })
 }
 
 fn bonds_and_unbonds<D, H, V, T>(
@@ -6,10 +6,12 @@ use std::ops::ControlFlow;
 use std::str::FromStr;
 
 use borsh::BorshDeserialize;
+use borsh_ext::BorshSerializeExt;
+use futures::StreamExt;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_account::Account;
+use namada_account::{Account, VestingSchedule};
 use namada_core::address::{Address, InternalAddress};
 use namada_core::hash::Hash;
 use namada_core::key::common;
@@ -23,9 +25,9 @@ use namada_core::{storage, token};
 use namada_governance::parameters::GovernanceParameters;
 use namada_governance::pgf::parameters::PgfParameters;
 use namada_governance::pgf::storage::steward::StewardDetail;
-use namada_governance::storage::proposal::StorageProposal;
+use namada_governance::storage::proposal::{StoragePgfStream, StorageProposal};
 use namada_governance::utils::{
-    compute_proposal_result, ProposalResult, ProposalVotes, Vote,
+    compute_proposal_result, ProposalResult, ProposalTally, ProposalVotes, Vote,
 };
 use namada_ibc::storage::{
     ibc_denom_key, ibc_denom_key_prefix, is_ibc_denom_key,
@@ -33,10 +35,11 @@ use namada_ibc::storage::{
 use namada_parameters::{storage as params_storage, EpochDuration};
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, ValidatorMetaData, ValidatorState,
+    BondsAndUnbondsDetails, CommissionPair, JailEvent, ValidatorMetaData,
+    ValidatorState,
 };
 use namada_state::LastBlock;
-use namada_tx::data::{ResultCode, TxResult};
+use namada_tx::data::{ResultCode, StorageDiff, TxResult};
 use serde::Serialize;
 
 use crate::args::InputAmount;
@@ -46,8 +49,13 @@ use crate::events::Event;
 use crate::internal_macros::echo_error;
 use crate::io::Io;
 use crate::masp::MaspTokenRewardData;
-use crate::queries::vp::pos::EnrichedBondsAndUnbondsDetails;
-use crate::queries::{Client, RPC};
+use crate::queries::vp::pos::{
+    ClaimableRewards, EnrichedBondsAndUnbondsDetails, ValidatorLiveness,
+};
+use crate::queries::{
+    BlockEvent, Client, EventBatch, GasEstimate, SimulationRequest,
+    TxSimulation, RPC, SUBSCRIBE_ENDPOINT,
+};
 use crate::tendermint::block::Height;
 use crate::tendermint::merkle::proof::ProofOps;
 use crate::tendermint_rpc::error::Error as TError;
@@ -127,6 +135,14 @@ pub async fn query_native_token<C: crate::queries::Client + Sync>(
     convert_response::<C, _>(RPC.shell().native_token(client).await)
 }
 
+/// Query the base cost per unit of gas of every token allowed for fee payment.
+/// Txs paying a lower gas price are rejected.
+pub async fn query_base_gas_price<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<BTreeMap<Address, token::Amount>, error::Error> {
+    convert_response::<C, _>(RPC.shell().base_gas_price(client).await)
+}
+
 /// Query the epoch of the given block height, if it exists.
 /// Will return none if the input block height is greater than
 /// the latest committed block height.
@@ -249,6 +265,15 @@ pub async fn query_pgf_stewards<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Get the pgf funding streams
+pub async fn query_pgf_streams<C: crate::queries::Client + Sync>(
+    client: &C,
+) -> Result<Vec<StoragePgfStream>, error::Error> {
+    convert_response::<C, Vec<StoragePgfStream>>(
+        RPC.vp().pgf().streams(client).await,
+    )
+}
+
 /// Query the consensus key by validator address
 pub async fn query_validator_consensus_keys<
     C: crate::queries::Client + Sync,
@@ -518,6 +543,118 @@ pub async fn query_tx_events<C: crate::queries::Client + Sync>(
     }
 }
 
+/// Search the events emitted between the given block heights (inclusive)
+/// which match the given Tendermint query, e.g.
+/// `applied.hash='<hash>' AND applied.gas_used>1000`.
+pub async fn query_events<C: crate::queries::Client + Sync>(
+    client: &C,
+    query: &str,
+    from_height: BlockHeight,
+    to_height: BlockHeight,
+) -> Result<Vec<BlockEvent>, Error> {
+    let (data, height, prove) = (Some(query.as_bytes().to_vec()), None, false);
+    convert_response::<C, _>(
+        RPC.shell()
+            .events(client, data, height, prove, &from_height, &to_height)
+            .await,
+    )
+    .map(|response| response.data)
+}
+
+/// Poll the events matching the given query that were emitted at or past
+/// the `cursor` block height.
+///
+/// The returned batch always covers whole blocks, and holds the cursor from
+/// which polling should be resumed.
+pub async fn poll_events<C: crate::queries::Client + Sync>(
+    client: &C,
+    query: &str,
+    cursor: BlockHeight,
+) -> Result<EventBatch, Error> {
+    let (data, height, prove) = (Some(query.as_bytes().to_vec()), None, false);
+    convert_response::<C, _>(
+        RPC.shell()
+            .subscribe(client, data, height, prove, &cursor)
+            .await,
+    )
+    .map(|response| response.data)
+}
+
+/// Long-poll the events endpoint of a node, served at the given base URL,
+/// for the events matching the given query that were emitted at or past the
+/// `cursor` block height.
+///
+/// Once the cursor has caught up with the chain, the node holds the request
+/// until a new block is committed or the long-poll times out, in which case
+/// an empty batch is returned.
+pub async fn long_poll_events(
+    events_endpoint: &str,
+    query: &str,
+    cursor: BlockHeight,
+) -> Result<EventBatch, Error> {
+    let url = format!(
+        "{}/{SUBSCRIBE_ENDPOINT}/{cursor}",
+        events_endpoint.trim_end_matches('/')
+    );
+    let response = reqwest::Client::new()
+        .post(url)
+        .body(query.to_owned())
+        .send()
+        .await
+        .map_err(|err| Error::Query(QueryError::NoResponse(err.to_string())))?;
+    let status = response.status();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|err| Error::Query(QueryError::NoResponse(err.to_string())))?;
+    if !status.is_success() {
+        return Err(Error::Query(QueryError::General(
+            String::from_utf8_lossy(&body).into_owned(),
+        )));
+    }
+    EventBatch::try_from_slice(&body)
+        .map_err(|err| Error::Encode(EncodingError::Decoding(err.to_string())))
+}
+
+/// Subscribe to the events matching the given query, starting at the
+/// `from_height` block height, with the events endpoint of a node served at
+/// the given base URL.
+///
+/// The node is long-polled for new events, such that it only replies once it
+/// has committed a new block when the subscription has caught up with the
+/// chain. Failed polls are yielded as errors, and retried from the same
+/// cursor after `retry_interval`.
+pub fn subscribe_events<'a>(
+    events_endpoint: &'a str,
+    query: &'a str,
+    from_height: BlockHeight,
+    retry_interval: time::Duration,
+) -> impl futures::Stream<Item = Result<BlockEvent, Error>> + 'a {
+    futures::stream::unfold(
+        (from_height, false),
+        move |(cursor, should_retry)| async move {
+            if should_retry {
+                time::sleep(retry_interval).await;
+            }
+            let (items, state) =
+                match long_poll_events(events_endpoint, query, cursor).await {
+                    Ok(EventBatch {
+                        events,
+                        next_height,
+                        last_height: _,
+                    }) => {
+                        let items: Vec<_> =
+                            events.into_iter().map(Ok).collect();
+                        (items, (next_height, false))
+                    }
+                    Err(err) => (vec![Err(err)], (cursor, true)),
+                };
+            Some((futures::stream::iter(items), state))
+        },
+    )
+    .flatten()
+}
+
 /// Dry run a transaction
 pub async fn dry_run_tx<N: Namada>(
     context: &N,
@@ -547,6 +684,50 @@ pub async fn dry_run_tx<N: Namada>(
     Ok(result)
 }
 
+/// Dry run the given tx to estimate the gas it consumes, along with the
+/// breakdown of the gas by the kind of operations it was charged for.
+pub async fn estimate_gas<C: crate::queries::Client + Sync>(
+    client: &C,
+    tx_bytes: Vec<u8>,
+) -> Result<GasEstimate, Error> {
+    let (data, height, prove) = (Some(tx_bytes), None, false);
+    convert_response::<C, _>(
+        RPC.shell().estimate_gas(client, data, height, prove).await,
+    )
+    .map(|response| response.data)
+}
+
+/// Dry run the given tx on top of the given storage overrides, Borsh encoded,
+/// to get the values it changes and the events it emits along with its
+/// result. An override without a value deletes the key.
+pub async fn simulate_tx<C: crate::queries::Client + Sync>(
+    client: &C,
+    tx_bytes: Vec<u8>,
+    overrides: BTreeMap<storage::Key, Option<Vec<u8>>>,
+) -> Result<TxSimulation, Error> {
+    let request = SimulationRequest {
+        tx: tx_bytes,
+        overrides,
+    };
+    let (data, height, prove) = (Some(request.serialize_to_vec()), None, false);
+    convert_response::<C, _>(
+        RPC.shell().simulate_tx(client, data, height, prove).await,
+    )
+    .map(|response| response.data)
+}
+
+/// Query the storage values changed by the tx with the given hash applied at
+/// the given height. Only available from the nodes indexing the storage diffs.
+pub async fn query_storage_diff<C: crate::queries::Client + Sync>(
+    client: &C,
+    height: BlockHeight,
+    tx_hash: &Hash,
+) -> Result<Option<BTreeMap<storage::Key, StorageDiff>>, Error> {
+    convert_response::<C, _>(
+        RPC.shell().storage_diff(client, &height, tx_hash).await,
+    )
+}
+
 /// Data needed for broadcasting a tx and
 /// monitoring its progress on chain
 ///
@@ -795,6 +976,18 @@ pub async fn get_validator_stake<C: crate::queries::Client + Sync>(
     .map(|t| t.unwrap_or_default())
 }
 
+/// Query the rewards of a bond that are available for claim, together with
+/// the address they would be credited to
+pub async fn query_rewards<C: crate::queries::Client + Sync>(
+    client: &C,
+    source: &Option<Address>,
+    validator: &Address,
+) -> Result<ClaimableRewards, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().rewards(client, validator, source).await,
+    )
+}
+
 /// Query and return a validator's state
 pub async fn get_validator_state<C: crate::queries::Client + Sync>(
     client: &C,
@@ -917,6 +1110,29 @@ pub async fn query_last_infraction_epoch<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Query the liveness of a validator within the current liveness window
+pub async fn query_validator_liveness<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<Option<ValidatorLiveness>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().pos().validator_liveness(client, validator).await,
+    )
+}
+
+/// Query the history of a validator's jail events
+pub async fn query_validator_jail_events<C: crate::queries::Client + Sync>(
+    client: &C,
+    validator: &Address,
+) -> Result<Vec<JailEvent>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_jail_events(client, validator)
+            .await,
+    )
+}
+
 /// Query the accunt substorage space of an address
 pub async fn get_account_info<C: crate::queries::Client + Sync>(
     client: &C,
@@ -935,6 +1151,33 @@ pub async fn is_public_key_revealed<C: crate::queries::Client + Sync>(
     convert_response::<C, bool>(RPC.shell().revealed(client, owner).await)
 }
 
+/// Query the vesting schedule locking the native tokens of an account, if any
+pub async fn get_vesting_schedule<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Option<VestingSchedule>, Error> {
+    let key = namada_account::vesting_key(owner);
+    match query_storage_value::<C, VestingSchedule>(client, &key).await {
+        Ok(schedule) => Ok(Some(schedule)),
+        Err(Error::Query(QueryError::NoSuchKey(_))) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Query the next sequence number expected from an account in the wrappers it
+/// pays the fees of
+pub async fn get_next_sequence<C: crate::queries::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<u64, Error> {
+    let key = namada_account::sequence_key(owner);
+    match query_storage_value::<C, u64>(client, &key).await {
+        Ok(sequence) => Ok(sequence),
+        Err(Error::Query(QueryError::NoSuchKey(_))) => Ok(0),
+        Err(e) => Err(e),
+    }
+}
+
 /// Query an account substorage at a specific index
 pub async fn get_public_key_at<C: crate::queries::Client + Sync>(
     client: &C,
@@ -1123,6 +1366,17 @@ pub async fn query_proposal_votes<C: crate::queries::Client + Sync>(
     )
 }
 
+/// Get the tally of a proposal computed from its current votes, including the
+/// votes of the delegators that override the votes of their validators
+pub async fn query_proposal_tally<C: crate::queries::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<Option<ProposalTally>, error::Error> {
+    convert_response::<C, Option<ProposalTally>>(
+        RPC.vp().gov().proposal_tally(client, &proposal_id).await,
+    )
+}
+
 pub async fn query_next_epoch_info<C: crate::queries::Client + Sync>(
     client: &C,
 ) -> Result<(BlockHeight, EpochDuration), error::Error> {
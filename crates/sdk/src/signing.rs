@@ -2,7 +2,7 @@
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Display;
 
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use data_encoding::HEXLOWER;
 use itertools::Itertools;
@@ -11,7 +11,10 @@ use masp_primitives::transaction::components::sapling::fees::{
     InputView, OutputView,
 };
 use masp_primitives::transaction::Transaction;
-use namada_account::{AccountPublicKeysMap, InitAccount, UpdateAccount};
+use namada_account::{
+    AccountPublicKeysMap, FeeAllowance, GrantFeeAllowance, InitAccount,
+    UpdateAccount,
+};
 use namada_core::address::{Address, ImplicitAddress, InternalAddress, MASP};
 use namada_core::key::*;
 use namada_core::masp::{AssetData, ExtendedViewingKey, PaymentAddress};
@@ -27,10 +30,13 @@ use namada_governance::storage::proposal::{
 use namada_governance::storage::vote::ProposalVote;
 use namada_parameters::storage as parameter_storage;
 use namada_token::storage_key::balance_key;
-use namada_tx::data::pgf::UpdateStewardCommission;
+use namada_tx::data::pgf::{
+    CancelPgfStream, ClaimPgfStream, UpdateStewardCommission,
+};
 use namada_tx::data::pos::BecomeValidator;
-use namada_tx::data::{pos, Fee};
-use namada_tx::{MaspBuilder, Section, Tx};
+use namada_tx::data::{pos, Fee, GasLimit, TxType};
+use namada_tx::{MaspBuilder, Section, Signer, Tx};
+use namada_vote_ext::{bridge_pool_roots, ethereum_events};
 use prost::Message;
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
@@ -48,14 +54,16 @@ use crate::io::*;
 use crate::rpc::validate_amount;
 use crate::tx::{
     Commitment, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+    TX_CANCEL_PGF_STREAM, TX_CANCEL_UNBOND_WASM, TX_CHANGE_AUTO_COMPOUND_WASM,
     TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-    TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-    TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-    TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
-    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    TX_CHANGE_METADATA_WASM, TX_CHANGE_REWARD_RECIPIENT_WASM,
+    TX_CLAIM_PGF_STREAM, TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+    TX_GRANT_FEE_ALLOWANCE_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
+    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDEEM_SHARES_WASM,
+    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TOKENIZE_BOND_WASM,
+    TX_TRANSFER_WASM, TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM,
+    TX_UPDATE_ACCOUNT_WASM, TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL,
+    TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 pub use crate::wallet::store::AddressVpType;
 use crate::wallet::{Wallet, WalletIo};
@@ -166,12 +174,35 @@ pub async fn tx_signers(
 }
 
 /// The different parts of a transaction that can be signed
-#[derive(Eq, Hash, PartialEq)]
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, BorshSerialize, BorshDeserialize,
+)]
 pub enum Signable {
     FeeHeader,
     RawHeader,
 }
 
+impl Signable {
+    /// Build the signature section, yet without any signature, that commits
+    /// to this part of the given tx with the given public key. The tx must
+    /// have been filtered for the protocol to sign its fee header.
+    pub fn signature_section(
+        self,
+        tx: &Tx,
+        pubkey: &common::PublicKey,
+    ) -> namada_tx::Signature {
+        let targets = match self {
+            Self::RawHeader => vec![tx.raw_header_hash()],
+            Self::FeeHeader => tx.sechashes(),
+        };
+        namada_tx::Signature {
+            targets,
+            signer: Signer::PubKeys(vec![pubkey.clone()]),
+            signatures: BTreeMap::new(),
+        }
+    }
+}
+
 /// Causes sign_tx to attempt signing using only the software wallet
 pub async fn default_sign(
     _tx: Tx,
@@ -185,6 +216,137 @@ pub async fn default_sign(
     )))
 }
 
+/// A vote extension signed by a validator with its protocol key
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum ProtocolVext {
+    /// A vote extension over the Ethereum events seen by the validator
+    EthereumEvents(ethereum_events::Vext),
+    /// A vote extension over the latest Ethereum bridge pool root
+    BridgePoolRoot(bridge_pool_roots::Vext),
+}
+
+impl ProtocolVext {
+    /// Sign the vote extension with the given protocol key
+    pub fn sign(self, protocol_key: &common::SecretKey) -> common::Signature {
+        match self {
+            Self::EthereumEvents(ext) => ext.sign(protocol_key).sig,
+            Self::BridgePoolRoot(ext) => ext.sign(protocol_key).0.sig,
+        }
+    }
+}
+
+/// A request sent to a remote signer. The signer is handed the data to sign,
+/// rather than its hash, such that it can show what it signs and compute the
+/// signed hashes itself.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum SignerRequest {
+    /// Get the public keys whose secret keys are held by the signer
+    PublicKeys,
+    /// Sign the given part of a tx with the secret key of the given public
+    /// key
+    SignTx {
+        /// The public key of the secret key to sign with
+        pubkey: common::PublicKey,
+        /// The tx to sign, filtered for the protocol
+        tx: Box<Tx>,
+        /// The part of the tx to sign
+        part: Signable,
+    },
+    /// Sign the given vote extension with the protocol key of the given
+    /// public key
+    SignVext {
+        /// The public key of the protocol key to sign with
+        pubkey: common::PublicKey,
+        /// The vote extension to sign
+        vext: ProtocolVext,
+    },
+}
+
+/// A response from a remote signer
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub enum SignerResponse {
+    /// The public keys whose secret keys are held by the signer
+    PublicKeys(Vec<common::PublicKey>),
+    /// The signature over the requested data
+    Signature(common::Signature),
+    /// The request could not be served
+    Error(String),
+}
+
+/// A signer holding secret keys outside of the wallet, e.g. in a separate
+/// process or in an HSM
+#[cfg_attr(feature = "async-send", async_trait::async_trait)]
+#[cfg_attr(not(feature = "async-send"), async_trait::async_trait(?Send))]
+pub trait ExternalSigner {
+    /// Get the public keys whose secret keys are held by the signer
+    async fn public_keys(&self) -> Result<Vec<common::PublicKey>, Error>;
+
+    /// Sign the given part of the given tx with the secret key of the given
+    /// public key. The signature is made over the raw hash of the section
+    /// built by [`Signable::signature_section`].
+    async fn sign_tx(
+        &self,
+        pubkey: &common::PublicKey,
+        tx: &Tx,
+        part: Signable,
+    ) -> Result<common::Signature, Error>;
+}
+
+/// Causes sign_tx to attempt signing with the given external signer the keys
+/// that are not found in the software wallet
+pub async fn with_external_signer<S: ExternalSigner>(
+    mut tx: Tx,
+    pubkey: common::PublicKey,
+    parts: HashSet<Signable>,
+    signer: &S,
+) -> Result<Tx, Error> {
+    // The private sections must not leave the client
+    let filtered = tx.protocol_filter();
+    // Sign the raw header if that is requested
+    if parts.contains(&Signable::RawHeader) {
+        let section =
+            external_signature(signer, &pubkey, &tx, Signable::RawHeader)
+                .await?;
+        tx.add_section(Section::Signature(section));
+    }
+    // Sign the fee header if that is requested, otherwise restore the private
+    // sections that may still be needed
+    if parts.contains(&Signable::FeeHeader) {
+        let section =
+            external_signature(signer, &pubkey, &tx, Signable::FeeHeader)
+                .await?;
+        tx.add_section(Section::Signature(section));
+    } else {
+        for section in filtered {
+            tx.add_section(section);
+        }
+    }
+    Ok(tx)
+}
+
+/// Request a signature over the given part of a tx from an external signer
+/// and check it before wrapping it into a signature section
+async fn external_signature<S: ExternalSigner>(
+    signer: &S,
+    pubkey: &common::PublicKey,
+    tx: &Tx,
+    part: Signable,
+) -> Result<namada_tx::Signature, Error> {
+    let mut section = part.signature_section(tx, pubkey);
+    let hash = section.get_raw_hash();
+    let signature = signer.sign_tx(pubkey, tx, part).await?;
+    common::SigScheme::verify_signature(pubkey, &hash, &signature).map_err(
+        |err| {
+            Error::Other(format!(
+                "Invalid signature from the external signer for {}: {}",
+                pubkey, err
+            ))
+        },
+    )?;
+    section.signatures.insert(0, signature);
+    Ok(section)
+}
+
 /// Sign a transaction with a given signing key or public key of a given signer.
 /// If no explicit signer given, use the `default`. If no `default` is given,
 /// Error.
@@ -449,6 +611,14 @@ pub async fn validate_fee_and_gen_unshield<N: Namada>(
             }
         }
     };
+    // The gas price must also cover the base gas price, which moves from
+    // block to block with the demand for block space
+    let minimum_fee = match rpc::query_base_gas_price(context.client()).await {
+        Ok(base_gas_price) => base_gas_price
+            .get(&args.fee_token)
+            .map_or(minimum_fee, |base| minimum_fee.max(*base)),
+        Err(_) => minimum_fee,
+    };
     let validated_minimum_fee = context
         .denominate_amount(&args.fee_token, minimum_fee)
         .await;
@@ -478,7 +648,19 @@ pub async fn validate_fee_and_gen_unshield<N: Namada>(
         None => validated_minimum_fee,
     };
 
-    let balance_key = balance_key(&args.fee_token, &fee_payer_address);
+    if args.fee_granter.is_some() && args.fee_unshield.is_some() {
+        return Err(Error::Other(
+            "Fee unshielding cannot be combined with a fee allowance: please \
+             remove either the --gas-spending-key or the --gas-granter"
+                .to_string(),
+        ));
+    }
+    // Fees are paid by the granter, if any, against its allowance
+    let fee_source = args
+        .fee_granter
+        .clone()
+        .unwrap_or_else(|| fee_payer_address.clone());
+    let balance_key = balance_key(&args.fee_token, &fee_source);
     let balance = rpc::query_storage_value::<_, token::Amount>(
         context.client(),
         &balance_key,
@@ -486,13 +668,57 @@ pub async fn validate_fee_and_gen_unshield<N: Namada>(
     .await
     .unwrap_or_default();
 
-    let total_fee = fee_amount.amount() * u64::from(args.gas_limit);
+    // Without a gas limit, the funds for the fees can only be checked and the
+    // fee unshielding generated once the limit has been estimated from the
+    // built tx, see `tx::prepare_tx`
+    let Some(gas_limit) = args.gas_limit else {
+        let updated_balance = TxSourcePostBalance {
+            post_balance: balance,
+            source: fee_source,
+            token: args.fee_token.clone(),
+        };
+        return Ok((fee_amount, updated_balance, None));
+    };
+    let total_fee = fee_amount.amount() * u64::from(gas_limit);
+    if let Some(granter) = &args.fee_granter {
+        validate_fee_allowance(
+            context,
+            args,
+            granter,
+            &fee_payer_address,
+            total_fee,
+        )
+        .await?;
+    }
     let mut updated_balance = TxSourcePostBalance {
         post_balance: balance,
-        source: fee_payer_address.clone(),
+        source: fee_source,
         token: args.fee_token.clone(),
     };
+    let unshield = gen_fee_unshield(
+        context,
+        args,
+        &fee_payer_address,
+        total_fee,
+        &mut updated_balance,
+    )
+    .await?;
 
+    Ok((fee_amount, updated_balance, unshield))
+}
+
+/// Generate the fee unshielding transaction from the `--gas-spending-key`, if
+/// the balance of the fee source doesn't cover the given total fee, and update
+/// the post balance of the fee source accordingly
+pub async fn gen_fee_unshield<N: Namada>(
+    context: &N,
+    args: &args::Tx<SdkTypes>,
+    fee_payer_address: &Address,
+    total_fee: Amount,
+    updated_balance: &mut TxSourcePostBalance,
+) -> Result<Option<Transaction>, Error> {
+    let balance = updated_balance.post_balance;
+    let fee_source = updated_balance.source.clone();
     let unshield = match total_fee.checked_sub(balance) {
         Some(diff) if !diff.is_zero() => {
             if let Some(spending_key) = args.fee_unshield.clone() {
@@ -601,10 +827,7 @@ pub async fn validate_fee_and_gen_unshield<N: Namada>(
                         context.format_amount(&token_addr, balance).await;
                     return Err(Error::from(
                         TxSubmitError::BalanceTooLowForFees(
-                            fee_payer_address,
-                            token_addr,
-                            fee_amount,
-                            balance,
+                            fee_source, token_addr, fee_amount, balance,
                         ),
                     ));
                 }
@@ -626,7 +849,61 @@ pub async fn validate_fee_and_gen_unshield<N: Namada>(
         }
     };
 
-    Ok((fee_amount, updated_balance, unshield))
+    Ok(unshield)
+}
+
+/// Validate that the fee allowance granted to the fee payer by the given
+/// granter covers the fees of the transaction
+async fn validate_fee_allowance<N: Namada>(
+    context: &N,
+    args: &args::Tx<SdkTypes>,
+    granter: &Address,
+    grantee: &Address,
+    total_fee: Amount,
+) -> Result<(), Error> {
+    let allowance_key = namada_account::fee_grant_key(granter, grantee);
+    let allowance = match rpc::query_storage_value::<_, FeeAllowance>(
+        context.client(),
+        &allowance_key,
+    )
+    .await
+    {
+        Ok(allowance) => allowance,
+        Err(_) if args.force => return Ok(()),
+        Err(_) => {
+            return Err(Error::Other(format!(
+                "No fee allowance granted by {granter} to {grantee}"
+            )));
+        }
+    };
+    if args.force {
+        return Ok(());
+    }
+    if allowance.token != args.fee_token {
+        return Err(Error::Other(format!(
+            "The fee allowance granted by {granter} is in token {}, not in \
+             the gas token {}",
+            allowance.token, args.fee_token
+        )));
+    }
+    let epoch = rpc::query_epoch(context.client()).await?;
+    if allowance.is_expired(epoch) {
+        return Err(Error::Other(format!(
+            "The fee allowance granted by {granter} expired in epoch {}",
+            allowance.expiration.unwrap_or_default()
+        )));
+    }
+    if allowance.spend_limit < total_fee {
+        let spend_limit = context
+            .format_amount(&args.fee_token, allowance.spend_limit)
+            .await;
+        let total_fee = context.format_amount(&args.fee_token, total_fee).await;
+        return Err(Error::Other(format!(
+            "The fee allowance granted by {granter} of {spend_limit} is too \
+             low to pay fees of {total_fee}"
+        )));
+    }
+    Ok(())
 }
 
 /// Create a wrapper tx from a normal tx. Get the hash of the
@@ -640,6 +917,7 @@ pub async fn wrap_tx(
     unshield: Option<Transaction>,
     fee_amount: DenominatedAmount,
     fee_payer: common::PublicKey,
+    gas_limit: GasLimit,
 ) -> Result<(), Error> {
     let unshield_section_hash = unshield.map(|masp_tx| {
         let section = Section::MaspTx(masp_tx);
@@ -656,10 +934,13 @@ pub async fn wrap_tx(
         },
         fee_payer,
         epoch,
-        // TODO: partially validate the gas limit in client
-        args.gas_limit,
+        gas_limit,
         unshield_section_hash,
     );
+    if let TxType::Wrapper(wrapper) = &mut tx.header.tx_type {
+        wrapper.fee_granter.clone_from(&args.fee_granter);
+        wrapper.sequence = args.sequence;
+    }
 
     Ok(())
 }
@@ -905,6 +1186,21 @@ impl<'a> Display for LedgerProposalVote<'a> {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::NoWithVeto => write!(f, "no with veto"),
+            ProposalVote::Options(weights) => {
+                for (index, (option, weight)) in weights.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(
+                        f,
+                        "option {} ({})",
+                        option,
+                        to_ledger_decimal(&weight.to_string())
+                    )?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -929,6 +1225,7 @@ impl<'a> Display for LedgerProposalType<'a> {
             }
             ProposalType::PGFSteward(_) => write!(f, "PGF Steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF Payment"),
+            ProposalType::MultiOption { .. } => write!(f, "Multi-option"),
         }
     }
 }
@@ -1060,9 +1357,54 @@ fn proposal_type_to_ledger_vector(
                         output
                             .push(format!("Channel ID: {}", target.channel_id));
                     }
+                    PGFAction::Stream(stream) => {
+                        output.push("PGF Action : Add Stream".to_string());
+                        output.push(format!("Target: {}", stream.target));
+                        output.push(format!(
+                            "Amount per epoch: NAM {}",
+                            to_ledger_decimal(
+                                &stream.amount_per_epoch.to_string_native()
+                            )
+                        ));
+                        output.push(format!(
+                            "Cap: NAM {}",
+                            to_ledger_decimal(&stream.cap.to_string_native())
+                        ));
+                        output.push(format!(
+                            "Start epoch: {}",
+                            stream.start_epoch
+                        ));
+                        output.push(format!(
+                            "Cliff epoch: {}",
+                            stream.cliff_epoch
+                        ));
+                        output.push(format!("End epoch: {}", stream.end_epoch));
+                    }
+                    PGFAction::CancelStream(target) => {
+                        output.push("PGF Action : Cancel Stream".to_string());
+                        output.push(format!("Target: {}", target));
+                    }
                 }
             }
         }
+        ProposalType::MultiOption { code, options } => {
+            output.push("Proposal type : Multi-option".to_string());
+            if let Some(hash) = code {
+                let extra = tx
+                    .get_section(hash)
+                    .and_then(|x| Section::extra_data_sec(x.as_ref()))
+                    .expect("unable to load vp code")
+                    .code
+                    .hash();
+                output.push(format!(
+                    "Proposal hash : {}",
+                    HEXLOWER.encode(&extra.0)
+                ));
+            }
+            for (index, option) in options.iter().enumerate() {
+                output.push(format!("Option {} : {}", index, option));
+            }
+        }
     }
 }
 
@@ -1144,6 +1486,19 @@ pub async fn to_ledger_vector(
             format!("Threshold : {}", init_account.threshold),
             format!("VP type : {}", HEXLOWER.encode(&extra.code.hash().0)),
         ]);
+        if let Some(vesting) = init_account.vesting {
+            let vesting = vec![
+                format!(
+                    "Vesting amount : NAM {}",
+                    to_ledger_decimal(&vesting.amount.to_string_native())
+                ),
+                format!("Vesting start epoch : {}", vesting.start_epoch),
+                format!("Vesting cliff epoch : {}", vesting.cliff_epoch),
+                format!("Vesting end epoch : {}", vesting.end_epoch),
+            ];
+            tv.output.extend(vesting.clone());
+            tv.output_expert.extend(vesting);
+        }
     } else if code_sec.tag == Some(TX_BECOME_VALIDATOR_WASM.to_string()) {
         let init_validator = BecomeValidator::try_from_slice(
             &tx.data()
@@ -1242,6 +1597,9 @@ pub async fn to_ledger_vector(
             format!("Grace epoch : {}", init_proposal_data.grace_epoch),
             format!("Content : {}", HEXLOWER.encode(&extra.0)),
         ]);
+        if init_proposal_data.expedited {
+            tv.output.push("Expedited : true".to_string());
+        }
 
         tv.output_expert
             .push(format!("ID : {}", init_proposal_data.id));
@@ -1263,6 +1621,9 @@ pub async fn to_ledger_vector(
             format!("Grace epoch : {}", init_proposal_data.grace_epoch),
             format!("Content : {}", HEXLOWER.encode(&extra.0)),
         ]);
+        if init_proposal_data.expedited {
+            tv.output_expert.push("Expedited : true".to_string());
+        }
     } else if code_sec.tag == Some(TX_VOTE_PROPOSAL.to_string()) {
         let vote_proposal = VoteProposalData::try_from_slice(
             &tx.data()
@@ -1581,6 +1942,45 @@ pub async fn to_ledger_vector(
         }
         tv.output_expert
             .push(format!("Validator : {}", withdraw.validator));
+    } else if code_sec.tag == Some(TX_CANCEL_UNBOND_WASM.to_string()) {
+        let cancel_unbond = pos::CancelUnbond::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Cancel_Unbond_0".to_string();
+
+        tv.output.push("Type : Cancel Unbond".to_string());
+        if let Some(source) = cancel_unbond.source.as_ref() {
+            tv.output.push(format!("Source : {}", source));
+        }
+        tv.output.extend(vec![
+            format!("Validator : {}", cancel_unbond.validator),
+            format!("Withdraw epoch : {}", cancel_unbond.withdraw_epoch),
+        ]);
+        if let Some(amount) = cancel_unbond.amount {
+            tv.output.push(format!(
+                "Amount : NAM {}",
+                to_ledger_decimal(&amount.to_string_native())
+            ));
+        }
+
+        if let Some(source) = cancel_unbond.source.as_ref() {
+            tv.output_expert.push(format!("Source : {}", source));
+        }
+        tv.output_expert.extend(vec![
+            format!("Validator : {}", cancel_unbond.validator),
+            format!("Withdraw epoch : {}", cancel_unbond.withdraw_epoch),
+        ]);
+        if let Some(amount) = cancel_unbond.amount {
+            tv.output_expert.push(format!(
+                "Amount : NAM {}",
+                to_ledger_decimal(&amount.to_string_native())
+            ));
+        }
     } else if code_sec.tag == Some(TX_CLAIM_REWARDS_WASM.to_string()) {
         let claim = pos::Withdraw::try_from_slice(
             &tx.data()
@@ -1603,6 +2003,57 @@ pub async fn to_ledger_vector(
         }
         tv.output_expert
             .push(format!("Validator : {}", claim.validator));
+    } else if code_sec.tag == Some(TX_CHANGE_AUTO_COMPOUND_WASM.to_string()) {
+        let auto_compound = pos::AutoCompound::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Change_Auto_Compound_0".to_string();
+
+        tv.output.push("Type : Change Auto Compound".to_string());
+        if let Some(source) = auto_compound.source.as_ref() {
+            tv.output.push(format!("Source : {}", source));
+        }
+        tv.output
+            .push(format!("Validator : {}", auto_compound.validator));
+        tv.output
+            .push(format!("Enabled : {}", auto_compound.enabled));
+
+        if let Some(source) = auto_compound.source.as_ref() {
+            tv.output_expert.push(format!("Source : {}", source));
+        }
+        tv.output_expert
+            .push(format!("Validator : {}", auto_compound.validator));
+        tv.output_expert
+            .push(format!("Enabled : {}", auto_compound.enabled));
+    } else if code_sec.tag == Some(TX_CHANGE_REWARD_RECIPIENT_WASM.to_string())
+    {
+        let reward_recipient = pos::RewardRecipient::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Change_Reward_Recipient_0".to_string();
+
+        tv.output.push("Type : Change Reward Recipient".to_string());
+        tv.output
+            .push(format!("Source : {}", reward_recipient.source));
+        let recipient = reward_recipient
+            .recipient
+            .as_ref()
+            .unwrap_or(&reward_recipient.source);
+        tv.output.push(format!("Recipient : {}", recipient));
+
+        tv.output_expert
+            .push(format!("Source : {}", reward_recipient.source));
+        tv.output_expert.push(format!("Recipient : {}", recipient));
     } else if code_sec.tag == Some(TX_CHANGE_COMMISSION_WASM.to_string()) {
         let commission_change = pos::CommissionChange::try_from_slice(
             &tx.data()
@@ -1769,6 +2220,64 @@ pub async fn to_ledger_vector(
                 to_ledger_decimal(&redelegation.amount.to_string_native())
             ),
         ]);
+    } else if code_sec.tag == Some(TX_TOKENIZE_BOND_WASM.to_string()) {
+        let tokenize = pos::TokenizeBond::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Tokenize_Bond_0".to_string();
+
+        tv.output.extend(vec![
+            format!("Type : Tokenize Bond"),
+            format!("Validator : {}", tokenize.validator),
+            format!("Source : {}", tokenize.source),
+            format!(
+                "Amount : {}",
+                to_ledger_decimal(&tokenize.amount.to_string_native())
+            ),
+        ]);
+
+        tv.output_expert.extend(vec![
+            format!("Validator : {}", tokenize.validator),
+            format!("Source : {}", tokenize.source),
+            format!(
+                "Amount : {}",
+                to_ledger_decimal(&tokenize.amount.to_string_native())
+            ),
+        ]);
+    } else if code_sec.tag == Some(TX_REDEEM_SHARES_WASM.to_string()) {
+        let redeem = pos::RedeemShares::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Redeem_Shares_0".to_string();
+
+        tv.output.extend(vec![
+            format!("Type : Redeem Shares"),
+            format!("Validator : {}", redeem.validator),
+            format!("Owner : {}", redeem.owner),
+            format!(
+                "Amount : {}",
+                to_ledger_decimal(&redeem.amount.to_string_native())
+            ),
+        ]);
+
+        tv.output_expert.extend(vec![
+            format!("Validator : {}", redeem.validator),
+            format!("Owner : {}", redeem.owner),
+            format!(
+                "Amount : {}",
+                to_ledger_decimal(&redeem.amount.to_string_native())
+            ),
+        ]);
     } else if code_sec.tag == Some(TX_UPDATE_STEWARD_COMMISSION.to_string()) {
         let update = UpdateStewardCommission::try_from_slice(
             &tx.data()
@@ -1814,6 +2323,83 @@ pub async fn to_ledger_vector(
         ]);
 
         tv.output_expert.push(format!("Steward : {}", address));
+    } else if code_sec.tag == Some(TX_CLAIM_PGF_STREAM.to_string()) {
+        let claim = ClaimPgfStream::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Claim_Pgf_Stream_0".to_string();
+
+        tv.output.extend(vec![
+            format!("Type : Claim Pgf Stream"),
+            format!("Target : {}", claim.target),
+        ]);
+
+        tv.output_expert.push(format!("Target : {}", claim.target));
+    } else if code_sec.tag == Some(TX_CANCEL_PGF_STREAM.to_string()) {
+        let cancel = CancelPgfStream::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Cancel_Pgf_Stream_0".to_string();
+
+        tv.output.extend(vec![
+            format!("Type : Cancel Pgf Stream"),
+            format!("Steward : {}", cancel.steward),
+            format!("Target : {}", cancel.target),
+        ]);
+
+        tv.output_expert.extend(vec![
+            format!("Steward : {}", cancel.steward),
+            format!("Target : {}", cancel.target),
+        ]);
+    } else if code_sec.tag == Some(TX_GRANT_FEE_ALLOWANCE_WASM.to_string()) {
+        let grant = GrantFeeAllowance::try_from_slice(
+            &tx.data()
+                .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+        )
+        .map_err(|err| {
+            Error::from(EncodingError::Conversion(err.to_string()))
+        })?;
+
+        tv.name = "Grant_Fee_Allowance_0".to_string();
+
+        let mut lines = vec![
+            format!("Granter : {}", grant.granter),
+            format!("Grantee : {}", grant.grantee),
+        ];
+        match &grant.allowance {
+            Some(allowance) => {
+                lines.extend(vec![
+                    format!("Token : {}", allowance.token),
+                    format!(
+                        "Spend limit : {}",
+                        to_ledger_decimal(
+                            &allowance.spend_limit.to_string_native()
+                        )
+                    ),
+                ]);
+                if let Some(expiration) = allowance.expiration {
+                    lines.push(format!("Expiration : {}", expiration));
+                }
+                for code_hash in allowance.allowed_txs.iter().flatten() {
+                    lines.push(format!("Allowed tx : {}", code_hash));
+                }
+            }
+            None => lines.push("Revoke : true".to_string()),
+        }
+
+        tv.output.push("Type : Grant Fee Allowance".to_string());
+        tv.output.extend(lines.clone());
+        tv.output_expert.extend(lines);
     } else if code_sec.tag == Some(TX_BRIDGE_POOL_WASM.to_string()) {
         let transfer = PendingTransfer::try_from_slice(
             &tx.data()
@@ -1885,6 +2471,12 @@ pub async fn to_ledger_vector(
             format!("Epoch : {}", wrapper.epoch),
             format!("Gas limit : {}", u64::from(wrapper.gas_limit)),
         ]);
+        if let Some(granter) = &wrapper.fee_granter {
+            tv.output_expert.push(format!("Fee granter : {}", granter));
+        }
+        if let Some(sequence) = wrapper.sequence {
+            tv.output_expert.push(format!("Sequence : {}", sequence));
+        }
         if let Some(token) = tokens.get(&wrapper.fee.token) {
             tv.output_expert.push(format!(
                 "Fees/gas unit : {} {}",
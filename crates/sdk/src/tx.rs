@@ -18,7 +18,9 @@ use masp_primitives::transaction::components::transparent::fees::{
     InputView as TransparentInputView, OutputView as TransparentOutputView,
 };
 use masp_primitives::transaction::components::I128Sum;
-use namada_account::{InitAccount, UpdateAccount};
+use namada_account::{
+    FeeAllowance, GrantFeeAllowance, InitAccount, UpdateAccount,
+};
 use namada_core::address::{Address, InternalAddress, MASP};
 use namada_core::dec::Dec;
 use namada_core::hash::Hash;
@@ -35,6 +37,7 @@ use namada_core::masp::{AssetData, TransferSource, TransferTarget};
 use namada_core::storage::Epoch;
 use namada_core::time::DateTimeUtc;
 use namada_core::{storage, token};
+use namada_gas::{Gas, TxGasMeter};
 use namada_governance::cli::onchain::{
     DefaultProposal, OnChainProposal, PgfFundingProposal, PgfStewardProposal,
 };
@@ -44,13 +47,17 @@ use namada_governance::storage::proposal::{
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::storage::channel_key;
+use namada_proof_of_stake::liquid_staking::share_token;
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::types::{CommissionPair, ValidatorState};
 use namada_token::storage_key::balance_key;
 use namada_token::DenominatedAmount;
-use namada_tx::data::pgf::UpdateStewardCommission;
-use namada_tx::data::{pos, ResultCode, TxResult};
+use namada_tx::data::pgf::{
+    CancelPgfStream, ClaimPgfStream, UpdateStewardCommission,
+};
+use namada_tx::data::{pos, GasLimit, ResultCode, TxResult, TxType};
 pub use namada_tx::{Signature, *};
+use rand_core::OsRng;
 
 use crate::args::{self, InputAmount};
 use crate::control_flow::time;
@@ -63,10 +70,12 @@ use crate::rpc::{
     self, query_wasm_code_hash, validate_amount, InnerTxResult,
     TxBroadcastData, TxResponse,
 };
-use crate::signing::{self, validate_fee_and_gen_unshield, SigningTxData};
+use crate::signing::{
+    self, validate_fee_and_gen_unshield, SigningTxData, TxSourcePostBalance,
+};
 use crate::tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use crate::tendermint_rpc::error::Error as RpcError;
-use crate::wallet::WalletIo;
+use crate::wallet::{gen_secret_key, WalletIo};
 use crate::{display_line, edisplay_line, Namada};
 
 /// Initialize account transaction WASM
@@ -93,14 +102,23 @@ pub const TX_TRANSFER_WASM: &str = "tx_transfer.wasm";
 pub const TX_IBC_WASM: &str = "tx_ibc.wasm";
 /// User validity predicate WASM path
 pub const VP_USER_WASM: &str = "vp_user.wasm";
+/// Vesting account validity predicate WASM path
+pub const VP_VESTING_WASM: &str = "vp_vesting.wasm";
 /// Bond WASM path
 pub const TX_BOND_WASM: &str = "tx_bond.wasm";
 /// Unbond WASM path
 pub const TX_UNBOND_WASM: &str = "tx_unbond.wasm";
 /// Withdraw WASM path
 pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
+/// Cancel unbond WASM path
+pub const TX_CANCEL_UNBOND_WASM: &str = "tx_cancel_unbond.wasm";
 /// Claim-rewards WASM path
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
+/// Change rewards auto-compounding WASM path
+pub const TX_CHANGE_AUTO_COMPOUND_WASM: &str = "tx_change_auto_compound.wasm";
+/// Change reward recipient WASM path
+pub const TX_CHANGE_REWARD_RECIPIENT_WASM: &str =
+    "tx_change_reward_recipient.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
 /// Change commission WASM path
@@ -115,8 +133,18 @@ pub const TX_RESIGN_STEWARD: &str = "tx_resign_steward.wasm";
 /// Update steward commission WASM path
 pub const TX_UPDATE_STEWARD_COMMISSION: &str =
     "tx_update_steward_commission.wasm";
+/// Claim pgf stream WASM path
+pub const TX_CLAIM_PGF_STREAM: &str = "tx_claim_pgf_stream.wasm";
+/// Cancel pgf stream WASM path
+pub const TX_CANCEL_PGF_STREAM: &str = "tx_cancel_pgf_stream.wasm";
+/// Grant fee allowance WASM path
+pub const TX_GRANT_FEE_ALLOWANCE_WASM: &str = "tx_grant_fee_allowance.wasm";
 /// Redelegate transaction WASM path
 pub const TX_REDELEGATE_WASM: &str = "tx_redelegate.wasm";
+/// Tokenize bond transaction WASM path
+pub const TX_TOKENIZE_BOND_WASM: &str = "tx_tokenize_bond.wasm";
+/// Redeem share tokens transaction WASM path
+pub const TX_REDEEM_SHARES_WASM: &str = "tx_redeem_shares.wasm";
 
 /// Default timeout in seconds for requests to the `/accepted`
 /// and `/applied` ABCI query endpoints.
@@ -182,23 +210,244 @@ pub fn dump_tx<IO: Io>(io: &IO, args: &args::Tx, tx: Tx) {
 /// Prepare a transaction for signing and submission by adding a wrapper header
 /// to it.
 #[allow(clippy::too_many_arguments)]
-pub async fn prepare_tx<C: crate::queries::Client + Sync>(
-    client: &C,
+pub async fn prepare_tx<N: Namada>(
+    context: &N,
     args: &args::Tx,
     tx: &mut Tx,
     unshield: Option<masp_primitives::transaction::Transaction>,
     fee_amount: DenominatedAmount,
-    fee_payer: common::PublicKey,
+    signing_data: &SigningTxData,
 ) -> Result<()> {
     if !args.dry_run {
-        let epoch = rpc::query_epoch(client).await?;
+        let fee_payer = signing_data.fee_payer.clone();
+        let epoch = rpc::query_epoch(context.client()).await?;
+        let (gas_limit, unshield) = match args.gas_limit {
+            Some(gas_limit) => (gas_limit, unshield),
+            None => {
+                let gas_limit = estimate_gas_limit(
+                    context.client(),
+                    args,
+                    tx,
+                    signing_data,
+                )
+                .await?;
+                if args.fee_unshield.is_some() {
+                    estimate_fee_unshield(
+                        context, args, &fee_payer, fee_amount, gas_limit,
+                    )
+                    .await?
+                } else {
+                    validate_fee_funds(
+                        context.client(),
+                        args,
+                        &fee_payer,
+                        fee_amount,
+                        gas_limit,
+                    )
+                    .await?;
+                    (gas_limit, unshield)
+                }
+            }
+        };
 
-        signing::wrap_tx(tx, args, epoch, unshield, fee_amount, fee_payer).await
+        signing::wrap_tx(
+            tx, args, epoch, unshield, fee_amount, fee_payer, gas_limit,
+        )
+        .await
     } else {
         Ok(())
     }
 }
 
+/// Estimate the gas limit of the given unwrapped tx, to be signed with the
+/// given signing data, by dry running it. The estimated gas is scaled by the
+/// gas limit multiplier of the arguments to leave a safety margin.
+pub async fn estimate_gas_limit<C: crate::queries::Client + Sync>(
+    client: &C,
+    args: &args::Tx,
+    tx: &Tx,
+    signing_data: &SigningTxData,
+) -> Result<GasLimit> {
+    if args.gas_limit_multiplier < Dec::one() {
+        return Err(Error::Other(format!(
+            "The gas limit multiplier must be at least 1, got {}",
+            args.gas_limit_multiplier
+        )));
+    }
+    let tx = with_placeholder_signatures(tx, signing_data);
+    let estimate = rpc::estimate_gas(client, tx.to_bytes()).await?;
+    let vps_result = &estimate.tx_result.vps_result;
+    if !estimate.tx_result.is_accepted() {
+        let rejected_vps = vps_result
+            .rejected_vps
+            .iter()
+            .map(Address::encode)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let errors = vps_result
+            .errors
+            .iter()
+            .map(|(addr, err)| format!("{addr}: {err}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(Error::Other(format!(
+            "The gas limit cannot be estimated, the dry run of the tx was \
+             rejected by the VPs of {rejected_vps}. Errors: [{errors}]"
+        )));
+    }
+    scale_gas(args, u64::from(estimate.gas_used))
+}
+
+/// Scale the given gas, in sub-units, by the gas limit multiplier of the
+/// arguments and round it up to whole gas units
+fn scale_gas(args: &args::Tx, gas: u64) -> Result<GasLimit> {
+    let gas = (args.gas_limit_multiplier * gas)
+        .to_uint()
+        .and_then(|gas| u64::try_from(gas).ok())
+        .ok_or_else(|| {
+            Error::Other("Overflow in the estimated gas limit".to_string())
+        })?;
+    Ok(GasLimit::from(Gas::from(gas).get_whole_gas_units()))
+}
+
+/// Generate the fee unshielding of a tx whose gas limit was estimated without
+/// it, if the balance of the fee payer doesn't cover the fees. The unshielding
+/// section enlarges the wrapper, so the gas limit is raised by the gas of its
+/// bytes and the unshielding regenerated to cover the raised fees.
+async fn estimate_fee_unshield<N: Namada>(
+    context: &N,
+    args: &args::Tx,
+    fee_payer: &common::PublicKey,
+    fee_amount: DenominatedAmount,
+    gas_limit: GasLimit,
+) -> Result<(GasLimit, Option<masp_primitives::transaction::Transaction>)> {
+    let fee_payer_address = Address::from(fee_payer);
+    let balance = rpc::get_token_balance(
+        context.client(),
+        &args.fee_token,
+        &fee_payer_address,
+    )
+    .await?;
+    let mut updated_balance = TxSourcePostBalance {
+        post_balance: balance,
+        source: fee_payer_address.clone(),
+        token: args.fee_token.clone(),
+    };
+    let total_fee = fee_amount.amount() * u64::from(gas_limit);
+    let Some(unshield) = signing::gen_fee_unshield(
+        context,
+        args,
+        &fee_payer_address,
+        total_fee,
+        &mut updated_balance,
+    )
+    .await?
+    else {
+        // The balance of the fee payer covers the fees
+        return Ok((gas_limit, None));
+    };
+
+    let unshield_len = u64::try_from(unshield.serialize_to_vec().len())
+        .expect("The length of the unshielding should fit in u64");
+    let unshield_gas = TxGasMeter::tx_bytes_gas(unshield_len)
+        .map_err(|err| Error::Other(err.to_string()))?;
+    let raised_gas_limit = u64::from(gas_limit)
+        .checked_add(u64::from(scale_gas(args, unshield_gas)?))
+        .map(GasLimit::from)
+        .ok_or_else(|| {
+            Error::Other("Overflow in the estimated gas limit".to_string())
+        })?;
+    let raised_fee = fee_amount.amount()
+        * (u64::from(raised_gas_limit) - u64::from(gas_limit));
+    if raised_fee <= updated_balance.post_balance {
+        return Ok((raised_gas_limit, Some(unshield)));
+    }
+    let mut updated_balance = TxSourcePostBalance {
+        post_balance: balance,
+        source: fee_payer_address.clone(),
+        token: args.fee_token.clone(),
+    };
+    let unshield = signing::gen_fee_unshield(
+        context,
+        args,
+        &fee_payer_address,
+        fee_amount.amount() * u64::from(raised_gas_limit),
+        &mut updated_balance,
+    )
+    .await?;
+    Ok((raised_gas_limit, unshield))
+}
+
+/// Add to a copy of the given tx placeholder signatures of the same count and
+/// size as the ones it carries once signed with the given signing data, such
+/// that its dry run accounts for their size. The VPs only meter the gas of
+/// checking the signatures of a tx dry run to estimate its gas, so the
+/// placeholders don't get it rejected.
+fn with_placeholder_signatures(tx: &Tx, signing_data: &SigningTxData) -> Tx {
+    let placeholder = |pubkey: &common::PublicKey, hash: Hash| {
+        let scheme = match pubkey {
+            common::PublicKey::Ed25519(_) => SchemeType::Ed25519,
+            common::PublicKey::Secp256k1(_) => SchemeType::Secp256k1,
+        };
+        common::SigScheme::sign(&gen_secret_key(scheme, &mut OsRng), hash)
+    };
+    let mut tx = tx.clone();
+    if !signing_data.public_keys.is_empty() {
+        let raw_header_hash = tx.raw_header_hash();
+        let signer = match &signing_data.owner {
+            Some(owner) => Signer::Address(owner.clone()),
+            None => Signer::PubKeys(signing_data.public_keys.clone()),
+        };
+        let signatures = (0..)
+            .zip(&signing_data.public_keys)
+            .map(|(idx, pubkey)| (idx, placeholder(pubkey, raw_header_hash)))
+            .collect();
+        tx.add_section(Section::Signature(Signature {
+            targets: vec![raw_header_hash],
+            signer,
+            signatures,
+        }));
+    }
+    let signature = placeholder(&signing_data.fee_payer, tx.header_hash());
+    tx.add_section(Section::Signature(Signature {
+        targets: tx.sechashes(),
+        signer: Signer::PubKeys(vec![signing_data.fee_payer.clone()]),
+        signatures: [(0, signature)].into(),
+    }));
+    tx
+}
+
+/// Check that the fee source can pay for the fees of a tx with the given gas
+/// limit. Used when the gas limit was not known while validating the fees.
+async fn validate_fee_funds<C: crate::queries::Client + Sync>(
+    client: &C,
+    args: &args::Tx,
+    fee_payer: &common::PublicKey,
+    fee_amount: DenominatedAmount,
+    gas_limit: GasLimit,
+) -> Result<()> {
+    if args.force {
+        return Ok(());
+    }
+    let fee_source = args
+        .fee_granter
+        .clone()
+        .unwrap_or_else(|| Address::from(fee_payer));
+    let balance =
+        rpc::get_token_balance(client, &args.fee_token, &fee_source).await?;
+    let total_fee = fee_amount.amount() * u64::from(gas_limit);
+    if balance < total_fee {
+        let denom = fee_amount.denom();
+        return Err(Error::from(TxSubmitError::BalanceTooLowForFees(
+            fee_source,
+            args.fee_token.clone(),
+            DenominatedAmount::new(total_fee, denom).to_string(),
+            DenominatedAmount::new(balance, denom).to_string(),
+        )));
+    }
+    Ok(())
+}
+
 /// Submit transaction and wait for result. Returns a list of addresses
 /// initialized in the transaction if any. In dry run, this is always empty.
 pub async fn process_tx(
@@ -299,7 +548,7 @@ pub async fn build_reveal_pk(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -677,7 +926,7 @@ pub async fn build_validator_commission_change(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -817,7 +1066,7 @@ pub async fn build_validator_metadata_change(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -889,7 +1138,7 @@ pub async fn build_update_steward_commission(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -940,8 +1189,159 @@ pub async fn build_resign_steward(
         do_nothing,
         unshield,
         fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit transaction to claim the vested funds of a pgf stream
+pub async fn build_claim_pgf_stream(
+    context: &impl Namada,
+    args::ClaimPgfStream {
+        tx: tx_args,
+        target,
+        tx_code_path,
+    }: &args::ClaimPgfStream,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(target.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(target.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
+        &signing_data.fee_payer,
+    )
+    .await?;
+
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    let stream = rpc::query_pgf_streams(context.client())
+        .await?
+        .into_iter()
+        .find(|stream| &stream.detail.target == target);
+    match stream {
+        Some(stream) if stream.claimable(current_epoch).is_zero() => {
+            edisplay_line!(
+                context.io(),
+                "The pgf stream to {} has no funds to claim at epoch {}.",
+                target,
+                current_epoch
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::NoPgfStreamFunds(
+                    target.clone(),
+                    current_epoch,
+                )));
+            }
+        }
+        Some(_) => {}
+        None => {
+            edisplay_line!(
+                context.io(),
+                "The address {} is not the target of a pgf stream.",
+                target
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::InvalidPgfStream(
+                    target.clone(),
+                )));
+            }
+        }
+    }
+
+    let data = ClaimPgfStream {
+        target: target.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit transaction for a pgf steward to cancel a pgf stream
+pub async fn build_cancel_pgf_stream(
+    context: &impl Namada,
+    args::CancelPgfStream {
+        tx: tx_args,
+        steward,
+        target,
+        tx_code_path,
+    }: &args::CancelPgfStream,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(steward.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(steward.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
         &signing_data.fee_payer,
     )
+    .await?;
+
+    if !rpc::is_steward(context.client(), steward).await {
+        edisplay_line!(
+            context.io(),
+            "The given address {} is not a steward.",
+            &steward
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidSteward(
+                steward.clone(),
+            )));
+        }
+    };
+
+    let is_stream_target = rpc::query_pgf_streams(context.client())
+        .await?
+        .iter()
+        .any(|stream| &stream.detail.target == target);
+    if !is_stream_target {
+        edisplay_line!(
+            context.io(),
+            "The address {} is not the target of a pgf stream.",
+            target
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidPgfStream(
+                target.clone(),
+            )));
+        }
+    }
+
+    let data = CancelPgfStream {
+        steward: steward.clone(),
+        target: target.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
     .await
     .map(|tx| (tx, signing_data))
 }
@@ -1047,7 +1447,7 @@ pub async fn build_unjail_validator(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1125,7 +1525,7 @@ pub async fn build_deactivate_validator(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1202,7 +1602,7 @@ pub async fn build_reactivate_validator(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1393,7 +1793,155 @@ pub async fn build_redelegation(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Tokenize bonded tokens into the share token of the validator
+pub async fn build_tokenize_bond(
+    context: &impl Namada,
+    args::TokenizeBond {
+        tx: tx_args,
+        validator,
+        source,
+        amount,
+        tx_code_path,
+    }: &args::TokenizeBond,
+) -> Result<(Tx, SigningTxData)> {
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+    let source =
+        source_exists_or_err(source.clone(), tx_args.force, context).await?;
+
+    // There must be at least as many tokens in the bond as the requested
+    // amount
+    let bond_amount =
+        rpc::query_bond(context.client(), &source, &validator, None).await?;
+    if *amount > bond_amount {
+        edisplay_line!(
+            context.io(),
+            "There are not enough bonded tokens to tokenize. Requested to \
+             tokenize {} tokens but only {} tokens are bonded.",
+            amount.to_string_native(),
+            bond_amount.to_string_native()
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::TokenizeAmountTooLarge(
+                amount.to_string_native(),
+                bond_amount.to_string_native(),
+            )));
+        }
+    }
+
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
+        &signing_data.fee_payer,
+    )
+    .await?;
+
+    let data = pos::TokenizeBond {
+        validator,
+        source,
+        amount: *amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Redeem share tokens of a validator back into a bond
+pub async fn build_redeem_shares(
+    context: &impl Namada,
+    args::RedeemShares {
+        tx: tx_args,
+        validator,
+        owner,
+        amount,
+        tx_code_path,
+    }: &args::RedeemShares,
+) -> Result<(Tx, SigningTxData)> {
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+    let owner =
+        source_exists_or_err(owner.clone(), tx_args.force, context).await?;
+
+    // The owner must hold enough share tokens
+    let share_token = share_token(&validator).ok_or_else(|| {
+        Error::from(TxSubmitError::InvalidValidatorAddress(validator.clone()))
+    })?;
+    let balance =
+        rpc::get_token_balance(context.client(), &share_token, &owner).await?;
+    if *amount > balance {
+        edisplay_line!(
+            context.io(),
+            "The owner {} holds {} share tokens of the validator {}, less \
+             than the requested {}.",
+            owner,
+            balance.to_string_native(),
+            validator,
+            amount.to_string_native()
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::RedeemAmountTooLarge(
+                amount.to_string_native(),
+                balance.to_string_native(),
+            )));
+        }
+    }
+
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
+        &signing_data.fee_payer,
+    )
+    .await?;
+
+    let data = pos::RedeemShares {
+        validator,
+        owner,
+        amount: *amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1483,8 +2031,116 @@ pub async fn build_withdraw(
         do_nothing,
         unshield,
         fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit transaction to cancel an unbond that is not yet withdrawable
+pub async fn build_cancel_unbond(
+    context: &impl Namada,
+    args::CancelUnbond {
+        tx: tx_args,
+        validator,
+        source,
+        withdraw_epoch,
+        amount,
+        tx_code_path,
+    }: &args::CancelUnbond,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(default_address),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
         &signing_data.fee_payer,
     )
+    .await?;
+
+    let epoch = rpc::query_epoch(context.client()).await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Check that the source address exists on chain
+    let source = match source.clone() {
+        Some(source) => source_exists_or_err(source, tx_args.force, context)
+            .await
+            .map(Some),
+        None => Ok(source.clone()),
+    }?;
+
+    // Check the source's unbond that becomes withdrawable at the given epoch
+    let bond_source = source.clone().unwrap_or_else(|| validator.clone());
+    let unbonded: token::Amount = rpc::query_unbond_with_slashing(
+        context.client(),
+        &bond_source,
+        &validator,
+    )
+    .await?
+    .into_iter()
+    .filter_map(|((_start_epoch, unbond_withdraw_epoch), amount)| {
+        (unbond_withdraw_epoch == *withdraw_epoch).then_some(amount)
+    })
+    .sum();
+
+    if unbonded.is_zero() || *withdraw_epoch <= epoch {
+        edisplay_line!(
+            context.io(),
+            "There is no unbond withdrawable at epoch {} that can still be \
+             cancelled in the current epoch {}.",
+            withdraw_epoch,
+            epoch
+        );
+        rpc::query_and_print_unbonds(context, &bond_source, &validator).await?;
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::NoCancellableUnbond(
+                *withdraw_epoch,
+            )));
+        }
+    } else if let Some(amount) = amount.filter(|amount| *amount > unbonded) {
+        edisplay_line!(
+            context.io(),
+            "The unbond amount {} is lower than the amount to cancel {}.",
+            unbonded.to_string_native(),
+            amount.to_string_native()
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::LowerUnbondThanCancel(
+                bond_source,
+                amount.to_string_native(),
+                unbonded.to_string_native(),
+            )));
+        }
+    }
+
+    let data = pos::CancelUnbond {
+        validator,
+        source,
+        withdraw_epoch: *withdraw_epoch,
+        amount: *amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
     .await
     .map(|tx| (tx, signing_data))
 }
@@ -1498,6 +2154,66 @@ pub async fn build_claim_rewards(
         source,
         tx_code_path,
     }: &args::ClaimRewards,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    // The rewards of a source that has set another reward recipient can be
+    // claimed by anyone, as they can only be credited to that recipient, so
+    // the tx doesn't have to be authorized by the source
+    let recipient = rpc::query_rewards(context.client(), source, validator)
+        .await?
+        .recipient;
+    let owner = (recipient == default_address).then_some(default_address);
+    let signing_data =
+        signing::aux_signing_data(context, tx_args, owner, default_signer)
+            .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
+        &signing_data.fee_payer,
+    )
+    .await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Check that the source address exists on chain
+    let source = match source.clone() {
+        Some(source) => source_exists_or_err(source, tx_args.force, context)
+            .await
+            .map(Some),
+        None => Ok(source.clone()),
+    }?;
+
+    let data = pos::ClaimRewards { validator, source };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to enable or disable the auto-compounding of the
+/// rewards of a bond
+pub async fn build_change_auto_compound(
+    context: &impl Namada,
+    args::ChangeAutoCompound {
+        tx: tx_args,
+        validator,
+        source,
+        enabled,
+        tx_code_path,
+    }: &args::ChangeAutoCompound,
 ) -> Result<(Tx, SigningTxData)> {
     let default_address = source.clone().unwrap_or(validator.clone());
     let default_signer = Some(default_address.clone());
@@ -1528,7 +2244,11 @@ pub async fn build_claim_rewards(
         None => Ok(source.clone()),
     }?;
 
-    let data = pos::ClaimRewards { validator, source };
+    let data = pos::AutoCompound {
+        validator,
+        source,
+        enabled: *enabled,
+    };
 
     build(
         context,
@@ -1538,8 +2258,62 @@ pub async fn build_claim_rewards(
         do_nothing,
         unshield,
         fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to change the address credited with the claimed
+/// rewards of the bonds of a source
+pub async fn build_change_reward_recipient(
+    context: &impl Namada,
+    args::ChangeRewardRecipient {
+        tx: tx_args,
+        source,
+        recipient,
+        tx_code_path,
+    }: &args::ChangeRewardRecipient,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
         &signing_data.fee_payer,
     )
+    .await?;
+
+    // Check that the source and the recipient addresses exist on chain
+    let source =
+        source_exists_or_err(source.clone(), tx_args.force, context).await?;
+    let recipient = match recipient.clone() {
+        Some(recipient) => {
+            target_exists_or_err(recipient, tx_args.force, context)
+                .await
+                .map(Some)
+        }
+        None => Ok(None),
+    }?;
+
+    let data = pos::RewardRecipient { source, recipient };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
     .await
     .map(|tx| (tx, signing_data))
 }
@@ -1673,7 +2447,7 @@ pub async fn build_unbond(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await?;
     Ok((tx, signing_data, latest_withdrawal_pre))
@@ -1886,7 +2660,7 @@ pub async fn build_bond(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1929,8 +2703,15 @@ pub async fn build_default_proposal(
             if let Some(init_proposal_code) = proposal.data {
                 let (_, extra_section_hash) =
                     tx_builder.add_extra_section(init_proposal_code, None);
-                init_proposal_data.r#type =
-                    ProposalType::Default(Some(extra_section_hash));
+                match &mut init_proposal_data.r#type {
+                    ProposalType::MultiOption { code, .. } => {
+                        *code = Some(extra_section_hash)
+                    }
+                    r#type => {
+                        *r#type =
+                            ProposalType::Default(Some(extra_section_hash))
+                    }
+                }
             };
             Ok(())
         };
@@ -1943,7 +2724,7 @@ pub async fn build_default_proposal(
         push_data,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -1991,6 +2772,19 @@ pub async fn build_vote_proposal(
         )));
     };
 
+    if !proposal.r#type.is_valid_vote(&proposal_vote) {
+        edisplay_line!(
+            context.io(),
+            "Vote {} cannot be cast on {} proposal {}.",
+            proposal_vote,
+            proposal.r#type,
+            proposal_id
+        );
+        if !tx.force {
+            return Err(Error::from(TxSubmitError::InvalidProposalVote));
+        }
+    }
+
     let is_validator = rpc::is_validator(context.client(), voter).await?;
 
     if !proposal.can_be_voted(epoch, is_validator) {
@@ -2040,7 +2834,7 @@ pub async fn build_vote_proposal(
         do_nothing,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -2089,7 +2883,7 @@ pub async fn build_pgf_funding_proposal(
         add_section,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -2139,7 +2933,7 @@ pub async fn build_pgf_stewards_proposal(
         add_section,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -2323,18 +3117,56 @@ pub async fn build_ibc_transfer(
     .add_serialized_data(data);
 
     prepare_tx(
-        context.client(),
+        context,
         &args.tx,
         &mut tx,
         unshield,
         fee_amount,
-        signing_data.fee_payer.clone(),
+        &signing_data,
     )
     .await?;
 
     Ok((tx, signing_data, shielded_tx_epoch))
 }
 
+/// Build an atomic batch of the given transactions, to be applied under the
+/// wrapper of the first one. The gas limit of the batch is the sum of the gas
+/// limits of the given transactions. Returns the batch along with the signing
+/// data of all of its entries.
+pub fn build_batch(
+    txs: Vec<(Tx, SigningTxData)>,
+) -> Result<(Tx, Vec<SigningTxData>)> {
+    let mut txs = txs.into_iter();
+    let (mut batch, first_signing_data) = txs.next().ok_or_else(|| {
+        Error::Other("Cannot build an empty batch of transactions".to_string())
+    })?;
+    let mut gas_limit = batch
+        .header()
+        .wrapper()
+        .map(|wrapper| u64::from(wrapper.gas_limit))
+        .unwrap_or_default();
+    let mut signing_data = vec![first_signing_data];
+
+    for (tx, tx_signing_data) in txs {
+        if let Some(wrapper) = tx.header().wrapper() {
+            gas_limit = gas_limit
+                .checked_add(u64::from(wrapper.gas_limit))
+                .ok_or_else(|| {
+                    Error::Other(
+                        "Overflow in the gas limit of the batch".to_string(),
+                    )
+                })?;
+        }
+        batch.add_batch_entry(tx);
+        signing_data.push(tx_signing_data);
+    }
+    if let TxType::Wrapper(wrapper) = &mut batch.header.tx_type {
+        wrapper.gas_limit = gas_limit.into();
+    }
+
+    Ok((batch, signing_data))
+}
+
 /// Abstraction for helping build transactions
 #[allow(clippy::too_many_arguments)]
 pub async fn build<F, D>(
@@ -2345,14 +3177,21 @@ pub async fn build<F, D>(
     on_tx: F,
     unshield: Option<masp_primitives::transaction::Transaction>,
     fee_amount: DenominatedAmount,
-    gas_payer: &common::PublicKey,
+    signing_data: &SigningTxData,
 ) -> Result<Tx>
 where
     F: FnOnce(&mut Tx, &mut D) -> Result<()>,
     D: BorshSerialize,
 {
     build_pow_flag(
-        context, tx_args, path, data, on_tx, unshield, fee_amount, gas_payer,
+        context,
+        tx_args,
+        path,
+        data,
+        on_tx,
+        unshield,
+        fee_amount,
+        signing_data,
     )
     .await
 }
@@ -2366,7 +3205,7 @@ async fn build_pow_flag<F, D>(
     on_tx: F,
     unshield: Option<masp_primitives::transaction::Transaction>,
     fee_amount: DenominatedAmount,
-    gas_payer: &common::PublicKey,
+    signing_data: &SigningTxData,
 ) -> Result<Tx>
 where
     F: FnOnce(&mut Tx, &mut D) -> Result<()>,
@@ -2393,12 +3232,12 @@ where
         .add_data(data);
 
     prepare_tx(
-        context.client(),
+        context,
         tx_args,
         &mut tx_builder,
         unshield,
         fee_amount,
-        gas_payer.clone(),
+        signing_data,
     )
     .await?;
     Ok(tx_builder)
@@ -2592,7 +3431,7 @@ pub async fn build_transfer<N: Namada>(
         add_shielded,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await?;
     Ok((tx, signing_data, shielded_tx_epoch))
@@ -2655,6 +3494,7 @@ pub async fn build_init_account(
         tx_code_path,
         public_keys,
         threshold,
+        vesting,
     }: &args::TxInitAccount,
 ) -> Result<(Tx, SigningTxData)> {
     let signing_data =
@@ -2681,11 +3521,32 @@ pub async fn build_init_account(
         }
     };
 
+    if let Some(vesting) = vesting {
+        if !vesting.is_valid() {
+            return Err(Error::from(TxSubmitError::InvalidVestingSchedule(
+                "the amount must be positive and the start, cliff and end \
+                 epochs must be ordered"
+                    .to_string(),
+            )));
+        }
+        // The schedule is only enforced by the vesting VP, so the tx rejects
+        // it on accounts with any other VP
+        if vp_code_path != Path::new(VP_VESTING_WASM) {
+            return Err(Error::from(TxSubmitError::InvalidVestingSchedule(
+                format!(
+                    "the account must use the {} validity predicate",
+                    VP_VESTING_WASM
+                ),
+            )));
+        }
+    }
+
     let data = InitAccount {
         public_keys: public_keys.clone(),
         // We will add the hash inside the add_code_hash function
         vp_code_hash: Hash::zero(),
         threshold,
+        vesting: *vesting,
     };
 
     let add_code_hash = |tx: &mut Tx, data: &mut InitAccount| {
@@ -2704,7 +3565,7 @@ pub async fn build_init_account(
         add_code_hash,
         unshield,
         fee_amount,
-        &signing_data.fee_payer,
+        &signing_data,
     )
     .await
     .map(|tx| (tx, signing_data))
@@ -2798,8 +3659,83 @@ pub async fn build_update_account(
         add_code_hash,
         unshield,
         fee_amount,
+        &signing_data,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to grant, update or revoke a fee allowance
+pub async fn build_grant_fee_allowance(
+    context: &impl Namada,
+    args::GrantFeeAllowance {
+        tx: tx_args,
+        granter,
+        grantee,
+        token,
+        spend_limit,
+        expiration,
+        allowed_txs,
+        tx_code_path,
+    }: &args::GrantFeeAllowance,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(granter.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(granter.clone()),
+        default_signer,
+    )
+    .await?;
+    let (fee_amount, _, unshield) = validate_fee_and_gen_unshield(
+        context,
+        tx_args,
         &signing_data.fee_payer,
     )
+    .await?;
+
+    let allowance = match spend_limit {
+        Some(spend_limit) => {
+            let spend_limit =
+                validate_amount(context, *spend_limit, token, tx_args.force)
+                    .await?;
+            let allowed_txs = if allowed_txs.is_empty() {
+                None
+            } else {
+                let mut hashes = Vec::with_capacity(allowed_txs.len());
+                for code_path in allowed_txs {
+                    hashes.push(
+                        query_wasm_code_hash_buf(context, code_path).await?,
+                    );
+                }
+                Some(hashes)
+            };
+            Some(FeeAllowance {
+                token: token.clone(),
+                spend_limit: spend_limit.amount(),
+                expiration: *expiration,
+                allowed_txs,
+            })
+        }
+        None => None,
+    };
+
+    let data = GrantFeeAllowance {
+        granter: granter.clone(),
+        grantee: grantee.clone(),
+        allowance,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        unshield,
+        fee_amount,
+        &signing_data,
+    )
     .await
     .map(|tx| (tx, signing_data))
 }
@@ -2853,12 +3789,12 @@ pub async fn build_custom(
     };
 
     prepare_tx(
-        context.client(),
+        context,
         tx_args,
         &mut tx,
         unshield,
         fee_amount,
-        signing_data.fee_payer.clone(),
+        &signing_data,
     )
     .await?;
 
@@ -33,16 +33,18 @@ pub enum ConfirmationResponse {
 /// Special keys for a validator
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ValidatorKeys {
-    /// Special keypair for signing protocol txs
-    pub protocol_keypair: common::SecretKey,
+    /// Special keypair for signing protocol txs. It is absent from the wallet
+    /// of a validator whose protocol key is held by a remote signer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_keypair: Option<common::SecretKey>,
     /// Special hot keypair for signing Ethereum bridge txs
     pub eth_bridge_keypair: common::SecretKey,
 }
 
 impl ValidatorKeys {
-    /// Get the protocol keypair
-    pub fn get_protocol_keypair(&self) -> &common::SecretKey {
-        &self.protocol_keypair
+    /// Get the protocol keypair, if held by the wallet
+    pub fn get_protocol_keypair(&self) -> Option<&common::SecretKey> {
+        self.protocol_keypair.as_ref()
     }
 }
 
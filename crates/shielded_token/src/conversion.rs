@@ -587,6 +587,7 @@ mod tests {
             pos_inflation_amount: Default::default(),
             fee_unshielding_gas_limit: 0,
             fee_unshielding_descriptions_limit: 0,
+            fee_burn_rate: Default::default(),
             minimum_gas_price: Default::default(),
         };
 
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 
 use namada_core::validity_predicate::VpSentinel;
-use namada_gas::{GasMetering, TxGasMeter, VpGasMeter};
+use namada_gas::{GasCategory, GasMetering, TxGasMeter, VpGasMeter};
 use namada_tx::data::TxSentinel;
 
 use crate::in_memory::InMemory;
@@ -67,14 +67,21 @@ where
     }
 
     fn charge_gas(&self, gas: u64) -> Result<()> {
-        self.gas_meter.borrow_mut().consume(gas).map_err(|err| {
-            self.sentinel.borrow_mut().set_out_of_gas();
-            tracing::info!(
-                "Stopping transaction execution because of gas error: {}",
-                err
-            );
-            Error::Gas(err)
-        })
+        self.charge_gas_for(gas, GasCategory::Other)
+    }
+
+    fn charge_gas_for(&self, gas: u64, category: GasCategory) -> Result<()> {
+        self.gas_meter
+            .borrow_mut()
+            .consume_for(gas, category)
+            .map_err(|err| {
+                self.sentinel.borrow_mut().set_out_of_gas();
+                tracing::info!(
+                    "Stopping transaction execution because of gas error: {}",
+                    err
+                );
+                Error::Gas(err)
+            })
     }
 }
 
@@ -16,6 +16,7 @@ use namada_storage::{
     Header, Key, KeySeg, StorageHasher, TxIndex, BLOCK_HASH_LENGTH,
     BLOCK_HEIGHT_LENGTH, EPOCH_TYPE_LENGTH,
 };
+use namada_tx::data::BlockStorageDiffs;
 
 use crate::{Error, Result};
 
@@ -106,10 +107,42 @@ pub struct BlockStorage<H: StorageHasher> {
     pub epoch: Epoch,
     /// Results of applying transactions
     pub results: BlockResults,
+    /// The storage diffs of the applied transactions, only captured when
+    /// indexing them is enabled
+    pub storage_diffs: BlockStorageDiffs,
     /// Predecessor block epochs
     pub pred_epochs: Epochs,
 }
 
+/// Update the given merkle tree with the epoch data. These leaves of the tree
+/// aren't written to the account subspace.
+pub fn update_epoch_in_merkle_tree<H: StorageHasher + Default>(
+    tree: &mut MerkleTree<H>,
+    epoch: Epoch,
+    next_epoch_min_start_height: BlockHeight,
+    next_epoch_min_start_time: DateTimeUtc,
+) -> Result<()> {
+    let key_prefix: Key =
+        Address::Internal(InternalAddress::PoS).to_db_key().into();
+
+    let key = key_prefix
+        .push(&"epoch_start_height".to_string())
+        .map_err(Error::KeyError)?;
+    tree.update(&key, encode(&next_epoch_min_start_height))?;
+
+    let key = key_prefix
+        .push(&"epoch_start_time".to_string())
+        .map_err(Error::KeyError)?;
+    tree.update(&key, encode(&next_epoch_min_start_time))?;
+
+    let key = key_prefix
+        .push(&"current_epoch".to_string())
+        .map_err(Error::KeyError)?;
+    tree.update(&key, encode(&epoch))?;
+
+    Ok(())
+}
+
 impl<H> InMemory<H>
 where
     H: StorageHasher,
@@ -127,6 +160,7 @@ where
             epoch: Epoch::default(),
             pred_epochs: Epochs::default(),
             results: BlockResults::default(),
+            storage_diffs: BlockStorageDiffs::default(),
         };
         InMemory::<H> {
             chain_id,
@@ -252,29 +286,12 @@ where
 
     /// Update the merkle tree with epoch data
     pub fn update_epoch_in_merkle_tree(&mut self) -> Result<()> {
-        let key_prefix: Key =
-            Address::Internal(InternalAddress::PoS).to_db_key().into();
-
-        let key = key_prefix
-            .push(&"epoch_start_height".to_string())
-            .map_err(Error::KeyError)?;
-        self.block
-            .tree
-            .update(&key, encode(&self.next_epoch_min_start_height))?;
-
-        let key = key_prefix
-            .push(&"epoch_start_time".to_string())
-            .map_err(Error::KeyError)?;
-        self.block
-            .tree
-            .update(&key, encode(&self.next_epoch_min_start_time))?;
-
-        let key = key_prefix
-            .push(&"current_epoch".to_string())
-            .map_err(Error::KeyError)?;
-        self.block.tree.update(&key, encode(&self.block.epoch))?;
-
-        Ok(())
+        update_epoch_in_merkle_tree(
+            &mut self.block.tree,
+            self.block.epoch,
+            self.next_epoch_min_start_height,
+            self.next_epoch_min_start_time,
+        )
     }
 
     /// Get the height of the last committed block or 0 if no block has been
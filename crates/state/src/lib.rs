@@ -9,7 +9,9 @@ use std::fmt::Debug;
 use std::iter::Peekable;
 
 pub use host_env::{TxHostEnvState, VpHostEnvState};
-pub use in_memory::{BlockStorage, InMemory, LastBlock};
+pub use in_memory::{
+    update_epoch_in_merkle_tree, BlockStorage, InMemory, LastBlock,
+};
 use namada_core::address::Address;
 use namada_core::eth_bridge_pool::is_pending_transfer_key;
 pub use namada_core::hash::Sha256Hasher;
@@ -21,7 +23,7 @@ pub use namada_core::storage::{
 };
 use namada_core::tendermint::merkle::proof::ProofOps;
 use namada_gas::{
-    MEMORY_ACCESS_GAS_PER_BYTE, STORAGE_ACCESS_GAS_PER_BYTE,
+    GasCategory, MEMORY_ACCESS_GAS_PER_BYTE, STORAGE_ACCESS_GAS_PER_BYTE,
     STORAGE_WRITE_GAS_PER_BYTE,
 };
 use namada_merkle_tree::Error as MerkleTreeError;
@@ -70,6 +72,12 @@ pub trait StateRead: StorageRead + Debug {
 
     fn charge_gas(&self, gas: u64) -> Result<()>;
 
+    /// Charge gas for an operation of the given category. Defaults to
+    /// [`StateRead::charge_gas`] for states that don't track a breakdown.
+    fn charge_gas_for(&self, gas: u64, _category: GasCategory) -> Result<()> {
+        self.charge_gas(gas)
+    }
+
     /// Check if the given key is present in storage. Returns the result and the
     /// gas cost.
     fn db_has_key(&self, key: &storage::Key) -> Result<(bool, u64)> {
@@ -198,7 +206,10 @@ macro_rules! impl_storage_read {
             ) -> namada_storage::Result<Option<Vec<u8>>> {
                 // try to read from the write log first
                 let (log_val, gas) = self.write_log().read(key);
-                self.charge_gas(gas).into_storage_result()?;
+                self.charge_gas_for(
+                    gas,
+                    namada_gas::GasCategory::StorageRead,
+                ).into_storage_result()?;
                 match log_val {
                     Some(write_log::StorageModification::Write { ref value }) => {
                         Ok(Some(value.clone()))
@@ -213,7 +224,10 @@ macro_rules! impl_storage_read {
                     None => {
                         // when not found in write log, try to read from the storage
                         let (value, gas) = self.db_read(key).into_storage_result()?;
-                        self.charge_gas(gas).into_storage_result()?;
+                        self.charge_gas_for(
+                            gas,
+                            namada_gas::GasCategory::StorageRead,
+                        ).into_storage_result()?;
                         Ok(value)
                     }
                 }
@@ -222,7 +236,10 @@ macro_rules! impl_storage_read {
             fn has_key(&self, key: &storage::Key) -> namada_storage::Result<bool> {
                 // try to read from the write log first
                 let (log_val, gas) = self.write_log().read(key);
-                self.charge_gas(gas).into_storage_result()?;
+                self.charge_gas_for(
+                    gas,
+                    namada_gas::GasCategory::StorageRead,
+                ).into_storage_result()?;
                 match log_val {
                     Some(&write_log::StorageModification::Write { .. })
                     | Some(&write_log::StorageModification::InitAccount { .. })
@@ -234,7 +251,10 @@ macro_rules! impl_storage_read {
                     None => {
                         // when not found in write log, try to check the storage
                         let (present, gas) = self.db_has_key(key).into_storage_result()?;
-                        self.charge_gas(gas).into_storage_result()?;
+                        self.charge_gas_for(
+                            gas,
+                            namada_gas::GasCategory::StorageRead,
+                        ).into_storage_result()?;
                         Ok(present)
                     }
                 }
@@ -246,7 +266,10 @@ macro_rules! impl_storage_read {
             ) -> namada_storage::Result<Self::PrefixIter<'iter>> {
                 let (iter, gas) =
                     iter_prefix_post(self.write_log(), self.db(), prefix);
-                self.charge_gas(gas).into_storage_result()?;
+                self.charge_gas_for(
+                    gas,
+                    namada_gas::GasCategory::StorageRead,
+                ).into_storage_result()?;
                 Ok(iter)
             }
 
@@ -255,7 +278,10 @@ macro_rules! impl_storage_read {
                 iter: &mut Self::PrefixIter<'iter>,
             ) -> namada_storage::Result<Option<(String, Vec<u8>)>> {
                 iter.next().map(|(key, val, gas)| {
-                    self.charge_gas(gas).into_storage_result()?;
+                    self.charge_gas_for(
+                        gas,
+                        namada_gas::GasCategory::StorageRead,
+                    ).into_storage_result()?;
                     Ok((key, val))
                 }).transpose()
             }
@@ -346,7 +372,10 @@ macro_rules! impl_storage_write {
                     .write_log_mut()
                     .write(key, val.as_ref().to_vec())
                     .into_storage_result()?;
-                self.charge_gas(gas).into_storage_result()?;
+                self.charge_gas_for(
+                    gas,
+                    namada_gas::GasCategory::StorageWrite,
+                ).into_storage_result()?;
                 Ok(())
             }
 
@@ -355,7 +384,10 @@ macro_rules! impl_storage_write {
                     .write_log_mut()
                     .delete(key)
                     .into_storage_result()?;
-                self.charge_gas(gas).into_storage_result()?;
+                self.charge_gas_for(
+                    gas,
+                    namada_gas::GasCategory::StorageWrite,
+                ).into_storage_result()?;
                 Ok(())
             }
         }
@@ -617,6 +649,7 @@ pub mod testing {
                 epoch: Epoch::default(),
                 pred_epochs: Epochs::default(),
                 results: BlockResults::default(),
+                storage_diffs: Default::default(),
             };
             Self {
                 chain_id,
@@ -741,6 +774,7 @@ mod tests {
                 pos_inflation_amount: token::Amount::zero(),
                 fee_unshielding_gas_limit: 20_000,
                 fee_unshielding_descriptions_limit: 15,
+                fee_burn_rate: Dec::zero(),
                 minimum_gas_price: BTreeMap::default(),
             };
             namada_parameters::init_storage(&parameters, &mut state).unwrap();
@@ -4,6 +4,7 @@ use std::ops::{Deref, DerefMut};
 use namada_core::address::Address;
 use namada_core::borsh::BorshSerializeExt;
 use namada_core::chain::ChainId;
+use namada_core::event::Event;
 use namada_core::storage;
 use namada_core::time::DateTimeUtc;
 use namada_parameters::EpochDuration;
@@ -178,6 +179,21 @@ where
     /// Commit the current block's write log to the storage and commit the block
     /// to DB. Starts a new block write log.
     pub fn commit_block(&mut self) -> StorageResult<()> {
+        self.commit_block_with_events(&[], None, false)
+    }
+
+    /// Commit the current block's write log to the storage together with the
+    /// given events emitted while finalizing the block, and commit the block
+    /// to DB. If `prune_events_below` is set, events emitted below that
+    /// height are deleted from the DB. If `archive` is set, the versions of the
+    /// changed subspace key-vals are also written to the archive of the DB.
+    /// Starts a new block write log.
+    pub fn commit_block_with_events(
+        &mut self,
+        events: &[Event],
+        prune_events_below: Option<BlockHeight>,
+        archive: bool,
+    ) -> StorageResult<()> {
         if self.in_mem.last_epoch != self.in_mem.block.epoch {
             self.in_mem_mut()
                 .update_epoch_in_merkle_tree()
@@ -185,8 +201,45 @@ where
         }
 
         let mut batch = D::batch();
+        if archive {
+            let changes: Vec<(Key, Option<Vec<u8>>)> = self
+                .0
+                .write_log
+                .block_write_log
+                .iter()
+                .filter_map(|(key, entry)| {
+                    let value = match entry {
+                        StorageModification::Write { value } => {
+                            Some(value.clone())
+                        }
+                        StorageModification::Delete => None,
+                        StorageModification::InitAccount { vp_code_hash } => {
+                            Some(vp_code_hash.to_vec())
+                        }
+                        // temporary value isn't persisted
+                        StorageModification::Temp { .. } => return None,
+                    };
+                    Some((key.clone(), value))
+                })
+                .collect();
+            self.db
+                .add_archive_to_batch(
+                    &mut batch,
+                    self.in_mem.block.height,
+                    &changes,
+                )
+                .into_storage_result()?;
+        }
         self.commit_write_log_block(&mut batch)
             .into_storage_result()?;
+        self.db
+            .add_events_to_batch(&mut batch, self.in_mem.block.height, events)
+            .into_storage_result()?;
+        if let Some(height) = prune_events_below {
+            self.db
+                .prune_events(&mut batch, height)
+                .into_storage_result()?;
+        }
         self.commit_block_from_batch(batch).into_storage_result()
     }
 
@@ -476,7 +529,7 @@ where
 
     /// Load the full state at the last committed height, if any. Returns the
     /// Merkle root hash and the height of the committed block.
-    fn load_last_state(&mut self) {
+    pub fn load_last_state(&mut self) {
         if let Some(BlockStateRead {
             merkle_tree_stores,
             hash,
@@ -569,6 +622,7 @@ where
                 .time,
             epoch: self.in_mem.block.epoch,
             results: &self.in_mem.block.results,
+            storage_diffs: &self.in_mem.block.storage_diffs,
             pred_epochs: &self.in_mem.block.pred_epochs,
             next_epoch_min_start_height: self
                 .in_mem
@@ -94,6 +94,11 @@ pub struct WriteLog {
     /// cleaned either when committing or dumping the `tx_write_log`
     pub(crate) tx_precommit_write_log:
         HashMap<storage::Key, StorageModification>,
+    /// The storage modifications of the accepted transactions of the current
+    /// batch. A batch is executed atomically: these modifications are only
+    /// committed to the `block_write_log` together with the last transaction
+    /// of the batch and are dropped if any of its transactions is rejected
+    pub(crate) batch_write_log: HashMap<storage::Key, StorageModification>,
     /// The IBC events for the current transaction
     pub(crate) ibc_events: BTreeSet<IbcEvent>,
     /// Storage modifications for the replay protection storage, always
@@ -124,6 +129,7 @@ impl Default for WriteLog {
             block_write_log: HashMap::with_capacity(100_000),
             tx_write_log: HashMap::with_capacity(100),
             tx_precommit_write_log: HashMap::with_capacity(100),
+            batch_write_log: HashMap::with_capacity(100),
             ibc_events: BTreeSet::new(),
             replay_protection: HashMap::with_capacity(1_000),
         }
@@ -141,6 +147,10 @@ impl WriteLog {
         match self
             .tx_write_log
             .get(key)
+            .or_else(|| {
+                // If not found, then try to read from batch write log
+                self.batch_write_log.get(key)
+            })
             .or_else(|| {
                 // If not found, then try to read from tx precommit write log
                 self.tx_precommit_write_log.get(key)
@@ -175,7 +185,11 @@ impl WriteLog {
         &self,
         key: &storage::Key,
     ) -> (Option<&StorageModification>, u64) {
-        match self.block_write_log.get(key) {
+        // try to read from batch write log first
+        match self.batch_write_log.get(key).or_else(|| {
+            // if not found, then try to read from block write log
+            self.block_write_log.get(key)
+        }) {
             Some(v) => {
                 let gas = match v {
                     StorageModification::Write { ref value } => {
@@ -453,31 +467,59 @@ impl WriteLog {
         self.tx_precommit_write_log.extend(tx_log)
     }
 
-    /// Commit the current transaction's write log and precommit log to the
-    /// block when it's accepted by all the triggered validity predicates.
+    /// Commit the current transaction's write log, batch log and precommit log
+    /// to the block when it's accepted by all the triggered validity
+    /// predicates.
     /// Starts a new transaction write log.
     pub fn commit_tx(&mut self) {
-        // First precommit everything
-        self.precommit_tx();
-
-        // Then commit to block
-        self.tx_precommit_write_log.retain(|_, v| {
-            !matches!(v, StorageModification::Temp { value: _ })
-        });
-        let tx_precommit_write_log = std::mem::replace(
+        let mut tx_precommit_write_log = std::mem::replace(
             &mut self.tx_precommit_write_log,
             HashMap::with_capacity(100),
         );
+        let batch_write_log = std::mem::replace(
+            &mut self.batch_write_log,
+            HashMap::with_capacity(100),
+        );
+        let mut tx_write_log = std::mem::replace(
+            &mut self.tx_write_log,
+            HashMap::with_capacity(100),
+        );
 
+        // Commit to block from the oldest to the most recent modifications:
+        // the precommit, then the batch and finally the current tx
+        tx_precommit_write_log.retain(|_, v| {
+            !matches!(v, StorageModification::Temp { value: _ })
+        });
+        tx_write_log.retain(|_, v| {
+            !matches!(v, StorageModification::Temp { value: _ })
+        });
         self.block_write_log.extend(tx_precommit_write_log);
+        self.block_write_log.extend(batch_write_log);
+        self.block_write_log.extend(tx_write_log);
         self.take_ibc_events();
     }
 
-    /// Drop the current transaction's write log and IBC events and precommit
-    /// when it's declined by any of the triggered validity predicates.
+    /// Move the current transaction's write log to the batch one when it's
+    /// part of a batch and it's accepted by all the triggered validity
+    /// predicates. Starts a new transaction write log.
+    pub fn commit_batched_tx(&mut self) {
+        let mut tx_write_log = std::mem::replace(
+            &mut self.tx_write_log,
+            HashMap::with_capacity(100),
+        );
+
+        tx_write_log.retain(|_, v| {
+            !matches!(v, StorageModification::Temp { value: _ })
+        });
+        self.batch_write_log.extend(tx_write_log);
+    }
+
+    /// Drop the current transaction's write log, IBC events, precommit and
+    /// batch when it's declined by any of the triggered validity predicates.
     /// Starts a new transaction write log.
     pub fn drop_tx(&mut self) {
         self.tx_precommit_write_log.clear();
+        self.batch_write_log.clear();
         self.tx_write_log.clear();
         self.ibc_events.clear();
     }
@@ -557,6 +599,11 @@ impl WriteLog {
                 matches.insert(key.to_string(), modification.clone());
             }
         }
+        for (key, modification) in &self.batch_write_log {
+            if key.split_prefix(prefix).is_some() {
+                matches.insert(key.to_string(), modification.clone());
+            }
+        }
 
         let iter = matches.into_iter();
         PrefixIter { iter }
@@ -572,6 +619,11 @@ impl WriteLog {
                 matches.insert(key.to_string(), modification.clone());
             }
         }
+        for (key, modification) in &self.batch_write_log {
+            if key.split_prefix(prefix).is_some() {
+                matches.insert(key.to_string(), modification.clone());
+            }
+        }
         for (key, modification) in &self.tx_write_log {
             if key.split_prefix(prefix).is_some() {
                 matches.insert(key.to_string(), modification.clone());
@@ -871,6 +923,95 @@ mod tests {
         assert_eq!(value, None);
     }
 
+    #[test]
+    fn test_commit_batch() {
+        let mut state = crate::testing::TestState::default();
+
+        let key1 =
+            storage::Key::parse("key1").expect("cannot parse the key string");
+        let key2 =
+            storage::Key::parse("key2").expect("cannot parse the key string");
+        let key3 =
+            storage::Key::parse("key3").expect("cannot parse the key string");
+        let val1 = "val1".as_bytes().to_vec();
+        let val2 = "val2".as_bytes().to_vec();
+
+        // the first tx of the batch is visible to the following ones, also as
+        // their prior state, but temporary values are not
+        state.write_log.write(&key1, val1.clone()).unwrap();
+        state.write_log.write_temp(&key3, val1.clone()).unwrap();
+        state.write_log.commit_batched_tx();
+        assert!(state.write_log.get_keys().is_empty());
+        let (value, _) = state.write_log.read_pre(&key1);
+        assert_matches!(
+            value,
+            Some(StorageModification::Write { value }) if *value == val1
+        );
+        assert!(state.write_log.read(&key3).0.is_none());
+
+        // these values are not written as the batch is dropped
+        state.write_log.write(&key2, val2.clone()).unwrap();
+        state.write_log.drop_tx();
+        assert!(state.write_log.read(&key1).0.is_none());
+
+        // a batch that is committed as a whole
+        state.write_log.write(&key1, val1.clone()).unwrap();
+        state.write_log.commit_batched_tx();
+        state.write_log.write(&key1, val2.clone()).unwrap();
+        state.write_log.write(&key2, val1.clone()).unwrap();
+        state.write_log.commit_tx();
+
+        // commit a block
+        state.commit_block().expect("commit failed");
+
+        let (value, _) = state.db_read(&key1).expect("read failed");
+        assert_eq!(value.expect("no read value"), val2);
+        let (value, _) = state.db_read(&key2).expect("read failed");
+        assert_eq!(value.expect("no read value"), val1);
+        let (value, _) = state.db_read(&key3).expect("read failed");
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn test_batch_over_precommit() {
+        let mut state = crate::testing::TestState::default();
+
+        let key1 =
+            storage::Key::parse("key1").expect("cannot parse the key string");
+        let key2 =
+            storage::Key::parse("key2").expect("cannot parse the key string");
+        let val1 = "val1".as_bytes().to_vec();
+        let val2 = "val2".as_bytes().to_vec();
+
+        // the precommit holds older modifications than the batch
+        state.write_log.write(&key1, val1.clone()).unwrap();
+        state.write_log.write(&key2, val1.clone()).unwrap();
+        state.write_log.precommit_tx();
+        state.write_log.write(&key1, val2.clone()).unwrap();
+        state.write_log.commit_batched_tx();
+
+        // the batch modifications take precedence over the precommit ones
+        let (value, _) = state.write_log.read(&key1);
+        assert_matches!(
+            value,
+            Some(StorageModification::Write { value }) if *value == val2
+        );
+        let (value, _) = state.write_log.read(&key2);
+        assert_matches!(
+            value,
+            Some(StorageModification::Write { value }) if *value == val1
+        );
+
+        // and so they do when committed to the block
+        state.write_log.commit_tx();
+        state.commit_block().expect("commit failed");
+
+        let (value, _) = state.db_read(&key1).expect("read failed");
+        assert_eq!(value.expect("no read value"), val2);
+        let (value, _) = state.db_read(&key2).expect("read failed");
+        assert_eq!(value.expect("no read value"), val1);
+    }
+
     #[test]
     fn test_replay_protection_commit() {
         let mut state = crate::testing::TestState::default();
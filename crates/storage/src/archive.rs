@@ -0,0 +1,282 @@
+//! Key layout of the archive of the account subspace.
+//!
+//! When the archive mode is enabled, the DB implementations keep every
+//! version of the account subspace key-vals under the following keys:
+//!
+//! - `{key}\0{height}`: the borsh encoded `Option<Vec<u8>>` written to `key` at
+//!   block height `height`, where `None` marks a deletion
+//!
+//! Heights are zero-padded and the NUL separator sorts before any character
+//! of a storage key, such that the versions of a key are contiguous and
+//! ordered by height. The value of a key at some height is found with a
+//! single reverse seek from its version key at that height, regardless of
+//! how far back the height is.
+//!
+//! The archive mode may be turned on and off, so the archived heights are
+//! recorded as ranges of consecutive heights. The first height of a range is
+//! seeded with the versions of all the subspace key-vals, such that the
+//! heights of a range are served from its own versions only. The seeding is
+//! done ahead of the commit of the first block of the range, when the node
+//! starts or the chain is initialized, and the seeded height is recorded.
+
+use std::fmt::Display;
+
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::storage::{BlockHeight, Key};
+
+use crate::db::{Error, Result};
+
+/// Separator between a storage key and the height of its version.
+pub const VERSION_SEPARATOR: char = '\0';
+
+/// The character following the [`VERSION_SEPARATOR`], which sorts after the
+/// versions of a storage key but before any key that extends it.
+const VERSIONS_END: char = '\u{1}';
+
+/// Key of the state under which the ranges of the archived heights are
+/// stored.
+pub const ARCHIVE_RANGES_KEY: &str = "archive_ranges";
+
+/// Key of the state under which the height seeded with all the subspace
+/// key-vals, to start a new range, is stored.
+pub const ARCHIVE_SEED_KEY: &str = "archive_seed";
+
+/// A range of consecutive block heights archived while the archive mode was
+/// enabled, including both its `start` and `end`.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub struct ArchiveRange {
+    /// The first archived height, seeded with all the subspace key-vals
+    pub start: BlockHeight,
+    /// The last archived height
+    pub end: BlockHeight,
+}
+
+/// Find the range of the archived heights that covers the given height.
+pub fn find_range(
+    ranges: &[ArchiveRange],
+    height: BlockHeight,
+) -> Option<ArchiveRange> {
+    ranges
+        .iter()
+        .find(|range| range.start <= height && height <= range.end)
+        .copied()
+}
+
+/// Add the given height, following any archived height, to the ranges.
+/// Returns `true` if the height starts a new range, in which case the archive
+/// must be seeded with all the subspace key-vals at this height.
+pub fn add_height(ranges: &mut Vec<ArchiveRange>, height: BlockHeight) -> bool {
+    match ranges.last_mut() {
+        Some(last) if last.end.next_height() == height => {
+            last.end = height;
+            false
+        }
+        _ => {
+            ranges.push(ArchiveRange {
+                start: height,
+                end: height,
+            });
+            true
+        }
+    }
+}
+
+/// Remove the given height, if it's the last archived height, from the
+/// ranges. Used when the last block is rolled back.
+pub fn remove_last_height(ranges: &mut Vec<ArchiveRange>, height: BlockHeight) {
+    if let Some(last) = ranges.last_mut() {
+        if last.end == height {
+            if last.start == height {
+                ranges.pop();
+            } else {
+                last.end = height.prev_height();
+            }
+        }
+    }
+}
+
+/// Check if the archive must be seeded to archive the given height, that is
+/// if the height doesn't follow the last archived height and wasn't seeded
+/// yet.
+pub fn needs_seed(
+    ranges: &[ArchiveRange],
+    seeded: Option<BlockHeight>,
+    height: BlockHeight,
+) -> bool {
+    let is_continued = ranges
+        .last()
+        .is_some_and(|last| last.end.next_height() == height);
+    !is_continued && seeded != Some(height)
+}
+
+/// Get the key of the version of the given storage key written at the given
+/// height.
+pub fn version_key(key: &impl Display, height: BlockHeight) -> String {
+    format!("{}{:020}", versions_prefix(key), height.0)
+}
+
+/// Get the prefix of the keys of all the versions of the given storage key,
+/// including the trailing separator.
+pub fn versions_prefix(key: &impl Display) -> String {
+    format!("{key}{VERSION_SEPARATOR}")
+}
+
+/// Get the key following the keys of all the versions of the given storage
+/// key, to seek past them to the versions of the next storage key.
+pub fn versions_end(key: &impl Display) -> String {
+    format!("{key}{VERSIONS_END}")
+}
+
+/// Get the prefix of the keys of all the versions of the storage keys under
+/// the given prefix, matching the keys of a subspace prefix iterator.
+pub fn sub_keys_prefix(prefix: &Key) -> String {
+    if prefix.is_empty() {
+        String::new()
+    } else {
+        format!("{prefix}/")
+    }
+}
+
+/// Parse the storage key and the height out of a version key.
+pub fn parse_version_key(version_key: &str) -> Option<(&str, BlockHeight)> {
+    let (key, height) = version_key.rsplit_once(VERSION_SEPARATOR)?;
+    Some((key, BlockHeight(height.parse().ok()?)))
+}
+
+/// Decode the value of a version, `None` if the key was deleted.
+pub fn decode_version(bytes: &[u8]) -> Result<Option<Vec<u8>>> {
+    Option::<Vec<u8>>::try_from_slice(bytes).map_err(Error::BorshCodingError)
+}
+
+/// Get the value of the given storage key at a height covered by the given
+/// range, out of the version found with a reverse seek from the version key
+/// at that height, if any. Returns `None` if the key was deleted or not yet
+/// written at that height.
+pub fn value_of_version(
+    key: &str,
+    version: Option<(&str, &[u8])>,
+    range: ArchiveRange,
+) -> Result<Option<Vec<u8>>> {
+    let Some((version_key, bytes)) = version else {
+        return Ok(None);
+    };
+    match parse_version_key(version_key) {
+        // The seek may have landed on the versions of another key, while the
+        // versions older than the range may be stale
+        Some((version_of, height))
+            if version_of == key && range.start <= height =>
+        {
+            decode_version(bytes)
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::borsh::BorshSerializeExt;
+
+    use super::*;
+
+    /// Test that the versions of a key are contiguous and ordered by height,
+    /// also when other keys extend it.
+    #[test]
+    fn test_version_keys_order() {
+        let key = Key::parse("a/b").unwrap();
+        let sub_key = Key::parse("a/b/c").unwrap();
+        let sibling = Key::parse("a/ba").unwrap();
+
+        let mut keys = vec![
+            version_key(&sub_key, BlockHeight(1)),
+            version_key(&key, BlockHeight(10)),
+            version_key(&sibling, BlockHeight(2)),
+            version_key(&key, BlockHeight(9)),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                version_key(&key, BlockHeight(9)),
+                version_key(&key, BlockHeight(10)),
+                version_key(&sub_key, BlockHeight(1)),
+                version_key(&sibling, BlockHeight(2)),
+            ]
+        );
+        assert_eq!(parse_version_key(&keys[1]), Some(("a/b", BlockHeight(10))));
+        // Seeking past the versions of a key lands on the keys extending it
+        assert!(versions_end(&key) > keys[1]);
+        assert!(versions_end(&key) < keys[2]);
+    }
+
+    /// Test that the value at a height is only read from a version of the
+    /// same key within the range.
+    #[test]
+    fn test_value_of_version() {
+        let range = ArchiveRange {
+            start: BlockHeight(2),
+            end: BlockHeight(10),
+        };
+        let key = Key::parse("a/b").unwrap();
+        let value = Some(vec![1_u8]).serialize_to_vec();
+        let deleted = Option::<Vec<u8>>::None.serialize_to_vec();
+        let version =
+            |key: &Key, height: u64| version_key(key, BlockHeight(height));
+        let value_of = |version_key: &str, bytes: &[u8]| {
+            value_of_version("a/b", Some((version_key, bytes)), range).unwrap()
+        };
+
+        assert_eq!(value_of(&version(&key, 2), &value), Some(vec![1]));
+        assert_eq!(value_of(&version(&key, 5), &deleted), None);
+        // A stale version from before the range
+        assert_eq!(value_of(&version(&key, 1), &value), None);
+        // A version of another key
+        let other = Key::parse("a/a").unwrap();
+        assert_eq!(value_of(&version(&other, 5), &value), None);
+        assert_eq!(value_of_version("a/b", None, range).unwrap(), None);
+    }
+
+    /// Test that the archived heights are recorded as ranges of consecutive
+    /// heights.
+    #[test]
+    fn test_archive_ranges() {
+        let mut ranges = vec![];
+        assert!(add_height(&mut ranges, BlockHeight(2)));
+        assert!(!add_height(&mut ranges, BlockHeight(3)));
+        // Archiving was turned off for height 4
+        assert!(add_height(&mut ranges, BlockHeight(5)));
+        assert_eq!(
+            ranges,
+            vec![
+                ArchiveRange {
+                    start: BlockHeight(2),
+                    end: BlockHeight(3),
+                },
+                ArchiveRange {
+                    start: BlockHeight(5),
+                    end: BlockHeight(5),
+                },
+            ]
+        );
+        assert_eq!(find_range(&ranges, BlockHeight(1)), None);
+        assert_eq!(find_range(&ranges, BlockHeight(3)), Some(ranges[0]));
+        assert_eq!(find_range(&ranges, BlockHeight(4)), None);
+        assert_eq!(find_range(&ranges, BlockHeight(5)), Some(ranges[1]));
+        assert_eq!(find_range(&ranges, BlockHeight(6)), None);
+        assert!(!needs_seed(&ranges, None, BlockHeight(6)));
+        assert!(needs_seed(&ranges, None, BlockHeight(7)));
+        assert!(!needs_seed(&ranges, Some(BlockHeight(7)), BlockHeight(7)));
+
+        remove_last_height(&mut ranges, BlockHeight(5));
+        assert_eq!(ranges.len(), 1);
+        remove_last_height(&mut ranges, BlockHeight(3));
+        assert_eq!(
+            ranges,
+            vec![ArchiveRange {
+                start: BlockHeight(2),
+                end: BlockHeight(2),
+            }]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use namada_core::address::EstablishedAddressGen;
+use namada_core::event::Event;
 use namada_core::hash::{Error as HashError, Hash};
 use namada_core::storage::{
     BlockHash, BlockHeight, BlockResults, DbColFam, Epoch, Epochs,
@@ -12,10 +13,13 @@ use namada_merkle_tree::{
     Error as MerkleTreeError, MerkleTreeStoresRead, MerkleTreeStoresWrite,
     StoreType,
 };
+use namada_tx::data::BlockStorageDiffs;
 use regex::Regex;
 use thiserror::Error;
 
+use crate::archive;
 use crate::conversion_state::ConversionState;
+use crate::event_store::EventIndex;
 use crate::tx_queue::TxQueue;
 
 #[allow(missing_docs)]
@@ -105,6 +109,8 @@ pub struct BlockStateWrite<'a> {
     pub address_gen: &'a EstablishedAddressGen,
     /// Results of applying transactions
     pub results: &'a BlockResults,
+    /// The storage diffs of the applied transactions, if captured
+    pub storage_diffs: &'a BlockStorageDiffs,
     /// The conversion state
     pub conversion_state: &'a ConversionState,
     /// Wrapper txs to be decrypted in the next block proposal
@@ -147,6 +153,13 @@ pub trait DB: Debug {
     /// Read the block header with the given height from the DB
     fn read_block_header(&self, height: BlockHeight) -> Result<Option<Header>>;
 
+    /// Read the storage diffs of the transactions applied in the block with
+    /// the given height, if they were captured
+    fn read_storage_diffs(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<BlockStorageDiffs>>;
+
     /// Read the merkle tree stores with the given epoch. If a store_type is
     /// given, it reads only the the specified tree. Otherwise, it reads all
     /// trees.
@@ -166,7 +179,8 @@ pub trait DB: Debug {
     /// Read the value for account subspace key at the given height from the DB.
     /// In our `PersistentStorage` (rocksdb), to find a value from arbitrary
     /// height requires looking for diffs from the given `height`, possibly
-    /// up to the `last_height`.
+    /// up to the `last_height`, unless the height is covered by the archive of
+    /// the subspace.
     fn read_subspace_val_with_height(
         &self,
         key: &Key,
@@ -278,6 +292,77 @@ pub trait DB: Debug {
         key: &Key,
         new_value: impl AsRef<[u8]>,
     ) -> Result<()>;
+
+    /// Batch write the events emitted at the given height, together with
+    /// their index entries. Any events previously written at the same height
+    /// are overwritten.
+    fn add_events_to_batch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[Event],
+    ) -> Result<()>;
+
+    /// Read the events matching the given index, emitted between the given
+    /// heights (inclusive), in the order in which they were emitted. At most
+    /// `limit` events are returned, if any limit is given.
+    fn read_events(
+        &self,
+        index: &EventIndex,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+        limit: Option<usize>,
+    ) -> Result<Vec<(BlockHeight, Event)>>;
+
+    /// Find the most recent event matching the given index and predicate,
+    /// emitted at or below the given height. The index is iterated from its
+    /// most recent entries and the search stops at the first match.
+    fn find_last_event(
+        &self,
+        index: &EventIndex,
+        to_height: BlockHeight,
+        matches: &dyn Fn(&Event) -> bool,
+    ) -> Result<Option<(BlockHeight, Event)>>;
+
+    /// Batch delete all the events emitted below the given height, together
+    /// with their index entries.
+    fn prune_events(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        below_height: BlockHeight,
+    ) -> Result<()>;
+
+    /// Write the versions of all the account subspace key-vals at the given
+    /// height to the archive, such that a new range of archived heights can
+    /// start at this height. The versions are written in batches of bounded
+    /// size, separately from the commit of the block at this height. Does
+    /// nothing if the height follows the last archived height or was already
+    /// seeded.
+    fn seed_archive(&mut self, height: BlockHeight) -> Result<()>;
+
+    /// Batch write the versions of the account subspace key-vals changed at
+    /// the given height to the archive, where `None` marks a deleted key. The
+    /// height is added to the archived ranges. If it starts a new range, it
+    /// must have been seeded with [`DB::seed_archive`].
+    fn add_archive_to_batch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        changes: &[(Key, Option<Vec<u8>>)],
+    ) -> Result<()>;
+
+    /// Read the ranges of the archived heights, empty if the archive mode was
+    /// never enabled.
+    fn read_archive_ranges(&self) -> Result<Vec<archive::ArchiveRange>>;
+
+    /// Read the account subspace key-vals under the given prefix at the given
+    /// height from the archive. Returns `None` if the height is not covered
+    /// by the archive.
+    fn read_archive_prefix(
+        &self,
+        prefix: &Key,
+        height: BlockHeight,
+    ) -> Result<Option<Vec<(String, Vec<u8>)>>>;
 }
 
 /// A database prefix iterator.
@@ -0,0 +1,208 @@
+//! Key layout of the persistent event store.
+//!
+//! Events emitted by `FinalizeBlock` calls are persisted by the DB
+//! implementations under the following keys:
+//!
+//! - `event/{height}/{index}`: the borsh encoded [`Event`] emitted at block
+//!   height `height`, at position `index` of the block's events
+//! - `type/{event_type}/{height}/{index}`: index entries of events by their
+//!   [`EventType`]
+//! - `attr/{hash}/{height}/{index}`: index entries of events by their
+//!   attributes, where `hash` is the hash of an attribute's key-value pair
+//!
+//! Heights and indices are zero-padded, such that the lexicographic order of
+//! the keys matches the order in which events were emitted.
+
+use namada_core::borsh::BorshSerializeExt;
+use namada_core::event::{Event, EventType};
+use namada_core::hash::Hash;
+use namada_core::storage::BlockHeight;
+
+/// Prefix of the keys holding the persisted events.
+pub const EVENT_PREFIX: &str = "event";
+/// Prefix of the keys indexing events by their type.
+pub const TYPE_INDEX_PREFIX: &str = "type";
+/// Prefix of the keys indexing events by their attributes.
+pub const ATTRIBUTE_INDEX_PREFIX: &str = "attr";
+
+/// An index that can be used to look up events in the event store.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventIndex {
+    /// Match all the events.
+    All,
+    /// Match events whose type is displayed as the given string.
+    Type(String),
+    /// Match events holding the given attribute key-value pair.
+    Attribute {
+        /// The key of the attribute.
+        key: String,
+        /// The value of the attribute.
+        value: String,
+    },
+}
+
+impl EventIndex {
+    /// Index of events with the given type.
+    pub fn event_type(event_type: &EventType) -> Self {
+        Self::Type(event_type.to_string())
+    }
+
+    /// Index of events pertaining to the given tx hash.
+    pub fn tx_hash(hash: &Hash) -> Self {
+        Self::Attribute {
+            key: "hash".to_string(),
+            value: hash.to_string(),
+        }
+    }
+
+    /// Return the key prefix of this index, including the trailing
+    /// separator.
+    pub fn prefix(&self) -> String {
+        match self {
+            Self::All => format!("{EVENT_PREFIX}/"),
+            Self::Type(event_type) => {
+                format!("{TYPE_INDEX_PREFIX}/{event_type}/")
+            }
+            Self::Attribute { key, value } => format!(
+                "{ATTRIBUTE_INDEX_PREFIX}/{}/",
+                attribute_hash(key, value)
+            ),
+        }
+    }
+
+    /// Return the first key of this index at the given height, inclusive.
+    pub fn lower_bound(&self, height: BlockHeight) -> String {
+        format!("{}{}", self.prefix(), height_segment(height))
+    }
+
+    /// Return the first key of this index past the given height, i.e. the
+    /// exclusive upper bound of an iteration up to `height`.
+    pub fn upper_bound(&self, height: BlockHeight) -> String {
+        match height.0.checked_add(1) {
+            Some(next) => self.lower_bound(BlockHeight(next)),
+            None => {
+                // Past all the zero-padded height segments
+                format!("{}{}", self.prefix(), '~')
+            }
+        }
+    }
+}
+
+/// Get the key of the event emitted at the given height and position.
+pub fn event_key(height: BlockHeight, index: u32) -> String {
+    format!("{EVENT_PREFIX}/{}", position_segments(height, index))
+}
+
+/// Get all the keys under which the given event is indexed, other than its
+/// main [`event_key`].
+pub fn index_keys(
+    event: &Event,
+    height: BlockHeight,
+    index: u32,
+) -> Vec<String> {
+    let position = position_segments(height, index);
+    std::iter::once(EventIndex::event_type(&event.event_type))
+        .chain(event.attributes.iter().map(|(key, value)| {
+            EventIndex::Attribute {
+                key: key.clone(),
+                value: value.clone(),
+            }
+        }))
+        .map(|index| format!("{}{position}", index.prefix()))
+        .collect()
+}
+
+/// Parse the height and the position of an event out of a key of the given
+/// index.
+pub fn parse_position(
+    index: &EventIndex,
+    key: &str,
+) -> Option<(BlockHeight, u32)> {
+    let position = key.strip_prefix(&index.prefix())?;
+    let (height, index) = position.split_once('/')?;
+    Some((BlockHeight(height.parse().ok()?), index.parse().ok()?))
+}
+
+fn height_segment(height: BlockHeight) -> String {
+    format!("{:020}", height.0)
+}
+
+fn position_segments(height: BlockHeight, index: u32) -> String {
+    format!("{}/{:010}", height_segment(height), index)
+}
+
+fn attribute_hash(key: &str, value: &str) -> Hash {
+    Hash::sha256((key, value).serialize_to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use namada_core::event::EventLevel;
+
+    use super::*;
+
+    fn applied_event(hash: &str) -> Event {
+        Event {
+            event_type: EventType::Applied,
+            level: EventLevel::Tx,
+            attributes: HashMap::from([("hash".to_string(), hash.to_string())]),
+        }
+    }
+
+    /// Test that keys of all the indices can be parsed back into the
+    /// position of the event.
+    #[test]
+    fn test_event_index_keys_roundtrip() {
+        let event = applied_event("DEADBEEF");
+        let height = BlockHeight(42);
+
+        let keys = index_keys(&event, height, 7);
+        assert_eq!(keys.len(), 2);
+
+        let indices = [
+            EventIndex::event_type(&EventType::Applied),
+            EventIndex::Attribute {
+                key: "hash".to_string(),
+                value: "DEADBEEF".to_string(),
+            },
+        ];
+        for index in indices {
+            let key = keys
+                .iter()
+                .find(|key| key.starts_with(&index.prefix()))
+                .expect("The event should be indexed");
+            assert_eq!(parse_position(&index, key), Some((height, 7)));
+        }
+        assert_eq!(
+            parse_position(&EventIndex::All, &event_key(height, 7)),
+            Some((height, 7))
+        );
+    }
+
+    /// Test that the bounds of an index enclose exactly the keys of the
+    /// requested heights.
+    #[test]
+    fn test_event_index_bounds() {
+        let index = EventIndex::event_type(&EventType::Applied);
+        let event = applied_event("DEADBEEF");
+        let lower = index.lower_bound(BlockHeight(9));
+        let upper = index.upper_bound(BlockHeight(10));
+
+        let key_at = |height: u64| {
+            index_keys(&event, BlockHeight(height), 0)
+                .into_iter()
+                .find(|key| key.starts_with(&index.prefix()))
+                .unwrap()
+        };
+        assert!(key_at(8) < lower);
+        assert!(lower <= key_at(9));
+        assert!(key_at(10) < upper);
+        assert!(upper <= key_at(11));
+        assert!(
+            index_keys(&event, BlockHeight(u64::MAX), u32::MAX)[0]
+                < index.upper_bound(BlockHeight(u64::MAX))
+        );
+    }
+}
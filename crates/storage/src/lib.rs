@@ -1,10 +1,12 @@
 //! The common storage read trait is implemented in the storage, client RPC, tx
 //! and VPs (both native and WASM).
 
+pub mod archive;
 pub mod collections;
 pub mod conversion_state;
 mod db;
 mod error;
+pub mod event_store;
 pub mod mockdb;
 pub mod tx_queue;
 pub mod types;
@@ -2,12 +2,13 @@
 
 use std::cell::RefCell;
 use std::collections::{btree_map, BTreeMap};
-use std::ops::Bound::{Excluded, Included};
+use std::ops::Bound::{Excluded, Included, Unbounded};
 use std::path::Path;
 use std::str::FromStr;
 
 use itertools::Either;
 use namada_core::borsh::{BorshDeserialize, BorshSerializeExt};
+use namada_core::event::Event;
 use namada_core::hash::Hash;
 use namada_core::storage::{
     BlockHeight, BlockResults, DbColFam, Epoch, EthEventsQueue, Header, Key,
@@ -19,16 +20,21 @@ use namada_merkle_tree::{
     base_tree_key_prefix, subtree_key_prefix, MerkleTreeStoresRead, StoreType,
 };
 use namada_replay_protection as replay_protection;
+use namada_tx::data::BlockStorageDiffs;
 use regex::Regex;
 
+use crate::archive;
 use crate::conversion_state::ConversionState;
 use crate::db::{
     BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, Error, Result, DB,
 };
+use crate::event_store::{self, EventIndex};
 use crate::tx_queue::TxQueue;
 use crate::types::{KVBytes, PatternIterator, PrefixIterator};
 
 const SUBSPACE_CF: &str = "subspace";
+const EVENTS_CF: &str = "events";
+const ARCHIVE_CF: &str = "archive";
 
 const OLD_DIFF_PREFIX: &str = "old";
 const NEW_DIFF_PREFIX: &str = "new";
@@ -245,6 +251,7 @@ impl DB for MockDB {
             update_epoch_blocks_delay,
             address_gen,
             results,
+            storage_diffs,
             conversion_state,
             ethereum_height,
             eth_events_queue,
@@ -358,9 +365,27 @@ impl DB for MockDB {
             let results_path = format!("results/{}", height.raw());
             self.0.borrow_mut().insert(results_path, encode(&results));
         }
+        // Storage diffs of the block's txs
+        if !storage_diffs.is_empty() {
+            let storage_diffs_path = format!("storage_diffs/{}", height.raw());
+            self.0
+                .borrow_mut()
+                .insert(storage_diffs_path, encode(storage_diffs));
+        }
         Ok(())
     }
 
+    fn read_storage_diffs(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<BlockStorageDiffs>> {
+        let storage_diffs_path = format!("storage_diffs/{}", height.raw());
+        match self.0.borrow().get(storage_diffs_path.as_str()) {
+            Some(bytes) => Ok(Some(decode(bytes).map_err(Error::CodingError)?)),
+            None => Ok(None),
+        }
+    }
+
     fn read_block_header(&self, height: BlockHeight) -> Result<Option<Header>> {
         let prefix_key = Key::from(height.to_db_key());
         let key = prefix_key
@@ -459,9 +484,14 @@ impl DB for MockDB {
     fn read_subspace_val_with_height(
         &self,
         key: &Key,
-        _height: BlockHeight,
+        height: BlockHeight,
         _last_height: BlockHeight,
     ) -> Result<Option<Vec<u8>>> {
+        if let Some(range) =
+            archive::find_range(&self.read_archive_ranges()?, height)
+        {
+            return self.read_archive_val(&key.to_string(), range, height);
+        }
         tracing::warn!(
             "read_subspace_val_with_height is not implemented, will read \
              subspace value from latest height"
@@ -701,6 +731,266 @@ impl DB for MockDB {
     ) -> Result<()> {
         unimplemented!()
     }
+
+    fn add_events_to_batch(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        events: &[Event],
+    ) -> Result<()> {
+        let mut db = self.0.borrow_mut();
+        for (index, event) in events.iter().enumerate() {
+            let index = index as u32;
+            db.insert(
+                format!(
+                    "{EVENTS_CF}/{}",
+                    event_store::event_key(height, index)
+                ),
+                event.serialize_to_vec(),
+            );
+            for key in event_store::index_keys(event, height, index) {
+                db.insert(format!("{EVENTS_CF}/{key}"), vec![]);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_events(
+        &self,
+        index: &EventIndex,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+        limit: Option<usize>,
+    ) -> Result<Vec<(BlockHeight, Event)>> {
+        let db = self.0.borrow();
+        let lower = format!("{EVENTS_CF}/{}", index.lower_bound(from_height));
+        let upper = format!("{EVENTS_CF}/{}", index.upper_bound(to_height));
+        let mut events = vec![];
+        for (key, _) in
+            db.range::<str, _>((Included(&*lower), Excluded(&*upper)))
+        {
+            if limit.map(|limit| events.len() >= limit).unwrap_or_default() {
+                break;
+            }
+            let key = key
+                .strip_prefix(&format!("{EVENTS_CF}/"))
+                .expect("Events should be stored under their CF prefix");
+            let (height, position) = event_store::parse_position(index, key)
+                .ok_or_else(|| Error::UnknownKey {
+                    key: key.to_string(),
+                })?;
+            let event_key = format!(
+                "{EVENTS_CF}/{}",
+                event_store::event_key(height, position)
+            );
+            let event = match db.get(&event_key) {
+                Some(bytes) => Event::try_from_slice(bytes)
+                    .map_err(Error::BorshCodingError)?,
+                None => return Err(Error::UnknownKey { key: event_key }),
+            };
+            events.push((height, event));
+        }
+        Ok(events)
+    }
+
+    fn find_last_event(
+        &self,
+        index: &EventIndex,
+        to_height: BlockHeight,
+        matches: &dyn Fn(&Event) -> bool,
+    ) -> Result<Option<(BlockHeight, Event)>> {
+        let db = self.0.borrow();
+        let lower = format!("{EVENTS_CF}/{}", index.prefix());
+        let upper = format!("{EVENTS_CF}/{}", index.upper_bound(to_height));
+        for (key, _) in db
+            .range::<str, _>((Included(&*lower), Excluded(&*upper)))
+            .rev()
+        {
+            let key = key
+                .strip_prefix(&format!("{EVENTS_CF}/"))
+                .expect("Events should be stored under their CF prefix");
+            let (height, position) = event_store::parse_position(index, key)
+                .ok_or_else(|| Error::UnknownKey {
+                    key: key.to_string(),
+                })?;
+            let event_key = format!(
+                "{EVENTS_CF}/{}",
+                event_store::event_key(height, position)
+            );
+            let event = match db.get(&event_key) {
+                Some(bytes) => Event::try_from_slice(bytes)
+                    .map_err(Error::BorshCodingError)?,
+                None => return Err(Error::UnknownKey { key: event_key }),
+            };
+            if matches(&event) {
+                return Ok(Some((height, event)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn prune_events(
+        &mut self,
+        _batch: &mut Self::WriteBatch,
+        below_height: BlockHeight,
+    ) -> Result<()> {
+        let index = EventIndex::All;
+        let lower = format!("{EVENTS_CF}/{}", index.prefix());
+        let upper = format!("{EVENTS_CF}/{}", index.lower_bound(below_height));
+        let mut db = self.0.borrow_mut();
+        let pruned: Vec<(String, Vec<u8>)> = db
+            .range::<str, _>((Included(&*lower), Excluded(&*upper)))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        for (key, value) in pruned {
+            let (height, position) = event_store::parse_position(
+                &index,
+                key.strip_prefix(&format!("{EVENTS_CF}/")).unwrap(),
+            )
+            .ok_or_else(|| Error::UnknownKey { key: key.clone() })?;
+            let event = Event::try_from_slice(&value)
+                .map_err(Error::BorshCodingError)?;
+            for index_key in event_store::index_keys(&event, height, position) {
+                db.remove(&format!("{EVENTS_CF}/{index_key}"));
+            }
+            db.remove(&key);
+        }
+        Ok(())
+    }
+
+    fn seed_archive(&mut self, height: BlockHeight) -> Result<()> {
+        let ranges = self.read_archive_ranges()?;
+        if !archive::needs_seed(&ranges, self.read_archive_seed()?, height) {
+            return Ok(());
+        }
+        let mut db = self.0.borrow_mut();
+        let subspace_prefix = format!("{SUBSPACE_CF}/");
+        let seed: Vec<(String, Vec<u8>)> = db
+            .range::<str, _>((Included(&*subspace_prefix), Unbounded))
+            .take_while(|(key, _)| key.starts_with(&subspace_prefix))
+            .map(|(key, value)| {
+                let key = &key[subspace_prefix.len()..];
+                (
+                    format!(
+                        "{ARCHIVE_CF}/{}",
+                        archive::version_key(&key, height)
+                    ),
+                    Some(value.clone()).serialize_to_vec(),
+                )
+            })
+            .collect();
+        db.extend(seed);
+        db.insert(archive::ARCHIVE_SEED_KEY.to_owned(), encode(&height));
+        Ok(())
+    }
+
+    fn add_archive_to_batch(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        height: BlockHeight,
+        changes: &[(Key, Option<Vec<u8>>)],
+    ) -> Result<()> {
+        let mut ranges = self.read_archive_ranges()?;
+        if archive::needs_seed(&ranges, self.read_archive_seed()?, height) {
+            return Err(Error::DBError(format!(
+                "The archive must be seeded at height {height} to start a new \
+                 range of archived heights"
+            )));
+        }
+        archive::add_height(&mut ranges, height);
+        let mut db = self.0.borrow_mut();
+        db.insert(archive::ARCHIVE_RANGES_KEY.to_owned(), encode(&ranges));
+        for (key, value) in changes {
+            db.insert(
+                format!("{ARCHIVE_CF}/{}", archive::version_key(key, height)),
+                value.serialize_to_vec(),
+            );
+        }
+        Ok(())
+    }
+
+    fn read_archive_ranges(&self) -> Result<Vec<archive::ArchiveRange>> {
+        match self.0.borrow().get(archive::ARCHIVE_RANGES_KEY) {
+            Some(bytes) => decode(bytes).map_err(Error::CodingError),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn read_archive_prefix(
+        &self,
+        prefix: &Key,
+        height: BlockHeight,
+    ) -> Result<Option<Vec<(String, Vec<u8>)>>> {
+        let Some(range) =
+            archive::find_range(&self.read_archive_ranges()?, height)
+        else {
+            return Ok(None);
+        };
+        let cf_prefix = format!("{ARCHIVE_CF}/");
+        let db_prefix =
+            format!("{cf_prefix}{}", archive::sub_keys_prefix(prefix));
+        let mut values = vec![];
+        let mut next = db_prefix.clone();
+        loop {
+            // Find the next key under the prefix, past the versions of the
+            // previous one
+            let key = match self
+                .0
+                .borrow()
+                .range::<str, _>((Included(&*next), Unbounded))
+                .next()
+            {
+                Some((version_key, _))
+                    if version_key.starts_with(&db_prefix) =>
+                {
+                    archive::parse_version_key(&version_key[cf_prefix.len()..])
+                        .map(|(key, _)| key.to_owned())
+                        .ok_or_else(|| Error::UnknownKey {
+                            key: version_key.clone(),
+                        })?
+                }
+                _ => break,
+            };
+            if let Some(value) = self.read_archive_val(&key, range, height)? {
+                values.push((key.clone(), value));
+            }
+            next = format!("{cf_prefix}{}", archive::versions_end(&key));
+        }
+        Ok(Some(values))
+    }
+}
+
+impl MockDB {
+    /// Read the value of an account subspace key at the given height, covered
+    /// by the given range, from the archive.
+    fn read_archive_val(
+        &self,
+        key: &str,
+        range: archive::ArchiveRange,
+        height: BlockHeight,
+    ) -> Result<Option<Vec<u8>>> {
+        let cf_prefix = format!("{ARCHIVE_CF}/");
+        let upper =
+            format!("{cf_prefix}{}", archive::version_key(&key, height));
+        let db = self.0.borrow();
+        let version = db
+            .range::<str, _>((Unbounded, Included(&*upper)))
+            .next_back()
+            .and_then(|(version_key, bytes)| {
+                Some((version_key.strip_prefix(&cf_prefix)?, bytes.as_slice()))
+            });
+        archive::value_of_version(key, version, range)
+    }
+
+    /// Read the height seeded with all the subspace key-vals to start a new
+    /// range of the archive, if any.
+    fn read_archive_seed(&self) -> Result<Option<BlockHeight>> {
+        self.0
+            .borrow()
+            .get(archive::ARCHIVE_SEED_KEY)
+            .map(|bytes| decode(bytes).map_err(Error::CodingError))
+            .transpose()
+    }
 }
 
 impl<'iter> DBIter<'iter> for MockDB {
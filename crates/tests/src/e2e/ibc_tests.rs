@@ -1820,6 +1820,8 @@ fn propose_funding(
             port_id: src_port_id.clone(),
             channel_id: src_channel_id.clone(),
         })],
+        streams: vec![],
+        cancelled_streams: vec![],
     };
 
     std::env::set_var(ENV_VAR_CHAIN_ID, test_a.net.chain_id.to_string());
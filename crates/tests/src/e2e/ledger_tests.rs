@@ -2471,6 +2471,8 @@ fn pgf_governance_proposal() -> Result<()> {
             amount: token::Amount::from_u64(5),
             target: christel,
         })],
+        streams: vec![],
+        cancelled_streams: vec![],
     };
 
     let valid_proposal_json_path =
@@ -232,6 +232,59 @@ mod tests {
         tx::ctx().init_account(code_hash, &None).unwrap();
     }
 
+    /// Test that a vesting schedule is only written when initializing an
+    /// account with the vesting validity predicate, which enforces it.
+    #[test]
+    fn test_tx_init_account_with_vesting_schedule() {
+        use namada::account::{
+            vesting_schedule, InitAccount, VestingSchedule, VP_VESTING_WASM,
+        };
+
+        // The environment must be initialized first
+        tx_host_env::init();
+
+        let code = TestWasms::VpAlwaysTrue.read_bytes();
+        let code_hash = Hash::sha256(&code);
+        tx_host_env::with(|env| {
+            // store wasm code
+            let key = Key::wasm_code(&code_hash);
+            env.state.write_bytes(&key, &code).unwrap();
+        });
+        let data = InitAccount {
+            public_keys: vec![key::testing::keypair_1().ref_to()],
+            vp_code_hash: code_hash,
+            threshold: 1,
+            vesting: Some(VestingSchedule {
+                amount: token::Amount::native_whole(100),
+                start_epoch: storage::Epoch(0),
+                cliff_epoch: storage::Epoch(0),
+                end_epoch: storage::Epoch(10),
+            }),
+        };
+
+        // The schedule is rejected on an account with another VP
+        let address = tx::ctx().init_account(code_hash, &None).unwrap();
+        assert!(
+            namada_tx_prelude::account::init_account(
+                tx::ctx(),
+                &address,
+                data.clone()
+            )
+            .is_err()
+        );
+
+        // The schedule is written on an account with the vesting VP
+        tx_host_env::with(|env| {
+            env.state
+                .write_bytes(&Key::wasm_hash(VP_VESTING_WASM), code_hash)
+                .unwrap();
+        });
+        let address = tx::ctx().init_account(code_hash, &None).unwrap();
+        namada_tx_prelude::account::init_account(tx::ctx(), &address, data)
+            .unwrap();
+        assert!(vesting_schedule(tx::ctx(), &address).unwrap().is_some());
+    }
+
     /// Test that a tx updating validity predicate that is not in the allowlist
     /// fails.
     #[test]
@@ -598,6 +651,65 @@ mod tests {
         }
     }
 
+    /// Test that the placeholder signatures of a tx, made with keys other
+    /// than the ones of the signer, are rejected but can still be metered
+    #[test]
+    fn test_vp_meter_tx_placeholder_signature() {
+        use namada::tx::{Section, Signature, Signer};
+
+        let pk = key::testing::keypair_1().ref_to();
+        let placeholder_keypair = key::testing::keypair_2();
+        let pks_map = AccountPublicKeysMap::from_iter(vec![pk.clone()]);
+
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code(vec![4, 3, 2, 1, 0], None)
+            .add_serialized_data([1, 2, 3, 4].repeat(10));
+        let raw_header_hash = tx.raw_header_hash();
+        tx.add_section(Section::Signature(Signature {
+            targets: vec![raw_header_hash],
+            signer: Signer::PubKeys(vec![pk]),
+            signatures: [(
+                0,
+                common::SigScheme::sign(&placeholder_keypair, raw_header_hash),
+            )]
+            .into(),
+        }));
+
+        let mut verified_sigs = 0;
+        assert!(
+            tx.verify_signatures(
+                &[tx.raw_header_hash()],
+                pks_map.clone(),
+                &None,
+                1,
+                None,
+                || {
+                    verified_sigs += 1;
+                    Ok(())
+                }
+            )
+            .is_err()
+        );
+        assert_eq!(verified_sigs, 1);
+
+        let mut metered_sigs = 0;
+        assert!(
+            tx.meter_signatures(
+                &[tx.raw_header_hash()],
+                pks_map,
+                &None,
+                1,
+                None,
+                || {
+                    metered_sigs += 1;
+                    Ok(())
+                }
+            )
+            .is_ok()
+        );
+        assert_eq!(metered_sigs, 1);
+    }
+
     #[test]
     fn test_vp_get_metadata() {
         // The environment must be initialized first
@@ -13,7 +13,7 @@ pub mod protocol;
 /// wrapper txs with encrypted payloads
 pub mod wrapper;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Display};
 use std::str::FromStr;
 
@@ -189,6 +189,57 @@ pub struct TxResult {
     pub ibc_events: BTreeSet<IbcEvent>,
     /// Ethereum bridge events emitted by the transaction
     pub eth_bridge_events: BTreeSet<EthBridgeEvent>,
+    /// The results of the entries of a batch of inner transactions, in the
+    /// order of their execution. Empty for transactions that aren't batches.
+    pub batch_results: Vec<BatchedTxResult>,
+    /// The values of the changed storage keys before and after the
+    /// transaction. Only captured on demand, empty otherwise.
+    pub storage_diffs: BTreeMap<storage::Key, StorageDiff>,
+}
+
+/// The result of an inner transaction executed as part of a batch
+// TODO derive BorshSchema after <https://github.com/near/borsh-rs/issues/82>
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct BatchedTxResult {
+    /// Gas used by the entry (includes the gas used by its VPs)
+    pub gas_used: Gas,
+    /// The results of the validity predicates triggered by the entry
+    pub vps_result: VpsResult,
+}
+
+/// The storage diffs of the transactions applied in a block, by the hash of
+/// their raw header
+pub type BlockStorageDiffs =
+    BTreeMap<Hash, BTreeMap<storage::Key, StorageDiff>>;
+
+/// The values of a storage key before and after the execution of a
+/// transaction. A missing value means that the key is not present in storage.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct StorageDiff {
+    /// The value before the execution of the transaction
+    pub old_value: Option<Vec<u8>>,
+    /// The value after the execution of the transaction
+    pub new_value: Option<Vec<u8>>,
 }
 
 impl TxResult {
@@ -237,7 +288,15 @@ impl fmt::Display for TxResult {
                 self.gas_used,
                 iterable_to_string("Changed keys", self.changed_keys.iter()),
                 self.vps_result,
-            )
+            )?;
+            for (index, result) in self.batch_results.iter().enumerate() {
+                write!(
+                    f,
+                    " Batch entry {}: gas used: {}; VPs result: {}",
+                    index, result.gas_used, result.vps_result,
+                )?;
+            }
+            Ok(())
         } else {
             write!(f, "{}", serde_json::to_string(self).unwrap())
         }
@@ -34,6 +34,40 @@ pub struct UpdateStewardCommission {
     pub commission: HashMap<Address, Dec>,
 }
 
+/// A tx data type to claim the vested funds of a pgf stream
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct ClaimPgfStream {
+    /// The target address of the stream
+    pub target: Address,
+}
+
+/// A tx data type to cancel a pgf stream
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct CancelPgfStream {
+    /// The pgf steward address
+    pub steward: Address,
+    /// The target address of the stream
+    pub target: Address,
+}
+
 #[cfg(any(test, feature = "testing"))]
 /// Tests and strategies for PGF
 pub mod tests {
@@ -41,7 +75,7 @@ pub mod tests {
     use namada_core::dec::testing::arb_dec;
     use proptest::{collection, prop_compose};
 
-    use super::UpdateStewardCommission;
+    use super::{CancelPgfStream, ClaimPgfStream, UpdateStewardCommission};
 
     prop_compose! {
         /// Generate an arbitraary steward commission update
@@ -55,4 +89,23 @@ pub mod tests {
             }
         }
     }
+
+    prop_compose! {
+        /// Generate an arbitrary pgf stream claim
+        pub fn arb_claim_pgf_stream()(
+            target in arb_non_internal_address(),
+        ) -> ClaimPgfStream {
+            ClaimPgfStream { target }
+        }
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary pgf stream cancellation
+        pub fn arb_cancel_pgf_stream()(
+            steward in arb_non_internal_address(),
+            target in arb_non_internal_address(),
+        ) -> CancelPgfStream {
+            CancelPgfStream { steward, target }
+        }
+    }
 }
@@ -4,6 +4,7 @@ use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::dec::Dec;
 use namada_core::key::{common, secp256k1};
+use namada_core::storage::Epoch;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -102,6 +103,32 @@ pub struct Withdraw {
     pub source: Option<Address>,
 }
 
+/// A cancellation of an unbond that is not yet withdrawable.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct CancelUnbond {
+    /// Validator address
+    pub validator: Address,
+    /// Source address of the unbond. For unbonds from self-bonds, the
+    /// validator is also the source
+    pub source: Option<Address>,
+    /// The epoch at which the unbond becomes withdrawable
+    pub withdraw_epoch: Epoch,
+    /// The amount of tokens to cancel, or `None` to cancel the whole unbond
+    pub amount: Option<token::Amount>,
+}
+
 /// A claim of pending rewards.
 #[derive(
     Debug,
@@ -124,6 +151,54 @@ pub struct ClaimRewards {
     pub source: Option<Address>,
 }
 
+/// A change to the automatic compounding of the rewards of a bond.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct AutoCompound {
+    /// Validator address
+    pub validator: Address,
+    /// Source address of the bond. For self-bonds, the validator is also the
+    /// source
+    pub source: Option<Address>,
+    /// Whether the rewards of the bond are automatically bonded back to the
+    /// validator at every new epoch
+    pub enabled: bool,
+}
+
+/// A change of the address credited with the claimed rewards of all the bonds
+/// of a source.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct RewardRecipient {
+    /// Source address of the bonds
+    pub source: Address,
+    /// The address credited with the claimed rewards, or `None` to credit
+    /// the source itself
+    pub recipient: Option<Address>,
+}
+
 /// A redelegation of bonded tokens from one validator to another.
 #[derive(
     Debug,
@@ -149,6 +224,52 @@ pub struct Redelegation {
     pub amount: token::Amount,
 }
 
+/// A tokenization of bonded tokens into the share token of the validator.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct TokenizeBond {
+    /// Validator address
+    pub validator: Address,
+    /// Owner (delegator) of the bond to be tokenized
+    pub source: Address,
+    /// The amount of bonded tokens
+    pub amount: token::Amount,
+}
+
+/// A redemption of share tokens of a validator back into a bond.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct RedeemShares {
+    /// Validator address
+    pub validator: Address,
+    /// Owner of the share tokens, who becomes the owner of the bond
+    pub owner: Address,
+    /// The amount of share tokens
+    pub amount: token::Amount,
+}
+
 /// A change to the validator commission rate.
 #[derive(
     Debug,
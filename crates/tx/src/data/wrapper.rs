@@ -206,6 +206,13 @@ pub mod wrapper_tx {
         /// The hash of the optional, unencrypted, unshielding transaction for
         /// fee payment
         pub unshield_section_hash: Option<Hash>,
+        /// The optional account paying the fees in place of the fee payer,
+        /// out of the allowance it granted to the fee payer
+        pub fee_granter: Option<Address>,
+        /// The optional sequence number of this wrapper among the ones paid
+        /// by the fee payer. If present, it must match the next sequence
+        /// number expected from the fee payer.
+        pub sequence: Option<u64>,
     }
 
     impl WrapperTx {
@@ -227,9 +234,25 @@ pub mod wrapper_tx {
                 epoch,
                 gas_limit,
                 unshield_section_hash: unshield_hash,
+                fee_granter: None,
+                sequence: None,
             }
         }
 
+        /// Pay the fees out of the allowance granted by the given account to
+        /// the fee payer
+        pub fn with_fee_granter(mut self, fee_granter: Address) -> Self {
+            self.fee_granter = Some(fee_granter);
+            self
+        }
+
+        /// Order this wrapper among the ones paid by the fee payer with the
+        /// given sequence number
+        pub fn with_sequence(mut self, sequence: u64) -> Self {
+            self.sequence = Some(sequence);
+            self
+        }
+
         /// Get the address of the implicit account associated
         /// with the public key
         /// NOTE: this is safe in case someone tried to use the masp address to
@@ -239,6 +262,12 @@ pub mod wrapper_tx {
             Address::from(&self.pk)
         }
 
+        /// Get the address whose balance the fees are debited from: the fee
+        /// granter, if any, or the fee payer
+        pub fn fee_source(&self) -> Address {
+            self.fee_granter.clone().unwrap_or_else(|| self.fee_payer())
+        }
+
         /// Produce a SHA-256 hash of this section
         pub fn hash<'a>(&self, hasher: &'a mut Sha256) -> &'a mut Sha256 {
             hasher.update(self.serialize_to_vec());
@@ -11,7 +11,7 @@ use namada_core::event::{Event, EventLevel, EventType};
 pub use namada_core::key::SignableEthMessage;
 pub use namada_core::sign::SignatureIndex;
 pub use types::{
-    standalone_signature, verify_standalone_sig, Code, Commitment,
+    standalone_signature, verify_standalone_sig, BatchEntry, Code, Commitment,
     CompressedSignature, Data, DecodeError, Header, MaspBuilder, Memo, Section,
     Signature, Signed, Signer, Tx, TxError, VerifySigError,
 };
@@ -79,4 +79,30 @@ mod tests {
         let tx_from_bytes = Tx::decode(&tx_from_hex[..]).unwrap();
         assert_eq!(tx, tx_from_bytes);
     }
+
+    #[test]
+    fn batch_entries_share_header_hashes() {
+        let mut first = Tx::from_type(TxType::Raw);
+        first
+            .add_code(b"first code".to_vec(), None)
+            .add_serialized_data(b"first data".to_vec());
+        let mut second = Tx::from_type(TxType::Raw);
+        second
+            .add_code(b"second code".to_vec(), None)
+            .add_serialized_data(b"second data".to_vec());
+
+        let mut batch = first.clone();
+        batch.add_batch_entry(second.clone());
+        assert!(batch.is_batch());
+        let entries = batch.batch();
+        assert_eq!(entries, [first.batch(), second.batch()].concat());
+
+        for (entry, tx) in entries.iter().zip([first, second]) {
+            let view = batch.batch_entry(entry);
+            assert_eq!(view.header_hash(), batch.header_hash());
+            assert_eq!(view.raw_header_hash(), batch.raw_header_hash());
+            assert_eq!(view.code(), tx.code());
+            assert_eq!(view.data(), tx.data());
+        }
+    }
 }
@@ -474,6 +474,28 @@ impl Signature {
         signer: &Option<Address>,
         consume_verify_sig_gas: &mut F,
     ) -> std::result::Result<u8, VerifySigError>
+    where
+        F: FnMut() -> std::result::Result<(), namada_gas::Error>,
+    {
+        self.check_signature(
+            verified_pks,
+            public_keys_index_map,
+            signer,
+            consume_verify_sig_gas,
+            true,
+        )
+    }
+
+    /// Charge the gas of the signatures contained in this section for the
+    /// given public keys, verifying them only if `verify` is set
+    fn check_signature<F>(
+        &self,
+        verified_pks: &mut HashSet<u8>,
+        public_keys_index_map: &AccountPublicKeysMap,
+        signer: &Option<Address>,
+        consume_verify_sig_gas: &mut F,
+        verify: bool,
+    ) -> std::result::Result<u8, VerifySigError>
     where
         F: FnMut() -> std::result::Result<(), namada_gas::Error>,
     {
@@ -488,11 +510,13 @@ impl Signature {
                         public_keys_index_map.get_public_key_from_index(*idx)
                     {
                         consume_verify_sig_gas()?;
-                        common::SigScheme::verify_signature(
-                            &pk,
-                            &self.get_raw_hash(),
-                            sig,
-                        )?;
+                        if verify {
+                            common::SigScheme::verify_signature(
+                                &pk,
+                                &self.get_raw_hash(),
+                                sig,
+                            )?;
+                        }
                         verified_pks.insert(*idx);
                         verifications += 1;
                     }
@@ -509,11 +533,13 @@ impl Signature {
                         public_keys_index_map.get_index_from_public_key(pk)
                     {
                         consume_verify_sig_gas()?;
-                        common::SigScheme::verify_signature(
-                            pk,
-                            &self.get_raw_hash(),
-                            &self.signatures[&(idx as u8)],
-                        )?;
+                        if verify {
+                            common::SigScheme::verify_signature(
+                                pk,
+                                &self.get_raw_hash(),
+                                &self.signatures[&(idx as u8)],
+                            )?;
+                        }
                         verified_pks.insert(map_idx);
                         verifications += 1;
                     }
@@ -892,6 +918,32 @@ pub struct Header {
     pub memo_hash: namada_core::hash::Hash,
     /// The type of this transaction
     pub tx_type: TxType,
+    /// The inner transactions to be executed atomically under this header.
+    ///
+    /// When empty, the header carries a single inner transaction whose
+    /// sections are designated by `code_hash` and `data_hash`. Otherwise,
+    /// both of these hashes are left zeroed.
+    pub batch: Vec<BatchEntry>,
+}
+
+/// The code and data sections of an inner transaction in a batch
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct BatchEntry {
+    /// The SHA-256 hash of the entry's code section
+    pub code_hash: namada_core::hash::Hash,
+    /// The SHA-256 hash of the entry's data section
+    pub data_hash: namada_core::hash::Hash,
 }
 
 impl Header {
@@ -905,6 +957,7 @@ impl Header {
             code_hash: namada_core::hash::Hash::default(),
             data_hash: namada_core::hash::Hash::default(),
             memo_hash: namada_core::hash::Hash::default(),
+            batch: vec![],
         }
     }
 
@@ -914,6 +967,18 @@ impl Header {
         hasher
     }
 
+    /// Get the header committed to by signatures. The code and data of a
+    /// batch are committed to by its entries, so that the view of any one of
+    /// its entries shares the hash of the whole batch.
+    fn committed(&self) -> Self {
+        let mut header = self.clone();
+        if !header.batch.is_empty() {
+            header.code_hash = namada_core::hash::Hash::default();
+            header.data_hash = namada_core::hash::Hash::default();
+        }
+        header
+    }
+
     /// Get the wrapper header if it is present
     pub fn wrapper(&self) -> Option<WrapperTx> {
         if let TxType::Wrapper(wrapper) = &self.tx_type {
@@ -1044,12 +1109,12 @@ impl Tx {
 
     /// Get the transaction header hash
     pub fn header_hash(&self) -> namada_core::hash::Hash {
-        Section::Header(self.header.clone()).get_hash()
+        Section::Header(self.header.committed()).get_hash()
     }
 
     /// Gets the hash of the decrypted transaction's header
     pub fn raw_header_hash(&self) -> namada_core::hash::Hash {
-        let mut raw_header = self.header();
+        let mut raw_header = self.header.committed();
         raw_header.tx_type = TxType::Raw;
 
         Section::Header(raw_header).get_hash()
@@ -1076,9 +1141,9 @@ impl Tx {
         hash: &namada_core::hash::Hash,
     ) -> Option<Cow<Section>> {
         if self.header_hash() == *hash {
-            return Some(Cow::Owned(Section::Header(self.header.clone())));
+            return Some(Cow::Owned(Section::Header(self.header.committed())));
         } else if self.raw_header_hash() == *hash {
-            let mut header = self.header();
+            let mut header = self.header.committed();
             header.tx_type = TxType::Raw;
             return Some(Cow::Owned(Section::Header(header)));
         }
@@ -1181,6 +1246,61 @@ impl Tx {
         }
     }
 
+    /// Get the entries of the batch of inner transactions carried by this
+    /// transaction. A transaction that isn't a batch carries a batch of a
+    /// single entry.
+    pub fn batch(&self) -> Vec<BatchEntry> {
+        if self.header.batch.is_empty() {
+            vec![BatchEntry {
+                code_hash: self.header.code_hash,
+                data_hash: self.header.data_hash,
+            }]
+        } else {
+            self.header.batch.clone()
+        }
+    }
+
+    /// Check if this transaction carries a batch of inner transactions
+    pub fn is_batch(&self) -> bool {
+        !self.header.batch.is_empty()
+    }
+
+    /// Get a view of this transaction whose code and data hashes designate
+    /// the sections of the given batch entry. The view has the same header
+    /// hashes, and hence the same signatures, as this transaction.
+    pub fn batch_entry(&self, entry: &BatchEntry) -> Tx {
+        let mut tx = self.clone();
+        if tx.is_batch() {
+            tx.set_code_sechash(entry.code_hash);
+            tx.set_data_sechash(entry.data_hash);
+        }
+        tx
+    }
+
+    /// Add the inner transactions of the given transaction to the batch of
+    /// this transaction, together with all of its sections but the
+    /// signatures. If this transaction carries a single inner transaction, it
+    /// becomes the first entry of the batch.
+    pub fn add_batch_entry(&mut self, tx: Tx) -> &mut Self {
+        if !self.is_batch()
+            && *self.code_sechash() != namada_core::hash::Hash::default()
+        {
+            self.header.batch = self.batch();
+        }
+        self.header.batch.extend(tx.batch());
+        self.set_code_sechash(namada_core::hash::Hash::default());
+        self.set_data_sechash(namada_core::hash::Hash::default());
+
+        let hashes: HashSet<_> =
+            self.sections.iter().map(Section::get_hash).collect();
+        self.sections
+            .extend(tx.sections.into_iter().filter(|section| {
+                !matches!(section, Section::Signature(_))
+                    && !hashes.contains(&section.get_hash())
+            }));
+        self
+    }
+
     /// Convert this transaction into protobufs bytes
     pub fn to_bytes(&self) -> Vec<u8> {
         use prost::Message;
@@ -1197,6 +1317,60 @@ impl Tx {
     /// Verify that the section with the given hash has been signed by the given
     /// public key
     pub fn verify_signatures<F>(
+        &self,
+        hashes: &[namada_core::hash::Hash],
+        public_keys_index_map: AccountPublicKeysMap,
+        signer: &Option<Address>,
+        threshold: u8,
+        max_signatures: Option<u8>,
+        consume_verify_sig_gas: F,
+    ) -> std::result::Result<Vec<&Signature>, VerifySigError>
+    where
+        F: FnMut() -> std::result::Result<(), namada_gas::Error>,
+    {
+        self.check_signatures(
+            hashes,
+            public_keys_index_map,
+            signer,
+            threshold,
+            max_signatures,
+            consume_verify_sig_gas,
+            true,
+        )
+    }
+
+    /// Charge the gas of verifying the signatures of the section with the
+    /// given hash like [`Tx::verify_signatures`], but without checking them,
+    /// such that the signatures of the given public keys are all accepted.
+    /// Must only be used to estimate the gas of a tx carrying placeholder
+    /// signatures.
+    pub fn meter_signatures<F>(
+        &self,
+        hashes: &[namada_core::hash::Hash],
+        public_keys_index_map: AccountPublicKeysMap,
+        signer: &Option<Address>,
+        threshold: u8,
+        max_signatures: Option<u8>,
+        consume_verify_sig_gas: F,
+    ) -> std::result::Result<Vec<&Signature>, VerifySigError>
+    where
+        F: FnMut() -> std::result::Result<(), namada_gas::Error>,
+    {
+        self.check_signatures(
+            hashes,
+            public_keys_index_map,
+            signer,
+            threshold,
+            max_signatures,
+            consume_verify_sig_gas,
+            false,
+        )
+    }
+
+    /// Charge the gas of the signatures of the section with the given hash,
+    /// verifying them only if `verify` is set
+    #[allow(clippy::too_many_arguments)]
+    fn check_signatures<F>(
         &self,
         hashes: &[namada_core::hash::Hash],
         public_keys_index_map: AccountPublicKeysMap,
@@ -1204,6 +1378,7 @@ impl Tx {
         threshold: u8,
         max_signatures: Option<u8>,
         mut consume_verify_sig_gas: F,
+        verify: bool,
     ) -> std::result::Result<Vec<&Signature>, VerifySigError>
     where
         F: FnMut() -> std::result::Result<(), namada_gas::Error>,
@@ -1234,11 +1409,12 @@ impl Tx {
 
                     // Finally verify that the signature itself is valid
                     let amt_verifieds = signatures
-                        .verify_signature(
+                        .check_signature(
                             &mut verified_pks,
                             &public_keys_index_map,
                             signer,
                             &mut consume_verify_sig_gas,
+                            verify,
                         )
                         .map_err(|_e| {
                             VerifySigError::InvalidSectionSignature(
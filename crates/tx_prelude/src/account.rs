@@ -13,6 +13,31 @@ pub fn init_account(
         &data.public_keys,
         data.threshold,
     )?;
+    if let Some(schedule) = data.vesting {
+        // The schedule is only enforced by the vesting VP
+        if !namada_account::has_vesting_vp(ctx, owner)? {
+            return Err(Error::new_const(
+                "A vesting schedule can only be set on an account with the \
+                 vesting validity predicate",
+            ));
+        }
+        namada_account::write_vesting_schedule(ctx, owner, schedule)?;
+    }
 
     Ok(owner.to_owned())
 }
+
+/// Grant, update or revoke the fee allowance of the grantee, paid by the
+/// granter
+pub fn grant_fee_allowance(
+    ctx: &mut Ctx,
+    data: GrantFeeAllowance,
+) -> EnvResult<()> {
+    ctx.insert_verifier(&data.granter)?;
+    namada_account::write_fee_allowance(
+        ctx,
+        &data.granter,
+        &data.grantee,
+        data.allowance,
+    )
+}
@@ -1,4 +1,6 @@
-use namada_tx::data::pgf::UpdateStewardCommission;
+use namada_tx::data::pgf::{
+    CancelPgfStream, ClaimPgfStream, UpdateStewardCommission,
+};
 
 use super::*;
 
@@ -20,3 +22,33 @@ pub fn remove_steward(ctx: &mut Ctx, data: &Address) -> EnvResult<()> {
 
     Ok(())
 }
+
+pub fn claim_pgf_stream(
+    ctx: &mut Ctx,
+    data: ClaimPgfStream,
+) -> EnvResult<token::Amount> {
+    ctx.insert_verifier(&data.target)?;
+    let current_epoch = ctx.get_block_epoch()?;
+    let amount = namada_governance::pgf::storage::claim_stream(
+        ctx,
+        &data.target,
+        current_epoch,
+    )?;
+
+    Ok(amount)
+}
+
+pub fn cancel_pgf_stream(
+    ctx: &mut Ctx,
+    data: CancelPgfStream,
+) -> EnvResult<()> {
+    ctx.insert_verifier(&data.steward)?;
+    let current_epoch = ctx.get_block_epoch()?;
+    namada_governance::pgf::storage::cancel_stream(
+        ctx,
+        &data.target,
+        current_epoch,
+    )?;
+
+    Ok(())
+}
@@ -2,11 +2,15 @@
 
 use namada_core::dec::Dec;
 use namada_core::{key, token};
+use namada_proof_of_stake::liquid_staking::{
+    redeem_share_tokens, tokenize_bond,
+};
 pub use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::storage::read_pos_params;
 use namada_proof_of_stake::types::{ResultSlashing, ValidatorMetaData};
 use namada_proof_of_stake::{
-    become_validator, bond_tokens, change_consensus_key,
+    become_validator, bond_tokens, cancel_unbond, change_auto_compound_rewards,
+    change_consensus_key, change_reward_recipient,
     change_validator_commission_rate, change_validator_metadata,
     claim_reward_tokens, deactivate_validator, reactivate_validator,
     redelegate_tokens, unbond_tokens, unjail_validator, withdraw_tokens,
@@ -55,6 +59,26 @@ impl Ctx {
         withdraw_tokens(self, source, validator, current_epoch)
     }
 
+    /// Cancel an unbond that is not yet withdrawable, bonding the tokens back
+    /// to the `validator` at the pipeline offset.
+    pub fn cancel_unbond(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        withdraw_epoch: Epoch,
+        amount: Option<token::Amount>,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        cancel_unbond(
+            self,
+            source,
+            validator,
+            withdraw_epoch,
+            amount,
+            current_epoch,
+        )
+    }
+
     /// Change validator consensus key.
     pub fn change_validator_consensus_key(
         &mut self,
@@ -100,6 +124,30 @@ impl Ctx {
         )
     }
 
+    /// Tokenize bonded tokens into the share token of the validator. Returns
+    /// the amount of minted share tokens.
+    pub fn tokenize_bond(
+        &mut self,
+        source: &Address,
+        validator: &Address,
+        amount: token::Amount,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        tokenize_bond(self, source, validator, amount, current_epoch)
+    }
+
+    /// Redeem share tokens of a validator back into a bond. Returns the
+    /// redeemed bond amount.
+    pub fn redeem_share_tokens(
+        &mut self,
+        owner: &Address,
+        validator: &Address,
+        amount: token::Amount,
+    ) -> EnvResult<token::Amount> {
+        let current_epoch = self.get_block_epoch()?;
+        redeem_share_tokens(self, owner, validator, amount, current_epoch)
+    }
+
     /// Claim available reward tokens
     pub fn claim_reward_tokens(
         &mut self,
@@ -110,6 +158,25 @@ impl Ctx {
         claim_reward_tokens(self, source, validator, current_epoch)
     }
 
+    /// Enable or disable the automatic compounding of the rewards of a bond
+    pub fn change_auto_compound_rewards(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        enabled: bool,
+    ) -> TxResult {
+        change_auto_compound_rewards(self, source, validator, enabled)
+    }
+
+    /// Change the address credited with the claimed rewards of a source
+    pub fn change_reward_recipient(
+        &mut self,
+        source: &Address,
+        recipient: Option<&Address>,
+    ) -> TxResult {
+        change_reward_recipient(self, source, recipient)
+    }
+
     /// Attempt to initialize a validator account. On success, returns the
     /// initialized validator account's address.
     pub fn become_validator(
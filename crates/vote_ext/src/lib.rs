@@ -143,19 +143,26 @@ impl EthereumTxData {
         signing_key: &common::SecretKey,
         chain_id: ChainId,
     ) -> Tx {
+        let mut outer_tx = self.unsigned(signing_key.to_public(), chain_id);
+        outer_tx.add_section(namada_tx::Section::Signature(Signature::new(
+            outer_tx.sechashes(),
+            [(0, signing_key.clone())].into_iter().collect(),
+            None,
+        )));
+        outer_tx
+    }
+
+    /// Wrap transaction Ethereum data in a [`Tx`] to be signed with the
+    /// protocol key of the given public key.
+    pub fn unsigned(&self, pk: common::PublicKey, chain_id: ChainId) -> Tx {
         let (tx_data, tx_type) = self.serialize();
         let mut outer_tx =
             Tx::from_type(TxType::Protocol(Box::new(ProtocolTx {
-                pk: signing_key.to_public(),
+                pk,
                 tx: tx_type,
             })));
         outer_tx.header.chain_id = chain_id;
         outer_tx.set_data(namada_tx::Data::new(tx_data));
-        outer_tx.add_section(namada_tx::Section::Signature(Signature::new(
-            outer_tx.sechashes(),
-            [(0, signing_key.clone())].into_iter().collect(),
-            None,
-        )));
         outer_tx
     }
 
@@ -1,19 +1,33 @@
+#[cfg(any(feature = "vp_user", feature = "vp_vesting"))]
+mod pos_changes;
 #[cfg(feature = "tx_become_validator")]
 pub mod tx_become_validator;
 #[cfg(feature = "tx_bond")]
 pub mod tx_bond;
 #[cfg(feature = "tx_bridge_pool")]
 pub mod tx_bridge_pool;
+#[cfg(feature = "tx_cancel_pgf_stream")]
+pub mod tx_cancel_pgf_stream;
+#[cfg(feature = "tx_cancel_unbond")]
+pub mod tx_cancel_unbond;
+#[cfg(feature = "tx_change_auto_compound")]
+pub mod tx_change_auto_compound;
 #[cfg(feature = "tx_change_consensus_key")]
 pub mod tx_change_consensus_key;
+#[cfg(feature = "tx_change_reward_recipient")]
+pub mod tx_change_reward_recipient;
 #[cfg(feature = "tx_change_validator_commission")]
 pub mod tx_change_validator_commission;
 #[cfg(feature = "tx_change_validator_metadata")]
 pub mod tx_change_validator_metadata;
+#[cfg(feature = "tx_claim_pgf_stream")]
+pub mod tx_claim_pgf_stream;
 #[cfg(feature = "tx_claim_rewards")]
 pub mod tx_claim_rewards;
 #[cfg(feature = "tx_deactivate_validator")]
 pub mod tx_deactivate_validator;
+#[cfg(feature = "tx_grant_fee_allowance")]
+pub mod tx_grant_fee_allowance;
 #[cfg(feature = "tx_ibc")]
 pub mod tx_ibc;
 #[cfg(feature = "tx_init_account")]
@@ -22,12 +36,16 @@ pub mod tx_init_account;
 pub mod tx_init_proposal;
 #[cfg(feature = "tx_reactivate_validator")]
 pub mod tx_reactivate_validator;
+#[cfg(feature = "tx_redeem_shares")]
+pub mod tx_redeem_shares;
 #[cfg(feature = "tx_redelegate")]
 pub mod tx_redelegate;
 #[cfg(feature = "tx_resign_steward")]
 pub mod tx_resign_steward;
 #[cfg(feature = "tx_reveal_pk")]
 pub mod tx_reveal_pk;
+#[cfg(feature = "tx_tokenize_bond")]
+pub mod tx_tokenize_bond;
 #[cfg(feature = "tx_transfer")]
 pub mod tx_transfer;
 #[cfg(feature = "tx_unbond")]
@@ -47,3 +65,5 @@ pub mod tx_withdraw;
 pub mod vp_implicit;
 #[cfg(feature = "vp_user")]
 pub mod vp_user;
+#[cfg(feature = "vp_vesting")]
+pub mod vp_vesting;
@@ -0,0 +1,223 @@
+//! Validation of the PoS storage changes shared by the user VPs.
+
+use core::ops::Deref;
+
+use namada_vp_prelude::*;
+use proof_of_stake::storage::{
+    read_pos_params, read_reward_recipient, validator_state_handle,
+};
+use proof_of_stake::storage_key::{
+    is_auto_compound_bonds_key, is_below_capacity_validator_set_key,
+    is_bond_epoched_meta_key, is_bond_key, is_consensus_keys_key,
+    is_consensus_validator_set_key, is_delegator_redelegations_key,
+    is_last_pos_reward_claim_epoch_key, is_rewards_counter_key,
+    is_total_consensus_stake_key, is_total_deltas_key, is_unbond_key,
+    is_validator_address_raw_hash_key, is_validator_addresses_key,
+    is_validator_commission_rate_key, is_validator_deltas_key,
+    is_validator_eth_cold_key_key, is_validator_eth_hot_key_key,
+    is_validator_max_commission_rate_change_key, is_validator_metadata_key,
+    is_validator_redelegations_key, is_validator_set_positions_key,
+    is_validator_state_epoched_meta_key, is_validator_state_key,
+    is_validator_total_bond_or_unbond_key,
+};
+
+/// Validate a change of a PoS storage key for an account owned by `owner`.
+/// Changes that affect the owner's bonds, validator or rewards must be
+/// signed by the owner.
+pub fn validate_pos_changes(
+    ctx: &Ctx,
+    owner: &Address,
+    key: &storage::Key,
+    valid_sig: &impl Deref<Target = bool>,
+) -> VpResult {
+    // Bond or unbond
+    let is_valid_bond_or_unbond_change = || {
+        let bond_id = is_bond_key(key)
+            .map(|(bond_id, _)| bond_id)
+            .or_else(|| is_bond_epoched_meta_key(key))
+            .or_else(|| is_unbond_key(key).map(|(bond_id, _, _)| bond_id));
+        if let Some(bond_id) = bond_id {
+            // Bonds and unbonds changes for this address must be signed
+            return &bond_id.source != owner || **valid_sig;
+        };
+        // Unknown changes are not allowed
+        false
+    };
+
+    // Commission rate changes must be signed by the validator
+    let is_valid_commission_rate_change = || {
+        if let Some(validator) = is_validator_commission_rate_key(key) {
+            return validator == owner && **valid_sig;
+        }
+        false
+    };
+
+    // Metadata changes must be signed by the validator whose
+    // metadata is manipulated
+    let is_valid_metadata_change = || {
+        let metadata = is_validator_metadata_key(key);
+        match metadata {
+            Some(address) => address == owner && **valid_sig,
+            None => false,
+        }
+    };
+
+    // Changes in validator state
+    let is_valid_state_change = || {
+        let state_change = is_validator_state_key(key);
+        let is_valid_state = match state_change {
+            Some((address, epoch)) => {
+                let params_pre = read_pos_params(&ctx.pre())?;
+                let state_pre = validator_state_handle(address).get(
+                    &ctx.pre(),
+                    epoch,
+                    &params_pre,
+                )?;
+
+                let params_post = read_pos_params(&ctx.post())?;
+                let state_post = validator_state_handle(address).get(
+                    &ctx.post(),
+                    epoch,
+                    &params_post,
+                )?;
+
+                match (state_pre, state_post) {
+                    (Some(pre), Some(post)) => {
+                        use proof_of_stake::types::ValidatorState::*;
+
+                        if (
+                            // Deactivation case
+                            matches!(
+                                    pre,
+                                    Consensus | BelowCapacity | BelowThreshold
+                                ) && post == Inactive)
+                            // Reactivation case
+                            || (pre == Inactive && post != Inactive)
+                            // Unjail case
+                            || (pre == Jailed
+                                && matches!(
+                                    post,
+                                    Consensus
+                                        | BelowCapacity
+                                        | BelowThreshold
+                                ))
+                        {
+                            if address == owner { **valid_sig } else { true }
+                        } else if
+                        // Bonding and unbonding may affect validator sets
+                        matches!(
+                            pre,
+                            Consensus | BelowCapacity | BelowThreshold
+                        ) && matches!(
+                            post,
+                            Consensus | BelowCapacity | BelowThreshold
+                        ) {
+                            true
+                        } else {
+                            // Unknown state changes are not allowed
+                            false
+                        }
+                    }
+                    (None, Some(_post)) => {
+                        // Becoming a validator must be authorized
+                        address == owner && **valid_sig
+                    }
+                    (Some(_pre), None) => {
+                        // Clearing of old epoched data
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            None => false,
+        };
+
+        VpResult::Ok(
+            is_valid_state
+                || is_validator_state_epoched_meta_key(key)
+                || is_consensus_validator_set_key(key)
+                || is_below_capacity_validator_set_key(key),
+        )
+    };
+
+    // The rewards of an address that has set another reward recipient can
+    // be claimed by anyone, as they can only be credited to that recipient
+    let is_claim_to_other_recipient = || -> VpResult {
+        Ok(&read_reward_recipient(&ctx.pre(), owner)? != owner)
+    };
+
+    let is_valid_reward_claim = || -> VpResult {
+        if let Some(bond_id) = is_last_pos_reward_claim_epoch_key(key) {
+            // Claims for this address must be signed, unless claimed in the
+            // current epoch to another recipient
+            if &bond_id.source != owner || **valid_sig {
+                return Ok(true);
+            }
+            let claim_epoch: Option<storage::Epoch> = ctx.read_post(key)?;
+            return Ok(claim_epoch == Some(ctx.get_block_epoch()?)
+                && is_claim_to_other_recipient()?);
+        }
+        if let Some(bond_id) = is_rewards_counter_key(key) {
+            // Claims for this address must be signed, unless the counter is
+            // emptied to another recipient
+            if &bond_id.source != owner || **valid_sig {
+                return Ok(true);
+            }
+            let counter: Option<token::Amount> = ctx.read_post(key)?;
+            return Ok(counter.is_none() && is_claim_to_other_recipient()?);
+        }
+        Ok(false)
+    };
+
+    let is_valid_auto_compound_change = || {
+        if let Some(bond_id) = is_auto_compound_bonds_key(key) {
+            // Auto-compounding changes for this address must be signed
+            return &bond_id.source != owner || **valid_sig;
+        }
+        false
+    };
+
+    let is_valid_redelegation = || {
+        if is_validator_redelegations_key(key) {
+            return true;
+        }
+        if let Some(delegator) = is_delegator_redelegations_key(key) {
+            // Redelegations for this address must be signed
+            return delegator != owner || **valid_sig;
+        }
+        if let Some(bond_id) = is_rewards_counter_key(key) {
+            // Redelegations auto-claim rewards
+            return &bond_id.source != owner || **valid_sig;
+        }
+        false
+    };
+
+    let is_valid_become_validator = || {
+        if is_validator_addresses_key(key)
+            || is_consensus_keys_key(key)
+            || is_validator_eth_cold_key_key(key).is_some()
+            || is_validator_eth_hot_key_key(key).is_some()
+            || is_validator_max_commission_rate_change_key(key).is_some()
+            || is_validator_address_raw_hash_key(key).is_some()
+        {
+            // A signature is required to become validator
+            return **valid_sig;
+        }
+        false
+    };
+
+    Ok(is_valid_bond_or_unbond_change()
+        || is_total_deltas_key(key)
+        || is_validator_deltas_key(key)
+        || is_validator_total_bond_or_unbond_key(key)
+        || is_validator_set_positions_key(key)
+        || is_total_consensus_stake_key(key)
+        || is_valid_state_change()?
+        || is_valid_reward_claim()?
+        || is_valid_auto_compound_change()
+        || is_valid_redelegation()
+        || is_valid_commission_rate_change()
+        || is_valid_metadata_change()
+        || is_valid_become_validator()
+        || **valid_sig)
+}
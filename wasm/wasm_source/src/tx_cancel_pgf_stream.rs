@@ -0,0 +1,19 @@
+//! A tx for a pgf steward to cancel a pgf stream
+
+use namada_tx_prelude::transaction::pgf::CancelPgfStream;
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1222239)]
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let cancel = CancelPgfStream::try_from_slice(&data[..])
+        .wrap_err("failed to decode a CancelPgfStream")?;
+
+    pgf::cancel_pgf_stream(ctx, cancel)?;
+
+    Ok(())
+}
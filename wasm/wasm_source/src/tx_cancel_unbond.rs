@@ -0,0 +1,25 @@
+//! A tx for a PoS unbond cancellation that bonds the tokens of an unbond that
+//! is not yet withdrawable back to their validator.
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1119469)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data")?;
+    let transaction::pos::CancelUnbond {
+        validator,
+        source,
+        withdraw_epoch,
+        amount,
+    } = transaction::pos::CancelUnbond::try_from_slice(&data[..])
+        .wrap_err("failed to decode CancelUnbond")?;
+
+    let bonded =
+        ctx.cancel_unbond(source.as_ref(), &validator, withdraw_epoch, amount)?;
+    debug_log!(
+        "Bonded back {} after cancelling unbond",
+        bonded.to_string_native()
+    );
+    Ok(())
+}
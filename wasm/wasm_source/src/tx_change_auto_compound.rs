@@ -0,0 +1,18 @@
+//! A tx for a user to enable or disable the automatic compounding of the PoS
+//! rewards of a bond.
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 260000)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data")?;
+    let transaction::pos::AutoCompound {
+        validator,
+        source,
+        enabled,
+    } = transaction::pos::AutoCompound::try_from_slice(&data[..])
+        .wrap_err("failed to decode AutoCompound")?;
+
+    ctx.change_auto_compound_rewards(source.as_ref(), &validator, enabled)
+}
@@ -0,0 +1,15 @@
+//! A tx for a user to change the address credited with the claimed PoS
+//! rewards of their bonds.
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 220000)] // TODO: needs to be benchmarked
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data")?;
+    let transaction::pos::RewardRecipient { source, recipient } =
+        transaction::pos::RewardRecipient::try_from_slice(&data[..])
+            .wrap_err("failed to decode RewardRecipient")?;
+
+    ctx.change_reward_recipient(&source, recipient.as_ref())
+}
@@ -0,0 +1,19 @@
+//! A tx to grant, update or revoke a fee allowance
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 1222239)]
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let grant = account::GrantFeeAllowance::try_from_slice(&data[..])
+        .wrap_err("failed to decode a GrantFeeAllowance")?;
+    debug_log!("apply_tx called to grant a fee allowance: {:#?}", grant);
+
+    account::grant_fee_allowance(ctx, grant)?;
+
+    Ok(())
+}
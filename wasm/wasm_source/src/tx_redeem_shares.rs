@@ -0,0 +1,24 @@
+//! A tx for a holder of share tokens to redeem them back into a bond to the
+//! validator.
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 2578311)]
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let transaction::pos::RedeemShares {
+        validator,
+        owner,
+        amount,
+    } = transaction::pos::RedeemShares::try_from_slice(&data[..])
+        .wrap_err("failed to decode a RedeemShares")?;
+
+    let bond = ctx.redeem_share_tokens(&owner, &validator, amount)?;
+    debug_log!("Redeemed a bond of {}", bond.to_string_native());
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! A tx for a delegator to tokenize bonded tokens into the share token of the
+//! validator.
+
+use namada_tx_prelude::*;
+
+#[transaction(gas = 2631750)]
+fn apply_tx(ctx: &mut Ctx, tx_data: Tx) -> TxResult {
+    let signed = tx_data;
+    let data = signed.data().ok_or_err_msg("Missing data").map_err(|err| {
+        ctx.set_commitment_sentinel();
+        err
+    })?;
+    let transaction::pos::TokenizeBond {
+        validator,
+        source,
+        amount,
+    } = transaction::pos::TokenizeBond::try_from_slice(&data[..])
+        .wrap_err("failed to decode a TokenizeBond")?;
+
+    let shares = ctx.tokenize_bond(&source, &validator, amount)?;
+    debug_log!("Minted {} share tokens", shares.to_string_native());
+
+    Ok(())
+}
@@ -171,6 +171,7 @@ fn validate_pos_changes(
     key: &storage::Key,
     valid_sig: &impl Deref<Target = bool>,
 ) -> VpResult {
+    use namada_vp_prelude::storage::Epoch;
     use proof_of_stake::{storage, storage_key};
 
     // Bond or unbond
@@ -239,18 +240,43 @@ fn validate_pos_changes(
         )
     };
 
-    let is_valid_reward_claim = || {
+    // The rewards of an address that has set another reward recipient can
+    // be claimed by anyone, as they can only be credited to that recipient
+    let is_claim_to_other_recipient = || -> VpResult {
+        Ok(&storage::read_reward_recipient(&ctx.pre(), owner)? != owner)
+    };
+
+    let is_valid_reward_claim = || -> VpResult {
         if let Some(bond_id) =
             storage_key::is_last_pos_reward_claim_epoch_key(key)
         {
-            // Claims for this address must be signed
-            return &bond_id.source != owner || **valid_sig;
+            // Claims for this address must be signed, unless claimed in the
+            // current epoch to another recipient
+            if &bond_id.source != owner || **valid_sig {
+                return Ok(true);
+            }
+            let claim_epoch: Option<Epoch> = ctx.read_post(key)?;
+            return Ok(claim_epoch == Some(ctx.get_block_epoch()?)
+                && is_claim_to_other_recipient()?);
         }
         if let Some(bond_id) = storage_key::is_rewards_counter_key(key) {
-            // Redelegations auto-claim rewards
-            return &bond_id.source != owner || **valid_sig;
+            // Claims for this address must be signed, unless the counter is
+            // emptied to another recipient
+            if &bond_id.source != owner || **valid_sig {
+                return Ok(true);
+            }
+            let counter: Option<token::Amount> = ctx.read_post(key)?;
+            return Ok(counter.is_none() && is_claim_to_other_recipient()?);
         }
 
+        Ok(false)
+    };
+
+    let is_valid_auto_compound_change = || {
+        if let Some(bond_id) = storage_key::is_auto_compound_bonds_key(key) {
+            // Auto-compounding changes for this address must be signed
+            return &bond_id.source != owner || **valid_sig;
+        }
         false
     };
 
@@ -278,7 +304,8 @@ fn validate_pos_changes(
         || storage_key::is_validator_set_positions_key(key)
         || storage_key::is_total_consensus_stake_key(key)
         || is_valid_state_change()?
-        || is_valid_reward_claim()
+        || is_valid_reward_claim()?
+        || is_valid_auto_compound_change()
         || is_valid_redelegation()
         || **valid_sig)
 }
@@ -656,6 +683,83 @@ mod tests {
         );
     }
 
+    /// Test that the rewards of the VP owner can be claimed without its
+    /// signature only when they are credited to another reward recipient.
+    #[test]
+    fn test_unsigned_reward_claim() {
+        let target = address::testing::established_address_2();
+        for (recipient, expected) in [(Some(&target), true), (None, false)] {
+            // Init PoS genesis
+            let pos_params = PosParams::default();
+            let validator = address::testing::established_address_3();
+            let initial_stake =
+                token::Amount::from_uint(10_098_123, 0).unwrap();
+            let genesis_validators = [GenesisValidator {
+                address: validator.clone(),
+                tokens: initial_stake,
+                consensus_key: key::testing::keypair_2().ref_to(),
+                protocol_key: key::testing::keypair_1().ref_to(),
+                commission_rate: Dec::new(5, 2).unwrap(),
+                max_commission_rate_change: Dec::new(1, 2).unwrap(),
+                eth_hot_key: key::testing::keypair_4().ref_to(),
+                eth_cold_key: key::testing::keypair_3().ref_to(),
+                metadata: Default::default(),
+            }];
+            init_pos(&genesis_validators[..], &pos_params, Epoch(0));
+
+            // Initialize a tx environment
+            let mut tx_env = tx_host_env::take();
+
+            let public_key = key::testing::keypair_1().ref_to();
+            let vp_owner: Address = (&public_key).into();
+            let token = address::testing::nam();
+            let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+
+            // Spawn the accounts to be able to modify their storage
+            tx_env.spawn_accounts([&target, &token]);
+            tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+            tx_env.credit_tokens(&vp_owner, &token, amount);
+            // write the denomination of NAM into storage
+            token::write_denom(
+                &mut tx_env.state,
+                &token,
+                token::NATIVE_MAX_DECIMAL_PLACES.into(),
+            )
+            .unwrap();
+
+            // Bond the tokens and set the reward recipient in a previous tx
+            tx_host_env::set(tx_env);
+            tx::ctx()
+                .bond_tokens(Some(&vp_owner), &validator, amount)
+                .unwrap();
+            tx::ctx()
+                .change_reward_recipient(&vp_owner, recipient)
+                .unwrap();
+            tx_host_env::commit_tx_and_block();
+            let tx_env = tx_host_env::take();
+
+            // Initialize VP environment from a claim of the rewards
+            vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |_address| {
+                tx::ctx()
+                    .claim_reward_tokens(Some(&vp_owner), &validator)
+                    .unwrap();
+            });
+
+            let vp_env = vp_host_env::take();
+            let mut tx_data = Tx::from_type(TxType::Raw);
+            tx_data.set_data(Data::new(vec![]));
+            let keys_changed: BTreeSet<storage::Key> =
+                vp_env.all_touched_storage_keys();
+            let verifiers: BTreeSet<Address> = BTreeSet::default();
+            vp_host_env::set(vp_env);
+            assert_eq!(
+                validate_tx(&CTX, tx_data, vp_owner, keys_changed, verifiers)
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
     /// Test that a debit transfer without a valid signature is rejected.
     #[test]
     fn test_unsigned_debit_transfer_rejected() {
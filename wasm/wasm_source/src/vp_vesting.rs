@@ -0,0 +1,679 @@
+//! A vesting account VP, which extends the user VP with a vesting schedule.
+//!
+//! On top of the checks of the user VP, debits of the native token are only
+//! allowed as long as the balance left in the account, together with the
+//! tokens moved into PoS in the same tx, covers the amount that is still
+//! locked by the vesting schedule in the current epoch. Locked tokens can
+//! therefore be bonded, but not transferred. While some tokens are locked,
+//! the bonds cannot be tokenized into transferable share tokens either.
+//!
+//! The vesting schedule cannot be modified once written and the VP cannot be
+//! replaced while any tokens remain locked.
+
+use namada_vp_prelude::*;
+use once_cell::unsync::Lazy;
+use proof_of_stake::liquid_staking::share_token_validator;
+use proof_of_stake::storage_key::{
+    bonds_for_source_prefix, is_bond_key, is_pos_key, is_unbond_key,
+    unbonds_for_source_prefix,
+};
+
+use crate::pos_changes::validate_pos_changes;
+
+enum KeyType<'a> {
+    TokenBalance {
+        owner: &'a Address,
+        token: &'a Address,
+    },
+    TokenMinted,
+    TokenMinter(&'a Address),
+    PoS,
+    Vp(&'a Address),
+    Vesting(&'a Address),
+    Masp,
+    PgfSteward(&'a Address),
+    GovernanceVote(&'a Address),
+    Ibc,
+    Unknown,
+}
+
+impl<'a> From<&'a storage::Key> for KeyType<'a> {
+    fn from(key: &'a storage::Key) -> KeyType<'a> {
+        if let Some([token, owner]) =
+            token::storage_key::is_any_token_balance_key(key)
+        {
+            Self::TokenBalance { owner, token }
+        } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
+            Self::TokenMinted
+        } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
+        {
+            Self::TokenMinter(minter)
+        } else if is_pos_key(key) {
+            Self::PoS
+        } else if gov_storage::keys::is_vote_key(key) {
+            let voter_address = gov_storage::keys::get_voter_address(key);
+            if let Some(address) = voter_address {
+                Self::GovernanceVote(address)
+            } else {
+                Self::Unknown
+            }
+        } else if let Some(address) = pgf_storage::keys::is_stewards_key(key) {
+            Self::PgfSteward(address)
+        } else if let Some(address) = key.is_validity_predicate() {
+            Self::Vp(address)
+        } else if let Some(address) = account::is_vesting_key(key) {
+            Self::Vesting(address)
+        } else if token::storage_key::is_masp_key(key) {
+            Self::Masp
+        } else if ibc::is_ibc_key(key) {
+            Self::Ibc
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[validity_predicate(gas = 137325)]
+fn validate_tx(
+    ctx: &Ctx,
+    tx_data: Tx,
+    addr: Address,
+    keys_changed: BTreeSet<storage::Key>,
+    verifiers: BTreeSet<Address>,
+) -> VpResult {
+    debug_log!(
+        "vp_vesting called with user addr: {}, key_changed: {:?}, verifiers: \
+         {:?}",
+        addr,
+        keys_changed,
+        verifiers
+    );
+
+    let valid_sig = Lazy::new(|| {
+        matches!(verify_signatures(ctx, &tx_data, &addr), Ok(true))
+    });
+    let locked =
+        account::locked_amount(&ctx.pre(), &addr, ctx.get_block_epoch()?)?;
+    let native_token = ctx.get_native_token()?;
+
+    for key in keys_changed.iter() {
+        let key_type: KeyType = key.into();
+        let is_valid = match key_type {
+            KeyType::TokenBalance { owner, token } => {
+                if owner == &addr {
+                    let pre: token::Amount =
+                        ctx.read_pre(key)?.unwrap_or_default();
+                    let post: token::Amount =
+                        ctx.read_post(key)?.unwrap_or_default();
+                    let change = post.change() - pre.change();
+                    // debit has to signed, credit doesn't, and a debit of
+                    // the native token cannot spend locked tokens
+                    let valid = if change.non_negative() {
+                        // bonds holding locked tokens cannot be tokenized
+                        locked.is_zero() || !is_share_token_minted(ctx, token)?
+                    } else if token == &native_token {
+                        *valid_sig
+                            && is_unlocked_debit(ctx, &addr, post, locked)?
+                    } else {
+                        *valid_sig
+                    };
+                    debug_log!(
+                        "token key: {}, change: {:?}, valid_sig: {}, valid \
+                         modification: {}",
+                        key,
+                        change,
+                        *valid_sig,
+                        valid
+                    );
+                    valid
+                } else {
+                    debug_log!(
+                        "This address ({}) is not of owner ({}) of token key: \
+                         {}",
+                        addr,
+                        owner,
+                        key
+                    );
+                    // If this is not the owner, allow any change
+                    true
+                }
+            }
+            KeyType::TokenMinted => verifiers.contains(&address::MULTITOKEN),
+            KeyType::TokenMinter(minter) => minter != &addr || *valid_sig,
+            KeyType::PoS => validate_pos_changes(ctx, &addr, key, &valid_sig)?,
+            KeyType::PgfSteward(address) => address != &addr || *valid_sig,
+            KeyType::GovernanceVote(voter) => voter != &addr || *valid_sig,
+            KeyType::Vp(owner) => {
+                let has_post: bool = ctx.has_key_post(key)?;
+                if owner == &addr {
+                    // The VP cannot be replaced while tokens are locked
+                    has_post && *valid_sig && locked.is_zero()
+                } else {
+                    true
+                }
+            }
+            KeyType::Vesting(owner) => {
+                if owner == &addr {
+                    // A vesting schedule can only be written once
+                    !ctx.has_key_pre(key)? && *valid_sig
+                } else {
+                    true
+                }
+            }
+            KeyType::Masp | KeyType::Ibc => true,
+            KeyType::Unknown => {
+                // Unknown changes require a valid signature
+                *valid_sig
+            }
+        };
+        if !is_valid {
+            log_string(format!("key {} modification failed vp_vesting", key));
+            return reject();
+        }
+    }
+
+    accept()
+}
+
+/// Check that a debit of the native token leaves enough tokens to cover the
+/// amount that is still locked. The tokens of this account held by PoS (i.e.
+/// bonded or unbonded, but not yet withdrawn) are counted towards the locked
+/// amount.
+fn is_unlocked_debit(
+    ctx: &Ctx,
+    owner: &Address,
+    post: token::Amount,
+    locked: token::Amount,
+) -> VpResult {
+    let held_by_pos = read_pos_held_amount(ctx, owner)?;
+    let remaining = post
+        .checked_add(held_by_pos)
+        .unwrap_or(token::Amount::max());
+    Ok(remaining >= locked)
+}
+
+/// Check if the given token is a share token whose supply increases in this
+/// tx, i.e. if a bond is being tokenized into it.
+fn is_share_token_minted(ctx: &Ctx, token: &Address) -> VpResult {
+    if share_token_validator(token).is_none() {
+        return Ok(false);
+    }
+    let minted_key = token::storage_key::minted_balance_key(token);
+    let pre: token::Amount = ctx.read_pre(&minted_key)?.unwrap_or_default();
+    let post: token::Amount = ctx.read_post(&minted_key)?.unwrap_or_default();
+    Ok(post > pre)
+}
+
+/// Read the sum of the owner's bonds and unbonds in the posterior state.
+fn read_pos_held_amount(
+    ctx: &Ctx,
+    owner: &Address,
+) -> EnvResult<token::Amount> {
+    let mut held = token::Amount::zero();
+    for prefix in [
+        bonds_for_source_prefix(owner),
+        unbonds_for_source_prefix(owner),
+    ] {
+        for res in iter_prefix_bytes(&ctx.post(), &prefix)? {
+            let (key, _) = res?;
+            let is_owner_data_key = is_bond_key(&key)
+                .map(|(bond_id, _)| bond_id)
+                .or_else(|| is_unbond_key(&key).map(|(bond_id, _, _)| bond_id))
+                .is_some_and(|bond_id| &bond_id.source == owner);
+            if is_owner_data_key {
+                let amount: token::Amount =
+                    ctx.read_post(&key)?.unwrap_or_default();
+                held = held.checked_add(amount).unwrap_or(token::Amount::max());
+            }
+        }
+    }
+    Ok(held)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada::core::dec::Dec;
+    use namada::core::storage::Epoch;
+    use namada::ledger::pos::{GenesisValidator, PosParams};
+    use namada::tx::data::TxType;
+    use namada::tx::{Code, Data, Signature};
+    use namada_test_utils::TestWasms;
+    // Use this as `#[test]` annotation to enable logging
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::tx::{self, tx_host_env, TestTxEnv};
+    use namada_tests::vp::*;
+    use namada_vp_prelude::account::{AccountPublicKeysMap, VestingSchedule};
+    use namada_vp_prelude::key::RefTo;
+
+    use super::*;
+
+    /// Sign the tx in the VP env with the given key and run the VP on it
+    fn validate_signed(
+        vp_owner: Address,
+        keypair: key::common::SecretKey,
+    ) -> bool {
+        validate_signed_with_verifiers(vp_owner, keypair, BTreeSet::default())
+    }
+
+    /// Sign the tx in the VP env with the given key and run the VP on it with
+    /// the given verifiers
+    fn validate_signed_with_verifiers(
+        vp_owner: Address,
+        keypair: key::common::SecretKey,
+        verifiers: BTreeSet<Address>,
+    ) -> bool {
+        let pks_map = AccountPublicKeysMap::from_iter(vec![keypair.ref_to()]);
+
+        let mut vp_env = vp_host_env::take();
+        let mut tx = vp_env.tx.clone();
+        tx.set_data(Data::new(vec![]));
+        tx.set_code(Code::new(vec![], None));
+        tx.add_section(Section::Signature(Signature::new(
+            vec![tx.raw_header_hash()],
+            pks_map.index_secret_keys(vec![keypair]),
+            None,
+        )));
+        let signed_tx = tx.clone();
+        vp_env.tx = signed_tx.clone();
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        vp_host_env::set(vp_env);
+        validate_tx(&CTX, signed_tx, vp_owner, keys_changed, verifiers).unwrap()
+    }
+
+    /// A schedule locking the given amount until epoch 10
+    fn schedule(amount: token::Amount) -> VestingSchedule {
+        VestingSchedule {
+            amount,
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(10),
+        }
+    }
+
+    /// Init PoS genesis with a single validator and return its address
+    fn init_pos_with_validator() -> Address {
+        let pos_params = PosParams::default();
+        let validator = address::testing::established_address_3();
+        let initial_stake = token::Amount::from_uint(10_098_123, 0).unwrap();
+        let consensus_key = key::testing::keypair_2().ref_to();
+        let protocol_key = key::testing::keypair_1().ref_to();
+        let commission_rate = Dec::new(5, 2).unwrap();
+        let max_commission_rate_change = Dec::new(1, 2).unwrap();
+
+        let genesis_validators = [GenesisValidator {
+            address: validator.clone(),
+            tokens: initial_stake,
+            consensus_key,
+            protocol_key,
+            commission_rate,
+            max_commission_rate_change,
+            eth_hot_key: key::common::PublicKey::Secp256k1(
+                key::testing::gen_keypair::<key::secp256k1::SigScheme>()
+                    .ref_to(),
+            ),
+            eth_cold_key: key::common::PublicKey::Secp256k1(
+                key::testing::gen_keypair::<key::secp256k1::SigScheme>()
+                    .ref_to(),
+            ),
+            metadata: Default::default(),
+        }];
+
+        init_pos(&genesis_validators[..], &pos_params, Epoch(0));
+        validator
+    }
+
+    /// Test that no-op transaction (i.e. no storage modifications) accepted.
+    #[test]
+    fn test_no_op_transaction() {
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let addr: Address = address::testing::established_address_1();
+        let keys_changed: BTreeSet<storage::Key> = BTreeSet::default();
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+
+        // The VP env must be initialized before calling `validate_tx`
+        vp_host_env::init();
+
+        assert!(
+            validate_tx(&CTX, tx_data, addr, keys_changed, verifiers).unwrap()
+        );
+    }
+
+    /// Test that a signed debit transfer is only accepted as long as it
+    /// doesn't spend locked tokens.
+    #[test]
+    fn test_signed_debit_transfer_of_locked_tokens() {
+        let amount = token::Amount::from_uint(10_000_000, 0).unwrap();
+        let locked = token::Amount::from_uint(5_000_000, 0).unwrap();
+
+        for (transfer, expected) in [
+            (token::Amount::from_uint(5_000_000, 0).unwrap(), true),
+            (token::Amount::from_uint(5_000_001, 0).unwrap(), false),
+        ] {
+            // Initialize a tx environment
+            let mut tx_env = TestTxEnv::default();
+
+            let vp_owner = address::testing::established_address_1();
+            let keypair = key::testing::keypair_1();
+            let public_key = keypair.ref_to();
+            let target = address::testing::established_address_2();
+            let token = address::testing::nam();
+
+            // Spawn the accounts to be able to modify their storage
+            tx_env.spawn_accounts([&vp_owner, &target, &token]);
+            tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+            account::write_vesting_schedule(
+                &mut tx_env.state,
+                &vp_owner,
+                schedule(locked),
+            )
+            .unwrap();
+
+            // Credit the tokens to the VP owner before running the
+            // transaction to be able to transfer from it
+            tx_env.credit_tokens(&vp_owner, &token, amount);
+            // write the denomination of NAM into storage
+            token::write_denom(
+                &mut tx_env.state,
+                &token,
+                token::NATIVE_MAX_DECIMAL_PLACES.into(),
+            )
+            .unwrap();
+
+            let transfer = token::DenominatedAmount::new(
+                transfer,
+                token::NATIVE_MAX_DECIMAL_PLACES.into(),
+            );
+            // Initialize VP environment from a transaction
+            vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+                // Apply transfer in a transaction
+                tx_host_env::token::transfer(
+                    tx::ctx(),
+                    address,
+                    &target,
+                    &token,
+                    transfer,
+                )
+                .unwrap();
+            });
+
+            assert_eq!(validate_signed(vp_owner, keypair), expected);
+        }
+    }
+
+    /// Test that locked tokens can be bonded.
+    #[test]
+    fn test_signed_bond_of_locked_tokens_accepted() {
+        let validator = init_pos_with_validator();
+
+        // Initialize a tx environment
+        let mut tx_env = tx_host_env::take();
+
+        let keypair = key::testing::keypair_1();
+        let public_key = keypair.ref_to();
+        let vp_owner: Address = address::testing::established_address_2();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+        let bond_amount = token::Amount::from_uint(5_098_123, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&token]);
+        tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+        // Lock all the tokens of the VP owner
+        account::write_vesting_schedule(
+            &mut tx_env.state,
+            &vp_owner,
+            schedule(amount),
+        )
+        .unwrap();
+
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        // Credit the tokens to the VP owner before running the transaction to
+        // be able to bond them
+        tx_env.credit_tokens(&vp_owner, &token, amount);
+
+        // Initialize VP environment from a bond
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |_address| {
+            tx::ctx()
+                .bond_tokens(Some(&vp_owner), &validator, bond_amount)
+                .unwrap();
+        });
+
+        assert!(validate_signed(vp_owner, keypair));
+    }
+
+    /// Test that a bond of another account in the same tx doesn't allow to
+    /// spend locked tokens.
+    #[test]
+    fn test_signed_debit_of_locked_tokens_with_other_bond_rejected() {
+        let validator = init_pos_with_validator();
+
+        // Initialize a tx environment
+        let mut tx_env = tx_host_env::take();
+
+        let keypair = key::testing::keypair_1();
+        let public_key = keypair.ref_to();
+        let vp_owner: Address = address::testing::established_address_2();
+        let other = address::testing::established_address_1();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_000_000, 0).unwrap();
+        let transfer = token::Amount::from_uint(5_000_000, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&other, &token]);
+        tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+        // Lock all the tokens of the VP owner
+        account::write_vesting_schedule(
+            &mut tx_env.state,
+            &vp_owner,
+            schedule(amount),
+        )
+        .unwrap();
+
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        tx_env.credit_tokens(&vp_owner, &token, amount);
+        tx_env.credit_tokens(&other, &token, transfer);
+
+        let transfer = token::DenominatedAmount::new(
+            transfer,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        );
+        // Initialize VP environment from a tx that bonds the tokens of the
+        // other account and transfers the locked tokens of the VP owner to it
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            tx::ctx()
+                .bond_tokens(Some(&other), &validator, transfer.amount())
+                .unwrap();
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &other,
+                &token,
+                transfer,
+            )
+            .unwrap();
+        });
+
+        assert!(!validate_signed(vp_owner, keypair));
+    }
+
+    /// Test that tokens bonded in a previous tx are counted towards the
+    /// locked amount.
+    #[test]
+    fn test_signed_debit_with_bonded_locked_tokens_accepted() {
+        let validator = init_pos_with_validator();
+
+        // Initialize a tx environment
+        let mut tx_env = tx_host_env::take();
+
+        let keypair = key::testing::keypair_1();
+        let public_key = keypair.ref_to();
+        let vp_owner: Address = address::testing::established_address_2();
+        let target = address::testing::established_address_1();
+        let token = address::testing::nam();
+        let locked = token::Amount::from_uint(10_000_000, 0).unwrap();
+        let unlocked = token::Amount::from_uint(5_000_000, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&target, &token]);
+        tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+        account::write_vesting_schedule(
+            &mut tx_env.state,
+            &vp_owner,
+            schedule(locked),
+        )
+        .unwrap();
+
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        tx_env.credit_tokens(&vp_owner, &token, locked + unlocked);
+
+        // Bond all the locked tokens in a previous tx
+        tx_host_env::set(tx_env);
+        tx::ctx()
+            .bond_tokens(Some(&vp_owner), &validator, locked)
+            .unwrap();
+        tx_host_env::commit_tx_and_block();
+        let tx_env = tx_host_env::take();
+
+        let transfer = token::DenominatedAmount::new(
+            unlocked,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        );
+        // Initialize VP environment from a transfer of the unlocked tokens
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            tx_host_env::token::transfer(
+                tx::ctx(),
+                address,
+                &target,
+                &token,
+                transfer,
+            )
+            .unwrap();
+        });
+
+        assert!(validate_signed(vp_owner, keypair));
+    }
+
+    /// Test that a bond of locked tokens cannot be tokenized into
+    /// transferable share tokens, unless the tokens are unlocked.
+    #[test]
+    fn test_signed_tokenize_bond_of_locked_tokens() {
+        for (locked, expected) in [
+            (token::Amount::from_uint(10_000_000, 0).unwrap(), false),
+            (token::Amount::zero(), true),
+        ] {
+            let validator = init_pos_with_validator();
+
+            // Initialize a tx environment
+            let mut tx_env = tx_host_env::take();
+
+            let keypair = key::testing::keypair_1();
+            let public_key = keypair.ref_to();
+            let vp_owner: Address = address::testing::established_address_2();
+            let token = address::testing::nam();
+            let amount = token::Amount::from_uint(10_000_000, 0).unwrap();
+
+            // Spawn the accounts to be able to modify their storage
+            tx_env.spawn_accounts([&token]);
+            tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+            account::write_vesting_schedule(
+                &mut tx_env.state,
+                &vp_owner,
+                schedule(locked),
+            )
+            .unwrap();
+
+            // write the denomination of NAM into storage
+            token::write_denom(
+                &mut tx_env.state,
+                &token,
+                token::NATIVE_MAX_DECIMAL_PLACES.into(),
+            )
+            .unwrap();
+
+            tx_env.credit_tokens(&vp_owner, &token, amount);
+
+            // Bond all the tokens in a previous tx
+            tx_host_env::set(tx_env);
+            tx::ctx()
+                .bond_tokens(Some(&vp_owner), &validator, amount)
+                .unwrap();
+            tx_host_env::commit_tx_and_block();
+            let tx_env = tx_host_env::take();
+
+            // Initialize VP environment from a tokenization of the bond
+            vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+                tx::ctx()
+                    .tokenize_bond(address, &validator, amount)
+                    .unwrap();
+            });
+
+            // the shares are minted under the multitoken
+            let verifiers = BTreeSet::from([address::MULTITOKEN]);
+            assert_eq!(
+                validate_signed_with_verifiers(vp_owner, keypair, verifiers),
+                expected
+            );
+        }
+    }
+
+    /// Test that the validity predicate cannot be updated while some tokens
+    /// are locked.
+    #[test]
+    fn test_signed_vp_update_with_locked_tokens_rejected() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+        tx_env.init_parameters(None, None, None, None);
+
+        let vp_owner = address::testing::established_address_1();
+        let keypair = key::testing::keypair_1();
+        let public_key = keypair.ref_to();
+        let vp_code = TestWasms::VpAlwaysTrue.read_bytes();
+        let vp_hash = sha256(&vp_code);
+        // for the update
+        tx_env.store_wasm_code(vp_code);
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner]);
+        tx_env.init_account_storage(&vp_owner, vec![public_key], 1);
+        account::write_vesting_schedule(
+            &mut tx_env.state,
+            &vp_owner,
+            schedule(token::Amount::from_uint(1, 0).unwrap()),
+        )
+        .unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+            // Update VP in a transaction
+            tx::ctx()
+                .update_validity_predicate(address, vp_hash, &None)
+                .unwrap();
+        });
+
+        assert!(!validate_signed(vp_owner, keypair));
+    }
+}